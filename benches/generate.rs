@@ -0,0 +1,99 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use swagger_gen::v2::{
+    codegen::{
+        backend::{rust, CodegenBackend},
+        CodeGenerator,
+    },
+    Swagger,
+};
+
+use std::fmt::Write;
+
+/// Builds a spec with `n` object definitions, each with scalar, array, map, enum, nested and
+/// referencing properties, roughly the shape of large specs like Kubernetes'.
+fn large_spec(n: usize) -> String {
+    let mut spec = String::from("swagger: \"2.0\"\ndefinitions:\n");
+    for i in 0..n {
+        let next = (i + 1) % n;
+        write!(
+            spec,
+            r##"
+  Model{i}:
+    type: object
+    description: Model number {i} with a reasonably long description that needs escaping.
+    required: [Id, Items]
+    properties:
+      Id:
+        type: string
+      Count:
+        type: integer
+        format: int64
+      Ratio:
+        type: number
+        format: double
+      Created:
+        type: string
+        format: date-time
+      Items:
+        type: array
+        items:
+          type: string
+      Labels:
+        type: object
+        additionalProperties:
+          type: string
+      State:
+        type: string
+        enum: [created, running, exited]
+      Nested:
+        type: object
+        properties:
+          Flag:
+            type: boolean
+          Limit:
+            type: integer
+            default: 10
+      Next:
+        $ref: "#/definitions/Model{next}"
+  Model{i}List:
+    type: array
+    items:
+      $ref: "#/definitions/Model{i}"
+"##
+        )
+        .unwrap();
+    }
+    spec
+}
+
+fn generate(spec: &str, parallel: bool) -> String {
+    let swagger: Swagger<rust::Type> = serde_yaml::from_str(spec).unwrap();
+    let opts = rust::CodegenOptions {
+        parallel,
+        ..Default::default()
+    };
+    CodeGenerator::new(swagger, Box::new(rust::Codegen::new(opts)))
+        .generate_models_to_string()
+        .unwrap()
+}
+
+fn bench_generate(c: &mut Criterion) {
+    let spec = large_spec(500);
+    let mut group = c.benchmark_group("generate_rust_models");
+    group.sample_size(10);
+    group.bench_function("serial", |b| b.iter(|| generate(&spec, false)));
+    group.bench_function("parallel", |b| b.iter(|| generate(&spec, true)));
+    group.finish();
+}
+
+fn bench_prototypes(c: &mut Criterion) {
+    let swagger: Swagger<rust::Type> = serde_yaml::from_str(&large_spec(500)).unwrap();
+    let backend = rust::Codegen::default();
+    let mut group = c.benchmark_group("prototypes");
+    group.sample_size(10);
+    group.bench_function("rust", |b| b.iter(|| backend.prototypes(&swagger)));
+    group.finish();
+}
+
+criterion_group!(benches, bench_generate, bench_prototypes);
+criterion_main!(benches);
@@ -1,26 +1,92 @@
 use swagger_gen::v2::{
     codegen::{
-        backend::{python, rust},
-        CodeGenerator,
+        self,
+        backend::{self, python, rust, typescript},
+        diff, operations, CodeGenerator,
     },
+    openapi3,
+    validation::{Diagnostic, Severity},
     Swagger,
 };
 
-use clap::{Parser, Subcommand};
-use std::fmt;
+use clap::{
+    error::ErrorKind, parser::ValueSource, CommandFactory, FromArgMatches, Parser, Subcommand,
+};
+use log::LevelFilter;
+use serde_yaml::Value;
+use std::{collections::HashMap, fmt, io::Write};
 
 #[derive(Parser)]
 struct SwaggerGen {
+    /// Disable log messages, spec diagnostics are still printed.
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    quiet: bool,
+    /// Print more log messages, can be repeated up to `-vvvv`. Without this flag or `--quiet`
+    /// the level is taken from `RUST_LOG`.
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
     #[clap(subcommand)]
     subcommand: Command,
 }
 
+impl SwaggerGen {
+    fn init_logger(&self) {
+        let mut builder = pretty_env_logger::formatted_builder();
+        let level = match (self.quiet, self.verbose) {
+            (true, _) => Some(LevelFilter::Off),
+            (false, 0) => None,
+            (false, 1) => Some(LevelFilter::Warn),
+            (false, 2) => Some(LevelFilter::Info),
+            (false, 3) => Some(LevelFilter::Debug),
+            (false, _) => Some(LevelFilter::Trace),
+        };
+        match level {
+            Some(level) => {
+                builder.filter_level(level);
+            }
+            None => {
+                if let Ok(filters) = std::env::var("RUST_LOG") {
+                    builder.parse_filters(&filters);
+                }
+            }
+        }
+        builder.init();
+    }
+}
+
 #[derive(Subcommand)]
 enum Command {
     Generate {
         #[clap(subcommand)]
         target: GenerateTarget,
     },
+    /// Check a spec for problems without generating anything, exits with a non-zero status if
+    /// an error is found.
+    Validate {
+        /// Language whose type names are checked for collisions.
+        #[arg(short, long, default_value_t = Language::Rust)]
+        language: Language,
+        /// Exit with a non-zero status on warnings too.
+        #[arg(long)]
+        strict: bool,
+        swagger_location: std::path::PathBuf,
+    },
+    /// Print counts and size metrics of a spec, like the number of definitions and operations
+    /// or its largest schemas.
+    Stats {
+        /// Print JSON instead of text.
+        #[arg(long)]
+        json: bool,
+        swagger_location: std::path::PathBuf,
+    },
+    /// Print a spec back with sorted keys, keeping vendor extensions and other fields that are
+    /// not used for generating code.
+    Normalize {
+        /// Print JSON instead of YAML.
+        #[arg(long)]
+        json: bool,
+        swagger_location: std::path::PathBuf,
+    },
 }
 
 #[derive(Subcommand)]
@@ -28,6 +94,222 @@ enum GenerateTarget {
     Models {
         #[arg(short, long, default_value_t = Language::Rust)]
         language: Language,
+        /// Treat spec warnings (duplicate definitions, conflicting titles) as fatal errors.
+        #[arg(long)]
+        strict: bool,
+        /// Add a flattened map to every generated struct that captures fields missing from the spec.
+        #[arg(long)]
+        capture_unknown: bool,
+        /// Emit a `api_meta` module with tag and operation tables (Rust only).
+        #[arg(long)]
+        api_meta: bool,
+        /// Emit an `operations` module with a `(method, path)` constant for every operation
+        /// (Rust only).
+        #[arg(long)]
+        emit_openapi_operation_consts: bool,
+        /// Emit a test module round-tripping the JSON examples of responses through their
+        /// models (Rust only).
+        #[arg(long)]
+        emit_examples_as_tests: bool,
+        /// Emit a test module checking that every variant of every string enum survives a JSON
+        /// round trip and serializes to its `as_ref` value (Rust only).
+        #[arg(long)]
+        enum_roundtrip_tests: bool,
+        /// Reflow descriptions in doc comments to this many columns.
+        #[arg(long, value_name = "N")]
+        doc_width: Option<usize>,
+        /// Trim trailing whitespace and dedent descriptions in doc comments.
+        #[arg(long, value_name = "BOOL", default_value_t = true, action = clap::ArgAction::Set)]
+        trim_description_whitespace: bool,
+        /// Mark generated structs and enums `#[non_exhaustive]` and add constructors (Rust only).
+        #[arg(long)]
+        non_exhaustive: bool,
+        /// Give every struct a `new` constructor taking its required fields (Rust only).
+        #[arg(long)]
+        with_constructors: bool,
+        /// Leave `Clone` out of the derives of generated structs, for models of large payloads.
+        /// Code cloning the models won't compile anymore. The `x-no-clone` extension does the
+        /// same for single definitions (Rust only).
+        #[arg(long)]
+        no_clone: bool,
+        /// How float fields are compared (Rust only). `ordered` wraps them in
+        /// `ordered_float::OrderedFloat` so models can derive `Eq`, `Hash` and `Ord`.
+        #[arg(long, value_name = "MODE", default_value = "partial")]
+        float_eq: FloatEq,
+        /// How structs with float fields asking for `Hash` with `x-rust-derive` implement it
+        /// (Rust only). `bits` implements `PartialEq`, `Eq` and `Hash` by hand, treating `0.0` and
+        /// `-0.0` as equal, and all `NaN`s as equal.
+        #[arg(long, value_name = "MODE", default_value = "derive")]
+        float_hash: FloatHash,
+        /// Type of integers with a `bigint` format (Rust only), `num-bigint` generates
+        /// `num_bigint::BigInt` fields.
+        #[arg(long, value_name = "TYPE", default_value = "i128")]
+        big_int: BigInt,
+        /// Render models on multiple threads (Rust only). Off by default, the rendering is rarely
+        /// slow enough for the threads to pay off.
+        #[arg(long, value_name = "BOOL", default_value_t = false, action = clap::ArgAction::Set)]
+        parallel: bool,
+        /// Merge identical inline enums even if their descriptions differ.
+        #[arg(long)]
+        dedup_ignore_docs: bool,
+        /// Generate inline objects of the same structure, apart from descriptions and titles, as
+        /// a single model and type aliases of it.
+        #[arg(long)]
+        dedupe_identical_schemas: bool,
+        /// Group models into nested modules following the `x-go-package` of their
+        /// definitions (Rust only).
+        #[arg(long)]
+        use_go_packages: bool,
+        /// Generate a schema of a single `allOf` reference plus inline properties as a struct
+        /// with a `#[serde(flatten)]` field of the referenced model (Rust only).
+        #[arg(long)]
+        flatten_all_of: bool,
+        /// Generate definitions of a plain string, number or boolean as a newtype struct
+        /// instead of a type alias (Rust only).
+        #[arg(long)]
+        newtypes: bool,
+        /// Generate definitions of a string with a `maxLength` as a newtype checking the length
+        /// when it is constructed with `TryFrom` or deserialized (Rust only).
+        #[arg(long)]
+        bounded_strings: bool,
+        /// Generate models borrowing strings from the input with `Cow<'a, str>` fields, models
+        /// with such fields get a lifetime parameter (Rust only).
+        #[arg(long)]
+        borrowed: bool,
+        /// Use a single `#[serde(rename_all = "...")]` on enums whose values follow one case
+        /// convention instead of renaming each variant (Rust only).
+        #[arg(long)]
+        enum_rename_all: bool,
+        /// Put `#[serde(rename = "...")]` with the key of the spec on every field, even when the
+        /// field is named like the key (Rust only).
+        #[arg(long)]
+        always_rename: bool,
+        /// Give structs getters of optional fields, iterators over array fields and lookups of
+        /// map fields (Rust only).
+        #[arg(long)]
+        accessors: bool,
+        /// Implement `TryFrom<serde_json::Value>` for structs and give them a `to_value` method
+        /// (Rust only, not with `--wire-format any`).
+        #[arg(long)]
+        value_conversions: bool,
+        /// Give every struct and enum a `SCHEMA_HASH` of its fields or variants and write a
+        /// `MODELS_HASH` combining them, to detect changes of the generated models (Rust only).
+        #[arg(long)]
+        emit_schema_hashes: bool,
+        /// Group the models into definitions, responses and path-derived models, each section
+        /// starting with a banner comment (Rust only, not with modules).
+        #[arg(long)]
+        split_definitions_responses_paths: bool,
+        /// What to generate for a scalar definition named like the Rust type it maps to, like
+        /// a `String` of a string: `skip` it, a `newtype` named `String_` or a type alias
+        /// `rename`d with a numeric suffix (Rust only).
+        #[arg(long, value_name = "POLICY", default_value = "skip")]
+        primitive_names: PrimitiveNames,
+        /// Type of string fields: `string`, `box-str` for `Box<str>` or `cow` for
+        /// `Cow<'a, str>` borrowing from the input like `--borrowed` (Rust only).
+        #[arg(long, value_name = "TYPE", default_value = "string")]
+        string_type: StringType,
+        /// Order of the fields of generated models, `alpha` sorts them by name and `spec` keeps
+        /// the order properties are declared in. Python still puts required fields first.
+        #[arg(long, value_name = "ORDER", default_value = "alpha")]
+        field_order: FieldOrder,
+        /// How the variants of enums are named after their values (Rust and Python), the
+        /// convention of the language if not given. `verbatim` keeps values that are valid
+        /// identifiers as they are.
+        #[arg(long, value_name = "CASE")]
+        enum_variant_case: Option<EnumVariantCase>,
+        /// Derive `PartialOrd`/`Ord` for enums ordering variants like the values of the spec,
+        /// with an `ordinal` method (Rust only).
+        #[arg(long)]
+        ordered_enums: bool,
+        /// Only generate models of this kind, references to the others use the types they are
+        /// made of. Can be repeated.
+        #[arg(long, value_name = "KIND")]
+        only: Vec<ModelKind>,
+        /// Rust edition of the generated code, its keywords are escaped in field names
+        /// (Rust only).
+        #[arg(long, value_name = "EDITION", default_value = "2021")]
+        rust_edition: RustEdition,
+        /// Deserialize boolean fields from `"true"`/`"false"`/`"1"`/`"0"` strings and numbers
+        /// too, not only properties marked with `x-lenient-bool` (Rust only).
+        #[arg(long)]
+        lenient_bools: bool,
+        /// Generate optional `x-nullable` properties as `Option<Option<T>>` telling an explicit
+        /// `null` apart from a missing field, like `x-explicit-null` does for single properties
+        /// (Rust only, needs the `serde-with` feature).
+        #[arg(long)]
+        double_option: bool,
+        /// Let required array and map fields without a default be missing from payloads and
+        /// deserialize them as empty (Rust only). Deprecated, the default becomes `false` in
+        /// the next release.
+        #[arg(long, value_name = "BOOL", default_value_t = true, action = clap::ArgAction::Set)]
+        lenient_required_collections: bool,
+        /// Leave query parameters equal to their spec default out of the query strings built by
+        /// the models of query parameters (Rust only).
+        #[arg(long, value_name = "BOOL", default_value_t = true, action = clap::ArgAction::Set)]
+        omit_default_params: bool,
+        /// Generate definitions with `additionalProperties` of untyped values as a newtype of a
+        /// `serde_json::Map` with getters of their documented properties (Rust only).
+        #[arg(long)]
+        dynamic_maps: bool,
+        /// Inline definitions used by a single property with at most this many properties into
+        /// the model using them, scalar definitions are replaced by their type. `0` disables
+        /// inlining (Rust and Python).
+        #[arg(long, value_name = "N", default_value_t = 0)]
+        inline_threshold: usize,
+        /// How models of body parameters are named, `operation` names them
+        /// `{OperationId}{ParamName}Param` and `schema` after the definition they reference or
+        /// their title, falling back to `operation` (Rust and Python).
+        #[arg(long, value_name = "NAMES", default_value = "operation")]
+        body_names: BodyNames,
+        /// Generate a `{OperationId}Params` model of the query parameters of every operation
+        /// (Rust only).
+        #[arg(long)]
+        query_params_models: bool,
+        /// Give every inline object with the properties of `SIGNATURE` the model `NAME`, so
+        /// that they share a single model. The signature lists the sorted `name:type` pairs of
+        /// the properties separated by commas, like `code:integer,message:string`. Can be
+        /// repeated (Rust and Python).
+        #[arg(long, value_name = "SIGNATURE=NAME", value_parser = parse_common_inline_name)]
+        common_inline_name: Vec<(String, String)>,
+        /// Skip models of schemas whose inline objects, arrays and maps are nested deeper than
+        /// this, reporting an error instead (Rust and Python).
+        #[arg(long, value_name = "N", default_value_t = codegen::DEFAULT_MAX_DEPTH)]
+        max_depth: usize,
+        /// Move inline objects with at most this many scalar properties into an `inline`
+        /// module, `0` keeps them at the top level (Rust only).
+        #[arg(long, value_name = "N", default_value_t = 0)]
+        inline_module_threshold: usize,
+        /// Serde format the models are used with (Rust only). `any` leaves out helpers built
+        /// around JSON conventions, `msgpack` generates `serde_bytes::ByteBuf` for binary
+        /// strings.
+        #[arg(long, value_name = "FORMAT", default_value = "json")]
+        wire_format: WireFormat,
+        /// Also write a JSON manifest of the generated models to `PATH`, with the spec name and
+        /// kind of every model and the models that were merged or skipped.
+        #[arg(long, value_name = "PATH")]
+        manifest: Option<std::path::PathBuf>,
+        /// Compare the models with the ones in `FILE` instead of printing them, printing a diff
+        /// and exiting with a non-zero status if they differ.
+        #[arg(long, value_name = "FILE")]
+        check: Option<std::path::PathBuf>,
+        swagger_location: std::path::PathBuf,
+    },
+    /// Emit a table describing every operation and the models generated for it.
+    Operations {
+        /// Write the table as JSON instead of Rust code.
+        #[arg(long)]
+        json: bool,
+        /// Also emit a `paths` module with a constant and a builder function for the path of
+        /// every operation.
+        #[arg(long, conflicts_with = "json")]
+        paths: bool,
+        /// How models of body parameters are named, the same as for `generate models`.
+        #[arg(long, value_name = "NAMES", default_value = "operation")]
+        body_names: BodyNames,
+        /// List the models of query parameters, generated with `--query-params-models`.
+        #[arg(long)]
+        query_params_models: bool,
         swagger_location: std::path::PathBuf,
     },
 }
@@ -36,6 +318,193 @@ enum GenerateTarget {
 enum Language {
     Rust,
     Python,
+    #[value(name = "typescript")]
+    TypeScript,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy)]
+enum FloatEq {
+    Partial,
+    #[cfg(feature = "ordered-float")]
+    Ordered,
+}
+
+impl From<FloatEq> for rust::FloatEq {
+    fn from(float_eq: FloatEq) -> Self {
+        match float_eq {
+            FloatEq::Partial => rust::FloatEq::Partial,
+            #[cfg(feature = "ordered-float")]
+            FloatEq::Ordered => rust::FloatEq::Ordered,
+        }
+    }
+}
+
+#[derive(clap::ValueEnum, Clone, Copy)]
+enum FloatHash {
+    Derive,
+    Bits,
+}
+
+impl From<FloatHash> for rust::FloatHash {
+    fn from(float_hash: FloatHash) -> Self {
+        match float_hash {
+            FloatHash::Derive => rust::FloatHash::Derive,
+            FloatHash::Bits => rust::FloatHash::Bits,
+        }
+    }
+}
+
+#[derive(clap::ValueEnum, Clone, Copy)]
+enum BigInt {
+    I128,
+    #[cfg(feature = "num-bigint")]
+    NumBigint,
+}
+
+impl From<BigInt> for rust::BigInt {
+    fn from(big_int: BigInt) -> Self {
+        match big_int {
+            BigInt::I128 => rust::BigInt::I128,
+            #[cfg(feature = "num-bigint")]
+            BigInt::NumBigint => rust::BigInt::NumBigInt,
+        }
+    }
+}
+
+#[derive(clap::ValueEnum, Clone, Copy)]
+enum RustEdition {
+    #[value(name = "2018")]
+    E2018,
+    #[value(name = "2021")]
+    E2021,
+    #[value(name = "2024")]
+    E2024,
+}
+
+impl From<RustEdition> for rust::Edition {
+    fn from(edition: RustEdition) -> Self {
+        match edition {
+            RustEdition::E2018 => rust::Edition::E2018,
+            RustEdition::E2021 => rust::Edition::E2021,
+            RustEdition::E2024 => rust::Edition::E2024,
+        }
+    }
+}
+
+#[derive(clap::ValueEnum, Clone, Copy)]
+enum WireFormat {
+    Json,
+    #[value(name = "msgpack")]
+    MsgPack,
+    Any,
+}
+
+impl From<WireFormat> for rust::WireFormat {
+    fn from(wire_format: WireFormat) -> Self {
+        match wire_format {
+            WireFormat::Json => rust::WireFormat::Json,
+            WireFormat::MsgPack => rust::WireFormat::MsgPack,
+            WireFormat::Any => rust::WireFormat::Any,
+        }
+    }
+}
+
+#[derive(clap::ValueEnum, Clone, Copy)]
+enum FieldOrder {
+    Alpha,
+    Spec,
+}
+
+#[derive(clap::ValueEnum, Clone, Copy)]
+enum PrimitiveNames {
+    Skip,
+    Newtype,
+    Rename,
+}
+
+impl From<PrimitiveNames> for rust::PrimitiveNames {
+    fn from(names: PrimitiveNames) -> Self {
+        match names {
+            PrimitiveNames::Skip => rust::PrimitiveNames::Skip,
+            PrimitiveNames::Newtype => rust::PrimitiveNames::Newtype,
+            PrimitiveNames::Rename => rust::PrimitiveNames::Rename,
+        }
+    }
+}
+
+#[derive(clap::ValueEnum, Clone, Copy)]
+enum StringType {
+    String,
+    BoxStr,
+    Cow,
+}
+
+impl From<StringType> for rust::StringType {
+    fn from(ty: StringType) -> Self {
+        match ty {
+            StringType::String => rust::StringType::String,
+            StringType::BoxStr => rust::StringType::BoxStr,
+            StringType::Cow => rust::StringType::Cow,
+        }
+    }
+}
+
+#[derive(clap::ValueEnum, Clone, Copy)]
+enum BodyNames {
+    Operation,
+    Schema,
+}
+
+impl From<BodyNames> for codegen::BodyNames {
+    fn from(body_names: BodyNames) -> Self {
+        match body_names {
+            BodyNames::Operation => codegen::BodyNames::Operation,
+            BodyNames::Schema => codegen::BodyNames::Schema,
+        }
+    }
+}
+
+#[derive(clap::ValueEnum, Clone, Copy)]
+enum EnumVariantCase {
+    UpperCamel,
+    ScreamingSnake,
+    Verbatim,
+}
+
+impl From<EnumVariantCase> for backend::EnumVariantCase {
+    fn from(case: EnumVariantCase) -> Self {
+        match case {
+            EnumVariantCase::UpperCamel => backend::EnumVariantCase::UpperCamel,
+            EnumVariantCase::ScreamingSnake => backend::EnumVariantCase::ScreamingSnake,
+            EnumVariantCase::Verbatim => backend::EnumVariantCase::Verbatim,
+        }
+    }
+}
+
+#[derive(clap::ValueEnum, Clone, Copy)]
+enum ModelKind {
+    Enums,
+    Structs,
+    Aliases,
+}
+
+impl From<ModelKind> for codegen::ModelKind {
+    fn from(kind: ModelKind) -> Self {
+        match kind {
+            ModelKind::Enums => codegen::ModelKind::Enum,
+            ModelKind::Structs => codegen::ModelKind::Struct,
+            ModelKind::Aliases => codegen::ModelKind::Alias,
+        }
+    }
+}
+
+impl From<FieldOrder> for backend::FieldOrder {
+    fn from(field_order: FieldOrder) -> Self {
+        match field_order {
+            FieldOrder::Alpha => backend::FieldOrder::Alpha,
+            FieldOrder::Spec => backend::FieldOrder::Spec,
+        }
+    }
 }
 
 impl AsRef<str> for Language {
@@ -43,6 +512,7 @@ impl AsRef<str> for Language {
         match self {
             Language::Rust => "rust",
             Language::Python => "python",
+            Language::TypeScript => "typescript",
         }
     }
 }
@@ -73,47 +543,398 @@ impl DataFormat {
         data: &[u8],
     ) -> Result<T, Box<dyn std::error::Error>> {
         match self {
-            DataFormat::Json => Ok(serde_json::from_slice::<T>(&data)?),
-            DataFormat::Yaml => Ok(serde_yaml::from_slice::<T>(&data)?),
+            DataFormat::Json => Ok(serde_json::from_slice::<T>(data)?),
+            DataFormat::Yaml => Ok(serde_yaml::from_slice::<T>(data)?),
+        }
+    }
+}
+
+/// Parses a `SIGNATURE=NAME` pair of `--common-inline-name`.
+fn parse_common_inline_name(arg: &str) -> Result<(String, String), String> {
+    match arg.rsplit_once('=') {
+        Some((signature, name)) if !signature.is_empty() && !name.is_empty() => {
+            Ok((signature.to_string(), name.to_string()))
         }
+        _ => Err(format!("expected `SIGNATURE=NAME`, got `{arg}`")),
+    }
+}
+
+fn read_spec(location: &std::path::Path) -> (DataFormat, Vec<u8>) {
+    let data_format = location
+        .extension()
+        .and_then(|ext| DataFormat::from_extension(&ext.to_string_lossy()))
+        .unwrap_or(DataFormat::Yaml);
+    (data_format, std::fs::read(location).unwrap())
+}
+
+/// Parses a spec, converting it first if it is an OpenAPI 3 document. Returns the spec along
+/// with the notes of the conversion.
+fn try_load_spec<T: swagger_gen::v2::Type>(
+    data_format: DataFormat,
+    data: &[u8],
+) -> Result<(Swagger<T>, Vec<Diagnostic>), Box<dyn std::error::Error>> {
+    let mut doc: Value = data_format.deserialize_from_slice(data)?;
+    let mut diagnostics = vec![];
+    if openapi3::is_openapi3(&doc) {
+        (doc, diagnostics) = openapi3::convert(doc);
+    }
+    Ok((serde_yaml::from_value(doc)?, diagnostics))
+}
+
+fn load_spec<T: swagger_gen::v2::Type>(data_format: DataFormat, data: &[u8]) -> Swagger<T> {
+    let (swagger, diagnostics) = try_load_spec(data_format, data).unwrap();
+    for diagnostic in &diagnostics {
+        eprintln!("{diagnostic}");
+    }
+    swagger
+}
+
+/// Prints every problem of the spec followed by a summary and exits with status 1 if there are
+/// errors, or warnings in strict mode.
+fn validate<T: swagger_gen::v2::Type>(data_format: DataFormat, data: &[u8], strict: bool) {
+    let mut diagnostics = match try_load_spec::<T>(data_format, data) {
+        Ok((swagger, mut diagnostics)) => {
+            diagnostics.extend(swagger.validate());
+            diagnostics
+        }
+        Err(e) => vec![Diagnostic::error(
+            format!("failed to parse spec: {e}"),
+            vec![],
+        )],
+    };
+    diagnostics.sort_by_key(|diagnostic| std::cmp::Reverse(diagnostic.severity));
+    for diagnostic in &diagnostics {
+        println!("{diagnostic}");
+    }
+    let count = |severity| {
+        diagnostics
+            .iter()
+            .filter(|diagnostic| diagnostic.severity == severity)
+            .count()
+    };
+    let (errors, warnings) = (count(Severity::Error), count(Severity::Warning));
+    println!("{errors} error(s), {warnings} warning(s)");
+    if errors > 0 || (strict && warnings > 0) {
+        std::process::exit(1);
+    }
+}
+
+fn check_spec<T: swagger_gen::v2::Type>(codegen: &CodeGenerator<T>, strict: bool) {
+    let diagnostics = codegen.diagnostics();
+    for diagnostic in &diagnostics {
+        eprintln!("{diagnostic}");
+    }
+    if strict
+        && diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.severity > Severity::Info)
+    {
+        eprintln!("aborting due to spec warnings in strict mode");
+        std::process::exit(1);
+    }
+}
+
+/// Prints the models or, with `check`, compares them with that file, printing a diff and
+/// exiting with status 1 if it is out of date.
+fn write_models<T: swagger_gen::v2::Type>(
+    codegen: &mut CodeGenerator<T>,
+    check: Option<&std::path::Path>,
+) {
+    let Some(path) = check else {
+        let mut writer = Box::new(std::io::stdout()) as Box<dyn std::io::Write>;
+        codegen.generate_models(&mut writer).unwrap();
+        return;
+    };
+    let existing = match std::fs::read_to_string(path) {
+        Ok(existing) => existing,
+        Err(e) => {
+            eprintln!("failed to read `{}`: {e}", path.display());
+            std::process::exit(1);
+        }
+    };
+    let generated = codegen.generate_models_to_string().unwrap();
+    let name = path.display().to_string();
+    if let Some(diff) = diff::unified_diff(&existing, &generated, &name, "generated") {
+        print!("{diff}");
+        eprintln!("`{name}` is not up to date with the spec");
+        std::process::exit(1);
+    }
+}
+
+fn write_manifest<T: swagger_gen::v2::Type>(
+    codegen: &CodeGenerator<T>,
+    path: Option<&std::path::Path>,
+) {
+    let Some(path) = path else {
+        return;
+    };
+    let written =
+        std::fs::File::create(path).and_then(|mut file| codegen.manifest().write_json(&mut file));
+    if let Err(e) = written {
+        eprintln!("failed to write manifest `{}`: {e}", path.display());
+        std::process::exit(1);
+    }
+}
+
+/// Exits with a usage error if `generate models` was given a flag documented as `(Rust only)`,
+/// or `(Rust and Python)` for TypeScript, for a language it doesn't apply to.
+fn check_language_flags(matches: &clap::ArgMatches, language: &Language) {
+    let unsupported: &[&str] = match language {
+        Language::Rust => return,
+        Language::Python => &["(Rust only)"],
+        Language::TypeScript => &["(Rust only)", "(Rust and Python)"],
+    };
+    let mut command = SwaggerGen::command();
+    let Some(matches) = matches
+        .subcommand_matches("generate")
+        .and_then(|matches| matches.subcommand_matches("models"))
+    else {
+        return;
+    };
+    let models = command
+        .find_subcommand("generate")
+        .and_then(|generate| generate.find_subcommand("models"))
+        .unwrap();
+    let flag = models.get_arguments().find_map(|arg| {
+        let help = [arg.get_help(), arg.get_long_help()]
+            .into_iter()
+            .flatten()
+            .map(|help| help.to_string())
+            .collect::<String>();
+        let id = arg.get_id().as_str();
+        (unsupported.iter().any(|note| help.contains(note))
+            && matches.value_source(id) == Some(ValueSource::CommandLine))
+        .then(|| arg.get_long().unwrap_or(id).to_string())
+    });
+    if let Some(flag) = flag {
+        command
+            .error(
+                ErrorKind::ArgumentConflict,
+                format!("`--{flag}` is not supported with `--language {language}`"),
+            )
+            .exit();
     }
 }
 
 fn main() {
-    let gen = SwaggerGen::parse();
-    pretty_env_logger::init();
+    let matches = SwaggerGen::command().get_matches();
+    let gen = SwaggerGen::from_arg_matches(&matches).unwrap_or_else(|err| err.exit());
+    gen.init_logger();
 
     match gen.subcommand {
+        Command::Validate {
+            language,
+            strict,
+            swagger_location,
+        } => {
+            let (data_format, data) = read_spec(&swagger_location);
+            match language {
+                Language::Rust => validate::<rust::Type>(data_format, &data, strict),
+                Language::Python => validate::<python::Type>(data_format, &data, strict),
+                Language::TypeScript => validate::<typescript::Type>(data_format, &data, strict),
+            }
+        }
+        Command::Stats {
+            json,
+            swagger_location,
+        } => {
+            let (data_format, data) = read_spec(&swagger_location);
+            let swagger = load_spec::<rust::Type>(data_format, &data);
+            let stats = swagger.stats();
+            if json {
+                println!("{}", serde_json::to_string_pretty(&stats).unwrap());
+            } else {
+                print!("{stats}");
+            }
+        }
+        Command::Normalize {
+            json,
+            swagger_location,
+        } => {
+            let (data_format, data) = read_spec(&swagger_location);
+            let swagger = load_spec::<rust::Type>(data_format, &data);
+            if json {
+                println!("{}", swagger.to_json().unwrap());
+            } else {
+                print!("{}", swagger.to_yaml().unwrap());
+            }
+        }
         Command::Generate { target } => match target {
             GenerateTarget::Models {
                 swagger_location,
                 language,
+                strict,
+                capture_unknown,
+                api_meta,
+                emit_openapi_operation_consts,
+                emit_examples_as_tests,
+                enum_roundtrip_tests,
+                doc_width,
+                trim_description_whitespace,
+                non_exhaustive,
+                with_constructors,
+                no_clone,
+                float_eq,
+                float_hash,
+                big_int,
+                parallel,
+                dedup_ignore_docs,
+                dedupe_identical_schemas,
+                use_go_packages,
+                flatten_all_of,
+                newtypes,
+                bounded_strings,
+                borrowed,
+                enum_rename_all,
+                always_rename,
+                accessors,
+                value_conversions,
+                emit_schema_hashes,
+                split_definitions_responses_paths,
+                primitive_names,
+                string_type,
+                field_order,
+                enum_variant_case,
+                ordered_enums,
+                only,
+                rust_edition,
+                lenient_bools,
+                double_option,
+                lenient_required_collections,
+                omit_default_params,
+                dynamic_maps,
+                inline_threshold,
+                body_names,
+                query_params_models,
+                common_inline_name,
+                max_depth,
+                inline_module_threshold,
+                wire_format,
+                manifest,
+                check,
             } => {
-                let data_format = swagger_location
-                    .extension()
-                    .and_then(|ext| DataFormat::from_extension(&ext.to_string_lossy()))
-                    .unwrap_or(DataFormat::Yaml);
-                let data = std::fs::read(swagger_location).unwrap();
+                check_language_flags(&matches, &language);
+                let (data_format, data) = read_spec(&swagger_location);
+                let common_inline_names: HashMap<_, _> = common_inline_name.into_iter().collect();
 
                 match language {
                     Language::Rust => {
-                        let swagger: Swagger<rust::Type> =
-                            data_format.deserialize_from_slice(&data).unwrap();
-                        let backend = Box::new(rust::Codegen::default());
+                        let swagger: Swagger<rust::Type> = load_spec(data_format, &data);
+                        let opts = rust::CodegenOptions {
+                            capture_unknown,
+                            api_meta,
+                            operation_consts: emit_openapi_operation_consts,
+                            examples_as_tests: emit_examples_as_tests,
+                            enum_round_trip_tests: enum_roundtrip_tests,
+                            doc_width,
+                            trim_description_whitespace,
+                            non_exhaustive,
+                            with_constructors,
+                            no_clone,
+                            float_eq: float_eq.into(),
+                            float_hash: float_hash.into(),
+                            big_int: big_int.into(),
+                            parallel,
+                            dedup_ignore_docs,
+                            dedupe_identical_schemas,
+                            use_go_packages,
+                            flatten_all_of,
+                            newtypes,
+                            bounded_strings,
+                            borrowed,
+                            enum_rename_all,
+                            always_rename,
+                            accessors,
+                            value_conversions,
+                            schema_hashes: emit_schema_hashes,
+                            section_banners: split_definitions_responses_paths,
+                            primitive_names: primitive_names.into(),
+                            string_type: string_type.into(),
+                            field_order: field_order.into(),
+                            enum_variant_case: enum_variant_case
+                                .map(Into::into)
+                                .unwrap_or_default(),
+                            ordered_enums,
+                            only: only.into_iter().map(Into::into).collect(),
+                            edition: rust_edition.into(),
+                            lenient_bools,
+                            double_option,
+                            lenient_required_collections,
+                            omit_default_params,
+                            dynamic_maps,
+                            inline_threshold,
+                            body_names: body_names.into(),
+                            query_params_models,
+                            common_inline_names: common_inline_names.clone(),
+                            max_depth,
+                            inline_module_threshold,
+                            wire_format: wire_format.into(),
+                        };
+                        let backend = Box::new(rust::Codegen::new(opts));
                         let mut codegen = CodeGenerator::new(swagger, backend);
-                        let mut writer = Box::new(std::io::stdout()) as Box<dyn std::io::Write>;
-                        codegen.generate_models(&mut writer).unwrap();
+                        check_spec(&codegen, strict);
+                        write_models(&mut codegen, check.as_deref());
+                        write_manifest(&codegen, manifest.as_deref());
                     }
                     Language::Python => {
-                        let swagger: Swagger<python::Type> =
-                            data_format.deserialize_from_slice(&data).unwrap();
-                        let backend = Box::new(python::Codegen::default());
+                        let swagger: Swagger<python::Type> = load_spec(data_format, &data);
+                        let opts = python::CodegenOptions {
+                            doc_width,
+                            trim_description_whitespace,
+                            dedup_ignore_docs,
+                            dedupe_identical_schemas,
+                            field_order: field_order.into(),
+                            enum_variant_case: enum_variant_case
+                                .map(Into::into)
+                                .unwrap_or_default(),
+                            inline_threshold,
+                            body_names: body_names.into(),
+                            common_inline_names,
+                            max_depth,
+                            only: only.into_iter().map(Into::into).collect(),
+                        };
+                        let backend = Box::new(python::Codegen::new(opts));
+                        let mut codegen = CodeGenerator::new(swagger, backend);
+                        check_spec(&codegen, strict);
+                        write_models(&mut codegen, check.as_deref());
+                        write_manifest(&codegen, manifest.as_deref());
+                    }
+                    Language::TypeScript => {
+                        let swagger: Swagger<typescript::Type> = load_spec(data_format, &data);
+                        let opts = typescript::CodegenOptions {
+                            field_order: field_order.into(),
+                            only: only.into_iter().map(Into::into).collect(),
+                        };
+                        let backend = Box::new(typescript::Codegen::new(opts));
                         let mut codegen = CodeGenerator::new(swagger, backend);
-                        let mut writer = Box::new(std::io::stdout()) as Box<dyn std::io::Write>;
-                        codegen.generate_models(&mut writer).unwrap();
+                        check_spec(&codegen, strict);
+                        write_models(&mut codegen, check.as_deref());
+                        write_manifest(&codegen, manifest.as_deref());
                     }
                 };
             }
+            GenerateTarget::Operations {
+                json,
+                paths,
+                body_names,
+                query_params_models,
+                swagger_location,
+            } => {
+                let (data_format, data) = read_spec(&swagger_location);
+                let swagger: Swagger<rust::Type> = load_spec(data_format, &data);
+                let operations =
+                    operations::operation_defs(&swagger, body_names.into(), query_params_models);
+                let mut writer = std::io::stdout();
+                if json {
+                    operations::write_json(&operations, &mut writer).unwrap();
+                } else {
+                    operations::write_rust(&operations, &mut writer).unwrap();
+                }
+                if paths {
+                    writeln!(writer).unwrap();
+                    operations::write_rust_paths(&swagger, &mut writer).unwrap();
+                }
+            }
         },
     }
 }
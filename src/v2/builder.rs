@@ -0,0 +1,332 @@
+//! Builds specs in code instead of parsing them. This is the supported way to unit test a
+//! `CodegenBackend`, a spec only needs the definitions and responses a test is about:
+//!
+//! ```
+//! use swagger_gen::v2::{
+//!     builder::{SchemaBuilder, SwaggerBuilder},
+//!     codegen::{backend::rust, CodeGenerator},
+//! };
+//!
+//! let swagger = SwaggerBuilder::new()
+//!     .definition(
+//!         "Foo",
+//!         SchemaBuilder::object()
+//!             .prop("id", SchemaBuilder::string().required())
+//!             .prop("tags", SchemaBuilder::array(SchemaBuilder::string())),
+//!     )
+//!     .build::<rust::Type>();
+//! let out = CodeGenerator::new(swagger, Box::new(rust::Codegen::default()))
+//!     .generate_models_to_string()
+//!     .unwrap();
+//! assert!(out.contains("pub struct Foo {"));
+//! ```
+use crate::v2::{
+    definitions::Definitions,
+    items::{Item, Items},
+    responses::{ResponseObject, Responses},
+    Response, Schema, Swagger, Type, DEFINITIONS_REF,
+};
+
+use std::{collections::HashMap, marker::PhantomData, sync::Arc, sync::OnceLock};
+
+/// Builds a `Swagger` from definitions and responses.
+#[derive(Debug, Default)]
+pub struct SwaggerBuilder {
+    definitions: HashMap<String, Schema>,
+    responses: HashMap<String, Response>,
+}
+
+impl SwaggerBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the definition `name`, replacing an earlier one of the same name.
+    pub fn definition(mut self, name: impl Into<String>, schema: SchemaBuilder) -> Self {
+        self.definitions.insert(name.into(), schema.build());
+        self
+    }
+
+    /// Adds the response `name` to the `responses` section, with a body of `schema` if given.
+    pub fn response(
+        mut self,
+        name: impl Into<String>,
+        description: impl Into<String>,
+        schema: Option<SchemaBuilder>,
+    ) -> Self {
+        let schema = schema.map(SchemaBuilder::build);
+        let response = ResponseObject {
+            description: Some(description.into()),
+            schema: schema.clone(),
+            examples: HashMap::new(),
+            extra: HashMap::new(),
+        };
+        let response = match schema.and_then(|schema| schema.ref_) {
+            Some(ref_) => Response::Reference(ref_, Box::new(response)),
+            None => Response::Object(Box::new(response)),
+        };
+        self.responses.insert(name.into(), response);
+        self
+    }
+
+    pub fn build<T: Type>(self) -> Swagger<T> {
+        Swagger {
+            swagger: "2.0".into(),
+            definitions: (!self.definitions.is_empty())
+                .then(|| Definitions::from(self.definitions)),
+            paths: None,
+            responses: (!self.responses.is_empty()).then(|| Responses(self.responses)),
+            consumes: vec![],
+            produces: vec![],
+            tags: vec![],
+            extra: HashMap::new(),
+            type_names: OnceLock::new(),
+            map_key_types: OnceLock::new(),
+            _data: PhantomData,
+        }
+    }
+}
+
+/// Builds a `Schema`, starting from its type.
+#[derive(Debug, Clone, Default)]
+pub struct SchemaBuilder {
+    schema: Schema,
+    required: bool,
+}
+
+impl SchemaBuilder {
+    fn of_type(type_: &str) -> Self {
+        Self {
+            schema: Schema {
+                type_: Some(type_.into()),
+                ..Default::default()
+            },
+            required: false,
+        }
+    }
+
+    pub fn object() -> Self {
+        Self::of_type("object")
+    }
+
+    pub fn string() -> Self {
+        Self::of_type("string")
+    }
+
+    pub fn integer() -> Self {
+        Self::of_type("integer")
+    }
+
+    pub fn number() -> Self {
+        Self::of_type("number")
+    }
+
+    pub fn boolean() -> Self {
+        Self::of_type("boolean")
+    }
+
+    pub fn array(items: SchemaBuilder) -> Self {
+        let mut array = Self::of_type("array");
+        array.schema.items = Some(items.into_item());
+        array
+    }
+
+    /// A map of string keys to values of `values`.
+    pub fn map(values: SchemaBuilder) -> Self {
+        let mut map = Self::object();
+        map.schema.additional_properties = Some(values.into_item());
+        map
+    }
+
+    /// A string enum of `values`.
+    pub fn string_enum<S: Into<String>>(values: impl IntoIterator<Item = S>) -> Self {
+        let mut enum_ = Self::string();
+        enum_.schema.enum_ = values
+            .into_iter()
+            .map(|value| serde_yaml::Value::String(value.into()))
+            .collect();
+        enum_
+    }
+
+    /// A reference to the definition `name`.
+    pub fn reference(name: &str) -> Self {
+        Self {
+            schema: Schema {
+                ref_: Some(format!("{DEFINITIONS_REF}{name}")),
+                ..Default::default()
+            },
+            required: false,
+        }
+    }
+
+    /// A schema composed of `members` with `allOf`.
+    pub fn all_of(members: impl IntoIterator<Item = SchemaBuilder>) -> Self {
+        Self {
+            schema: Schema {
+                all_of: members.into_iter().map(SchemaBuilder::build).collect(),
+                ..Default::default()
+            },
+            required: false,
+        }
+    }
+
+    /// Adds the property `name`, listing it in `required` if `schema` is marked required.
+    pub fn prop(mut self, name: impl Into<String>, schema: SchemaBuilder) -> Self {
+        let name = name.into();
+        if schema.required {
+            self.schema.required.push(name.clone());
+        }
+        self.schema
+            .properties
+            .get_or_insert_with(Items::default)
+            .0
+            .insert(name, schema.into_item());
+        self
+    }
+
+    /// Marks this schema as a required property of the schema it is added to.
+    pub fn required(mut self) -> Self {
+        self.required = true;
+        self
+    }
+
+    pub fn format(mut self, format: impl Into<String>) -> Self {
+        self.schema.format = Some(format.into());
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.schema.title = Some(title.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.schema.description = Some(description.into());
+        self
+    }
+
+    pub fn build(self) -> Schema {
+        self.schema
+    }
+
+    /// Properties and items that only reference a definition are kept as a plain reference,
+    /// the same way they are parsed.
+    fn into_item(self) -> Item {
+        match self.schema {
+            Schema {
+                ref_: Some(ref_), ..
+            } => Item::Reference(ref_),
+            schema => Item::Object(Arc::new(schema)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{SchemaBuilder, SwaggerBuilder};
+    use crate::v2::{codegen::backend::rust, Item, Response};
+
+    #[test]
+    fn matches_parsed_spec() {
+        let built = SwaggerBuilder::new()
+            .definition(
+                "Container",
+                SchemaBuilder::object()
+                    .prop("Id", SchemaBuilder::string().required())
+                    .prop("Names", SchemaBuilder::array(SchemaBuilder::string()))
+                    .prop("State", SchemaBuilder::reference("State"))
+                    .prop("Labels", SchemaBuilder::map(SchemaBuilder::string())),
+            )
+            .definition("State", SchemaBuilder::string_enum(["running", "exited"]))
+            .definition(
+                "Detailed",
+                SchemaBuilder::all_of([
+                    SchemaBuilder::reference("Container"),
+                    SchemaBuilder::object().prop("Size", SchemaBuilder::integer().format("int64")),
+                ]),
+            )
+            .response(
+                "NotFound",
+                "No such container.",
+                Some(SchemaBuilder::reference("Container")),
+            )
+            .response(
+                "Error",
+                "Server error.",
+                Some(SchemaBuilder::object().prop("message", SchemaBuilder::string())),
+            )
+            .build::<rust::Type>();
+        let parsed: crate::v2::Swagger<rust::Type> = serde_yaml::from_str(
+            r##"
+swagger: "2.0"
+definitions:
+  Container:
+    type: object
+    required: [Id]
+    properties:
+      Id:
+        type: string
+      Names:
+        type: array
+        items:
+          type: string
+      State:
+        $ref: "#/definitions/State"
+      Labels:
+        type: object
+        additionalProperties:
+          type: string
+  State:
+    type: string
+    enum: [running, exited]
+  Detailed:
+    allOf:
+      - $ref: "#/definitions/Container"
+      - type: object
+        properties:
+          Size:
+            type: integer
+            format: int64
+responses:
+  NotFound:
+    description: No such container.
+    schema:
+      $ref: "#/definitions/Container"
+  Error:
+    description: Server error.
+    schema:
+      type: object
+      properties:
+        message:
+          type: string
+"##,
+        )
+        .unwrap();
+
+        let definitions = built.definitions.as_ref().unwrap();
+        let parsed_definitions = parsed.definitions.as_ref().unwrap();
+        for name in ["Container", "State", "Detailed"] {
+            assert_eq!(
+                definitions.get(name),
+                parsed_definitions.get(name),
+                "{name}"
+            );
+        }
+        let container = definitions.get("Container").unwrap();
+        let props = &container.properties.as_ref().unwrap().0;
+        assert_eq!(
+            props["State"],
+            Item::Reference("#/definitions/State".into())
+        );
+
+        let responses = &built.responses.as_ref().unwrap().0;
+        assert!(matches!(
+            &responses["NotFound"],
+            Response::Reference(ref_, _) if ref_ == "#/definitions/Container"
+        ));
+        assert!(
+            matches!(&responses["Error"], Response::Object(response) if response.schema.is_some())
+        );
+        assert_eq!(built.to_json().unwrap(), parsed.to_json().unwrap());
+    }
+}
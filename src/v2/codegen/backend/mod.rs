@@ -1,13 +1,162 @@
 pub mod python;
 pub mod rust;
+pub mod typescript;
 
 use crate::v2::{
-    codegen::{ModelPrototype, Prototyper},
-    Swagger, Type,
+    codegen::{
+        doc::{normalize_whitespace, reflow},
+        prototyper::{dedup_enums, dedup_schemas, filter_kinds, PrototypeOptions},
+        ModelKind, ModelPrototype, Prototyper,
+    },
+    validation::Diagnostic,
+    Item, Items, Swagger, Type,
 };
 
-use std::cmp::Ordering;
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, HashSet},
+};
+
+/// Splits a description into the lines of a doc comment, normalizing its whitespace when
+/// `trim` is set and reflowing it when `width` is set.
+fn doc_lines(text: &str, width: Option<usize>, trim: bool) -> Vec<String> {
+    let normalized;
+    let text = if trim {
+        normalized = normalize_whitespace(text);
+        &normalized
+    } else {
+        text
+    };
+    match width {
+        Some(width) => reflow(text, width),
+        None => text.lines().map(str::to_string).collect(),
+    }
+}
+
+/// Prepares `name` for case conversion into an identifier. Characters that can't appear in one
+/// become word separators and letters outside of ASCII are spelled as their code point, so
+/// `@odata.type` becomes `odata type` and `名前` becomes `u540d u524d`.
+pub fn sanitize_identifier(name: &str) -> String {
+    let mut sanitized = String::with_capacity(name.len());
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() || c == '_' {
+            sanitized.push(c);
+        } else if c.is_alphanumeric() {
+            sanitized.push_str(&format!(" u{:04x} ", c as u32));
+        } else {
+            sanitized.push(' ');
+        }
+    }
+    sanitized.trim().to_string()
+}
+
+/// Whether `name` can be used as an identifier as is, ignoring keywords.
+pub fn is_identifier(name: &str) -> bool {
+    name.starts_with(|c: char| c.is_ascii_alphabetic() || c == '_')
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_')
+        && name != "_"
+}
+
+/// Makes sure an identifier formatted from `name` is valid. Identifiers starting with a digit get
+/// `prefix` prepended, empty ones are replaced by `prefix` followed by the code points of `name`.
+pub fn finish_identifier(ident: String, name: &str, prefix: &str) -> String {
+    if ident.is_empty() {
+        let codes: Vec<_> = name.chars().map(|c| format!("{:x}", c as u32)).collect();
+        format!("{prefix}{}", codes.join("_"))
+    } else if ident.starts_with(|c: char| c.is_ascii_digit()) {
+        format!("{prefix}{ident}")
+    } else {
+        ident
+    }
+}
+
+/// Names the fields of the properties `props` with `format`. Properties whose names collide
+/// after sanitizing, like `@odata.type` and `odata_type`, are told apart by a numeric suffix,
+/// `odata_type` and `odata_type2`. A property whose name is already its field keeps it, the
+/// others are suffixed in sorted order so the names don't depend on the field order.
+pub fn field_names<'a>(
+    props: impl IntoIterator<Item = &'a String>,
+    format: impl Fn(&str) -> String,
+) -> HashMap<&'a str, String> {
+    let mut props: Vec<_> = props
+        .into_iter()
+        .map(|prop| (prop.as_str(), format(prop)))
+        .collect();
+    props.sort_unstable_by(|(a, a_field), (b, b_field)| (a_field != a, a).cmp(&(b_field != b, b)));
+    let mut taken: HashSet<_> = props.iter().map(|(_, field)| field.clone()).collect();
+    let mut names: HashMap<&str, String> = HashMap::new();
+    let mut used = HashSet::new();
+    for (prop, field) in props {
+        if used.insert(field.clone()) {
+            names.insert(prop, field);
+            continue;
+        }
+        let suffixed = (2..)
+            .map(|n| format!("{field}{n}"))
+            .find(|candidate| !taken.contains(candidate))
+            .unwrap();
+        taken.insert(suffixed.clone());
+        names.insert(prop, suffixed);
+    }
+    names
+}
+
+/// Order in which the properties of a schema are emitted as fields of its model.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FieldOrder {
+    /// Sorted alphabetically by property name.
+    #[default]
+    Alpha,
+    /// In the order the properties are declared in the spec.
+    Spec,
+}
+
+impl FieldOrder {
+    /// Returns the properties of `props` in this order.
+    pub fn props(self, props: &Items) -> Vec<(&String, &Item)> {
+        let mut props: Vec<_> = props.0.iter().collect();
+        if self == FieldOrder::Alpha {
+            props.sort_unstable_by_key(|(k, _)| *k);
+        }
+        props
+    }
+}
+
+/// How the variants of enums are named after their values. The value is always kept for
+/// (de)serialization, empty values and values starting with a digit get a prefix whatever the
+/// case.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum EnumVariantCase {
+    /// The convention of the language, `UpperCamel` in Rust and upper case words run together
+    /// in Python.
+    #[default]
+    Default,
+    UpperCamel,
+    ScreamingSnake,
+    /// The value itself if it is a valid identifier, otherwise named by the default case.
+    Verbatim,
+}
+
+/// The prototypes of all models of `swagger`, objects before references and otherwise sorted
+/// by name so that all references are valid.
+pub fn sorted_prototypes<T: Type>(
+    swagger: &Swagger<T>,
+    opts: &PrototypeOptions,
+) -> (Vec<ModelPrototype>, Vec<Diagnostic>) {
+    let p = Prototyper::new().options(opts.clone());
+    let (mut prototypes, diagnostics) = p.generate_prototypes_with_diagnostics(swagger);
+    prototypes.sort_by(
+        |a, b| match (a.schema.is_reference(), b.schema.is_reference()) {
+            (true, true) | (false, false) => a.name.cmp(&b.name),
+            (true, false) => Ordering::Greater,
+            (false, true) => Ordering::Less,
+        },
+    );
+    (prototypes, diagnostics)
+}
 
+/// Generates code from a spec. Implementations are best unit tested with specs made by
+/// `v2::builder`.
 pub trait CodegenBackend<T: Type> {
     fn generate_model(
         &mut self,
@@ -22,20 +171,61 @@ pub trait CodegenBackend<T: Type> {
         writer: &mut Box<dyn std::io::Write>,
     ) -> std::io::Result<()>;
 
+    /// Called once after all models were generated.
+    fn finalize(
+        &mut self,
+        _swagger: &Swagger<T>,
+        _writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        Ok(())
+    }
+
+    /// Options affecting which model prototypes are generated.
+    fn prototype_options(&self) -> PrototypeOptions {
+        PrototypeOptions::default()
+    }
+
+    /// What the model of `prototype` is generated as, see `PrototypeOptions::only`.
+    fn model_kind(&self, prototype: &ModelPrototype, _swagger: &Swagger<T>) -> ModelKind {
+        prototype.kind()
+    }
+
+    /// Returns the prototypes of all models to generate together with notes about how they
+    /// were derived from the spec, like merged duplicate enums.
+    fn prototypes_with_diagnostics(
+        &self,
+        swagger: &Swagger<T>,
+    ) -> (Vec<ModelPrototype>, Vec<Diagnostic>) {
+        let (mut prototypes, mut diagnostics) =
+            sorted_prototypes(swagger, &self.prototype_options());
+        diagnostics.extend(self.finish_prototypes(&mut prototypes, swagger));
+        (prototypes, diagnostics)
+    }
+
+    /// Merges duplicates of the prototypes of `sorted_prototypes` and drops the ones of kinds
+    /// that aren't generated.
+    fn finish_prototypes(
+        &self,
+        prototypes: &mut Vec<ModelPrototype>,
+        swagger: &Swagger<T>,
+    ) -> Vec<Diagnostic> {
+        let opts = self.prototype_options();
+        let mut diagnostics = dedup_enums(prototypes, &opts);
+        if opts.dedupe_identical_schemas {
+            diagnostics.extend(dedup_schemas(prototypes));
+        }
+        if !opts.only.is_empty() {
+            let kinds: Vec<_> = prototypes
+                .iter()
+                .map(|prototype| self.model_kind(prototype, swagger))
+                .collect();
+            diagnostics.extend(filter_kinds(prototypes, &kinds, &opts.only, swagger));
+        }
+        diagnostics
+    }
+
     fn prototypes(&self, swagger: &Swagger<T>) -> Vec<ModelPrototype> {
-        let p = Prototyper::default();
-        let mut prototypes = p.generate_prototypes(swagger);
-
-        // Generate object schemas first so that all references are valid
-        // and fallback to alphabetical sorting
-        prototypes.sort_by(
-            |a, b| match (a.schema.is_reference(), b.schema.is_reference()) {
-                (true, true) | (false, false) => a.name.cmp(&b.name),
-                (true, false) => Ordering::Greater,
-                (false, true) => Ordering::Less,
-            },
-        );
-        prototypes
+        self.prototypes_with_diagnostics(swagger).0
     }
 
     fn generate_models(
@@ -58,6 +248,143 @@ pub trait CodegenBackend<T: Type> {
         writer: &mut Box<dyn std::io::Write>,
     ) -> std::io::Result<()> {
         self.generate_helpers(swagger, writer)?;
-        self.generate_models(swagger, writer)
+        self.generate_models(swagger, writer)?;
+        self.finalize(swagger, writer)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_util {
+    use super::CodegenBackend;
+    use crate::v2::{codegen::CodeGenerator, Swagger, Type};
+
+    /// Runs `backend` over the YAML `spec` and returns everything it wrote.
+    pub fn generate<T: Type>(backend: impl CodegenBackend<T> + 'static, spec: &str) -> String {
+        let swagger: Swagger<T> = serde_yaml::from_str(spec).unwrap();
+        generate_built(backend, swagger)
+    }
+
+    /// Like `generate` for a spec made with `v2::builder`.
+    pub fn generate_built<T: Type>(
+        backend: impl CodegenBackend<T> + 'static,
+        swagger: Swagger<T>,
+    ) -> String {
+        CodeGenerator::new(swagger, Box::new(backend))
+            .generate_models_to_string()
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{field_names, python, rust, test_util::generate, typescript};
+
+    #[test]
+    fn exotic_names_become_identifiers() {
+        assert_eq!(rust::format_var_name("@odata.type"), "odata_type");
+        assert_eq!(rust::format_var_name("x-$ref"), "x_ref");
+        assert_eq!(rust::format_var_name("名前"), "u_540_d_u_524_d");
+        assert_eq!(rust::format_var_name("123abc"), "field_123_abc");
+        assert_eq!(rust::format_var_name("$"), "field_24");
+        assert_eq!(rust::format_type_name("@odata.type"), "OdataType");
+        assert_eq!(rust::format_type_name("123abc"), "Type123Abc");
+        assert_eq!(rust::format_type_name("名前"), "U540DU524D");
+        assert_eq!(rust::format_type_name("String_"), "String_");
+        assert_eq!(rust::format_type_name("Name_"), "Name");
+        assert_eq!(rust::format_enum_value_name("a+b"), "AB");
+
+        assert_eq!(python::format_var_name("x-$ref"), "x_ref");
+        assert_eq!(python::format_var_name("123abc"), "field_123_abc");
+        assert_eq!(python::format_type_name("名前"), "U540DU524D");
+        assert_eq!(typescript::format_type_name("@odata.type"), "OdataType");
+    }
+
+    #[test]
+    fn colliding_names_get_suffixes() {
+        let props = ["@odata.type", "odata_type", "odata-type", "odata_type2"].map(String::from);
+        let names = field_names(&props, rust::format_var_name);
+        assert_eq!(names["odata_type"], "odata_type");
+        assert_eq!(names["@odata.type"], "odata_type2");
+        assert_eq!(names["odata-type"], "odata_type3");
+        assert_eq!(names["odata_type2"], "odata_type_2");
+
+        let spec = r##"
+swagger: "2.0"
+definitions:
+  "@odata.type":
+    type: object
+    properties:
+      "@odata.type":
+        type: string
+      odata_type:
+        type: integer
+  odata_type:
+    type: string
+"##;
+        let out = generate(rust::Codegen::default(), spec);
+        assert!(out.contains("pub struct OdataType {"), "{out}");
+        assert!(out.contains("pub type OdataType2 = String;"), "{out}");
+        assert!(out.contains(
+            "    #[serde(rename = \"@odata.type\", skip_serializing_if = \"Option::is_none\")]\n    pub odata_type2: Option<String>,"
+        ));
+        assert!(out.contains("    pub odata_type: Option<isize>,"));
+
+        let out = generate(python::Codegen::default(), spec);
+        assert!(
+            out.contains("    odata_type2: Optional[str] = None"),
+            "{out}"
+        );
+        assert!(out.contains("    odata_type: Optional[int] = None"));
+    }
+
+    #[test]
+    fn open_objects_only_get_models_in_rust() {
+        let spec = r##"
+swagger: "2.0"
+definitions:
+  Container:
+    type: object
+    properties:
+      Config:
+        type: object
+        required: [Image]
+"##;
+        let out = generate(rust::Codegen::default(), spec);
+        assert!(
+            out.contains("pub struct ContainerConfigInlineItem {"),
+            "{out}"
+        );
+
+        let out = generate(python::Codegen::default(), spec);
+        assert!(!out.contains("ContainerConfigInlineItem"), "{out}");
+        assert!(out.contains("    config: Optional[Dict[str, str]] = None"));
+
+        let out = generate(typescript::Codegen::default(), spec);
+        assert!(!out.contains("ContainerConfigInlineItem"), "{out}");
+    }
+
+    #[test]
+    fn enum_variant_cases() {
+        use super::EnumVariantCase::*;
+
+        let values = ["not-found", "404", "UP", ""];
+        let rust = |case| values.map(|value| rust::format_enum_value_name_with(value, case));
+        assert_eq!(rust(Default), ["NotFound", "Value404", "Up", "Empty"]);
+        assert_eq!(rust(UpperCamel), rust(Default));
+        assert_eq!(
+            rust(ScreamingSnake),
+            ["NOT_FOUND", "VALUE_404", "UP", "EMPTY"]
+        );
+        assert_eq!(rust(Verbatim), ["NotFound", "Value404", "UP", "Empty"]);
+        assert_eq!(rust::format_enum_value_name_with("self", Verbatim), "self_");
+
+        let python = |case| values.map(|value| python::format_enum_value_name_with(value, case));
+        assert_eq!(python(Default), ["NOTFOUND", "VALUE404", "UP", "EMPTY"]);
+        assert_eq!(python(UpperCamel), ["NotFound", "Value404", "Up", "Empty"]);
+        assert_eq!(
+            python(ScreamingSnake),
+            ["NOT_FOUND", "VALUE_404", "UP", "EMPTY"]
+        );
+        assert_eq!(python(Verbatim), ["NOTFOUND", "VALUE404", "UP", "EMPTY"]);
     }
 }
@@ -1,17 +1,69 @@
 use crate::v2::codegen::{
     backend::{
-        python::{self, format_enum_value_name, format_type_name, format_var_name},
-        CodegenBackend,
+        doc_lines, field_names,
+        python::{self, format_enum_value_name_with, format_type_name, format_var_name},
+        CodegenBackend, EnumVariantCase, FieldOrder,
     },
-    ModelPrototype,
+    BodyNames, ModelKind, ModelPrototype, PrototypeOptions, DEFAULT_MAX_DEPTH,
 };
 use crate::v2::{Item, Schema, Swagger};
 
 use log::{debug, error, trace};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub struct CodegenOptions {
+    /// Reflow descriptions in docstrings and comments to this many columns instead of copying
+    /// them verbatim.
+    pub doc_width: Option<usize>,
+    /// Trim trailing whitespace and dedent descriptions before writing them.
+    pub trim_description_whitespace: bool,
+    /// Merge identical inline enums even if their descriptions differ.
+    pub dedup_ignore_docs: bool,
+    /// Generate inline objects of the same structure as one model and aliases of it, see
+    /// `PrototypeOptions::dedupe_identical_schemas`.
+    pub dedupe_identical_schemas: bool,
+    /// Order of the fields of a dataclass. Required fields always come before optional ones.
+    pub field_order: FieldOrder,
+    /// How the members of enums are named after their values.
+    pub enum_variant_case: EnumVariantCase,
+    /// Inline single use definitions with at most this many properties, see
+    /// `PrototypeOptions::inline_threshold`.
+    pub inline_threshold: usize,
+    /// How the models of body parameters are named.
+    pub body_names: BodyNames,
+    /// Shared names of inline objects by their property signature, see
+    /// `PrototypeOptions::common_inline_names`.
+    pub common_inline_names: HashMap<String, String>,
+    /// How deeply inline schemas may be nested, see `PrototypeOptions::max_depth`.
+    pub max_depth: usize,
+    /// Only generate models of these kinds, or all of them if empty, see
+    /// `PrototypeOptions::only`.
+    pub only: Vec<ModelKind>,
+}
+
+impl Default for CodegenOptions {
+    fn default() -> Self {
+        Self {
+            doc_width: None,
+            trim_description_whitespace: true,
+            dedup_ignore_docs: false,
+            dedupe_identical_schemas: false,
+            field_order: FieldOrder::default(),
+            enum_variant_case: EnumVariantCase::default(),
+            inline_threshold: 0,
+            body_names: BodyNames::default(),
+            common_inline_names: HashMap::new(),
+            max_depth: DEFAULT_MAX_DEPTH,
+            only: vec![],
+        }
+    }
+}
 
 #[derive(Default)]
 pub struct Codegen {
     generated_models: Vec<String>,
+    opts: CodegenOptions,
 }
 
 impl CodegenBackend<python::Type> for Codegen {
@@ -22,6 +74,12 @@ impl CodegenBackend<python::Type> for Codegen {
         writer: &mut Box<dyn std::io::Write>,
     ) -> std::io::Result<()> {
         trace!("generating {} `{}`", model.schema.type_(), &model.name);
+        if let Some(target) = &model.alias_of {
+            let type_name = format_type_name(&model.name);
+            writeln!(writer, "{type_name} = {}\n", format_type_name(target))?;
+            self.generated_models.push(type_name);
+            return Ok(());
+        }
         match &model.schema {
             Item::Reference(ref_) => {
                 self.generate_reference_model(ref_, &model, swagger, writer)?
@@ -31,6 +89,19 @@ impl CodegenBackend<python::Type> for Codegen {
         Ok(())
     }
 
+    fn prototype_options(&self) -> PrototypeOptions {
+        PrototypeOptions {
+            dedup_ignore_docs: self.opts.dedup_ignore_docs,
+            dedupe_identical_schemas: self.opts.dedupe_identical_schemas,
+            inline_threshold: self.opts.inline_threshold,
+            body_names: self.opts.body_names,
+            common_inline_names: self.opts.common_inline_names.clone(),
+            max_depth: self.opts.max_depth,
+            only: self.opts.only.clone(),
+            ..Default::default()
+        }
+    }
+
     fn generate_helpers(
         &mut self,
         _swagger: &Swagger<python::Type>,
@@ -60,6 +131,13 @@ from json import JSONEncoder, JSONDecoder
 }
 
 impl Codegen {
+    pub fn new(opts: CodegenOptions) -> Self {
+        Self {
+            generated_models: vec![],
+            opts,
+        }
+    }
+
     fn generate_reference_model(
         &mut self,
         ref_: &str,
@@ -68,11 +146,11 @@ impl Codegen {
         writer: &mut Box<dyn std::io::Write>,
     ) -> std::io::Result<()> {
         if let Some(schema) = swagger.get_ref_schema(ref_) {
-            let schema = swagger.merge_all_of_schema(schema.clone());
+            let schema = swagger.merge_all_of_schema(schema);
             if !schema.is_object() {
                 return Ok(());
             }
-            if let Some(ty) = swagger.map_reference_type(&ref_, true, Some(&model.name)) {
+            if let Some(ty) = swagger.map_reference_type(ref_, true, Some(&model.name)) {
                 let type_name = format_type_name(&model.name);
                 let ty_str = ty.to_string();
 
@@ -102,7 +180,7 @@ impl Codegen {
         swagger: &Swagger<python::Type>,
         writer: &mut Box<dyn std::io::Write>,
     ) -> std::io::Result<()> {
-        let schema = swagger.merge_all_of_schema(schema.clone());
+        let schema = swagger.merge_all_of_schema(schema);
         self.generate_schema(
             &model.name,
             model.parent_name.as_deref(),
@@ -161,7 +239,7 @@ impl Codegen {
             if let Some(description) = &schema.description {
                 self.print_doc_comment(description, None, writer)?;
             }
-            writeln!(writer, "{type_name} = {}\n", ty.to_string())?;
+            writeln!(writer, "{type_name} = {}\n", ty)?;
             self.generated_models.push(type_name);
         } else {
             error!("unhandled schema {schema:?}");
@@ -179,7 +257,7 @@ impl Codegen {
     ) -> std::io::Result<()> {
         debug!("handling property schema `{name}`");
         let props = schema.properties.as_ref().unwrap();
-        let type_name = format_type_name(&name);
+        let type_name = format_type_name(name);
 
         struct Prop<'a> {
             comment: Option<&'a String>,
@@ -187,15 +265,21 @@ impl Codegen {
             ty: python::Type,
         }
 
-        let mut props: Vec<_> = props.0.iter().collect();
-        props.sort_unstable_by_key(|(k, _)| *k);
+        let names = field_names(props.0.keys(), format_var_name);
+        let props = self.opts.field_order.props(props);
 
         let mut required = vec![];
         let mut optional = vec![];
-        let mut has_comments = schema.description.is_some();
+        let mut aliases = vec![];
+        let example = schema.example.as_ref().and_then(|example| {
+            serde_json::to_string_pretty(example)
+                .map_err(|err| log::warn!("leaving out the example of `{type_name}`: {err}"))
+                .ok()
+        });
+        let mut has_comments = schema.description.is_some() || example.is_some();
 
         for (prop, item) in &props {
-            let is_required = schema.required.contains(prop);
+            let is_required = schema.is_required(prop, item);
             debug!("handling property `{prop}`");
 
             let prop = match item {
@@ -208,7 +292,7 @@ impl Codegen {
                     } else {
                         python::Type::Value
                     };
-                    let name = format_var_name(prop);
+                    let name = names[prop.as_str()].clone();
                     Prop {
                         comment: None,
                         name,
@@ -217,7 +301,7 @@ impl Codegen {
                 }
                 it @ Item::Object(item) => {
                     trace!("`{prop}` is an object {item:?}");
-                    let name = format_var_name(prop);
+                    let name = names[prop.as_str()].clone();
 
                     let prop_ty_name = format!("{type_name}_{prop}");
 
@@ -229,6 +313,11 @@ impl Codegen {
                         python::Type::Value
                     };
                     debug!("mapped type for `{name}` `{prop}` - {ty}");
+                    aliases.extend(
+                        item.x_aliases
+                            .iter()
+                            .map(|alias| (alias.as_str(), prop.as_str())),
+                    );
 
                     Prop {
                         comment: item.description.as_ref(),
@@ -247,7 +336,7 @@ impl Codegen {
             }
         }
 
-        self.print_json_encoders(&type_name, writer)?;
+        self.print_json_encoders(&type_name, &aliases, writer)?;
 
         writeln!(writer, "@dataclass")?;
         writeln!(writer, "class {type_name}:")?;
@@ -256,7 +345,11 @@ impl Codegen {
             writeln!(writer, "    \"\"\"")?;
         }
         if let Some(description) = &schema.description {
-            for line in description.lines() {
+            for line in doc_lines(
+                description,
+                self.opts.doc_width,
+                self.opts.trim_description_whitespace,
+            ) {
                 writeln!(writer, "{line}")?;
             }
         }
@@ -267,12 +360,7 @@ impl Codegen {
         }
         for prop in &required {
             if let Some(comment) = prop.comment {
-                writeln!(
-                    writer,
-                    "    * {}: {}",
-                    prop.name,
-                    comment.replace("\"", "'")
-                )?;
+                self.print_prop_comment(&prop.name, comment, writer)?;
             }
         }
         if !optional.is_empty() && has_comments {
@@ -281,12 +369,16 @@ impl Codegen {
         }
         for prop in &optional {
             if let Some(comment) = prop.comment {
-                writeln!(
-                    writer,
-                    "    * {}: {}",
-                    prop.name,
-                    comment.replace("\"", "'")
-                )?;
+                self.print_prop_comment(&prop.name, comment, writer)?;
+            }
+        }
+        if let Some(example) = example {
+            writeln!(writer)?;
+            writeln!(writer, "Example:")?;
+            // escaped so the docstring shows the JSON as it is
+            let example = example.replace('\\', "\\\\").replace("\"\"\"", "\\\"\"\"");
+            for line in example.lines() {
+                writeln!(writer, "    {line}")?;
             }
         }
         if has_comments {
@@ -328,7 +420,7 @@ impl Codegen {
     ) -> std::io::Result<()> {
         debug!("handling array schema `{name}`");
         if let Some(item) = &schema.items {
-            let ty = swagger.map_item_type(&item, true, Some(&name));
+            let ty = swagger.map_item_type(item, true, Some(name));
             if ty.is_none() {
                 return Ok(());
             }
@@ -349,8 +441,8 @@ impl Codegen {
                 return Ok(());
             }
 
-            self.print_json_encoders(&type_name, writer)?;
-            self.print_description(&schema, writer)?;
+            self.print_json_encoders(&type_name, &[], writer)?;
+            self.print_description(schema, writer)?;
             writeln!(writer, "{type_name}: TypeAlias = \"{ty_str}\"\n")?;
             self.generated_models.push(type_name);
         }
@@ -366,18 +458,23 @@ impl Codegen {
     ) -> std::io::Result<()> {
         debug!("handling enum schema `{name}`");
 
-        let type_name = format_type_name(&name);
+        let type_name = format_type_name(name);
         writeln!(writer, "class {type_name}(Enum):")?;
         if let Some(description) = &schema.description {
-            writeln!(writer, "    \"\"\"{}\"\"\"", description.trim_end())?;
+            let description = doc_lines(
+                description.trim_end(),
+                self.opts.doc_width,
+                self.opts.trim_description_whitespace,
+            );
+            writeln!(writer, "    \"\"\"{}\"\"\"", description.join("\n"))?;
         }
         for enum_value in &schema.enum_ {
             if let Some(val) = enum_value.as_str() {
                 writeln!(
                     writer,
                     "    {} = \"{}\"",
-                    format_enum_value_name(val),
-                    if val.is_empty() { "empty" } else { val }
+                    format_enum_value_name_with(val, self.opts.enum_variant_case),
+                    val
                 )?;
             }
         }
@@ -405,22 +502,55 @@ impl Codegen {
         let indentation = indentation
             .map(|i| " ".repeat(i.into()))
             .unwrap_or_default();
-        for line in comment.as_ref().lines() {
+        for line in doc_lines(
+            comment.as_ref(),
+            self.opts.doc_width,
+            self.opts.trim_description_whitespace,
+        ) {
             writeln!(writer, "{indentation}# {line}")?;
         }
         Ok(())
     }
 
+    fn print_prop_comment(
+        &self,
+        name: &str,
+        comment: &str,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        let comment = comment.replace('"', "'");
+        let Some(width) = self.opts.doc_width else {
+            return writeln!(writer, "    * {name}: {comment}");
+        };
+        let prefix = format!("    * {name}: ");
+        let lines = doc_lines(
+            &comment,
+            Some(width.saturating_sub(prefix.len()).max(1)),
+            self.opts.trim_description_whitespace,
+        );
+        for (i, line) in lines.iter().enumerate() {
+            if i == 0 {
+                writeln!(writer, "{prefix}{line}")?;
+            } else if line.is_empty() {
+                writeln!(writer)?;
+            } else {
+                writeln!(writer, "{:width$}{line}", "", width = prefix.len())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the JSON encoder and decoder of `ty`. The decoder renames every `(alias, key)`
+    /// of `aliases` found in the input to the key it is an alias of.
     fn print_json_encoders(
         &self,
         ty: &str,
+        aliases: &[(&str, &str)],
         writer: &mut Box<dyn std::io::Write>,
     ) -> std::io::Result<()> {
         write!(
             writer,
-            "{}",
-            format!(
-                "
+            "
 class {ty}JsonEncoder(JSONEncoder):
     def default(self, o):
         return {{k: v for k, v in o.__dict__.items() if v is not None}}
@@ -430,10 +560,22 @@ class {ty}JsonDecoder(JSONDecoder):
 
     @staticmethod
     def from_dict(d):
-        return {ty}(**d)
 "
-            )
-        )
+        )?;
+        if !aliases.is_empty() {
+            let aliases: Vec<_> = aliases
+                .iter()
+                .map(|(alias, key)| format!("({alias:?}, {key:?})"))
+                .collect();
+            writeln!(
+                writer,
+                "        for alias, key in [{}]:",
+                aliases.join(", ")
+            )?;
+            writeln!(writer, "            if alias in d and key not in d:")?;
+            writeln!(writer, "                d[key] = d.pop(alias)")?;
+        }
+        writeln!(writer, "        return {ty}(**d)")
     }
 
     pub fn generate_forward_declarations(
@@ -463,3 +605,89 @@ class {ty}JsonDecoder(JSONDecoder):
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{Codegen, CodegenOptions};
+    use crate::v2::codegen::backend::{test_util::generate, FieldOrder};
+
+    #[test]
+    fn spec_field_order_keeps_required_first() {
+        let opts = CodegenOptions {
+            field_order: FieldOrder::Spec,
+            ..Default::default()
+        };
+        let out = generate(
+            Codegen::new(opts),
+            r#"
+swagger: "2.0"
+definitions:
+  Point:
+    type: object
+    required: [Y, X]
+    properties:
+      Label:
+        type: string
+      Y:
+        type: integer
+      Comment:
+        type: string
+      X:
+        type: integer
+"#,
+        );
+        let fields: Vec<_> = out
+            .lines()
+            .filter_map(|line| line.strip_prefix("    "))
+            .filter_map(|line| line.split_once(": "))
+            .map(|(name, _)| name)
+            .filter(|name| !name.contains(' '))
+            .collect();
+        assert_eq!(fields, ["y", "x", "label", "comment"]);
+    }
+
+    #[test]
+    fn example_in_docstring() {
+        let out = generate(
+            Codegen::default(),
+            r#"
+swagger: "2.0"
+definitions:
+  Mount:
+    type: object
+    description: A mount of a container.
+    example:
+      Target: C:\data
+      ReadOnly: true
+    properties:
+      Target:
+        type: string
+      ReadOnly:
+        type: boolean
+"#,
+        );
+        assert!(out.contains(
+            "\nExample:\n    {\n      \"Target\": \"C:\\\\\\\\data\",\n      \"ReadOnly\": true\n    }\n\"\"\"\n"
+        ), "{out}");
+    }
+
+    #[test]
+    fn aliases_in_from_dict() {
+        let out = generate(
+            Codegen::default(),
+            r#"
+swagger: "2.0"
+definitions:
+  Container:
+    type: object
+    properties:
+      Image:
+        type: string
+        x-aliases: [ImageName, image_ref]
+"#,
+        );
+        assert!(out.contains(
+            "    def from_dict(d):\n        for alias, key in [(\"ImageName\", \"Image\"), (\"image_ref\", \"Image\")]:\n            if alias in d and key not in d:\n                d[key] = d.pop(alias)\n        return Container(**d)\n"
+        ));
+    }
+}
@@ -1,9 +1,12 @@
 mod backend;
 mod types;
 
-pub use backend::Codegen;
+pub use backend::{Codegen, CodegenOptions};
 pub use types::Type;
 
+use crate::v2::codegen::backend::{
+    finish_identifier, is_identifier, sanitize_identifier, EnumVariantCase,
+};
 use crate::{Case, Casing};
 
 pub const KEYWORDS: &[&str] = &[
@@ -25,37 +28,53 @@ pub fn fix_name_if_keyword(name: &mut String) {
 }
 
 pub fn format_type_name(name: &str) -> String {
-    let mut name = name.to_case(Case::UpperCamel);
-    fix_name_if_keyword(&mut name);
-    name
+    let mut ident = sanitize_identifier(name).to_case(Case::UpperCamel);
+    fix_name_if_keyword(&mut ident);
+    finish_identifier(ident, name, "Type")
 }
 
 pub fn format_var_name(name: &str) -> String {
-    let name = name.replace('-', "_");
-    let name = name.replace('.', "_");
-    let name = name.replace('/', "_");
-    let mut name = name.to_case(Case::Snake);
-    fix_name_if_keyword(&mut name);
-    name
+    let mut ident = sanitize_identifier(name).to_case(Case::Snake);
+    fix_name_if_keyword(&mut ident);
+    finish_identifier(ident, name, "field_")
 }
 
 pub fn format_enum_value_name(name: &str) -> String {
-    let name = name.replace('-', " ");
-    let name = name.replace('.', " ");
-    let name = name.replace('/', " ");
-    let mut name = name.to_case(Case::Upper);
+    format_enum_value_name_with(name, EnumVariantCase::Default)
+}
+
+/// Formats the name of the member of the enum value `name` in `case`.
+pub fn format_enum_value_name_with(name: &str, case: EnumVariantCase) -> String {
+    let (mut name, empty, prefix) = match case {
+        EnumVariantCase::Verbatim if is_identifier(name) => (name.to_string(), "EMPTY", "VALUE"),
+        EnumVariantCase::UpperCamel => (
+            sanitize_identifier(name).to_case(Case::UpperCamel),
+            "Empty",
+            "Value",
+        ),
+        EnumVariantCase::ScreamingSnake => (
+            sanitize_identifier(name).to_case(Case::UpperSnake),
+            "EMPTY",
+            "VALUE_",
+        ),
+        _ => (
+            sanitize_identifier(name).to_case(Case::Upper),
+            "EMPTY",
+            "VALUE",
+        ),
+    };
     name = name.replace(' ', "");
     fix_name_if_keyword(&mut name);
 
     if name.is_empty() {
-        "EMPTY".into()
+        empty.into()
     } else if name
         .chars()
         .next()
         .map(|c| c.is_numeric())
         .unwrap_or_default()
     {
-        format!("VALUE{name}")
+        format!("{prefix}{name}")
     } else {
         name
     }
@@ -10,6 +10,7 @@ pub enum Type {
     Bool,
     Int,
     Float,
+    Bytes,
     List(Box<Type>),
     Dict(Box<Type>),
     Optional(Box<Type>),
@@ -25,6 +26,7 @@ impl fmt::Display for Type {
             Bool => write!(f, "bool"),
             Int => write!(f, "int"),
             Float => write!(f, "float"),
+            Bytes => write!(f, "bytes"),
             List(ty) => write!(f, "List[{ty}]"),
             Dict(ty) => write!(f, "Dict[str, {ty}]"),
             Value => write!(f, "{}", Type::Dict(Box::new(Type::String))),
@@ -62,6 +64,8 @@ impl crate::v2::Type for Type {
                 Some("binary") => Type::List(Box::new(Type::Int)),
                 _ => Type::String,
             },
+            // the content of an uploaded or downloaded file
+            "file" => Type::Bytes,
             "boolean" => Type::Bool,
             "array" => {
                 let ty = if let Some(ref_) = ref_ {
@@ -81,7 +85,7 @@ impl crate::v2::Type for Type {
             "object" => {
                 let ty = if let Some(ref_) = ref_ {
                     Type::Custom(trim_reference(ref_).to_string())
-                } else if let Some(item) = &schema.additional_properties {
+                } else if let Some(item) = &schema.map_values() {
                     if let Some(ty) = Self::map_item_type(item, true, parent_name, swagger) {
                         Type::Dict(Box::new(ty))
                     } else {
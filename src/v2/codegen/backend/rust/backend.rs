@@ -1,356 +1,4227 @@
 use crate::v2::codegen::{
     backend::{
-        rust::{self, format_enum_value_name, format_type_name, format_var_name},
-        CodegenBackend,
+        doc_lines, field_names,
+        rust::{
+            self, enum_rename_all, enum_variant_names, format_module_name, format_type_name,
+            format_var_name_in,
+        },
+        sorted_prototypes, CodegenBackend, EnumVariantCase, FieldOrder,
     },
-    ModelPrototype,
+    operations::operation_ident,
+    prototyper::{
+        enum_key, enum_order_conflicts, query_params_model_name, response_model_name, EnumKey,
+    },
+    render_to_vec, BodyNames, ModelKind, ModelPrototype, PrototypeOptions, PrototypeSource,
+    DEFAULT_MAX_DEPTH,
+};
+use crate::v2::{
+    parameter::Parameter,
+    responses::Response,
+    schema::{EnumKind, Variants},
+    Item, Items, Schema, Swagger, Value, DEFINITIONS_REF,
 };
-use crate::v2::{Item, Schema, Swagger};
 
 use log::{debug, error, trace};
+use rayon::prelude::*;
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    sync::Arc,
+};
+
+/// Serializes `num_bigint::BigInt` fields as strings and deserializes them from strings or
+/// integers, the serde implementation of `num_bigint` uses a sequence of digits instead.
+const BIG_INT_SERDE: &str = r#"
+mod big_int_serde {
+    use num_bigint::BigInt;
+    use serde::{de, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(v: &BigInt, s: S) -> Result<S::Ok, S::Error> {
+        s.collect_str(v)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<BigInt, D::Error> {
+        d.deserialize_any(BigIntVisitor)
+    }
+
+    struct BigIntVisitor;
+
+    impl de::Visitor<'_> for BigIntVisitor {
+        type Value = BigInt;
+
+        fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+            f.write_str("an integer or a string of one")
+        }
+
+        fn visit_i64<E: de::Error>(self, v: i64) -> Result<BigInt, E> {
+            Ok(v.into())
+        }
+
+        fn visit_u64<E: de::Error>(self, v: u64) -> Result<BigInt, E> {
+            Ok(v.into())
+        }
+
+        fn visit_i128<E: de::Error>(self, v: i128) -> Result<BigInt, E> {
+            Ok(v.into())
+        }
+
+        fn visit_u128<E: de::Error>(self, v: u128) -> Result<BigInt, E> {
+            Ok(v.into())
+        }
+
+        fn visit_str<E: de::Error>(self, v: &str) -> Result<BigInt, E> {
+            v.parse().map_err(E::custom)
+        }
+    }
+
+    pub mod option {
+        use super::*;
+        use serde::Deserialize;
+
+        #[derive(Deserialize)]
+        struct Wrapper(#[serde(with = "super")] BigInt);
+
+        pub fn serialize<S: Serializer>(v: &Option<BigInt>, s: S) -> Result<S::Ok, S::Error> {
+            match v {
+                Some(v) => s.collect_str(v),
+                None => s.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(d: D) -> Result<Option<BigInt>, D::Error> {
+            Option::<Wrapper>::deserialize(d).map(|v| v.map(|v| v.0))
+        }
+    }
+}
+"#;
+
+/// Deserializes booleans from JSON booleans, the strings `"true"`, `"false"`, `"1"` and `"0"`
+/// or numbers, where anything but zero is `true`.
+const LENIENT_BOOL_SERDE: &str = r#"
+fn deserialize_lenient_bool<'de, D: serde::de::Deserializer<'de>>(d: D) -> Result<bool, D::Error> {
+    d.deserialize_any(LenientBoolVisitor)
+}
+
+fn deserialize_lenient_option_bool<'de, D: serde::de::Deserializer<'de>>(
+    d: D,
+) -> Result<Option<bool>, D::Error> {
+    #[derive(Deserialize)]
+    struct Wrapper(#[serde(deserialize_with = "deserialize_lenient_bool")] bool);
+
+    Option::<Wrapper>::deserialize(d).map(|v| v.map(|v| v.0))
+}
+
+struct LenientBoolVisitor;
+
+impl serde::de::Visitor<'_> for LenientBoolVisitor {
+    type Value = bool;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("a boolean, a string of one or a number")
+    }
+
+    fn visit_bool<E: serde::de::Error>(self, v: bool) -> Result<bool, E> {
+        Ok(v)
+    }
+
+    fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<bool, E> {
+        Ok(v != 0)
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<bool, E> {
+        Ok(v != 0)
+    }
+
+    fn visit_f64<E: serde::de::Error>(self, v: f64) -> Result<bool, E> {
+        Ok(v != 0.0)
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<bool, E> {
+        match v {
+            "true" | "1" => Ok(true),
+            "false" | "0" => Ok(false),
+            _ => Err(E::invalid_value(serde::de::Unexpected::Str(v), &self)),
+        }
+    }
+}
+"#;
+
+const DATETIME_UTIL: &str = r#"
+/// Conversions between timestamps and the RFC 3339 strings they are (de)serialized as.
+pub mod datetime_util {
+    use chrono::{DateTime, Utc};
+
+    /// Parses an RFC 3339 timestamp like `2022-01-01T00:00:00Z`, converting it to UTC.
+    pub fn parse_rfc3339(s: &str) -> Result<DateTime<Utc>, chrono::ParseError> {
+        DateTime::parse_from_rfc3339(s).map(|dt| dt.with_timezone(&Utc))
+    }
+
+    /// Formats `dt` the way it is serialized, with as many fractional digits as it needs.
+    pub fn format_rfc3339(dt: &DateTime<Utc>) -> String {
+        dt.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true)
+    }
+}
+"#;
+
+/// Implementations (de)serializing the unit struct `type_name` of an object without keys as
+/// `{}`, rejecting objects that have any.
+fn empty_object_impls(type_name: &str) -> String {
+    format!(
+        r#"impl serde::Serialize for {type_name} {{
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {{
+        use serde::ser::SerializeMap;
+        s.serialize_map(Some(0))?.end()
+    }}
+}}
+
+impl<'de> serde::Deserialize<'de> for {type_name} {{
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {{
+        let map: HashMap<String, serde::de::IgnoredAny> = serde::Deserialize::deserialize(d)?;
+        if map.is_empty() {{
+            Ok(Self)
+        }} else {{
+            Err(serde::de::Error::invalid_length(map.len(), &"an empty object"))
+        }}
+    }}
+}}
+"#
+    )
+}
+
+/// Derives of the unit structs of objects without keys.
+const EMPTY_OBJECT_DERIVES: &[&str] = &[
+    "Debug",
+    "Clone",
+    "Copy",
+    "Default",
+    "PartialEq",
+    "Eq",
+    "Hash",
+    "PartialOrd",
+    "Ord",
+];
+
+/// Compares and hashes floats as map keys, for the manual `PartialEq` and `Hash` implementations
+/// of structs with float fields, see `FloatHash::Bits`.
+const FLOAT_KEY: &str = r#"
+/// Compares and hashes floats as map keys: `0.0` and `-0.0` are equal, and so are all `NaN`s.
+trait FloatKey {
+    fn key_eq(&self, other: &Self) -> bool;
+    fn key_hash<H: std::hash::Hasher>(&self, state: &mut H);
+}
+
+impl FloatKey for f32 {
+    fn key_eq(&self, other: &Self) -> bool {
+        self == other || (self.is_nan() && other.is_nan())
+    }
+
+    fn key_hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let bits = if self.is_nan() {
+            f32::NAN.to_bits()
+        } else if *self == 0.0 {
+            0
+        } else {
+            self.to_bits()
+        };
+        state.write_u32(bits);
+    }
+}
+
+impl FloatKey for f64 {
+    fn key_eq(&self, other: &Self) -> bool {
+        self == other || (self.is_nan() && other.is_nan())
+    }
+
+    fn key_hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let bits = if self.is_nan() {
+            f64::NAN.to_bits()
+        } else if *self == 0.0 {
+            0
+        } else {
+            self.to_bits()
+        };
+        state.write_u64(bits);
+    }
+}
+
+impl<T: FloatKey> FloatKey for Option<T> {
+    fn key_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Some(value), Some(other)) => value.key_eq(other),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    fn key_hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        match self {
+            Some(value) => {
+                state.write_u8(1);
+                value.key_hash(state);
+            }
+            None => state.write_u8(0),
+        }
+    }
+}
+
+impl<T: FloatKey> FloatKey for Vec<T> {
+    fn key_eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a.key_eq(b))
+    }
+
+    fn key_hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        for value in self {
+            value.key_hash(state);
+        }
+    }
+}
+"#;
+
+/// Whether `item` is a float or an array of them.
+fn is_float_item(item: &Item) -> bool {
+    let Item::Object(schema) = item else {
+        return false;
+    };
+    match schema.type_() {
+        Some("number") => matches!(schema.format.as_deref(), Some("double" | "float")),
+        Some("array") => schema.items.as_ref().is_some_and(is_float_item),
+        _ => false,
+    }
+}
+
+/// Whether `ty` holds floats that `FloatKey` compares and hashes.
+fn has_floats(ty: &rust::Type) -> bool {
+    match ty {
+        rust::Type::F32 | rust::Type::F64 => true,
+        rust::Type::Option(inner) | rust::Type::Vec(inner) => has_floats(inner),
+        _ => false,
+    }
+}
+
+/// Whether an inline schema is an object without keys, which is an `EmptyObject`.
+fn has_empty_objects(swagger: &Swagger<rust::Type>) -> bool {
+    let is_empty = |item: &Item| matches!(item, Item::Object(schema) if schema.is_empty_object());
+    let mut found = false;
+    swagger.visit_schemas(|schema, _| {
+        let props = schema.properties.iter().flat_map(|props| props.0.values());
+        let patterns = schema
+            .pattern_properties
+            .iter()
+            .flat_map(|props| props.0.values());
+        found |= props
+            .chain(patterns)
+            .chain(&schema.items)
+            .chain(&schema.additional_properties)
+            .any(is_empty);
+    });
+    found
+}
+
+/// The lifetime parameter of a type alias of `ty`.
+fn lifetime_param(ty: &rust::Type) -> &'static str {
+    if ty.has_lifetime() {
+        "<'a>"
+    } else {
+        ""
+    }
+}
+
+/// Serde only borrows strings of `Cow<'a, str>` fields themselves, optional fields go through
+/// this instead.
+const BORROWED_OPTION_SERDE: &str = r#"
+fn deserialize_borrowed_option<'de: 'a, 'a, D: serde::de::Deserializer<'de>>(
+    d: D,
+) -> Result<Option<std::borrow::Cow<'a, str>>, D::Error> {
+    #[derive(Deserialize)]
+    struct Wrapper<'a>(#[serde(borrow)] std::borrow::Cow<'a, str>);
+
+    Option::<Wrapper>::deserialize(d).map(|v| v.map(|v| v.0))
+}
+"#;
+
+/// Quotes `s` as a raw string literal with as many `#` as it needs.
+fn raw_string(s: &str) -> String {
+    let mut hashes = String::from("#");
+    while s.contains(&format!("\"{hashes}")) {
+        hashes.push('#');
+    }
+    format!("r{hashes}\"{s}\"{hashes}")
+}
+
+/// Collection formats of array query parameters serialized as a single joined value, `multi`
+/// is left to the query serializer which repeats the key for every value.
+const JOINED_COLLECTION_FORMATS: &[(&str, &str)] =
+    &[("csv", ","), ("ssv", " "), ("tsv", "\t"), ("pipes", "|")];
+
+fn joined_collection_format(schema: &Schema) -> Option<&str> {
+    let format = schema.collection_format.as_deref()?;
+    JOINED_COLLECTION_FORMATS
+        .iter()
+        .find(|(f, _)| *f == format)
+        .map(|(f, _)| *f)
+}
+
+/// Builds the query strings of the models of query parameters, see
+/// `Codegen::generate_query_string`.
+const QUERY_STRING: &str = r#"
+/// Percent-encodes everything but the unreserved characters of RFC 3986.
+fn encode_query_component(component: &str) -> String {
+    let mut encoded = String::with_capacity(component.len());
+    for byte in component.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+/// Joins the fields of `params` into a query string. Arrays repeat their key for every value and
+/// empty values of the fields in `bare_keys` are sent as the key alone.
+fn query_string(params: &impl serde::Serialize, bare_keys: &[&str]) -> String {
+    let Ok(serde_json::Value::Object(fields)) = serde_json::to_value(params) else {
+        return String::new();
+    };
+    let mut pairs = vec![];
+    for (key, value) in fields {
+        let values = match value {
+            serde_json::Value::Array(values) => values,
+            value => vec![value],
+        };
+        for value in values {
+            let value = match value {
+                serde_json::Value::Null => continue,
+                serde_json::Value::String(s) => s,
+                value => value.to_string(),
+            };
+            if value.is_empty() && bare_keys.contains(&key.as_str()) {
+                pairs.push(encode_query_component(&key));
+            } else {
+                pairs.push(format!(
+                    "{}={}",
+                    encode_query_component(&key),
+                    encode_query_component(&value)
+                ));
+            }
+        }
+    }
+    pairs.join("&")
+}
+"#;
+
+fn has_query_params(swagger: &Swagger<rust::Type>) -> bool {
+    swagger
+        .operations()
+        .flat_map(|(_, _, op)| &op.parameters)
+        .any(|param| matches!(param, Parameter::Query(_)))
+}
+
+/// Whether `type_name` is the model of the query parameters of an operation.
+fn is_query_params_model(type_name: &str, swagger: &Swagger<rust::Type>) -> bool {
+    swagger.operations().any(|(path, method, op)| {
+        op.parameters
+            .iter()
+            .any(|param| matches!(param, Parameter::Query(_)))
+            && format_type_name(&query_params_model_name::<rust::Type>(path, method, op))
+                == type_name
+    })
+}
+
+fn has_enum_query_params(swagger: &Swagger<rust::Type>) -> bool {
+    swagger
+        .operations()
+        .flat_map(|(_, _, op)| &op.parameters)
+        .any(|param| matches!(param, Parameter::Query(param) if !param.enum_.is_empty()))
+}
+
+/// Whether a collection of form parameters, or of query parameters with `query_models`, is
+/// joined into a single value.
+fn has_joined_params(swagger: &Swagger<rust::Type>, query_models: bool) -> bool {
+    swagger
+        .operations()
+        .flat_map(|(_, _, op)| &op.parameters)
+        .any(|param| match param {
+            Parameter::Query(param) if query_models && param.is_array() => {
+                joined_collection_format(&param.to_schema()).is_some()
+            }
+            Parameter::FormData(param) if param.is_array() => {
+                joined_collection_format(&param.to_schema()).is_some()
+            }
+            _ => false,
+        })
+}
+
+/// The formatted type names of all definitions.
+fn definition_type_names(swagger: &Swagger<rust::Type>) -> HashSet<String> {
+    swagger
+        .definitions
+        .iter()
+        .flat_map(|definitions| definitions.0.keys())
+        .map(|key| format_type_name(swagger.type_names().get(&format!("{DEFINITIONS_REF}{key}"))))
+        .collect()
+}
+
+/// How a property is described for its model's schema hash.
+fn field_shape(prop: &str, is_required: bool, ty: &rust::Type) -> String {
+    let required = if is_required { "" } else { "?" };
+    format!("{prop}{required}: {ty}")
+}
+
+/// 64 bit FNV-1a of `s`. The hashers of std may change between Rust releases, schema hashes
+/// must not.
+fn stable_hash(s: &str) -> u64 {
+    s.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// The comment starting the section of models derived from `source`.
+fn section_banner(source: PrototypeSource) -> &'static str {
+    match source {
+        PrototypeSource::Definition => "// ===== DEFINITIONS =====",
+        PrototypeSource::Response => "// ===== RESPONSES =====",
+        PrototypeSource::Operation => "// ===== PATHS =====",
+    }
+}
+
+/// Documents the `patternProperties` the keys of the map `schema` match.
+fn key_patterns_doc(schema: &Schema) -> Option<String> {
+    let patterns: Vec<_> = schema
+        .key_patterns()
+        .into_iter()
+        .map(|pattern| format!("`{pattern}`"))
+        .collect();
+    match patterns.as_slice() {
+        [] => None,
+        [pattern] => Some(format!("Keys match the pattern {pattern}.")),
+        patterns => Some(format!(
+            "Keys match one of the patterns {}.",
+            patterns.join(", ")
+        )),
+    }
+}
+
+/// Lists the allowed values of a mixed enum, which isn't checked by its type.
+fn enum_values_doc(schema: &Schema) -> Option<String> {
+    if !schema.is_mixed_enum() {
+        return None;
+    }
+    let values: Vec<_> = schema
+        .enum_
+        .iter()
+        .filter_map(|value| serde_json::to_string(value).ok())
+        .map(|value| format!("`{value}`"))
+        .collect();
+    Some(format!("One of {}.", values.join(", ")))
+}
+
+/// Whether a model has a field or is an alias of a string format type `is_type` accepts, like a
+/// `date-time`, which gets the `datetime_util` helpers, or a `time`, which needs `NaiveTime` in
+/// scope.
+fn has_string_type(swagger: &Swagger<rust::Type>, is_type: fn(&rust::Type) -> bool) -> bool {
+    // only strings map to a format type, checking the type first keeps nested arrays from being
+    // mapped at every level
+    let maps_to_type = |schema: &Schema| {
+        schema.type_() == Some("string")
+            && swagger
+                .map_schema_type(schema, None, true, None)
+                .is_some_and(|ty| is_type(&ty))
+    };
+    let mut found = false;
+    swagger.visit_schemas(|schema, _| found |= maps_to_type(schema));
+    found
+        || swagger
+            .operations()
+            .flat_map(|(_, _, op)| &op.parameters)
+            .any(|param| match param {
+                Parameter::Query(param) | Parameter::FormData(param) => {
+                    maps_to_type(&param.to_schema())
+                }
+                _ => false,
+            })
+}
+
+fn has_lenient_bools(swagger: &Swagger<rust::Type>) -> bool {
+    let mut found = false;
+    swagger.visit_schemas(|schema, _| found |= schema.x_lenient_bool);
+    found
+}
+
+/// Whether a struct has a required array or map field without a default, which is defaulted to
+/// empty with `lenient_required_collections`. Binary strings are `Vec<u8>` unless
+/// `byte_bufs` is set.
+fn has_required_collections(swagger: &Swagger<rust::Type>, byte_bufs: bool) -> bool {
+    let is_collection = |schema: &Schema| {
+        schema.default.is_none()
+            && (schema.is_array()
+                || schema.map_values().is_some()
+                || (!byte_bufs && schema.is_binary()))
+    };
+    let mut found = false;
+    swagger.visit_schemas(|schema, _| {
+        let schema = swagger.merge_all_of_schema(schema);
+        found |=
+            schema
+                .properties
+                .iter()
+                .flat_map(|props| &props.0)
+                .any(|(name, prop)| match prop {
+                    Item::Object(schema_item) => {
+                        schema.is_required(name, prop)
+                            && !schema_item.x_nullable
+                            && is_collection(schema_item)
+                    }
+                    Item::Reference(_) => false,
+                });
+    });
+    // the models of query and form parameters
+    found
+        || swagger
+            .operations()
+            .flat_map(|(_, _, op)| &op.parameters)
+            .any(|param| match param {
+                Parameter::Query(param) | Parameter::FormData(param) => {
+                    param.required && is_collection(&param.to_schema())
+                }
+                _ => false,
+            })
+}
 
+/// Collects the `serde` attribute arguments of a field so they are emitted as a single
+/// `#[serde(...)]` line.
 #[derive(Default)]
-pub struct Codegen {
-    generated_models: Vec<String>,
+struct SerdeAttrs(Vec<String>);
+
+impl SerdeAttrs {
+    fn push(&mut self, key: &str) {
+        self.0.push(key.to_string());
+    }
+
+    fn push_value(&mut self, key: &str, value: &str) {
+        self.0.push(format!("{key} = {value:?}"));
+    }
+
+    fn write(&self, writer: &mut Box<dyn std::io::Write>) -> std::io::Result<()> {
+        if self.0.is_empty() {
+            return Ok(());
+        }
+        writeln!(writer, "    #[serde({})]", self.0.join(", "))
+    }
 }
 
-impl CodegenBackend<rust::Type> for Codegen {
-    fn generate_model(
+/// Name of the field capturing unknown fields of a struct with the fields `fields`.
+fn extra_field_name(fields: &HashMap<&str, String>) -> String {
+    let mut field = String::from("extra");
+    while fields.values().any(|existing| *existing == field) {
+        field.push('_');
+    }
+    field
+}
+
+/// The fields closing a cycle of models that contain each other by value, by type name of their
+/// model. Such a model would have an infinite size unless the fields are boxed. A field closes a
+/// cycle if the model it holds contains the model of the field, so the fields between the models
+/// of a strongly connected component of the graph of by-value fields are boxed. Arrays and maps
+/// keep their values on the heap already and are no edges of the graph.
+fn recursive_fields(
+    prototypes: &[ModelPrototype],
+    swagger: &Swagger<rust::Type>,
+) -> HashMap<String, HashSet<String>> {
+    let mut graph = ValueGraph {
+        swagger,
+        ids: HashMap::new(),
+        names: vec![],
+        edges: vec![],
+        queue: vec![],
+    };
+    for prototype in prototypes {
+        if let Item::Object(schema) = &prototype.schema {
+            graph.node(
+                format_type_name(&prototype.name),
+                Cow::Owned((**schema).clone()),
+            );
+        }
+    }
+    while let Some((id, schema)) = graph.queue.pop() {
+        graph.add_edges(id, &schema);
+    }
+
+    let targets: Vec<Vec<usize>> = graph
+        .edges
+        .iter()
+        .map(|edges| edges.iter().map(|(_, to)| *to).collect())
+        .collect();
+    let component = strong_components(&targets);
+    let mut fields: HashMap<String, HashSet<String>> = HashMap::new();
+    for (from, edges) in graph.edges.iter().enumerate() {
+        for (prop, to) in edges {
+            if component[from] == component[*to] {
+                fields
+                    .entry(graph.names[from].clone())
+                    .or_default()
+                    .insert(prop.clone());
+            }
+        }
+    }
+    fields
+}
+
+/// The models of a spec and the fields holding other models by value, see `recursive_fields`.
+struct ValueGraph<'a> {
+    swagger: &'a Swagger<rust::Type>,
+    ids: HashMap<String, usize>,
+    names: Vec<String>,
+    /// The property and the model it holds for the fields of every model.
+    edges: Vec<Vec<(String, usize)>>,
+    /// Models whose fields weren't added yet.
+    queue: Vec<(usize, Cow<'a, Schema>)>,
+}
+
+impl<'a> ValueGraph<'a> {
+    /// The id of the model `name`, added with the fields of `schema` if it is new.
+    fn node(&mut self, name: String, schema: Cow<'a, Schema>) -> usize {
+        if let Some(id) = self.ids.get(&name) {
+            return *id;
+        }
+        let id = self.names.len();
+        self.ids.insert(name.clone(), id);
+        self.names.push(name);
+        self.edges.push(vec![]);
+        self.queue.push((id, schema));
+        id
+    }
+
+    fn add_edges(&mut self, id: usize, schema: &Schema) {
+        let schema = self.swagger.merge_all_of_schema(schema);
+        if let Some(ref_) = &schema.ref_ {
+            let item = Item::Reference(ref_.clone());
+            if let Some(to) = self.held_model(&item, id, "") {
+                self.edges[id].push((String::new(), to));
+            }
+            return;
+        }
+        for (prop, item) in schema.properties.iter().flat_map(|props| &props.0) {
+            if let Some(to) = self.held_model(item, id, prop) {
+                self.edges[id].push((prop.clone(), to));
+            }
+        }
+    }
+
+    /// The model that the field `prop` of the model `parent` holding `item` holds by value.
+    fn held_model(&mut self, item: &Item, parent: usize, prop: &str) -> Option<usize> {
+        match item {
+            Item::Reference(ref_) => {
+                let swagger = self.swagger;
+                let schema = swagger.get_ref_schema(ref_)?;
+                let name = format_type_name(swagger.type_names().get(ref_));
+                Some(self.node(name, Cow::Borrowed(schema)))
+            }
+            Item::Object(schema) => {
+                if let Some(ref_) = &schema.ref_ {
+                    let item = Item::Reference(ref_.clone());
+                    return self.held_model(&item, parent, prop);
+                }
+                if !schema.is_object() || schema.properties.is_none() {
+                    return None;
+                }
+                let name = schema
+                    .name()
+                    .unwrap_or_else(|| format!("{}{prop}InlineItem", self.names[parent]));
+                Some(self.node(format_type_name(&name), Cow::Owned((**schema).clone())))
+            }
+        }
+    }
+}
+
+/// The references reachable from `prototypes` of the models that fail `check` themselves or hold
+/// a model that does, through any number of other models. `check` tells whether a schema fails
+/// apart from the references it holds, which it adds to its second argument. References that
+/// don't resolve fail if `missing_fails` is set. Computed once per spec, instead of following the
+/// references of every model for every model holding it.
+fn tainted_refs(
+    prototypes: &[ModelPrototype],
+    swagger: &Swagger<rust::Type>,
+    missing_fails: bool,
+    check: impl Fn(&Schema, &mut Vec<String>) -> bool,
+) -> HashSet<String> {
+    let mut queue = vec![];
+    for prototype in prototypes {
+        match &prototype.schema {
+            Item::Reference(ref_) => queue.push(ref_.clone()),
+            Item::Object(schema) => {
+                check(schema, &mut queue);
+            }
+        }
+    }
+    let mut seen = HashSet::new();
+    let mut holders: HashMap<String, Vec<String>> = HashMap::new();
+    let mut failing = vec![];
+    while let Some(ref_) = queue.pop() {
+        if !seen.insert(ref_.clone()) {
+            continue;
+        }
+        let Some(schema) = swagger.get_ref_schema(&ref_) else {
+            if missing_fails {
+                failing.push(ref_);
+            }
+            continue;
+        };
+        let mut refs = vec![];
+        if check(schema, &mut refs) {
+            failing.push(ref_.clone());
+        }
+        for held in refs {
+            holders.entry(held.clone()).or_default().push(ref_.clone());
+            queue.push(held);
+        }
+    }
+
+    let mut tainted = HashSet::new();
+    while let Some(ref_) = failing.pop() {
+        if tainted.insert(ref_.clone()) {
+            failing.extend(holders.get(&ref_).into_iter().flatten().cloned());
+        }
+    }
+    tainted
+}
+
+/// The strongly connected component of every node of the graph with the successors `edges`,
+/// found with Kosaraju's algorithm.
+fn strong_components(edges: &[Vec<usize>]) -> Vec<usize> {
+    let mut finished = Vec::with_capacity(edges.len());
+    let mut visited = vec![false; edges.len()];
+    for start in 0..edges.len() {
+        if visited[start] {
+            continue;
+        }
+        visited[start] = true;
+        let mut stack = vec![(start, 0)];
+        while let Some(top) = stack.last_mut() {
+            let node = top.0;
+            match edges[node].get(top.1) {
+                Some(&next) => {
+                    top.1 += 1;
+                    if !visited[next] {
+                        visited[next] = true;
+                        stack.push((next, 0));
+                    }
+                }
+                None => {
+                    finished.push(node);
+                    stack.pop();
+                }
+            }
+        }
+    }
+
+    let mut predecessors = vec![vec![]; edges.len()];
+    for (from, successors) in edges.iter().enumerate() {
+        for to in successors {
+            predecessors[*to].push(from);
+        }
+    }
+    let mut component = vec![usize::MAX; edges.len()];
+    let mut count = 0;
+    for start in finished.into_iter().rev() {
+        if component[start] != usize::MAX {
+            continue;
+        }
+        component[start] = count;
+        let mut stack = vec![start];
+        while let Some(node) = stack.pop() {
+            for from in &predecessors[node] {
+                if component[*from] == usize::MAX {
+                    component[*from] = count;
+                    stack.push(*from);
+                }
+            }
+        }
+        count += 1;
+    }
+    component
+}
+
+/// Maps the type name of every model with a Go package to the module path of that package.
+/// Definitions take the package from `x-go-package` and inline models the package of their
+/// parent. The path prefix shared by all packages is dropped.
+fn go_package_modules(
+    prototypes: &[ModelPrototype],
+    swagger: &Swagger<rust::Type>,
+    edition: rust::Edition,
+) -> HashMap<String, Vec<String>> {
+    let mut packages = HashMap::new();
+    if let Some(definitions) = &swagger.definitions {
+        for (key, schema) in &definitions.0 {
+            if let Some(package) = &schema.x_go_package {
+                let ref_ = format!("{DEFINITIONS_REF}{key}");
+                let name = format_type_name(swagger.type_names().get(&ref_));
+                let path: Vec<_> = package.split('/').filter(|s| !s.is_empty()).collect();
+                packages.insert(name, path);
+            }
+        }
+    }
+    let mut paths = packages.values();
+    let prefix_len = match paths.next() {
+        Some(first) => paths.fold(first.len(), |len, path| {
+            first
+                .iter()
+                .zip(path)
+                .take(len)
+                .take_while(|(a, b)| a == b)
+                .count()
+        }),
+        None => 0,
+    };
+    let mut modules: HashMap<_, Vec<_>> = packages
+        .into_iter()
+        .map(|(name, path)| {
+            let path = path[prefix_len..]
+                .iter()
+                .map(|segment| format_module_name(segment, edition))
+                .collect();
+            (name, path)
+        })
+        .collect();
+
+    let parents: HashMap<_, _> = prototypes
+        .iter()
+        .filter_map(|prototype| {
+            let parent = prototype.parent_name.as_deref()?;
+            Some((format_type_name(&prototype.name), format_type_name(parent)))
+        })
+        .collect();
+    for name in parents.keys() {
+        let mut parent = parents.get(name);
+        // bounded in case the names of nested models form a cycle
+        for _ in 0..parents.len() {
+            let Some(ancestor) = parent else {
+                break;
+            };
+            if let Some(path) = modules.get(ancestor) {
+                let path = path.clone();
+                modules.entry(name.clone()).or_insert(path);
+                break;
+            }
+            parent = parents.get(ancestor);
+        }
+    }
+    modules
+}
+
+/// Whether `prototype` is an inline object with at most `threshold` properties that are all
+/// strings, numbers or booleans, see `CodegenOptions::inline_module_threshold`.
+fn is_small_inline_model(prototype: &ModelPrototype, threshold: usize) -> bool {
+    let Item::Object(schema) = &prototype.schema else {
+        return false;
+    };
+    let Some(props) = &schema.properties else {
+        return false;
+    };
+    prototype.parent_name.is_some()
+        && props.0.len() <= threshold
+        && props.0.values().all(|prop| match prop {
+            Item::Object(prop) => {
+                prop.enum_.is_empty()
+                    && matches!(
+                        prop.type_(),
+                        Some("string" | "integer" | "number" | "boolean")
+                    )
+            }
+            Item::Reference(_) => false,
+        })
+}
+
+/// Models of one module, a Go package or the `inline` module, and the modules nested in it.
+#[derive(Default)]
+struct Module {
+    out: Vec<u8>,
+    /// Generated types the models of this module refer to.
+    references: BTreeSet<String>,
+    children: BTreeMap<String, Module>,
+}
+
+impl Module {
+    /// Writes the models of this module followed by a `pub mod` for every child. Modules glob
+    /// import their parent so that the helpers and imports of the root stay in scope, types of
+    /// other packages are imported explicitly.
+    fn write(
+        &self,
+        path: &mut Vec<String>,
+        packages: &HashMap<String, Vec<String>>,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        let imports: Vec<_> = self
+            .references
+            .iter()
+            .filter_map(|name| {
+                let package = packages.get(name)?;
+                if package.is_empty() || package == path {
+                    return None;
+                }
+                let supers = "super::".repeat(path.len());
+                Some(format!("use {supers}{}::{name};", package.join("::")))
+            })
+            .collect();
+        if !path.is_empty() {
+            writeln!(writer, "use super::*;")?;
+        }
+        for import in &imports {
+            writeln!(writer, "{import}")?;
+        }
+        if !path.is_empty() || !imports.is_empty() {
+            writeln!(writer)?;
+        }
+        writer.write_all(&self.out)?;
+        for (name, child) in &self.children {
+            path.push(name.clone());
+            let out = render_to_vec(|writer| child.write(path, packages, writer))?;
+            path.pop();
+            writeln!(writer, "pub mod {name} {{")?;
+            for line in String::from_utf8_lossy(&out).trim_end().lines() {
+                if line.is_empty() {
+                    writeln!(writer)?;
+                } else {
+                    writeln!(writer, "    {line}")?;
+                }
+            }
+            writeln!(writer, "}}\n")?;
+        }
+        Ok(())
+    }
+}
+
+/// How float fields of generated models are compared.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FloatEq {
+    /// Plain `f32`/`f64` fields, models only derive `PartialEq`.
+    #[default]
+    Partial,
+    /// Wrap floats in `ordered_float::OrderedFloat` and derive `Eq`, `Hash` and `Ord` for every
+    /// model whose fields allow it.
+    #[cfg(feature = "ordered-float")]
+    Ordered,
+}
+
+/// How structs with float fields implement `Hash` when `x-rust-derive` asks for it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FloatHash {
+    /// `Hash` is left out of the derives, floats don't implement it.
+    #[default]
+    Derive,
+    /// Manual `PartialEq`, `Eq` and `Hash` implementations keeping the fields plain floats. Floats
+    /// are hashed by their bit patterns with `to_bits`, after mapping `-0.0` to `0.0` and every
+    /// `NaN` to one bit pattern, and all `NaN`s are equal, so the struct can be a map key.
+    Bits,
+}
+
+/// Type of integers with a `bigint` or `biginteger` format.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum BigInt {
+    #[default]
+    I128,
+    /// `num_bigint::BigInt`, serialized as a string and deserialized from a string or an
+    /// integer.
+    #[cfg(feature = "num-bigint")]
+    NumBigInt,
+}
+
+/// Type of the string fields of models.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum StringType {
+    #[default]
+    String,
+    /// `Box<str>`, a pointer and length smaller than a `String` for models that aren't changed
+    /// after deserializing.
+    BoxStr,
+    /// `Cow<'a, str>` borrowing from the deserialized input, the same as
+    /// `CodegenOptions::borrowed`.
+    Cow,
+}
+
+/// What is generated for a scalar definition named like the Rust type it maps to, like a
+/// definition `String` of a string, whose type alias would refer to itself.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum PrimitiveNames {
+    /// Leave the model out, references use the Rust type.
+    #[default]
+    Skip,
+    /// Generate a newtype named with a trailing underscore, `String_`, that references use.
+    Newtype,
+    /// Generate the type alias under the lowest numeric suffix not taken by another
+    /// definition, `String2`. References use the Rust type.
+    Rename,
+}
+
+/// Serde data format the generated models are used with.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum WireFormat {
+    #[default]
+    Json,
+    /// MessagePack, binary strings are `serde_bytes::ByteBuf` so that they are encoded as bytes
+    /// instead of an array of integers.
+    MsgPack,
+    /// Any self-describing format. Helpers built around the conventions of JSON APIs, like
+    /// reading booleans from strings or writing big integers as strings, are left out.
+    Any,
+}
+
+#[derive(Debug, Clone)]
+pub struct CodegenOptions {
+    /// Add a flattened `extra` map to every struct capturing fields missing from the spec.
+    pub capture_unknown: bool,
+    /// Emit a `pub mod api_meta` with tag and operation tables taken from the spec.
+    pub api_meta: bool,
+    /// Emit a `pub mod operations` with a `(method, path)` constant for every operation.
+    pub operation_consts: bool,
+    /// Emit a `#[cfg(test)]` module round-tripping the JSON examples of responses through the
+    /// models generated for them.
+    pub examples_as_tests: bool,
+    /// Emit a `#[cfg(test)]` module checking that every variant of every string enum
+    /// serializes to the value of its `AsRef<str>` implementation and deserializes back.
+    pub enum_round_trip_tests: bool,
+    /// Reflow descriptions in doc comments to this many columns instead of copying them verbatim.
+    pub doc_width: Option<usize>,
+    /// Trim trailing whitespace and dedent descriptions before writing them.
+    pub trim_description_whitespace: bool,
+    /// Merge identical inline enums even if their descriptions differ.
+    pub dedup_ignore_docs: bool,
+    /// Generate inline objects of the same structure as one model and type aliases of it, see
+    /// `PrototypeOptions::dedupe_identical_schemas`.
+    pub dedupe_identical_schemas: bool,
+    /// Mark generated structs and enums `#[non_exhaustive]`. Structs get a `new` constructor
+    /// and enums a `FromStr` implementation so they stay constructible outside the crate.
+    pub non_exhaustive: bool,
+    /// Give every struct a `new` constructor taking its required fields.
+    pub with_constructors: bool,
+    /// Leave `Clone` out of the derives of structs and of the enums wrapping them, like the
+    /// `x-no-clone` extension does for single schemas. Code relying on the models being `Clone`
+    /// stops compiling.
+    pub no_clone: bool,
+    pub float_eq: FloatEq,
+    pub float_hash: FloatHash,
+    pub big_int: BigInt,
+    /// Render models on the rayon thread pool. The output is identical to serial rendering. Off
+    /// by default, it only pays off for specs with many expensive models.
+    pub parallel: bool,
+    /// Group models into nested modules mirroring the `x-go-package` of their definitions.
+    pub use_go_packages: bool,
+    /// Compose a schema of a single `allOf` reference and inline members as a struct with the
+    /// referenced model as a `#[serde(flatten)]` field next to the inline properties.
+    pub flatten_all_of: bool,
+    /// Generate top level models of a plain string, integer, number or boolean as a tuple
+    /// struct wrapping the value instead of a type alias, along with conversions from and to
+    /// the value.
+    pub newtypes: bool,
+    /// Generate definitions of a string with a `maxLength` as a newtype that can only be
+    /// constructed with `TryFrom` and rejects longer strings, even without `newtypes`.
+    pub bounded_strings: bool,
+    /// Generate models borrowing strings from the deserialized input, with `Cow<'a, str>`
+    /// instead of `String` and a lifetime parameter on every model that has such a field.
+    pub borrowed: bool,
+    /// Type of string fields. Map keys and newtypes stay `String`.
+    pub string_type: StringType,
+    /// Put a single `#[serde(rename_all = "...")]` on enums whose values all follow one case
+    /// convention instead of renaming every variant.
+    pub enum_rename_all: bool,
+    /// Put `#[serde(rename = "...")]` with the key of the spec on every field, also on fields
+    /// named like their key.
+    pub always_rename: bool,
+    /// How the variants of string enums are named after their values.
+    pub enum_variant_case: EnumVariantCase,
+    /// Derive `PartialOrd` and `Ord` for string enums, ordering variants the way the spec
+    /// declares the values, and give them an `ordinal` method. Refused if inline enums that
+    /// declare the same values in different orders would be merged.
+    pub ordered_enums: bool,
+    /// Only generate models of these kinds, or all of them if empty. Definitions generated as
+    /// newtypes or dynamic maps count as structs, see `PrototypeOptions::only`.
+    pub only: Vec<ModelKind>,
+    /// Give structs getters of optional fields, iterators over array fields and lookups of map
+    /// fields.
+    pub accessors: bool,
+    /// Implement `TryFrom<serde_json::Value>` for structs and give them a `to_value` method.
+    /// Left out with `WireFormat::Any`.
+    pub value_conversions: bool,
+    /// Give every struct and enum a `SCHEMA_HASH` constant of its wire names, field types and
+    /// requiredness, and write a `MODELS_HASH` combining them. Descriptions and the order of
+    /// fields don't change the hashes, they stay the same across generator versions as long as
+    /// the models do.
+    pub schema_hashes: bool,
+    /// Group the models by the part of the spec they come from, definitions, responses and
+    /// paths, each section starting with a banner comment. Ignored when models are put into
+    /// modules.
+    pub section_banners: bool,
+    /// What is generated for a scalar definition named like the Rust type it maps to.
+    pub primitive_names: PrimitiveNames,
+    /// Order of the fields of a struct.
+    pub field_order: FieldOrder,
+    /// Edition whose keywords are escaped in field and module names.
+    pub edition: rust::Edition,
+    /// Deserialize every boolean field from strings like `"true"` or `"0"` and from numbers as
+    /// well, otherwise only properties marked with `x-lenient-bool` are.
+    pub lenient_bools: bool,
+    /// Generate every optional `x-nullable` property as `Option<Option<T>>`, the way
+    /// `x-explicit-null` does for single properties. Needs the `serde-with` feature.
+    pub double_option: bool,
+    /// Default required array and map fields without a spec default to empty when they are
+    /// missing, instead of failing to deserialize. Deprecated, it will be off by default in the
+    /// next release.
+    pub lenient_required_collections: bool,
+    /// Leave query parameters equal to their spec default out of the query string, the server
+    /// falls back to the default anyway.
+    pub omit_default_params: bool,
+    /// Generate definitions with `additionalProperties` of untyped values as a newtype of a
+    /// `serde_json::Map` keeping every key, with getters of the properties the spec documents.
+    /// Left out with `WireFormat::Any`.
+    pub dynamic_maps: bool,
+    /// Inline single use definitions with at most this many properties, see
+    /// `PrototypeOptions::inline_threshold`.
+    pub inline_threshold: usize,
+    /// How the models of body parameters are named.
+    pub body_names: BodyNames,
+    /// Generate models of the query parameters of operations, see
+    /// `PrototypeOptions::query_params_models`.
+    pub query_params_models: bool,
+    /// Shared names of inline objects by their property signature, see
+    /// `PrototypeOptions::common_inline_names`.
+    pub common_inline_names: HashMap<String, String>,
+    /// How deeply inline schemas may be nested, see `PrototypeOptions::max_depth`.
+    pub max_depth: usize,
+    /// Move inline objects with at most this many properties, all of them strings, numbers or
+    /// booleans, into an `inline` module next to the model they belong to. `0` keeps them at the
+    /// top level.
+    pub inline_module_threshold: usize,
+    pub wire_format: WireFormat,
+}
+
+impl Default for CodegenOptions {
+    fn default() -> Self {
+        Self {
+            capture_unknown: false,
+            api_meta: false,
+            operation_consts: false,
+            examples_as_tests: false,
+            enum_round_trip_tests: false,
+            doc_width: None,
+            trim_description_whitespace: true,
+            dedup_ignore_docs: false,
+            dedupe_identical_schemas: false,
+            non_exhaustive: false,
+            with_constructors: false,
+            no_clone: false,
+            float_eq: FloatEq::default(),
+            float_hash: FloatHash::default(),
+            big_int: BigInt::default(),
+            parallel: false,
+            use_go_packages: false,
+            flatten_all_of: false,
+            newtypes: false,
+            bounded_strings: false,
+            borrowed: false,
+            string_type: StringType::default(),
+            enum_rename_all: false,
+            always_rename: false,
+            enum_variant_case: EnumVariantCase::default(),
+            ordered_enums: false,
+            only: vec![],
+            accessors: false,
+            value_conversions: false,
+            schema_hashes: false,
+            section_banners: false,
+            primitive_names: PrimitiveNames::default(),
+            field_order: FieldOrder::default(),
+            edition: rust::Edition::default(),
+            lenient_bools: false,
+            double_option: false,
+            lenient_required_collections: true,
+            omit_default_params: true,
+            dynamic_maps: false,
+            inline_threshold: 0,
+            body_names: BodyNames::default(),
+            query_params_models: false,
+            common_inline_names: HashMap::new(),
+            max_depth: DEFAULT_MAX_DEPTH,
+            inline_module_threshold: 0,
+            wire_format: WireFormat::default(),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct Codegen {
+    generated_models: Vec<String>,
+    /// The subset of `generated_models` that are type aliases.
+    generated_aliases: Vec<String>,
+    /// Generated string enums along with the names of their variants.
+    generated_enums: Vec<(String, Vec<String>)>,
+    /// Schema hashes of the generated models, see `CodegenOptions::schema_hashes`.
+    schema_hashes: Vec<(String, String)>,
+    opts: CodegenOptions,
+    /// Generated types referenced by the models written so far.
+    referenced_types: BTreeSet<String>,
+    /// Content types of the model currently being generated.
+    content_types: Vec<String>,
+    /// Type names of the models with a lifetime parameter, see `CodegenOptions::borrowed`.
+    borrowing: Arc<HashSet<String>>,
+    /// Type names of the inline enum models by `enum_key`, the fields of enum query parameters
+    /// use them.
+    enum_models: Arc<HashMap<EnumKey, String>>,
+    /// Properties of the models that are boxed by type name, see `recursive_fields`.
+    recursive_fields: Arc<HashMap<String, HashSet<String>>>,
+    /// References of the models that can't derive `Eq`, `Hash` and `Ord`, see
+    /// `is_totally_ordered`.
+    unordered_refs: Arc<HashSet<String>>,
+    /// References of the models that can't derive `Clone`, see `is_schema_clone`.
+    non_clone_refs: Arc<HashSet<String>>,
+}
+
+impl CodegenBackend<rust::Type> for Codegen {
+    fn generate_model(
+        &mut self,
+        model: ModelPrototype,
+        swagger: &Swagger<rust::Type>,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        trace!("generating {} `{}`", model.schema.type_(), &model.name);
+        self.content_types = model.content_types.clone();
+        if let Some(target) = &model.alias_of {
+            return self.generate_alias_model(&model.name, target, writer);
+        }
+        match &model.schema {
+            Item::Reference(ref_) => {
+                self.generate_reference_model(ref_, &model, swagger, writer)?
+            }
+            Item::Object(schema) => self.generate_object_model(schema, &model, swagger, writer)?,
+        }
+        Ok(())
+    }
+
+    fn generate_models(
+        &mut self,
+        swagger: &Swagger<rust::Type>,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        let opts = self.prototype_options();
+        let (mut prototypes, _) = sorted_prototypes(swagger, &opts);
+        if self.opts.ordered_enums {
+            if let Some(names) = enum_order_conflicts(&prototypes, &opts).first() {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::InvalidInput,
+                    format!(
+                        "can't order enums by the spec, `{}` declare the same values in different orders",
+                        names.join("`, `")
+                    ),
+                ));
+            }
+        }
+        self.finish_prototypes(&mut prototypes, swagger);
+        self.recursive_fields = Arc::new(recursive_fields(&prototypes, swagger));
+        self.unordered_refs = Arc::new(tainted_refs(&prototypes, swagger, true, |schema, refs| {
+            !self.schema_ordered_parts(schema, swagger, refs)
+        }));
+        self.non_clone_refs =
+            Arc::new(tainted_refs(&prototypes, swagger, false, |schema, refs| {
+                !self.schema_clone_parts(schema, swagger, refs)
+            }));
+        if self.borrowed() || (self.opts.query_params_models && has_enum_query_params(swagger)) {
+            if self.borrowed() {
+                self.find_borrowing_models(&prototypes, swagger);
+            }
+            self.enum_models = Arc::new(
+                prototypes
+                    .iter()
+                    .filter(|prototype| prototype.parent_name.is_some())
+                    .filter_map(|prototype| match &prototype.schema {
+                        Item::Object(schema) if schema.is_string_enum() => {
+                            Some((enum_key(schema, &opts), format_type_name(&prototype.name)))
+                        }
+                        _ => None,
+                    })
+                    .collect(),
+            );
+        }
+        if self.opts.use_go_packages || self.opts.inline_module_threshold > 0 {
+            if self.opts.section_banners {
+                log::warn!("section banners are left out when models are put into modules");
+            }
+            return self.generate_models_in_modules(prototypes, swagger, writer);
+        }
+        if self.opts.section_banners {
+            return self.generate_models_in_sections(prototypes, swagger, writer);
+        }
+        self.generate_model_list(prototypes, swagger, writer)
+    }
+
+    fn prototype_options(&self) -> PrototypeOptions {
+        PrototypeOptions {
+            dedup_ignore_docs: self.opts.dedup_ignore_docs,
+            dedupe_identical_schemas: self.opts.dedupe_identical_schemas,
+            flatten_all_of: self.opts.flatten_all_of,
+            inline_threshold: self.opts.inline_threshold,
+            body_names: self.opts.body_names,
+            common_inline_names: self.opts.common_inline_names.clone(),
+            max_depth: self.opts.max_depth,
+            ordered_enums: self.opts.ordered_enums,
+            only: self.opts.only.clone(),
+            open_objects: true,
+            query_params_models: self.opts.query_params_models,
+        }
+    }
+
+    fn model_kind(&self, prototype: &ModelPrototype, swagger: &Swagger<rust::Type>) -> ModelKind {
+        let kind = prototype.kind();
+        let Item::Object(schema) = &prototype.schema else {
+            return kind;
+        };
+        if prototype.alias_of.is_none() && schema.is_open_object() {
+            return ModelKind::Struct;
+        }
+        if kind != ModelKind::Alias || prototype.parent_name.is_some() {
+            return kind;
+        }
+        let schema = swagger.merge_all_of_schema(schema);
+        if self.is_dynamic_map(&schema, swagger)
+            || self.newtype_inner(&schema, swagger).is_some()
+            || self
+                .primitive_newtype_name(&prototype.name, &schema, swagger)
+                .is_some()
+        {
+            ModelKind::Struct
+        } else {
+            kind
+        }
+    }
+
+    fn generate_helpers(
+        &mut self,
+        swagger: &Swagger<rust::Type>,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        let byte_bufs = self.opts.wire_format == WireFormat::MsgPack;
+        if self.opts.lenient_required_collections && has_required_collections(swagger, byte_bufs) {
+            writeln!(
+                writer,
+                "// Required array and map fields are empty when missing from the payload. This is
+// deprecated and will change in the next release, generate with
+// `--lenient-required-collections false` to reject payloads missing them instead."
+            )?;
+        }
+        if self.json_helpers() && self.opts.query_params_models && has_query_params(swagger) {
+            write!(writer, "{QUERY_STRING}")?;
+        }
+        if has_joined_params(swagger, self.opts.query_params_models) {
+            write!(
+                writer,
+                r#"
+trait QueryCollection<T> {{
+    fn values(&self) -> &[T];
+
+    fn from_values(values: Option<Vec<T>>) -> Self;
+}}
+
+impl<T> QueryCollection<T> for Vec<T> {{
+    fn values(&self) -> &[T] {{
+        self
+    }}
+
+    fn from_values(values: Option<Vec<T>>) -> Self {{
+        values.unwrap_or_default()
+    }}
+}}
+
+impl<T> QueryCollection<T> for Option<Vec<T>> {{
+    fn values(&self) -> &[T] {{
+        self.as_deref().unwrap_or_default()
+    }}
+
+    fn from_values(values: Option<Vec<T>>) -> Self {{
+        values
+    }}
+}}
+
+fn serialize_joined<S: serde::Serializer, T: std::fmt::Display>(
+    values: &[T],
+    separator: &str,
+    s: S,
+) -> Result<S::Ok, S::Error> {{
+    let values: Vec<_> = values.iter().map(|v| v.to_string()).collect();
+    s.serialize_str(&values.join(separator))
+}}
+
+/// A single value of a joined collection, deserialized as a number or a boolean if it parses as
+/// one and as a string otherwise.
+struct JoinedValue<'a, E>(&'a str, std::marker::PhantomData<E>);
+
+impl<'de, E: serde::de::Error> serde::de::Deserializer<'de> for JoinedValue<'_, E> {{
+    type Error = E;
+
+    fn deserialize_any<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {{
+        if let Ok(v) = self.0.parse() {{
+            visitor.visit_bool(v)
+        }} else if let Ok(v) = self.0.parse() {{
+            visitor.visit_i64(v)
+        }} else if let Ok(v) = self.0.parse() {{
+            visitor.visit_u64(v)
+        }} else if let Ok(v) = self.0.parse() {{
+            visitor.visit_f64(v)
+        }} else {{
+            visitor.visit_str(self.0)
+        }}
+    }}
+
+    fn deserialize_str<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {{
+        visitor.visit_str(self.0)
+    }}
+
+    fn deserialize_string<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {{
+        visitor.visit_str(self.0)
+    }}
+
+    fn deserialize_enum<V: serde::de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, E> {{
+        let d: serde::de::value::StrDeserializer<E> =
+            serde::de::IntoDeserializer::into_deserializer(self.0);
+        d.deserialize_enum(name, variants, visitor)
+    }}
+
+    serde::forward_to_deserialize_any! {{
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char bytes byte_buf option unit
+        unit_struct newtype_struct seq tuple tuple_struct map struct identifier ignored_any
+    }}
+}}
+
+fn deserialize_joined<'de, D, C, T>(separator: &str, d: D) -> Result<C, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    C: QueryCollection<T>,
+    T: serde::de::DeserializeOwned,
+{{
+    let joined: Option<String> = serde::de::Deserialize::deserialize(d)?;
+    let values = match joined.as_deref() {{
+        None => None,
+        Some("") => Some(vec![]),
+        Some(joined) => Some(
+            joined
+                .split(separator)
+                .map(|v| T::deserialize(JoinedValue(v, std::marker::PhantomData)))
+                .collect::<Result<_, _>>()?,
+        ),
+    }};
+    Ok(C::from_values(values))
+}}
+"#
+            )?;
+            for (format, separator) in JOINED_COLLECTION_FORMATS {
+                write!(
+                    writer,
+                    r#"
+fn serialize_{format}<S: serde::Serializer, T: std::fmt::Display>(
+    v: &impl QueryCollection<T>,
+    s: S,
+) -> Result<S::Ok, S::Error> {{
+    serialize_joined(v.values(), {separator:?}, s)
+}}
+
+fn deserialize_{format}<'de, D, C, T>(d: D) -> Result<C, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    C: QueryCollection<T>,
+    T: serde::de::DeserializeOwned,
+{{
+    deserialize_joined({separator:?}, d)
+}}
+"#
+                )?;
+            }
+        }
+        // borrowing models only implement `Deserialize` for the lifetime of the input
+        let bound = if self.borrowed() {
+            "serde::de::Deserialize<'de>"
+        } else {
+            "serde::de::DeserializeOwned"
+        };
+        write!(
+            writer,
+            r#"
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: {bound}>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: {bound} + Eq + std::hash::Hash,
+    T: {bound},
+{{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}}
+"#
+        )?;
+        let json_helpers = self.json_helpers();
+        if self.num_big_ints() {
+            if json_helpers {
+                write!(writer, "{BIG_INT_SERDE}")?;
+            } else {
+                log::warn!("big integers use the serde implementation of `num_bigint` with this wire format, it needs its `serde` feature");
+            }
+        }
+        if self.opts.lenient_bools || has_lenient_bools(swagger) {
+            if json_helpers {
+                writeln!(writer, "{LENIENT_BOOL_SERDE}")?;
+            } else {
+                log::warn!("lenient booleans are not supported with this wire format, generating plain `bool` fields");
+            }
+        }
+        if self.opts.value_conversions && !json_helpers {
+            log::warn!(
+                "conversions from and to `serde_json::Value` are left out with this wire format"
+            );
+        }
+        if self.borrowed() {
+            writeln!(writer, "{BORROWED_OPTION_SERDE}")?;
+        }
+        if has_string_type(swagger, |ty| matches!(ty, rust::Type::DateTime)) {
+            writeln!(writer, "{DATETIME_UTIL}")?;
+        }
+        if has_string_type(swagger, |ty| matches!(ty, rust::Type::Time)) {
+            writeln!(writer, "use chrono::NaiveTime;\n")?;
+        }
+        if self.opts.float_hash == FloatHash::Bits {
+            let mut found = false;
+            swagger.visit_schemas(|schema, _| found |= self.hashes_float_bits(schema, swagger));
+            if found {
+                writeln!(writer, "{FLOAT_KEY}")?;
+            }
+        }
+        if has_empty_objects(swagger) {
+            writeln!(
+                writer,
+                "/// An inline object that can't have any keys, (de)serialized as `{{}}`."
+            )?;
+            writeln!(writer, "#[derive({})]", EMPTY_OBJECT_DERIVES.join(", "))?;
+            writeln!(writer, "pub struct EmptyObject;\n")?;
+            writeln!(writer, "{}", empty_object_impls("EmptyObject"))?;
+        }
+        Ok(())
+    }
+
+    fn finalize(
+        &mut self,
+        swagger: &Swagger<rust::Type>,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        if self.opts.api_meta {
+            self.generate_api_meta(swagger, writer)?;
+        }
+        if self.opts.operation_consts {
+            self.generate_operation_consts(swagger, writer)?;
+        }
+        if self.opts.examples_as_tests {
+            self.generate_example_tests(swagger, writer)?;
+        }
+        if self.opts.enum_round_trip_tests {
+            self.generate_enum_tests(writer)?;
+        }
+        if self.opts.schema_hashes {
+            self.generate_models_hash(writer)?;
+        }
+        Ok(())
+    }
+}
+
+impl Codegen {
+    pub fn new(opts: CodegenOptions) -> Self {
+        Self {
+            generated_models: vec![],
+            generated_aliases: vec![],
+            generated_enums: vec![],
+            schema_hashes: vec![],
+            opts,
+            referenced_types: BTreeSet::new(),
+            content_types: vec![],
+            borrowing: Arc::default(),
+            enum_models: Arc::default(),
+            recursive_fields: Arc::default(),
+            unordered_refs: Arc::default(),
+            non_clone_refs: Arc::default(),
+        }
+    }
+
+    /// Renders every model into its own buffer, on the rayon thread pool if `parallel` is set.
+    fn render_models(
+        &self,
+        prototypes: Vec<ModelPrototype>,
+        swagger: &Swagger<rust::Type>,
+    ) -> std::io::Result<Vec<(Vec<u8>, Codegen)>> {
+        let opts = &self.opts;
+        let render = |prototype| {
+            let mut model = Codegen::new(opts.clone());
+            model.borrowing = self.borrowing.clone();
+            model.enum_models = self.enum_models.clone();
+            model.recursive_fields = self.recursive_fields.clone();
+            model.unordered_refs = self.unordered_refs.clone();
+            model.non_clone_refs = self.non_clone_refs.clone();
+            let out = render_to_vec(|writer| model.generate_model(prototype, swagger, writer))?;
+            Ok((out, model))
+        };
+        if opts.parallel {
+            prototypes.into_par_iter().map(render).collect()
+        } else {
+            prototypes.into_iter().map(render).collect()
+        }
+    }
+
+    /// Records a model rendered by `render_models`. Returns `false` if the model is a type alias
+    /// whose name was taken by an earlier model, it must not be written then, the same way
+    /// serial rendering skips it.
+    fn merge_model(&mut self, model: Codegen) -> bool {
+        if let Some(alias) = model
+            .generated_aliases
+            .iter()
+            .find(|alias| self.generated_models.contains(alias))
+        {
+            log::warn!("skipping type alias `{alias}`, a type with the same name already exists");
+            return false;
+        }
+        self.generated_models.extend(model.generated_models);
+        self.generated_aliases.extend(model.generated_aliases);
+        self.generated_enums.extend(model.generated_enums);
+        self.schema_hashes.extend(model.schema_hashes);
+        self.referenced_types.extend(model.referenced_types);
+        true
+    }
+
+    /// Writes the models of `prototypes` in order, rendering them in parallel with
+    /// `CodegenOptions::parallel`.
+    fn generate_model_list(
+        &mut self,
+        prototypes: Vec<ModelPrototype>,
+        swagger: &Swagger<rust::Type>,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        if self.opts.parallel {
+            return self.generate_models_parallel(prototypes, swagger, writer);
+        }
+        for prototype in prototypes {
+            self.generate_model(prototype, swagger, writer)?;
+        }
+        Ok(())
+    }
+
+    /// Renders every model into its own buffer on the rayon thread pool and writes the buffers
+    /// in prototype order.
+    fn generate_models_parallel(
+        &mut self,
+        prototypes: Vec<ModelPrototype>,
+        swagger: &Swagger<rust::Type>,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        for (out, model) in self.render_models(prototypes, swagger)? {
+            if self.merge_model(model) {
+                writer.write_all(&out)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the models grouped by the part of the spec they are derived from, every group that
+    /// has any after its `section_banner`.
+    fn generate_models_in_sections(
+        &mut self,
+        mut prototypes: Vec<ModelPrototype>,
+        swagger: &Swagger<rust::Type>,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        prototypes.sort_by_key(|prototype| prototype.source);
+        let mut sections: Vec<(PrototypeSource, Vec<ModelPrototype>)> = vec![];
+        for prototype in prototypes {
+            match sections.last_mut() {
+                Some((source, section)) if *source == prototype.source => section.push(prototype),
+                _ => sections.push((prototype.source, vec![prototype])),
+            }
+        }
+        for (source, section) in sections {
+            let out = render_to_vec(|writer| self.generate_model_list(section, swagger, writer))?;
+            if !out.is_empty() {
+                writeln!(writer, "{}\n", section_banner(source))?;
+                writer.write_all(&out)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the models without a module followed by a `pub mod` for every Go package and
+    /// `inline` module.
+    fn generate_models_in_modules(
+        &mut self,
+        prototypes: Vec<ModelPrototype>,
+        swagger: &Swagger<rust::Type>,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        let mut packages = if self.opts.use_go_packages {
+            go_package_modules(&prototypes, swagger, self.opts.edition)
+        } else {
+            HashMap::new()
+        };
+        for prototype in &prototypes {
+            if is_small_inline_model(prototype, self.opts.inline_module_threshold) {
+                packages
+                    .entry(format_type_name(&prototype.name))
+                    .or_default()
+                    .push("inline".into());
+            }
+        }
+        let paths: Vec<_> = prototypes
+            .iter()
+            .map(|prototype| {
+                packages
+                    .get(&format_type_name(&prototype.name))
+                    .cloned()
+                    .unwrap_or_default()
+            })
+            .collect();
+        let mut root = Module::default();
+        let rendered = self.render_models(prototypes, swagger)?;
+        for ((out, model), path) in rendered.into_iter().zip(paths) {
+            let references = model.referenced_types.clone();
+            if !self.merge_model(model) {
+                continue;
+            }
+            let module = path.iter().fold(&mut root, |module, segment| {
+                module.children.entry(segment.clone()).or_default()
+            });
+            module.out.extend(out);
+            module.references.extend(references);
+        }
+        root.write(&mut vec![], &packages, writer)
+    }
+
+    /// Whether models borrow from the deserialized input, see `CodegenOptions::borrowed`.
+    fn borrowed(&self) -> bool {
+        self.opts.borrowed || self.opts.string_type == StringType::Cow
+    }
+
+    /// Applies `CodegenOptions::borrowed` and `string_type` to `ty`.
+    fn borrow_type(&self, ty: rust::Type) -> rust::Type {
+        if self.borrowed() {
+            ty.borrowed(&self.borrowing)
+        } else if self.opts.string_type == StringType::BoxStr {
+            ty.with_boxed_strs()
+        } else {
+            ty
+        }
+    }
+
+    /// The lifetime parameter of the model `type_name`, if it has one.
+    fn lifetime_of(&self, type_name: &str) -> &'static str {
+        if self.borrowing.contains(type_name) {
+            "<'a>"
+        } else {
+            ""
+        }
+    }
+
+    /// Finds the models that borrow from the deserialized input, directly or through the
+    /// models they contain, and so get a lifetime parameter.
+    fn find_borrowing_models(
+        &mut self,
+        prototypes: &[ModelPrototype],
+        swagger: &Swagger<rust::Type>,
+    ) {
+        let mut remaining: Vec<_> = prototypes.iter().collect();
+        loop {
+            let (borrowing, rest): (Vec<_>, Vec<_>) =
+                remaining.into_iter().partition(|prototype| {
+                    self.model_types(prototype, swagger)
+                        .into_iter()
+                        .any(|ty| self.borrow_type(ty).has_lifetime())
+                });
+            if borrowing.is_empty() {
+                break;
+            }
+            Arc::make_mut(&mut self.borrowing).extend(
+                borrowing
+                    .into_iter()
+                    .map(|prototype| format_type_name(&prototype.name)),
+            );
+            remaining = rest;
+        }
+    }
+
+    /// The types the model of `prototype` is composed of, the way `generate_model` maps them.
+    /// Enums and newtypes own their values and have none.
+    fn model_types(
+        &self,
+        prototype: &ModelPrototype,
+        swagger: &Swagger<rust::Type>,
+    ) -> Vec<rust::Type> {
+        let schema = match &prototype.schema {
+            Item::Reference(ref_) => {
+                return swagger
+                    .map_reference_type(ref_, true, Some(&prototype.name))
+                    .map(|ty| self.map_numbers(ty))
+                    .into_iter()
+                    .collect()
+            }
+            Item::Object(schema) if self.opts.flatten_all_of => {
+                swagger.flatten_all_of_schema(schema)
+            }
+            Item::Object(schema) => swagger.merge_all_of_schema(schema),
+        };
+        let name = &prototype.name;
+        if let Some(variants) = &schema.x_variants {
+            return variants
+                .refs()
+                .filter_map(|(_, ref_)| swagger.map_reference_type(&ref_, true, None))
+                .collect();
+        }
+        if let Some(props) = &schema.properties {
+            let type_name = format_type_name(name);
+            let flattened = schema
+                .all_of
+                .iter()
+                .filter_map(|member| member.ref_.as_deref())
+                .filter_map(|ref_| swagger.map_reference_type(ref_, true, None));
+            return props
+                .0
+                .iter()
+                .map(|(prop, item)| {
+                    let is_required =
+                        self.is_field_required(&type_name, &schema, prop, item, swagger);
+                    self.property_type(&type_name, prop, item, is_required, swagger)
+                })
+                .chain(flattened)
+                .collect();
+        }
+        if schema.is_array() {
+            return schema
+                .items
+                .iter()
+                .filter_map(|item| swagger.map_item_type(item, true, Some(name)))
+                .map(|ty| self.map_numbers(rust::Type::Vec(Box::new(ty))))
+                .collect();
+        }
+        if schema.is_mixed_enum()
+            || schema.is_string_enum()
+            || schema.ref_.is_some()
+            || (prototype.parent_name.is_none() && self.newtype_inner(&schema, swagger).is_some())
+        {
+            return vec![];
+        }
+        swagger
+            .map_schema_type(&schema, None, true, Some(name))
+            .map(|ty| self.map_bytes(&schema, self.map_numbers(ty)))
+            .into_iter()
+            .collect()
+    }
+
+    /// Records the use of `ty` in a generated model.
+    fn note_type(&mut self, ty: &rust::Type) {
+        self.referenced_types
+            .extend(ty.custom_types().into_iter().map(format_type_name));
+    }
+
+    fn generate_reference_model(
+        &mut self,
+        ref_: &str,
+        model: &ModelPrototype,
+        swagger: &Swagger<rust::Type>,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        if let Some(schema) = swagger.get_ref_schema(ref_) {
+            let schema = swagger.merge_all_of_schema(schema);
+            if !schema.is_object() {
+                return Ok(());
+            }
+            if let Some(ty) = swagger.map_reference_type(ref_, true, Some(&model.name)) {
+                let ty = self.borrow_type(self.map_numbers(ty));
+                let type_name = format_type_name(&model.name);
+                let ty_str = ty.to_string();
+
+                if type_name == ty_str {
+                    log::warn!("skipping type alias with same name `{type_name} == {ty_str}`");
+                    return Ok(());
+                }
+
+                if self.generated_models.contains(&type_name) {
+                    log::warn!(
+                                    "skipping type alias `{type_name}`, a type with the same name already exists"
+                                );
+                    return Ok(());
+                }
+                self.print_description(&schema, writer)?;
+                self.note_type(&ty);
+                let lifetime = lifetime_param(&ty);
+                writeln!(writer, "pub type {type_name}{lifetime} = {ty_str};\n")?;
+                self.generated_aliases.push(type_name.clone());
+                self.generated_models.push(type_name);
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes a type alias of the model `target` for a model with the same structure.
+    fn generate_alias_model(
+        &mut self,
+        name: &str,
+        target: &str,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        let type_name = format_type_name(name);
+        let target = format_type_name(target);
+        let lifetime = self.lifetime_of(&target);
+        writeln!(
+            writer,
+            "pub type {type_name}{lifetime} = {target}{lifetime};\n"
+        )?;
+        self.referenced_types.insert(target);
+        self.generated_aliases.push(type_name.clone());
+        self.generated_models.push(type_name);
+        Ok(())
+    }
+
+    fn generate_object_model(
+        &mut self,
+        schema: &Schema,
+        model: &ModelPrototype,
+        swagger: &Swagger<rust::Type>,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        let schema = if self.opts.flatten_all_of {
+            swagger.flatten_all_of_schema(schema)
+        } else {
+            swagger.merge_all_of_schema(schema)
+        };
+        self.generate_schema(
+            &model.name,
+            model.parent_name.as_deref(),
+            &schema,
+            swagger,
+            writer,
+        )?;
+        if let Some(base) = &model.base {
+            self.generate_base_conversion(&model.name, &schema, base, swagger, writer)?;
+        }
+        Ok(())
+    }
+
+    /// Writes a `From` implementation populating the model `name` from the definition `base`
+    /// whose properties it shares. Properties only the model has start out empty, if one of
+    /// them is required no conversion is written.
+    fn generate_base_conversion(
+        &mut self,
+        name: &str,
+        schema: &Schema,
+        base: &str,
+        swagger: &Swagger<rust::Type>,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        let (Some(props), Some(base_schema)) = (&schema.properties, swagger.get_ref_schema(base))
+        else {
+            return Ok(());
+        };
+        let base_schema = swagger.merge_all_of_schema(base_schema);
+        let base_props = base_schema.properties.clone().unwrap_or_default();
+        let type_name = format_type_name(name);
+        let vars = self.field_names(props);
+        let base_vars = self.field_names(&base_props);
+        let mut fields = vec![];
+        for (prop, item) in self.opts.field_order.props(props) {
+            let var = &vars[prop.as_str()];
+            if let Some(base_var) = base_vars.get(prop.as_str()) {
+                fields.push(format!("{var}: value.{base_var}"));
+                continue;
+            }
+            let is_required = self.is_field_required(&type_name, schema, prop, item, swagger);
+            match self.property_type(&type_name, prop, item, is_required, swagger) {
+                rust::Type::Option(_) => fields.push(format!("{var}: None")),
+                rust::Type::Vec(_) | rust::Type::Object(_) | rust::Type::Map(..) => {
+                    fields.push(format!("{var}: Default::default()"))
+                }
+                _ => {
+                    debug!("not converting `{base}` to `{type_name}`, `{prop}` is required");
+                    return Ok(());
+                }
+            }
+        }
+        if self.opts.capture_unknown {
+            let field = extra_field_name(&vars);
+            let base_field = extra_field_name(&base_vars);
+            fields.push(format!("{field}: value.{base_field}"));
+        }
+
+        let base_ty = self.borrow_type(rust::Type::Custom(
+            swagger.type_names().get(base).to_string(),
+        ));
+        self.note_type(&base_ty);
+        let lifetime = self.lifetime_of(&type_name);
+        writeln!(
+            writer,
+            "impl{lifetime} From<{base_ty}> for {type_name}{lifetime} {{"
+        )?;
+        writeln!(writer, "    fn from(value: {base_ty}) -> Self {{")?;
+        writeln!(writer, "        Self {{")?;
+        for field in fields {
+            writeln!(writer, "            {field},")?;
+        }
+        writeln!(writer, "        }}\n    }}\n}}\n")
+    }
+
+    fn generate_schema(
+        &mut self,
+        name: &str,
+        parent_name: Option<&str>,
+        schema: &Schema,
+        swagger: &Swagger<rust::Type>,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        debug!("handling schema {name}, parent: {parent_name:?}");
+        trace!("{schema:?}");
+        let name = if name.is_empty() {
+            schema.name().unwrap_or(
+                parent_name
+                    .map(|parent_name| format!("{}InlineItem", parent_name))
+                    .unwrap_or(name.to_string()),
+            )
+        } else {
+            name.to_string()
+        };
+        let type_name = format_type_name(&name);
+        trace!("mapped name: {name}, type name: {type_name}");
+
+        if let Some(variants) = &schema.x_variants {
+            self.generate_variants_schema(&name, schema, variants, swagger, writer)?
+        } else if schema.is_empty_object() {
+            self.generate_empty_object(&name, schema, writer)?
+        } else if parent_name.is_none() && self.is_dynamic_map(schema, swagger) {
+            self.generate_dynamic_map(&name, schema, swagger, writer)?
+        } else if schema.properties.is_some() || schema.is_open_object() {
+            self.generate_props_schema(&name, schema, swagger, writer)?
+        } else if schema.is_array() {
+            self.generate_array_schema(&name, schema, swagger, writer)?
+        } else if schema.is_mixed_enum() {
+            self.generate_mixed_enum_schema(&name, schema, writer)?
+        } else if schema.is_string_enum() {
+            self.generate_enum_schema(&name, schema, swagger, writer)?
+        } else if let Some(ref_) = schema.ref_.as_deref() {
+            error!("got unhandled reference schema {ref_}");
+        } else if let Some(ty) = swagger.map_schema_type(schema, None, true, Some(&name)) {
+            let ty = self.map_bytes(schema, self.map_numbers(ty));
+            debug!("handling basic type schema {type_name} = {ty}");
+            let ty_str = ty.to_string();
+
+            let mut type_name = type_name;
+            if type_name == ty_str && parent_name.is_none() {
+                match self.opts.primitive_names {
+                    PrimitiveNames::Skip => {}
+                    PrimitiveNames::Newtype => {
+                        let type_name = format_type_name(&format!("{name}_"));
+                        return self.generate_newtype(&type_name, schema, ty, writer);
+                    }
+                    PrimitiveNames::Rename => {
+                        let names = definition_type_names(swagger);
+                        type_name = (2..)
+                            .map(|n| format!("{type_name}{n}"))
+                            .find(|candidate| !names.contains(candidate))
+                            .unwrap();
+                    }
+                }
+            }
+            if type_name == ty_str {
+                log::warn!("skipping type alias with same name `{type_name} == {ty_str}`");
+                return Ok(());
+            }
+            if self.generated_models.contains(&type_name) {
+                log::warn!(
+                    "skipping type alias `{type_name}`, a type with the same name already exists"
+                );
+                return Ok(());
+            }
+
+            if parent_name.is_none() && self.newtype_inner(schema, swagger).is_some() {
+                return self.generate_newtype(&type_name, schema, ty, writer);
+            }
+
+            if let Some(description) = &schema.description {
+                self.print_doc_comment(description, None, writer)?;
+            }
+            if let Some(patterns) = key_patterns_doc(schema) {
+                if schema.description.is_some() {
+                    writeln!(writer, "///")?;
+                }
+                self.print_doc_comment(patterns, None, writer)?;
+            }
+            let ty = self.borrow_type(ty);
+            self.note_type(&ty);
+            let lifetime = lifetime_param(&ty);
+            writeln!(writer, "pub type {type_name}{lifetime} = {ty};\n")?;
+            self.generated_aliases.push(type_name.clone());
+            self.generated_models.push(type_name);
+        } else {
+            error!("unhandled schema {schema:?}");
+        }
+
+        Ok(())
+    }
+
+    fn generate_props_schema(
+        &mut self,
+        name: &str,
+        schema: &Schema,
+        swagger: &Swagger<rust::Type>,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        debug!("handling property schema `{name}`");
+        let float_hash = self.hashes_float_bits(schema, swagger);
+        // the required keys of an open object become fields next to a map of the other keys
+        let open = schema.is_open_object();
+        let open_schema;
+        let schema = if open {
+            open_schema = Schema {
+                properties: Some(schema.open_object_properties()),
+                ..schema.clone()
+            };
+            &open_schema
+        } else {
+            schema
+        };
+        let props = schema.properties.as_ref().unwrap();
+        let type_name = format_type_name(name);
+        let is_totally_ordered =
+            !self.opts.capture_unknown && self.is_schema_totally_ordered(schema, swagger);
+        let is_clone = self.is_schema_clone(schema, swagger);
+        let mut derives = schema.x_rust_derive.clone();
+        if float_hash {
+            // implemented below
+            derives.retain(|derive| !matches!(derive.as_str(), "PartialEq" | "Eq" | "Hash"));
+        }
+        self.print_derives(
+            &type_name,
+            is_totally_ordered,
+            is_clone,
+            false,
+            float_hash,
+            &derives,
+            writer,
+        )?;
+        self.print_description(schema, writer)?;
+
+        let lifetime = self.lifetime_of(&type_name);
+        writeln!(writer, "pub struct {type_name}{lifetime} {{")?;
+        let is_params = self.opts.query_params_models && is_query_params_model(&type_name, swagger);
+        let mut default_fns = vec![];
+        // the default functions of query parameters by field
+        let mut param_defaults = HashMap::new();
+        let mut skip_fns = vec![];
+        let mut bare_keys = vec![];
+        let mut fields = vec![];
+        let mut shape = vec![];
+        let vars = self.field_names(props);
+        let props = self.opts.field_order.props(props);
+        if self.opts.flatten_all_of {
+            // the reference kept by `Swagger::flatten_all_of_schema`
+            for ref_ in schema
+                .all_of
+                .iter()
+                .filter_map(|member| member.ref_.as_deref())
+            {
+                let Some(ty) = swagger.map_reference_type(ref_, true, None) else {
+                    continue;
+                };
+                let mut field = self.var_name(&ty.to_string());
+                let ty = self.borrow_type(ty);
+                while vars.values().any(|var| *var == field) {
+                    field.push('_');
+                }
+                if ty.has_lifetime() {
+                    writeln!(writer, "    #[serde(borrow, flatten)]")?;
+                } else {
+                    writeln!(writer, "    #[serde(flatten)]")?;
+                }
+                self.note_type(&ty);
+                writeln!(writer, "    pub {field}: {ty},")?;
+                shape.push(format!("..{ty}"));
+                fields.push((field, ty, true));
+            }
+        }
+        for (prop, item) in &props {
+            let is_required = self.is_field_required(&type_name, schema, prop, item, swagger);
+            debug!("handling property `{prop}`, required: {is_required}");
+
+            match item {
+                Item::Reference(ref_) => {
+                    trace!("`{prop}` is a reference to `{ref_}`");
+                    let ty = self.property_type(&type_name, prop, item, is_required, swagger);
+                    let formatted_var = vars[prop.as_str()].clone();
+                    let mut serde_attrs = SerdeAttrs::default();
+                    if self.opts.always_rename || &&formatted_var != prop {
+                        serde_attrs.push_value("rename", prop);
+                    }
+                    if ty.has_lifetime() {
+                        serde_attrs.push("borrow");
+                    }
+                    serde_attrs.write(writer)?;
+                    self.note_type(&ty);
+                    writeln!(writer, "    pub {formatted_var}: {ty},")?;
+                    shape.push(field_shape(prop, is_required, &ty));
+                    let is_arg = !matches!(ty, rust::Type::Option(_));
+                    fields.push((formatted_var, ty, is_arg));
+                }
+                Item::Object(schema_item) => {
+                    trace!("`{prop}` is an object {schema_item:?}");
+                    let formatted_var = vars[prop.as_str()].clone();
+
+                    let ty = self.property_type(&type_name, prop, item, is_required, swagger);
+                    // inline enums are strings, except for query parameters
+                    let enum_model = (is_params && schema_item.is_string_enum())
+                        .then(|| {
+                            self.enum_models
+                                .get(&enum_key(schema_item, &self.prototype_options()))
+                        })
+                        .flatten();
+                    let ty = match enum_model {
+                        Some(enum_model) if is_required => rust::Type::Custom(enum_model.clone()),
+                        Some(enum_model) => {
+                            rust::Type::Option(Box::new(rust::Type::Custom(enum_model.clone())))
+                        }
+                        None => ty,
+                    };
+                    debug!("mapped type for `{name}` `{prop}` - {ty}");
+                    let double_option = self.is_double_option(schema_item, is_required, &ty);
+                    let ty = if double_option {
+                        rust::Type::Option(Box::new(ty))
+                    } else {
+                        ty
+                    };
+
+                    let mut serde_attrs = SerdeAttrs::default();
+                    if self.opts.always_rename || &&formatted_var != prop {
+                        serde_attrs.push_value("rename", prop);
+                    }
+                    for alias in &schema_item.x_aliases {
+                        serde_attrs.push_value("alias", alias);
+                    }
+                    if ty.has_lifetime() {
+                        serde_attrs.push("borrow");
+                    }
+
+                    if let Some(default) = &schema_item.default {
+                        let fn_name = format!(
+                            "default_{}_{}",
+                            self.var_name(&type_name),
+                            formatted_var.trim_end_matches('_')
+                        );
+                        let expr = self.default_value_expr(default, item, &ty, swagger);
+                        serde_attrs.push_value("default", &fn_name);
+                        if is_params {
+                            param_defaults.insert(formatted_var.clone(), fn_name.clone());
+                        }
+                        default_fns.push((fn_name, ty.clone(), expr));
+                    } else if self.opts.lenient_required_collections
+                        && matches!(
+                            ty,
+                            rust::Type::Vec(_) | rust::Type::Object(_) | rust::Type::Map(..)
+                        )
+                    {
+                        serde_attrs.push("default");
+                    }
+                    #[cfg(feature = "num-bigint")]
+                    match &ty {
+                        _ if !self.json_helpers() => {}
+                        rust::Type::BigInt => serde_attrs.push_value("with", "big_int_serde"),
+                        rust::Type::Option(inner) if matches!(**inner, rust::Type::BigInt) => {
+                            if schema_item.default.is_none() {
+                                serde_attrs.push("default");
+                            }
+                            serde_attrs.push_value("with", "big_int_serde::option");
+                        }
+                        _ => {}
+                    }
+                    let is_vec = match &ty {
+                        rust::Type::Option(ty) => matches!(**ty, rust::Type::Vec(_)),
+                        ty => matches!(ty, rust::Type::Vec(_)),
+                    };
+                    let joined = joined_collection_format(schema_item).filter(|_| is_vec);
+                    if double_option {
+                        serde_attrs.push("default");
+                        serde_attrs.push_value("with", "::serde_with::rust::double_option");
+                    } else if schema_item.write_only {
+                        serde_attrs.push("skip_deserializing");
+                    } else if let Some(format) = joined {
+                        if matches!(ty, rust::Type::Option(_)) && schema_item.default.is_none() {
+                            serde_attrs.push("default");
+                        }
+                        serde_attrs
+                            .push_value("deserialize_with", &format!("deserialize_{format}"));
+                    } else if matches!(ty, rust::Type::Vec(_)) {
+                        serde_attrs.push_value("deserialize_with", "deserialize_nonoptional_vec");
+                    } else if matches!(ty, rust::Type::Object(_) | rust::Type::Map(..)) {
+                        serde_attrs.push_value("deserialize_with", "deserialize_nonoptional_map");
+                    } else if matches!(&ty, rust::Type::Option(inner) if matches!(**inner, rust::Type::Cow))
+                    {
+                        if schema_item.default.is_none() {
+                            serde_attrs.push("default");
+                        }
+                        serde_attrs.push_value("deserialize_with", "deserialize_borrowed_option");
+                    } else if (self.opts.lenient_bools || schema_item.x_lenient_bool)
+                        && self.json_helpers()
+                    {
+                        match &ty {
+                            rust::Type::Bool => serde_attrs
+                                .push_value("deserialize_with", "deserialize_lenient_bool"),
+                            rust::Type::Option(inner) if matches!(**inner, rust::Type::Bool) => {
+                                if schema_item.default.is_none() {
+                                    serde_attrs.push("default");
+                                }
+                                serde_attrs.push_value(
+                                    "deserialize_with",
+                                    "deserialize_lenient_option_bool",
+                                );
+                            }
+                            _ => {}
+                        }
+                    }
+
+                    if let Some(format) = joined {
+                        serde_attrs.push_value("serialize_with", &format!("serialize_{format}"));
+                    }
+
+                    let skip_default = param_defaults
+                        .get(&formatted_var)
+                        .filter(|_| self.opts.omit_default_params);
+                    if schema_item.read_only {
+                        serde_attrs.push("skip_serializing");
+                    } else if let Some(default_fn) = skip_default {
+                        let fn_name = format!("is_{default_fn}");
+                        serde_attrs.push_value("skip_serializing_if", &fn_name);
+                        skip_fns.push((fn_name, default_fn.clone(), ty.clone()));
+                    } else if !is_required {
+                        serde_attrs.push_value("skip_serializing_if", "Option::is_none");
+                    }
+                    if is_params && schema_item.allow_empty_value {
+                        bare_keys.push(prop.to_string());
+                    }
+                    serde_attrs.write(writer)?;
+
+                    if let Some(descr) = &schema_item.description {
+                        self.print_doc_comment(descr, Some(4), writer)?;
+                    }
+                    if let Some(patterns) = key_patterns_doc(schema_item) {
+                        if schema_item.description.is_some() {
+                            writeln!(writer, "    ///")?;
+                        }
+                        self.print_doc_comment(patterns, Some(4), writer)?;
+                    }
+                    if let Some(values) = enum_values_doc(schema_item) {
+                        if schema_item.description.is_some() {
+                            writeln!(writer, "    ///")?;
+                        }
+                        self.print_doc_comment(values, Some(4), writer)?;
+                    }
+                    let is_date_time = match &ty {
+                        rust::Type::Option(ty) => matches!(**ty, rust::Type::DateTime),
+                        ty => matches!(ty, rust::Type::DateTime),
+                    };
+                    if is_date_time {
+                        if schema_item.description.is_some() {
+                            writeln!(writer, "    ///")?;
+                        }
+                        writeln!(
+                            writer,
+                            "    /// Converted from and to strings with `datetime_util`."
+                        )?;
+                    }
+
+                    self.note_type(&ty);
+                    writeln!(writer, "    pub {formatted_var}: {ty},")?;
+                    shape.push(field_shape(prop, is_required, &ty));
+                    let is_arg = !matches!(ty, rust::Type::Option(_));
+                    fields.push((formatted_var, ty, is_arg));
+                }
+            }
+        }
+        if self.opts.capture_unknown || open {
+            let field = extra_field_name(&vars);
+            let ty = rust::Type::Object(Box::new(rust::Type::Value));
+            writeln!(writer, "    #[serde(flatten)]")?;
+            writeln!(writer, "    pub {field}: {ty},")?;
+            shape.push(format!("..{ty}"));
+            fields.push((field, ty, false));
+        }
+        writeln!(writer, "}}\n")?;
+        if float_hash {
+            self.generate_float_hash(&type_name, &fields, writer)?;
+        }
+        if self.opts.non_exhaustive || self.opts.with_constructors {
+            self.generate_constructor(&type_name, &fields, writer)?;
+        }
+        if self.opts.accessors {
+            self.generate_accessors(&type_name, &fields, writer)?;
+        }
+        if self.opts.value_conversions && self.json_helpers() {
+            self.generate_value_conversions(&type_name, writer)?;
+        }
+        if self.opts.schema_hashes {
+            self.generate_schema_hash(&type_name, "struct", shape, writer)?;
+        }
+        if is_params {
+            self.generate_params_default(&type_name, &fields, &param_defaults, writer)?;
+            if self.json_helpers() {
+                self.generate_query_string(&type_name, &bare_keys, writer)?;
+            }
+        }
+        self.generated_models.push(type_name);
+
+        for (fn_name, ty, expr) in default_fns {
+            // defaults don't borrow from anything
+            let ty = ty.to_string().replace("'a", "'static");
+            writeln!(writer, "fn {fn_name}() -> {ty} {{\n    {expr}\n}}\n")?;
+        }
+        for (fn_name, default_fn, ty) in skip_fns {
+            let check = match ty {
+                rust::Type::Option(_) => format!("v.is_none() || *v == {default_fn}()"),
+                _ => format!("*v == {default_fn}()"),
+            };
+            let ty = ty.to_string().replace("'a", "'_");
+            writeln!(
+                writer,
+                "fn {fn_name}(v: &{ty}) -> bool {{\n    {check}\n}}\n"
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Writes `PartialEq`, `Eq` and `Hash` implementations comparing and hashing the floats of
+    /// `fields` with `FloatKey`, see `FloatHash::Bits`.
+    fn generate_float_hash(
+        &self,
+        type_name: &str,
+        fields: &[(String, rust::Type, bool)],
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        let lifetime = self.lifetime_of(type_name);
+        let eqs: Vec<_> = fields
+            .iter()
+            .map(|(field, ty, _)| {
+                if has_floats(ty) {
+                    format!("FloatKey::key_eq(&self.{field}, &other.{field})")
+                } else {
+                    format!("self.{field} == other.{field}")
+                }
+            })
+            .collect();
+        writeln!(
+            writer,
+            "/// Floats are compared with `FloatKey`, all `NaN`s are equal."
+        )?;
+        writeln!(
+            writer,
+            "impl{lifetime} PartialEq for {type_name}{lifetime} {{"
+        )?;
+        writeln!(writer, "    fn eq(&self, other: &Self) -> bool {{")?;
+        writeln!(writer, "        {}", eqs.join("\n            && "))?;
+        writeln!(writer, "    }}\n}}\n")?;
+        writeln!(writer, "impl{lifetime} Eq for {type_name}{lifetime} {{}}\n")?;
+        writeln!(
+            writer,
+            "/// Floats are hashed with `FloatKey`, agreeing with `PartialEq`."
+        )?;
+        writeln!(
+            writer,
+            "impl{lifetime} std::hash::Hash for {type_name}{lifetime} {{"
+        )?;
+        writeln!(
+            writer,
+            "    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {{"
+        )?;
+        for (field, ty, _) in fields {
+            if has_floats(ty) {
+                writeln!(writer, "        FloatKey::key_hash(&self.{field}, state);")?;
+            } else {
+                writeln!(
+                    writer,
+                    "        std::hash::Hash::hash(&self.{field}, state);"
+                )?;
+            }
+        }
+        writeln!(writer, "    }}\n}}\n")
+    }
+
+    /// Writes a `Default` implementation of the model of query parameters `type_name` if any of
+    /// them has a spec default, taken from the default functions of `defaults`. Other fields
+    /// start out empty, if one of them is required and not a collection none is written.
+    fn generate_params_default(
+        &self,
+        type_name: &str,
+        fields: &[(String, rust::Type, bool)],
+        defaults: &HashMap<String, String>,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        if defaults.is_empty() {
+            return Ok(());
+        }
+        let mut values = vec![];
+        for (name, ty, _) in fields {
+            let value = match (defaults.get(name), ty) {
+                (Some(default_fn), _) => format!("{default_fn}()"),
+                (None, rust::Type::Option(_)) => "None".to_string(),
+                (None, rust::Type::Vec(_) | rust::Type::Object(_) | rust::Type::Map(..)) => {
+                    "Default::default()".to_string()
+                }
+                (None, _) => {
+                    debug!("no `Default` for `{type_name}`, `{name}` is required");
+                    return Ok(());
+                }
+            };
+            values.push(format!("            {name}: {value},\n"));
+        }
+        let lifetime = self.lifetime_of(type_name);
+        writeln!(
+            writer,
+            "impl{lifetime} Default for {type_name}{lifetime} {{
+    fn default() -> Self {{
+        Self {{
+{}        }}
+    }}
+}}
+",
+            values.concat()
+        )
+    }
+
+    /// Writes a `to_query_string` method of the model of query parameters `type_name`. Empty
+    /// values of the parameters `bare_keys` allow are sent as their name alone.
+    fn generate_query_string(
+        &self,
+        type_name: &str,
+        bare_keys: &[String],
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        let bare_keys: Vec<_> = bare_keys.iter().map(|key| format!("{key:?}")).collect();
+        let lifetime = self.lifetime_of(type_name);
+        writeln!(
+            writer,
+            "impl{lifetime} {type_name}{lifetime} {{
+    /// The query string of these parameters, without the leading `?`.
+    pub fn to_query_string(&self) -> String {{
+        query_string(self, &[{}])
+    }}
+}}
+",
+            bare_keys.join(", ")
+        )
+    }
+
+    /// Writes a `new` constructor taking every required field as an argument, optional fields
+    /// start out as `None` and the captured unknown fields empty.
+    fn generate_constructor(
+        &self,
+        type_name: &str,
+        fields: &[(String, rust::Type, bool)],
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        let args: Vec<_> = fields
+            .iter()
+            .filter(|(_, _, is_arg)| *is_arg)
+            .map(|(name, ty, _)| format!("{name}: {ty}"))
+            .collect();
+        let lifetime = self.lifetime_of(type_name);
+        writeln!(writer, "impl{lifetime} {type_name}{lifetime} {{")?;
+        if args.len() > 7 {
+            writeln!(writer, "    #[allow(clippy::too_many_arguments)]")?;
+        }
+        writeln!(writer, "    pub fn new({}) -> Self {{", args.join(", "))?;
+        writeln!(writer, "        Self {{")?;
+        for (name, ty, is_arg) in fields {
+            match ty {
+                _ if *is_arg => writeln!(writer, "            {name},")?,
+                rust::Type::Option(_) => writeln!(writer, "            {name}: None,")?,
+                _ => writeln!(writer, "            {name}: Default::default(),")?,
+            }
+        }
+        writeln!(writer, "        }}\n    }}\n}}\n")
+    }
+
+    /// Writes the `SCHEMA_HASH` of a model from the lines describing its `shape`, which are
+    /// sorted first so only what they describe matters.
+    fn generate_schema_hash(
+        &mut self,
+        type_name: &str,
+        kind: &str,
+        mut shape: Vec<String>,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        shape.sort_unstable();
+        let hash = format!(
+            "{:016x}",
+            stable_hash(&format!("{kind}\n{}", shape.join("\n")))
+        );
+        let lifetime = self.lifetime_of(type_name);
+        writeln!(
+            writer,
+            "impl{lifetime} {type_name}{lifetime} {{
+    /// Changes whenever the fields or variants of this model do.
+    pub const SCHEMA_HASH: &str = \"{hash}\";
+}}
+"
+        )?;
+        self.schema_hashes.push((type_name.to_string(), hash));
+        Ok(())
+    }
+
+    /// Writes the `MODELS_HASH` combining the schema hashes of every model.
+    fn generate_models_hash(&self, writer: &mut Box<dyn std::io::Write>) -> std::io::Result<()> {
+        let mut hashes: Vec<_> = self
+            .schema_hashes
+            .iter()
+            .map(|(name, hash)| format!("{name}={hash}"))
+            .collect();
+        hashes.sort_unstable();
+        writeln!(
+            writer,
+            "/// Changes whenever the schema hash of any model does.
+pub const MODELS_HASH: &str = \"{:016x}\";
+",
+            stable_hash(&hashes.join("\n"))
+        )
+    }
+
+    /// Writes the conversions of a struct from and to a `serde_json::Value`. Borrowing models
+    /// can't be deserialized from a value they don't borrow, they only get `to_value`.
+    fn generate_value_conversions(
+        &self,
+        type_name: &str,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        let lifetime = self.lifetime_of(type_name);
+        if lifetime.is_empty() {
+            writeln!(
+                writer,
+                "impl TryFrom<serde_json::Value> for {type_name} {{
+    type Error = serde_json::Error;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {{
+        serde_json::from_value(value)
+    }}
+}}
+"
+            )?;
+        }
+        writeln!(
+            writer,
+            "impl{lifetime} {type_name}{lifetime} {{
+    pub fn to_value(&self) -> serde_json::Value {{
+        serde_json::to_value(self).expect(\"models serialize to JSON\")
+    }}
+}}
+"
+        )
+    }
+
+    /// Writes getters of optional fields, iterators over array fields and lookups of map fields.
+    /// Names taken by another field or method get a `_ref` suffix.
+    fn generate_accessors(
+        &self,
+        type_name: &str,
+        fields: &[(String, rust::Type, bool)],
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        let mut taken: Vec<String> = vec![];
+        if self.opts.non_exhaustive || self.opts.with_constructors {
+            taken.push("new".into());
+        }
+        if self.opts.value_conversions && self.json_helpers() {
+            taken.push("to_value".into());
+        }
+        let mut methods = vec![];
+        for (field, ty, _) in fields {
+            let mut name_method = |name: String| {
+                let mut name = name;
+                while taken.contains(&name)
+                    || fields.iter().any(|(f, _, _)| f != field && *f == name)
+                {
+                    name.push_str("_ref");
+                }
+                taken.push(name.clone());
+                name
+            };
+            let base = field.trim_end_matches('_');
+            let (inner, is_optional) = match ty {
+                rust::Type::Option(inner) => (&**inner, true),
+                ty => (ty, false),
+            };
+            if is_optional {
+                let name = name_method(field.clone());
+                let (inner, as_ref) = match inner {
+                    rust::Type::Boxed(inner) => (&**inner, "as_deref"),
+                    inner => (inner, "as_ref"),
+                };
+                methods.push(format!(
+                    "    pub fn {name}(&self) -> Option<&{inner}> {{\n        self.{field}.{as_ref}()\n    }}"
+                ));
+            }
+            let flatten = if is_optional { ".flatten()" } else { "" };
+            match inner {
+                rust::Type::Vec(item) => {
+                    let name = name_method(format!("{base}_iter"));
+                    methods.push(format!(
+                        "    pub fn {name}(&self) -> impl Iterator<Item = &{item}> {{\n        self.{field}.iter(){flatten}\n    }}"
+                    ));
+                }
+                rust::Type::Object(_) | rust::Type::Map(..) => {
+                    let (key, value) = match inner {
+                        rust::Type::Map(key, value) => (format!("&{key}"), value),
+                        rust::Type::Object(value) => ("&str".to_string(), value),
+                        _ => unreachable!(),
+                    };
+                    let name = name_method(format!("get_{base}"));
+                    let get = if is_optional {
+                        format!("self.{field}.as_ref().and_then(|map| map.get(key))")
+                    } else {
+                        format!("self.{field}.get(key)")
+                    };
+                    methods.push(format!(
+                        "    pub fn {name}(&self, key: {key}) -> Option<&{value}> {{\n        {get}\n    }}"
+                    ));
+                }
+                _ => {}
+            }
+        }
+        if methods.is_empty() {
+            return Ok(());
+        }
+        let lifetime = self.lifetime_of(type_name);
+        writeln!(writer, "impl{lifetime} {type_name}{lifetime} {{")?;
+        writeln!(writer, "{}", methods.join("\n\n"))?;
+        writeln!(writer, "}}\n")
+    }
+
+    /// Whether the field of `prop` is required. A required `writeOnly` field is skipped when
+    /// deserializing, so it's optional unless its type has a default to fill it with.
+    fn is_field_required(
+        &self,
+        type_name: &str,
+        schema: &Schema,
+        prop: &str,
+        item: &Item,
+        swagger: &Swagger<rust::Type>,
+    ) -> bool {
+        if !schema.is_required(prop, item) {
+            return false;
+        }
+        match item {
+            Item::Object(prop_schema) if prop_schema.write_only => self
+                .property_type(type_name, prop, item, true, swagger)
+                .is_default(),
+            _ => true,
+        }
+    }
+
+    fn property_type(
+        &self,
+        type_name: &str,
+        prop: &str,
+        item: &Item,
+        is_required: bool,
+        swagger: &Swagger<rust::Type>,
+    ) -> rust::Type {
+        if let Some(ty) = self.map_newtype_reference(item, is_required, swagger) {
+            return ty;
+        }
+        let ty = match item {
+            Item::Reference(ref_) => swagger.map_reference_type(ref_, is_required, Some(prop)),
+            Item::Object(schema) => {
+                let prop_ty_name = format!("{type_name}{prop}");
+                let is_required = is_required && !schema.x_nullable;
+                let ty = swagger.map_item_type(item, is_required, Some(&prop_ty_name));
+                ty.map(|ty| self.map_bytes(schema, ty))
+            }
+        };
+        // untyped properties hold any value, the required keys of open objects must be present
+        let ty = ty.unwrap_or_else(|| match item {
+            Item::Object(schema) if is_required && **schema == Schema::default() => {
+                rust::Type::Value
+            }
+            _ => rust::Type::Option(Box::new(rust::Type::Value)),
+        });
+        let ty = self.map_numbers(ty);
+        let ty = self.borrow_type(ty);
+        let type_name = format_type_name(type_name);
+        if !self
+            .recursive_fields
+            .get(&type_name)
+            .is_some_and(|props| props.contains(prop))
+        {
+            return ty;
+        }
+        debug!("boxing recursive field `{prop}` of `{type_name}`");
+        match ty {
+            rust::Type::Option(inner) => rust::Type::Option(Box::new(rust::Type::Boxed(inner))),
+            ty => rust::Type::Boxed(Box::new(ty)),
+        }
+    }
+
+    /// Whether helpers assuming the conventions of JSON APIs are used, see `WireFormat::Any`.
+    fn json_helpers(&self) -> bool {
+        self.opts.wire_format != WireFormat::Any
+    }
+
+    /// Maps the `Vec<u8>` of a binary string or file, or an array of them, to `serde_bytes::ByteBuf`
+    /// for MessagePack.
+    fn map_bytes(&self, schema: &Schema, ty: rust::Type) -> rust::Type {
+        let is_bytes = schema.is_binary()
+            || schema.is_array()
+                && matches!(&schema.items, Some(Item::Object(items)) if items.is_binary());
+        if self.opts.wire_format == WireFormat::MsgPack && is_bytes {
+            ty.with_byte_bufs()
+        } else {
+            ty
+        }
+    }
+
+    /// Type of the value a newtype wraps if a top level model of `schema` is generated as one,
+    /// see `CodegenOptions::newtypes`.
+    fn newtype_inner(&self, schema: &Schema, swagger: &Swagger<rust::Type>) -> Option<rust::Type> {
+        let schema = swagger.merge_all_of_schema(schema);
+        if !self.opts.newtypes && self.bounded_length(&schema).is_none() {
+            return None;
+        }
+        self.scalar_inner(&schema, swagger)
+    }
+
+    /// The name of the newtype generated for the scalar definition `name` with
+    /// `PrimitiveNames::Newtype`, if it is named like the type it maps to.
+    fn primitive_newtype_name(
+        &self,
+        name: &str,
+        schema: &Schema,
+        swagger: &Swagger<rust::Type>,
+    ) -> Option<String> {
+        if self.opts.primitive_names != PrimitiveNames::Newtype {
+            return None;
+        }
+        let inner = self.scalar_inner(&swagger.merge_all_of_schema(schema), swagger)?;
+        (inner.to_string() == format_type_name(name)).then(|| format!("{name}_"))
+    }
+
+    /// Type of the value a newtype of the scalar `schema` wraps, whether or not it is generated
+    /// as one.
+    fn scalar_inner(&self, schema: &Schema, swagger: &Swagger<rust::Type>) -> Option<rust::Type> {
+        use rust::Type::*;
+        if schema.ref_.is_some()
+            || schema.properties.is_some()
+            || schema.is_array()
+            || schema.is_string_enum()
+        {
+            return None;
+        }
+        let ty = swagger.map_schema_type(schema, None, true, None)?;
+        matches!(
+            ty,
+            I8 | U8 | I16 | U16 | I32 | U32 | I64 | U64 | ISize | USize | F32 | F64 | String | Bool
+        )
+        .then(|| self.map_numbers(ty))
+    }
+
+    /// Maximum length a newtype of the string `schema` enforces, see
+    /// `CodegenOptions::bounded_strings`.
+    fn bounded_length(&self, schema: &Schema) -> Option<usize> {
+        if !self.opts.bounded_strings || schema.type_() != Some("string") {
+            return None;
+        }
+        schema.max_length
+    }
+
+    /// Maps a reference to a model generated as a newtype, or an array of them, to the newtype.
+    /// References to scalar models otherwise map to the scalar type itself.
+    fn map_newtype_reference(
+        &self,
+        item: &Item,
+        is_required: bool,
+        swagger: &Swagger<rust::Type>,
+    ) -> Option<rust::Type> {
+        let ty = match item {
+            Item::Reference(ref_) => {
+                let schema = swagger.get_ref_schema(ref_)?;
+                let name = swagger.type_names().get(ref_);
+                match self.primitive_newtype_name(name, schema, swagger) {
+                    Some(name) => rust::Type::Custom(name),
+                    None => {
+                        self.newtype_inner(schema, swagger)?;
+                        rust::Type::Custom(name.to_string())
+                    }
+                }
+            }
+            Item::Object(schema) if schema.is_array() => {
+                let items = schema.items.as_ref()?;
+                if !items.is_reference() {
+                    return None;
+                }
+                let ty = self.map_newtype_reference(items, true, swagger)?;
+                rust::Type::Vec(Box::new(ty))
+            }
+            Item::Object(_) => return None,
+        };
+        Some(if is_required {
+            ty
+        } else {
+            rust::Type::Option(Box::new(ty))
+        })
+    }
+
+    fn ordered_floats(&self) -> bool {
+        #[cfg(feature = "ordered-float")]
+        return self.opts.float_eq == FloatEq::Ordered;
+        #[cfg(not(feature = "ordered-float"))]
+        false
+    }
+
+    fn var_name(&self, name: &str) -> String {
+        format_var_name_in(name, self.opts.edition)
+    }
+
+    /// Field names of the properties `props`, see `field_names`.
+    fn field_names<'a>(&self, props: &'a Items) -> HashMap<&'a str, String> {
+        field_names(props.0.keys(), |prop| self.var_name(prop))
+    }
+
+    /// Whether an optional property of type `ty` is an `Option<Option<T>>` serialized with
+    /// `serde_with::rust::double_option`, which an explicit `null` deserializes to `Some(None)`.
+    #[allow(unused_variables)]
+    fn is_double_option(&self, schema: &Schema, is_required: bool, ty: &rust::Type) -> bool {
+        #[cfg(feature = "serde-with")]
+        return (schema.x_explicit_null || self.opts.double_option && schema.x_nullable)
+            && !is_required
+            && schema.default.is_none()
+            && !schema.write_only
+            && matches!(ty, rust::Type::Option(_))
+            && !ty.has_lifetime();
+        #[cfg(not(feature = "serde-with"))]
+        {
+            if schema.x_explicit_null || self.opts.double_option && schema.x_nullable {
+                log::warn!(
+                    "explicit nulls need the `serde-with` feature, generating `{ty}` that can't \
+                     tell them apart from missing fields"
+                );
+            }
+            false
+        }
+    }
+
+    fn num_big_ints(&self) -> bool {
+        #[cfg(feature = "num-bigint")]
+        return self.opts.big_int == BigInt::NumBigInt;
+        #[cfg(not(feature = "num-bigint"))]
+        false
+    }
+
+    /// Applies `float_eq` and `big_int` to the numbers `ty` is composed of.
+    fn map_numbers(&self, ty: rust::Type) -> rust::Type {
+        #[cfg(feature = "num-bigint")]
+        let ty = if self.num_big_ints() {
+            ty.with_num_big_ints()
+        } else {
+            ty
+        };
+        #[cfg(feature = "ordered-float")]
+        if self.ordered_floats() {
+            return ty.with_ordered_floats();
+        }
+        ty
+    }
+
+    /// Whether a model of `item` can derive `Eq`, `Hash` and `Ord`, that is none of the types it
+    /// is composed of is a map, an untyped value or a float not wrapped in `OrderedFloat`.
+    fn is_totally_ordered(&self, item: &Item, swagger: &Swagger<rust::Type>) -> bool {
+        let mut refs = vec![];
+        self.item_ordered_parts(item, swagger, &mut refs) && self.refs_ordered(&refs, swagger)
+    }
+
+    fn is_schema_totally_ordered(&self, schema: &Schema, swagger: &Swagger<rust::Type>) -> bool {
+        let mut refs = vec![];
+        self.schema_ordered_parts(schema, swagger, &mut refs) && self.refs_ordered(&refs, swagger)
+    }
+
+    fn refs_ordered(&self, refs: &[String], swagger: &Swagger<rust::Type>) -> bool {
+        refs.iter().all(|ref_| {
+            swagger.get_ref_schema(ref_).is_some() && !self.unordered_refs.contains(ref_)
+        })
+    }
+
+    fn item_ordered_parts(
+        &self,
+        item: &Item,
+        swagger: &Swagger<rust::Type>,
+        refs: &mut Vec<String>,
+    ) -> bool {
+        match item {
+            Item::Reference(ref_) => {
+                refs.push(ref_.clone());
+                true
+            }
+            Item::Object(schema) => self.schema_ordered_parts(schema, swagger, refs),
+        }
+    }
+
+    /// `is_schema_totally_ordered` for `schema` and its inline schemas, adding the references
+    /// they hold to `refs` instead of following them.
+    fn schema_ordered_parts(
+        &self,
+        schema: &Schema,
+        swagger: &Swagger<rust::Type>,
+        refs: &mut Vec<String>,
+    ) -> bool {
+        let schema = swagger.merge_all_of_schema(schema);
+        if let Some(ref_) = &schema.ref_ {
+            refs.push(ref_.clone());
+            return true;
+        }
+        match schema.type_() {
+            Some("string" | "integer" | "boolean") => true,
+            Some("number") => {
+                self.ordered_floats()
+                    && matches!(schema.format.as_deref(), Some("double" | "float"))
+            }
+            Some("array") => schema
+                .items
+                .as_ref()
+                .is_some_and(|items| self.item_ordered_parts(items, swagger, refs)),
+            Some("object") | None => {
+                schema.is_empty_object()
+                    || schema.map_values().is_none()
+                        && schema.properties.as_ref().is_some_and(|props| {
+                            props
+                                .0
+                                .values()
+                                .all(|prop| self.item_ordered_parts(prop, swagger, refs))
+                        })
+            }
+            _ => false,
+        }
+    }
+
+    /// Whether the struct of `schema` implements `Hash` by hashing the bit patterns of its
+    /// floats, see `FloatHash::Bits`. Its other fields have to be hashable.
+    fn hashes_float_bits(&self, schema: &Schema, swagger: &Swagger<rust::Type>) -> bool {
+        if self.opts.float_hash != FloatHash::Bits
+            || self.ordered_floats()
+            || self.opts.capture_unknown
+            || !schema.x_rust_derive.iter().any(|derive| derive == "Hash")
+        {
+            return false;
+        }
+        let schema = swagger.merge_all_of_schema(schema);
+        if schema.is_open_object() || schema.map_values().is_some() {
+            return false;
+        }
+        let Some(props) = &schema.properties else {
+            return false;
+        };
+        props.0.values().any(is_float_item)
+            && props
+                .0
+                .values()
+                .all(|prop| is_float_item(prop) || self.is_totally_ordered(prop, swagger))
+    }
+
+    /// Whether a model of `item` can derive `Clone`, that is neither it nor any of the models
+    /// it holds is marked with `x-no-clone`. Every struct is left out with `--no-clone`.
+    fn is_schema_clone(&self, schema: &Schema, swagger: &Swagger<rust::Type>) -> bool {
+        let mut refs = vec![];
+        if !self.schema_clone_parts(schema, swagger, &mut refs) {
+            return false;
+        }
+        refs.iter().all(|ref_| !self.non_clone_refs.contains(ref_))
+    }
+
+    /// `is_schema_clone` for `schema` and its inline schemas, adding the references they hold
+    /// to `refs` instead of following them.
+    fn schema_clone_parts(
+        &self,
+        schema: &Schema,
+        swagger: &Swagger<rust::Type>,
+        refs: &mut Vec<String>,
+    ) -> bool {
+        let schema = swagger.merge_all_of_schema(schema);
+        if schema.x_no_clone || (self.opts.no_clone && schema.properties.is_some()) {
+            return false;
+        }
+        if let Some(ref_) = &schema.ref_ {
+            refs.push(ref_.clone());
+            return true;
+        }
+        refs.extend(
+            schema
+                .x_variants
+                .iter()
+                .flat_map(|variants| variants.refs())
+                .map(|(_, ref_)| ref_),
+        );
+        let props = schema.properties.iter().flat_map(|props| props.0.values());
+        let values = schema.map_values();
+        props
+            .chain(&schema.items)
+            .chain(&values)
+            .all(|item| match item {
+                Item::Reference(ref_) => {
+                    refs.push(ref_.clone());
+                    true
+                }
+                Item::Object(schema) => self.schema_clone_parts(schema, swagger, refs),
+            })
+    }
+
+    /// Builds a Rust expression evaluating to `value` of type `ty`. Scalars, collections and
+    /// generated structs or enums are constructed directly, anything else is deserialized from
+    /// an embedded JSON literal at runtime.
+    fn default_value_expr(
+        &mut self,
+        value: &Value,
+        item: &Item,
+        ty: &rust::Type,
+        swagger: &Swagger<rust::Type>,
+    ) -> String {
+        self.construct_value_expr(value, item, ty, swagger)
+            .unwrap_or_else(|| {
+                let json = serde_json::to_string(value).unwrap_or_default();
+                format!("serde_json::from_str({}).unwrap()", raw_string(&json))
+            })
+    }
+
+    fn construct_value_expr(
+        &mut self,
+        value: &Value,
+        item: &Item,
+        ty: &rust::Type,
+        swagger: &Swagger<rust::Type>,
+    ) -> Option<String> {
+        use rust::Type::*;
+        let expr = match ty {
+            Option(ty) => format!(
+                "Some({})",
+                self.construct_value_expr(value, item, ty, swagger)?
+            ),
+            Boxed(ty) => format!(
+                "Box::new({})",
+                self.construct_value_expr(value, item, ty, swagger)?
+            ),
+            String => format!("{:?}.to_string()", value.as_str()?),
+            BoxStr => format!("{:?}.into()", value.as_str()?),
+            Cow => format!("std::borrow::Cow::Borrowed({:?})", value.as_str()?),
+            Bool => value.as_bool()?.to_string(),
+            I8 | U8 | I16 | U16 | I32 | U32 | I64 | U64 | I128 | ISize | USize => {
+                if let Some(n) = value.as_i64() {
+                    n.to_string()
+                } else {
+                    value.as_u64()?.to_string()
+                }
+            }
+            #[cfg(feature = "num-bigint")]
+            BigInt => {
+                let n = match value.as_str() {
+                    Some(n) => n.to_string(),
+                    None => value
+                        .as_i64()
+                        .map(|n| n.to_string())
+                        .or_else(|| value.as_u64().map(|n| n.to_string()))?,
+                };
+                format!("{n:?}.parse::<num_bigint::BigInt>().unwrap()")
+            }
+            F32 | F64 => format!("{:?}", value.as_f64()?),
+            #[cfg(feature = "ordered-float")]
+            OrderedFloat(ty) => format!(
+                "ordered_float::OrderedFloat({})",
+                self.construct_value_expr(value, item, ty, swagger)?
+            ),
+            Vec(ty) => {
+                let schema = self.resolve_item_schema(item, swagger)?;
+                let items = schema.items.as_ref()?;
+                let values = value
+                    .as_sequence()?
+                    .iter()
+                    .map(|value| self.construct_value_expr(value, items, ty, swagger))
+                    .collect::<std::option::Option<std::vec::Vec<_>>>()?;
+                format!("vec![{}]", values.join(", "))
+            }
+            Object(ty) => {
+                let schema = self.resolve_item_schema(item, swagger)?;
+                let values = schema.map_values().or(schema.items.clone())?;
+                let entries = value
+                    .as_mapping()?
+                    .iter()
+                    .map(|(key, value)| {
+                        let value = self.construct_value_expr(value, &values, ty, swagger)?;
+                        Some(format!("({:?}.to_string(), {value})", key.as_str()?))
+                    })
+                    .collect::<std::option::Option<std::vec::Vec<_>>>()?;
+                format!("HashMap::from([{}])", entries.join(", "))
+            }
+            Custom(name) => {
+                let schema = self.resolve_item_schema(item, swagger)?;
+                let type_name = format_type_name(name);
+                self.referenced_types.insert(type_name.clone());
+                if schema.is_string_enum() {
+                    let value = value.as_str()?;
+                    let values = schema.enum_.iter().filter_map(|value| value.as_str());
+                    let (_, variant) = enum_variant_names(values, self.opts.enum_variant_case)
+                        .into_iter()
+                        .find(|(v, _)| *v == value)?;
+                    format!("{type_name}::{variant}")
+                } else if schema.is_array() {
+                    let items = schema.items.as_ref()?;
+                    let ty = swagger.map_item_type(items, true, Some(name))?;
+                    self.construct_value_expr(value, item, &Vec(Box::new(ty)), swagger)?
+                } else if schema.is_empty_object() {
+                    value.as_mapping().filter(|value| value.is_empty())?;
+                    type_name
+                } else if let Some(props) = &schema.properties {
+                    let value = value.as_mapping()?;
+                    let vars = self.field_names(props);
+                    let mut props: std::vec::Vec<_> = props.0.iter().collect();
+                    props.sort_unstable_by_key(|(k, _)| *k);
+                    let mut fields = vec![];
+                    for (prop, prop_item) in props {
+                        let is_required =
+                            self.is_field_required(&type_name, &schema, prop, prop_item, swagger);
+                        let prop_ty =
+                            self.property_type(&type_name, prop, prop_item, is_required, swagger);
+                        let field_value = match value.get(&crate::v2::Value::String(prop.clone())) {
+                            Some(value) => {
+                                self.construct_value_expr(value, prop_item, &prop_ty, swagger)?
+                            }
+                            None if matches!(prop_ty, Option(_)) => "None".into(),
+                            None if matches!(prop_ty, Vec(_) | Object(_) | Map(..)) => {
+                                "Default::default()".into()
+                            }
+                            None => return None,
+                        };
+                        fields.push(format!("{}: {field_value}", vars[prop.as_str()]));
+                    }
+                    format!("{type_name} {{ {} }}", fields.join(", "))
+                } else if let Some(inner) = self.newtype_inner(&schema, swagger) {
+                    let inner = self.construct_value_expr(value, item, &inner, swagger)?;
+                    format!("{type_name}({inner})")
+                } else {
+                    return None;
+                }
+            }
+            EmptyObject => {
+                value.as_mapping().filter(|value| value.is_empty())?;
+                "EmptyObject".into()
+            }
+            // keys would have to be constructed as enum variants
+            DateTime | Time | ByteBuf | Map(..) | Borrowed(_) | Value => return None,
+        };
+        Some(expr)
+    }
+
+    fn resolve_item_schema<'a>(
+        &self,
+        item: &'a Item,
+        swagger: &'a Swagger<rust::Type>,
+    ) -> Option<Cow<'a, Schema>> {
+        let schema = match item {
+            Item::Reference(ref_) => swagger.get_ref_schema(ref_)?,
+            Item::Object(schema) => schema,
+        };
+        Some(swagger.merge_all_of_schema(schema))
+    }
+
+    fn generate_array_schema(
+        &mut self,
+        name: &str,
+        schema: &Schema,
+        swagger: &Swagger<rust::Type>,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        debug!("handling array schema `{name}`");
+        if let Some(item) = &schema.items {
+            let ty = swagger.map_item_type(item, true, Some(name));
+            if ty.is_none() {
+                return Ok(());
+            }
+            let ty = ty.unwrap();
+            let ty = self.borrow_type(self.map_numbers(rust::Type::Vec(Box::new(ty))));
+            debug!("mapped type for `{name}` - {ty}");
+            let type_name = format_type_name(name);
+            let ty_str = ty.to_string();
+
+            if type_name == ty_str {
+                log::warn!("skipping type alias with same name `{type_name} == {ty_str}`");
+                return Ok(());
+            }
+            if self.generated_models.contains(&type_name) {
+                log::warn!(
+                    "skipping type alias `{type_name}`, a type with the same name already exists"
+                );
+                return Ok(());
+            }
+
+            self.print_description(schema, writer)?;
+            self.note_type(&ty);
+            let lifetime = lifetime_param(&ty);
+            writeln!(writer, "pub type {type_name}{lifetime} = {ty_str};\n")?;
+            self.generated_aliases.push(type_name.clone());
+            self.generated_models.push(type_name);
+        }
+        Ok(())
+    }
+
+    fn generate_enum_schema(
+        &mut self,
+        name: &str,
+        schema: &Schema,
+        swagger: &Swagger<rust::Type>,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        debug!("handling enum schema `{name}`");
+
+        let type_name = format_type_name(name);
+        // type declaration
+
+        let is_map_key = swagger
+            .map_key_types()
+            .iter()
+            .any(|ref_| format_type_name(swagger.type_names().get(ref_)) == type_name);
+        let mut derives = schema.x_rust_derive.clone();
+        if self.opts.ordered_enums {
+            derives.push("Ord".to_string());
+        }
+        self.print_derives(&type_name, true, true, is_map_key, false, &derives, writer)?;
+        self.print_description(schema, writer)?;
+        let case = self.opts.enum_variant_case;
+        let values = schema.enum_.iter().filter_map(|value| value.as_str());
+        let variants = enum_variant_names(values, case);
+        for (value, variant) in &variants {
+            if *variant != rust::format_enum_value_name_with(value, case) {
+                log::warn!(
+                    "value `{value}` of enum `{type_name}` has the same variant name as an earlier value, naming it `{variant}`"
+                );
+            }
+        }
+        let rename_all = if self.opts.enum_rename_all {
+            enum_rename_all(variants.iter().map(|(value, _)| *value), case)
+        } else {
+            None
+        };
+        match rename_all {
+            // variant names are the values already
+            Some("PascalCase") | None => {}
+            Some(rule) => writeln!(writer, "#[serde(rename_all = \"{rule}\")]")?,
+        }
+        if matches!(
+            case,
+            EnumVariantCase::ScreamingSnake | EnumVariantCase::Verbatim
+        ) {
+            writeln!(writer, "#[allow(non_camel_case_types)]")?;
+        }
+        writeln!(writer, "pub enum {type_name} {{")?;
+        for (val, variant) in &variants {
+            if rename_all.is_none() {
+                writeln!(writer, "    #[serde(rename = \"{val}\")]")?;
+            }
+            writeln!(writer, "{variant},")?;
+        }
+        writeln!(writer, "}}\n")?;
+
+        // implement AsRef<str>
+        writeln!(writer, "impl AsRef<str> for {type_name} {{")?;
+        writeln!(writer, "    fn as_ref(&self) -> &str {{")?;
+        writeln!(writer, "        match self {{")?;
+        for (val, variant) in &variants {
+            writeln!(writer, "            {type_name}::{variant} => \"{val}\",")?;
+        }
+        writeln!(writer, "        }}\n    }}\n}}\n")?;
+
+        writeln!(writer, "impl {type_name} {{")?;
+        writeln!(writer, "    /// Every variant, in the order of the spec.")?;
+        writeln!(writer, "    pub const VARIANTS: &[Self] = &[")?;
+        for (_, variant) in &variants {
+            writeln!(writer, "        {type_name}::{variant},")?;
+        }
+        writeln!(writer, "    ];\n")?;
+        writeln!(writer, "    pub fn iter() -> impl Iterator<Item = Self> {{")?;
+        writeln!(writer, "        Self::VARIANTS.iter().cloned()")?;
+        if self.opts.ordered_enums {
+            writeln!(writer, "    }}\n")?;
+            writeln!(
+                writer,
+                "    /// Position of the value in the spec, which variants are ordered by."
+            )?;
+            writeln!(writer, "    pub fn ordinal(&self) -> usize {{")?;
+            writeln!(writer, "        match self {{")?;
+            for (i, (_, variant)) in variants.iter().enumerate() {
+                writeln!(writer, "            {type_name}::{variant} => {i},")?;
+            }
+            writeln!(writer, "        }}")?;
+        }
+        writeln!(writer, "    }}\n}}\n")?;
+
+        self.print_display_impl(&type_name, "self.as_ref()", writer)?;
+        if self.opts.non_exhaustive {
+            let mut body = vec!["match s {".to_string()];
+            for (val, variant) in &variants {
+                body.push(format!("    {val:?} => Ok({type_name}::{variant}),"));
+            }
+            body.push(format!(
+                "    _ => Err(format!(\"unknown {type_name} value `{{s}}`\")),"
+            ));
+            body.push("}".to_string());
+            self.print_from_str_impl(&type_name, "String", &body, writer)?;
+        }
+        if self.opts.schema_hashes {
+            let shape = variants
+                .iter()
+                .map(|(value, _)| value.to_string())
+                .collect();
+            self.generate_schema_hash(&type_name, "enum", shape, writer)?;
+        }
+        let variants = variants.into_iter().map(|(_, variant)| variant).collect();
+        self.generated_enums.push((type_name.clone(), variants));
+        self.generated_models.push(type_name);
+        Ok(())
+    }
+
+    /// Writes an untagged enum for an `enum` with values of different kinds, with a variant for
+    /// each kind. The values themselves are only documented.
+    fn generate_mixed_enum_schema(
+        &mut self,
+        name: &str,
+        schema: &Schema,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        debug!("handling mixed enum schema `{name}`");
+        let type_name = format_type_name(name);
+        let kinds = schema.enum_kinds();
+        let variants: Vec<_> = kinds
+            .iter()
+            .map(|kind| match kind {
+                EnumKind::Integer => ("Int", rust::Type::I64),
+                EnumKind::Number => ("Float", self.map_numbers(rust::Type::F64)),
+                EnumKind::String => ("Str", rust::Type::String),
+                EnumKind::Boolean => ("Bool", rust::Type::Bool),
+            })
+            .collect();
+        let is_totally_ordered = !kinds.contains(&EnumKind::Number) || self.ordered_floats();
+        self.print_derives(
+            &type_name,
+            is_totally_ordered,
+            true,
+            false,
+            false,
+            &schema.x_rust_derive,
+            writer,
+        )?;
+        self.print_description(schema, writer)?;
+        if let Some(values) = enum_values_doc(schema) {
+            if schema.description.is_some() {
+                writeln!(writer, "///")?;
+            }
+            self.print_doc_comment(values, None, writer)?;
+        }
+        writeln!(writer, "#[serde(untagged)]")?;
+        writeln!(writer, "pub enum {type_name} {{")?;
+        for (variant, ty) in &variants {
+            writeln!(writer, "    {variant}({ty}),")?;
+        }
+        writeln!(writer, "}}\n")?;
+        if self.opts.schema_hashes {
+            let shape = variants.iter().map(|(_, ty)| ty.to_string()).collect();
+            self.generate_schema_hash(&type_name, "untagged enum", shape, writer)?;
+        }
+        self.generated_models.push(type_name);
+        Ok(())
+    }
+
+    /// Writes an internally tagged enum for a schema with `x-variants`, with a variant wrapping
+    /// each mapped definition.
+    fn generate_variants_schema(
         &mut self,
-        model: ModelPrototype,
+        name: &str,
+        schema: &Schema,
+        variants: &Variants,
         swagger: &Swagger<rust::Type>,
         writer: &mut Box<dyn std::io::Write>,
     ) -> std::io::Result<()> {
-        trace!("generating {} `{}`", model.schema.type_(), &model.name);
-        match &model.schema {
-            Item::Reference(ref_) => {
-                self.generate_reference_model(ref_, &model, swagger, writer)?
+        debug!("handling variants schema `{name}`");
+        let type_name = format_type_name(name);
+        let mut types = HashMap::new();
+        for (tag, ref_) in variants.refs() {
+            match swagger.map_reference_type(&ref_, true, None) {
+                Some(ty) => {
+                    types.insert(tag, (ref_, self.borrow_type(ty)));
+                }
+                None => log::warn!(
+                    "skipping variant `{tag}` of `{type_name}`, `{ref_}` is not a definition"
+                ),
             }
-            Item::Object(schema) => self.generate_object_model(schema, &model, swagger, writer)?,
         }
+        let is_totally_ordered = !types.is_empty()
+            && types
+                .values()
+                .all(|(ref_, _)| self.is_totally_ordered(&Item::Reference(ref_.clone()), swagger));
+        let is_clone = self.is_schema_clone(schema, swagger);
+        self.print_derives(
+            &type_name,
+            is_totally_ordered,
+            is_clone,
+            false,
+            false,
+            &schema.x_rust_derive,
+            writer,
+        )?;
+        self.print_description(schema, writer)?;
+        writeln!(writer, "#[serde(tag = \"{}\")]", variants.field)?;
+        let lifetime = self.lifetime_of(&type_name);
+        writeln!(writer, "pub enum {type_name}{lifetime} {{")?;
+        let mut written = vec![];
+        for (tag, variant) in enum_variant_names(
+            variants.mapping.keys().map(String::as_str),
+            EnumVariantCase::Default,
+        ) {
+            let Some((_, ty)) = types.get(tag) else {
+                continue;
+            };
+            writeln!(writer, "    #[serde(rename = \"{tag}\")]")?;
+            if ty.has_lifetime() {
+                writeln!(writer, "    {variant}(#[serde(borrow)] {ty}),")?;
+            } else {
+                writeln!(writer, "    {variant}({ty}),")?;
+            }
+            written.push((variant, ty.to_string()));
+        }
+        writeln!(writer, "}}\n")?;
+
+        // a conversion is only unambiguous for types that a single variant wraps
+        for (variant, ty) in &written {
+            if written.iter().filter(|(_, other)| other == ty).count() > 1 {
+                continue;
+            }
+            writeln!(
+                writer,
+                "impl{lifetime} From<{ty}> for {type_name}{lifetime} {{
+    fn from(value: {ty}) -> Self {{
+        Self::{variant}(value)
+    }}
+}}
+"
+            )?;
+        }
+        if self.opts.schema_hashes {
+            let shape = enum_variant_names(
+                variants.mapping.keys().map(String::as_str),
+                EnumVariantCase::Default,
+            )
+            .into_iter()
+            .filter_map(|(tag, _)| Some(format!("{tag}: {}", types.get(tag)?.1)))
+            .collect();
+            let kind = format!("variants tagged {}", variants.field);
+            self.generate_schema_hash(&type_name, &kind, shape, writer)?;
+        }
+        self.generated_models.push(type_name);
         Ok(())
     }
 
-    fn generate_helpers(
+    /// Writes a tuple struct wrapping a value of type `inner` together with conversions from the
+    /// value, from string slices if it is a string, and `Display`.
+    fn generate_newtype(
         &mut self,
-        _swagger: &Swagger<rust::Type>,
+        type_name: &str,
+        schema: &Schema,
+        inner: rust::Type,
         writer: &mut Box<dyn std::io::Write>,
     ) -> std::io::Result<()> {
-        write!(
+        debug!("handling newtype `{type_name}` wrapping {inner}");
+        self.print_derives(
+            type_name,
+            !matches!(inner, rust::Type::F32 | rust::Type::F64),
+            !self.opts.no_clone && !schema.x_no_clone,
+            false,
+            false,
+            &schema.x_rust_derive,
             writer,
-            r#"
-fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
-    d: D,
-) -> Result<Vec<T>, D::Error> {{
-    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
-}}
+        )?;
+        self.print_description(schema, writer)?;
+        if let Some(max_length) = self.bounded_length(schema) {
+            return self.generate_bounded_string(type_name, max_length, writer);
+        }
+        writeln!(writer, "#[serde(transparent)]")?;
+        writeln!(writer, "pub struct {type_name}(pub {inner});\n")?;
+        self.note_type(&inner);
 
-fn deserialize_nonoptional_map<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
-    d: D,
-) -> Result<HashMap<String, T>, D::Error> {{
-    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+        writeln!(
+            writer,
+            "impl From<{inner}> for {type_name} {{
+    fn from(value: {inner}) -> Self {{
+        Self(value)
+    }}
 }}
-            "#
-        )
+"
+        )?;
+        if matches!(inner, rust::Type::String) {
+            writeln!(
+                writer,
+                "impl From<&str> for {type_name} {{
+    fn from(value: &str) -> Self {{
+        Self(value.to_string())
+    }}
+}}
+"
+            )?;
+            self.print_from_str_impl(
+                type_name,
+                "std::convert::Infallible",
+                &["Ok(Self(s.to_string()))".to_string()],
+                writer,
+            )?;
+        }
+        self.print_display_impl(type_name, "self.0", writer)?;
+        if self.opts.schema_hashes {
+            self.generate_schema_hash(type_name, "newtype", vec![inner.to_string()], writer)?;
+        }
+        self.generated_models.push(type_name.to_string());
+        Ok(())
     }
-}
 
-impl Codegen {
-    fn generate_reference_model(
+    /// Writes an object that can't have any keys as a unit struct.
+    fn generate_empty_object(
         &mut self,
-        ref_: &str,
-        model: &ModelPrototype,
-        swagger: &Swagger<rust::Type>,
+        name: &str,
+        schema: &Schema,
         writer: &mut Box<dyn std::io::Write>,
     ) -> std::io::Result<()> {
-        if let Some(schema) = swagger.get_ref_schema(ref_) {
-            let schema = swagger.merge_all_of_schema(schema.clone());
-            if !schema.is_object() {
-                return Ok(());
-            }
-            if let Some(ty) = swagger.map_reference_type(&ref_, true, Some(&model.name)) {
-                let type_name = format_type_name(&model.name);
-                let ty_str = ty.to_string();
-
-                if type_name == ty_str {
-                    log::warn!("skipping type alias with same name `{type_name} == {ty_str}`");
-                    return Ok(());
-                }
-
-                if self.generated_models.contains(&type_name) {
-                    log::warn!(
-                                    "skipping type alias `{type_name}`, a type with the same name already exists"
-                                );
-                    return Ok(());
-                }
-                self.print_description(&schema, writer)?;
-                writeln!(writer, "pub type {type_name} = {ty_str};\n")?;
-                self.generated_models.push(type_name);
+        let type_name = format_type_name(name);
+        debug!("handling empty object `{type_name}`");
+        let mut derives = EMPTY_OBJECT_DERIVES.to_vec();
+        if self.opts.no_clone || schema.x_no_clone {
+            derives.retain(|derive| !matches!(*derive, "Clone" | "Copy"));
+        }
+        for derive in &schema.x_rust_derive {
+            if !derives.contains(&derive.as_str()) {
+                derives.push(derive);
             }
         }
+        writeln!(writer, "#[derive({})]", derives.join(", "))?;
+        self.print_description(schema, writer)?;
+        writeln!(writer, "pub struct {type_name};\n")?;
+        writeln!(writer, "{}", empty_object_impls(&type_name))?;
+        if self.opts.schema_hashes {
+            self.generate_schema_hash(&type_name, "struct", vec![], writer)?;
+        }
+        self.generated_models.push(type_name);
         Ok(())
     }
 
-    fn generate_object_model(
+    /// Whether `schema` is generated as a newtype of a `serde_json::Map`, see
+    /// `CodegenOptions::dynamic_maps`.
+    fn is_dynamic_map(&self, schema: &Schema, swagger: &Swagger<rust::Type>) -> bool {
+        if !self.opts.dynamic_maps || !self.json_helpers() || schema.x_key_type.is_some() {
+            return false;
+        }
+        match &schema.additional_properties {
+            Some(values) => matches!(
+                swagger.map_item_type(values, true, None),
+                None | Some(rust::Type::Value)
+            ),
+            None => false,
+        }
+    }
+
+    fn generate_dynamic_map(
         &mut self,
+        name: &str,
         schema: &Schema,
-        model: &ModelPrototype,
         swagger: &Swagger<rust::Type>,
         writer: &mut Box<dyn std::io::Write>,
     ) -> std::io::Result<()> {
-        let schema = swagger.merge_all_of_schema(schema.clone());
-        self.generate_schema(
-            &model.name,
-            model.parent_name.as_deref(),
-            &schema,
-            swagger,
+        let type_name = format_type_name(name);
+        debug!("handling dynamic map `{type_name}`");
+        let mut derives = vec!["Default".to_string()];
+        derives.extend(schema.x_rust_derive.iter().cloned());
+        self.print_derives(
+            &type_name,
+            false,
+            !self.opts.no_clone && !schema.x_no_clone,
+            false,
+            false,
+            &derives,
             writer,
-        )
+        )?;
+        self.print_description(schema, writer)?;
+        writeln!(writer, "#[serde(transparent)]")?;
+        writeln!(
+            writer,
+            "pub struct {type_name}(pub serde_json::Map<String, Value>);\n"
+        )?;
+        writeln!(
+            writer,
+            "impl From<serde_json::Map<String, Value>> for {type_name} {{
+    fn from(value: serde_json::Map<String, Value>) -> Self {{
+        Self(value)
+    }}
+}}
+"
+        )?;
+
+        let mut getters = vec![];
+        let props = schema.properties.clone().unwrap_or_default();
+        let methods = self.field_names(&props);
+        for (prop, item) in self.opts.field_order.props(&props) {
+            let method = &methods[prop.as_str()];
+            let get = format!("self.0.get({prop:?})");
+            let ty = match self.property_type(name, prop, item, true, swagger) {
+                rust::Type::Boxed(ty) => *ty,
+                ty => ty,
+            };
+            let (ret, body) = match ty {
+                rust::Type::String | rust::Type::BoxStr | rust::Type::Cow => {
+                    ("&str".to_string(), format!("{get}.and_then(Value::as_str)"))
+                }
+                rust::Type::Bool => (
+                    "bool".to_string(),
+                    format!("{get}.and_then(Value::as_bool)"),
+                ),
+                rust::Type::I64 => ("i64".to_string(), format!("{get}.and_then(Value::as_i64)")),
+                rust::Type::U64 => ("u64".to_string(), format!("{get}.and_then(Value::as_u64)")),
+                rust::Type::F64 => ("f64".to_string(), format!("{get}.and_then(Value::as_f64)")),
+                rust::Type::Value | rust::Type::Option(_) => ("&Value".to_string(), get),
+                ty if ty.has_lifetime() => {
+                    debug!("no getter of `{prop}` of `{type_name}`, `{ty}` borrows from the input");
+                    continue;
+                }
+                ty => {
+                    self.note_type(&ty);
+                    (
+                        ty.to_string(),
+                        format!(
+                            "{get}.and_then(|value| serde_json::from_value(value.clone()).ok())"
+                        ),
+                    )
+                }
+            };
+            getters.push(format!(
+                "    pub fn {method}(&self) -> Option<{ret}> {{\n        {body}\n    }}"
+            ));
+        }
+        if !getters.is_empty() {
+            writeln!(
+                writer,
+                "impl {type_name} {{\n{}\n}}\n",
+                getters.join("\n\n")
+            )?;
+        }
+        if self.opts.schema_hashes {
+            let shape = vec!["serde_json::Map<String, Value>".to_string()];
+            self.generate_schema_hash(&type_name, "newtype", shape, writer)?;
+        }
+        self.generated_models.push(type_name);
+        Ok(())
     }
 
-    fn generate_schema(
+    /// Writes the body of a newtype of a string of at most `max_length` characters, checked by
+    /// its `TryFrom` implementations. Derives and docs are written by `generate_newtype`.
+    fn generate_bounded_string(
         &mut self,
-        name: &str,
-        parent_name: Option<&str>,
-        schema: &Schema,
-        swagger: &Swagger<rust::Type>,
+        type_name: &str,
+        max_length: usize,
         writer: &mut Box<dyn std::io::Write>,
     ) -> std::io::Result<()> {
-        debug!("handling schema {name}, parent: {parent_name:?}");
-        trace!("{schema:?}");
-        let name = if name.is_empty() {
-            schema.name().unwrap_or(
-                parent_name
-                    .map(|parent_name| format!("{}InlineItem", parent_name))
-                    .unwrap_or(name.to_string()),
-            )
-        } else {
-            name.to_string()
-        };
-        let type_name = format_type_name(&name);
-        trace!("mapped name: {name}, type name: {type_name}");
+        writeln!(writer, "#[serde(try_from = \"String\")]")?;
+        writeln!(writer, "pub struct {type_name}(String);\n")?;
+        writeln!(
+            writer,
+            r#"impl {type_name} {{
+    pub const MAX_LENGTH: usize = {max_length};
 
-        if schema.properties.is_some() {
-            self.generate_props_schema(&name, schema, swagger, writer)?
-        } else if schema.is_array() {
-            self.generate_array_schema(&name, schema, swagger, writer)?
-        } else if schema.is_string_enum() {
-            self.generate_enum_schema(&name, schema, swagger, writer)?
-        } else if let Some(ref_) = schema.ref_.as_deref() {
-            error!("got unhandled reference schema {ref_}");
-        } else if let Some(ty) = swagger.map_schema_type(schema, None, true, Some(&name)) {
-            debug!("handling basic type schema {type_name} = {ty}");
-            let ty_str = ty.to_string();
+    pub fn as_str(&self) -> &str {{
+        &self.0
+    }}
 
-            if type_name == ty_str {
-                log::warn!("skipping type alias with same name `{type_name} == {ty_str}`");
-                return Ok(());
-            }
-            if self.generated_models.contains(&type_name) {
-                log::warn!(
-                    "skipping type alias `{type_name}`, a type with the same name already exists"
-                );
-                return Ok(());
-            }
+    pub fn into_inner(self) -> String {{
+        self.0
+    }}
+}}
 
-            if let Some(description) = &schema.description {
-                self.print_doc_comment(description, None, writer)?;
-            }
-            writeln!(writer, "pub type {type_name} = {};\n", ty.to_string())?;
-            self.generated_models.push(type_name);
-        } else {
-            error!("unhandled schema {schema:?}");
-        }
+impl TryFrom<String> for {type_name} {{
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {{
+        let length = value.chars().count();
+        if length > Self::MAX_LENGTH {{
+            return Err(format!(
+                "{type_name} is at most {{}} characters long, got {{length}}",
+                Self::MAX_LENGTH
+            ));
+        }}
+        Ok(Self(value))
+    }}
+}}
+
+impl TryFrom<&str> for {type_name} {{
+    type Error = String;
 
+    fn try_from(value: &str) -> Result<Self, Self::Error> {{
+        Self::try_from(value.to_string())
+    }}
+}}
+
+impl AsRef<str> for {type_name} {{
+    fn as_ref(&self) -> &str {{
+        &self.0
+    }}
+}}
+
+impl From<{type_name}> for String {{
+    fn from(value: {type_name}) -> Self {{
+        value.0
+    }}
+}}
+"#
+        )?;
+        self.print_from_str_impl(
+            type_name,
+            "String",
+            &["Self::try_from(s)".to_string()],
+            writer,
+        )?;
+        self.print_display_impl(type_name, "self.0", writer)?;
+        if self.opts.schema_hashes {
+            let shape = vec![format!("String, max length {max_length}")];
+            self.generate_schema_hash(type_name, "newtype", shape, writer)?;
+        }
+        self.generated_models.push(type_name.to_string());
         Ok(())
     }
 
-    fn generate_props_schema(
-        &mut self,
-        name: &str,
-        schema: &Schema,
-        swagger: &Swagger<rust::Type>,
+    /// Writes a `Display` implementation of `type_name` printing the expression `expr`.
+    fn print_display_impl(
+        &self,
+        type_name: &str,
+        expr: &str,
         writer: &mut Box<dyn std::io::Write>,
     ) -> std::io::Result<()> {
-        debug!("handling property schema `{name}`");
-        let props = schema.properties.as_ref().unwrap();
-        let type_name = format_type_name(&name);
-        self.print_derives(&schema, writer)?;
-        self.print_description(&schema, writer)?;
+        writeln!(
+            writer,
+            r#"impl std::fmt::Display for {type_name} {{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
+        write!(f, "{{}}", {expr})
+    }}
+}}
+"#
+        )
+    }
 
-        writeln!(writer, "pub struct {} {{", type_name)?;
-        let mut props: Vec<_> = props.0.iter().collect();
-        props.sort_unstable_by_key(|(k, _)| *k);
-        for (prop, item) in &props {
-            let is_required = schema.required.contains(prop);
-            debug!("handling property `{prop}`, required: {is_required}");
+    /// Writes a `FromStr` implementation of `type_name` with the error type `err` and the lines
+    /// of `body` as the body of `from_str`.
+    fn print_from_str_impl(
+        &self,
+        type_name: &str,
+        err: &str,
+        body: &[String],
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        writeln!(writer, "impl std::str::FromStr for {type_name} {{")?;
+        writeln!(writer, "    type Err = {err};\n")?;
+        writeln!(
+            writer,
+            "    fn from_str(s: &str) -> Result<Self, Self::Err> {{"
+        )?;
+        for line in body {
+            writeln!(writer, "        {line}")?;
+        }
+        writeln!(writer, "    }}\n}}\n")
+    }
 
-            match item {
-                Item::Reference(ref_) => {
-                    trace!("`{prop}` is a reference to `ref_`");
-                    let ty = if let Some(ty) =
-                        swagger.map_reference_type(ref_, is_required, Some(prop))
-                    {
-                        ty
-                    } else {
-                        rust::Type::Option(Box::new(rust::Type::Value))
-                    };
-                    let formatted_var = format_var_name(prop);
-                    if &&formatted_var != prop {
-                        writeln!(writer, "    #[serde(rename = \"{prop}\")]")?;
-                    }
-                    writeln!(writer, "    pub {formatted_var}: {ty},")?;
+    /// Writes a test for every response with a JSON example checking that the example
+    /// deserializes into the model of the response and survives serializing it again.
+    fn generate_example_tests(
+        &self,
+        swagger: &Swagger<rust::Type>,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        if self.opts.use_go_packages {
+            log::warn!("examples are not emitted as tests when grouping models into packages");
+            return Ok(());
+        }
+        let mut examples = vec![];
+        let mut responses: Vec<_> = swagger.responses.iter().flat_map(|r| &r.0).collect();
+        responses.sort_unstable_by_key(|(name, _)| *name);
+        for (name, response) in responses {
+            if let Response::Object(response) = response {
+                examples.push((name.clone(), response));
+            }
+        }
+        for (path, method, op) in swagger.operations() {
+            let mut responses: Vec<_> = op.responses.0.iter().collect();
+            responses.sort_unstable_by_key(|(code, _)| *code);
+            for (code, response) in responses {
+                if let Response::Object(response) = response {
+                    examples.push((
+                        response_model_name::<rust::Type>(path, method, op, code),
+                        response,
+                    ));
                 }
-                it @ Item::Object(item) => {
-                    trace!("`{prop}` is an object {item:?}");
-                    let formatted_var = format_var_name(prop);
-
-                    let prop_ty_name = format!("{type_name}{prop}");
-
-                    let ty = if let Some(ty) =
-                        swagger.map_item_type(it, is_required, Some(&prop_ty_name))
-                    {
-                        ty
-                    } else {
-                        rust::Type::Option(Box::new(rust::Type::Value))
-                    };
-                    debug!("mapped type for `{name}` `{prop}` - {ty}");
-
-                    if &&formatted_var != prop {
-                        writeln!(writer, "    #[serde(rename = \"{prop}\")]")?;
-                    }
+            }
+        }
 
-                    if matches!(ty, rust::Type::Vec(_) | rust::Type::Object(_)) {
-                        writeln!(writer, "    #[serde(default)]")?;
-                    }
-                    if matches!(ty, rust::Type::Vec(_)) {
-                        writeln!(
-                            writer,
-                            "    #[serde(deserialize_with = \"deserialize_nonoptional_vec\")]"
-                        )?;
-                    }
-                    if matches!(ty, rust::Type::Object(_)) {
-                        writeln!(
-                            writer,
-                            "    #[serde(deserialize_with = \"deserialize_nonoptional_map\")]"
-                        )?;
-                    }
+        let mut tests = vec![];
+        for (name, response) in examples {
+            let Some(example) = response.json_example() else {
+                continue;
+            };
+            let type_name = format_type_name(&name);
+            if response.schema.is_none() || !self.generated_models.contains(&type_name) {
+                log::warn!("no model was generated for the example of response `{name}`");
+                continue;
+            }
+            let json = serde_json::to_string(example).unwrap_or_default();
+            tests.push((type_name, json));
+        }
+        if tests.is_empty() {
+            return Ok(());
+        }
 
-                    if !is_required {
-                        writeln!(
-                            writer,
-                            "    #[serde(skip_serializing_if = \"Option::is_none\")]"
-                        )?;
-                    }
+        writeln!(writer, "#[cfg(test)]\nmod examples {{\n    use super::*;")?;
+        for (type_name, json) in tests {
+            write!(
+                writer,
+                r#"
+    #[test]
+    fn {}_example() {{
+        let model: {type_name} = serde_json::from_str({}).unwrap();
+        let json = serde_json::to_string(&model).unwrap();
+        assert_eq!(serde_json::from_str::<{type_name}>(&json).unwrap(), model);
+    }}
+"#,
+                self.var_name(&type_name),
+                raw_string(&json),
+            )?;
+        }
+        writeln!(writer, "}}")
+    }
 
-                    if let Some(descr) = &item.description {
-                        self.print_doc_comment(descr, Some(4), writer)?;
-                    }
+    /// Writes a test for every string enum checking that each variant serializes to the value
+    /// returned by `as_ref` and deserializes back to the same variant.
+    fn generate_enum_tests(&self, writer: &mut Box<dyn std::io::Write>) -> std::io::Result<()> {
+        if self.opts.use_go_packages {
+            log::warn!("enum round trip tests are not emitted when grouping models into packages");
+            return Ok(());
+        }
+        if self.generated_enums.is_empty() {
+            return Ok(());
+        }
 
-                    writeln!(writer, "    pub {formatted_var}: {ty},")?;
-                }
+        writeln!(
+            writer,
+            "#[cfg(test)]\nmod enum_round_trips {{\n    use super::*;"
+        )?;
+        for (type_name, variants) in &self.generated_enums {
+            if variants.is_empty() {
+                continue;
             }
+            let variants: Vec<_> = variants
+                .iter()
+                .map(|variant| format!("{type_name}::{variant}"))
+                .collect();
+            write!(
+                writer,
+                r#"
+    #[test]
+    fn {}_round_trip() {{
+        for value in [{}] {{
+            let json = serde_json::to_string(&value).unwrap();
+            assert_eq!(json, serde_json::to_string(value.as_ref()).unwrap());
+            assert_eq!(serde_json::from_str::<{type_name}>(&json).unwrap(), value);
+        }}
+    }}
+"#,
+                self.var_name(type_name),
+                variants.join(", "),
+            )?;
         }
-        self.generated_models.push(type_name);
-        writeln!(writer, "}}\n")
+        writeln!(writer, "}}")
     }
 
-    fn generate_array_schema(
-        &mut self,
-        name: &str,
-        schema: &Schema,
+    /// Writes a `(method, path)` constant for every operation, named like `operation_ident`.
+    fn generate_operation_consts(
+        &self,
         swagger: &Swagger<rust::Type>,
         writer: &mut Box<dyn std::io::Write>,
     ) -> std::io::Result<()> {
-        debug!("handling array schema `{name}`");
-        if let Some(item) = &schema.items {
-            let ty = swagger.map_item_type(&item, true, Some(&name));
-            if ty.is_none() {
-                return Ok(());
-            }
-            let ty = ty.unwrap();
-            let ty = rust::Type::Vec(Box::new(ty));
-            debug!("mapped type for `{name}` - {ty}");
-            let type_name = format_type_name(name);
-            let ty_str = ty.to_string();
-
-            if type_name == ty_str {
-                log::warn!("skipping type alias with same name `{type_name} == {ty_str}`");
-                return Ok(());
-            }
-            if self.generated_models.contains(&type_name) {
-                log::warn!(
-                    "skipping type alias `{type_name}`, a type with the same name already exists"
-                );
-                return Ok(());
+        debug!("generating operations module");
+        writeln!(writer, "pub mod operations {{")?;
+        let mut names = HashSet::new();
+        for (path, method, op) in swagger.operations() {
+            let name = operation_ident(path, method, op).to_uppercase();
+            if !names.insert(name.clone()) {
+                log::warn!("skipping operation `{method} {path}`, `{name}` is already used");
+                continue;
             }
-
-            self.print_description(&schema, writer)?;
-            writeln!(writer, "pub type {type_name} = {ty_str};\n")?;
-            self.generated_models.push(type_name);
+            let method = method.to_uppercase();
+            writeln!(
+                writer,
+                "    pub const {name}: (&str, &str) = ({method:?}, {path:?});"
+            )?;
         }
-        Ok(())
+        writeln!(writer, "}}\n")
     }
 
-    fn generate_enum_schema(
-        &mut self,
-        name: &str,
-        schema: &Schema,
-        _swagger: &Swagger<rust::Type>,
+    fn generate_api_meta(
+        &self,
+        swagger: &Swagger<rust::Type>,
         writer: &mut Box<dyn std::io::Write>,
     ) -> std::io::Result<()> {
-        debug!("handling enum schema `{name}`");
+        debug!("generating api_meta module");
+        let operations: Vec<_> = swagger.operations().collect();
 
-        let type_name = format_type_name(&name);
-        // type declaration
+        writeln!(writer, "pub mod api_meta {{")?;
+        writeln!(
+            writer,
+            "    /// Tags declared by the spec as `(name, description)`."
+        )?;
+        writeln!(writer, "    pub const TAGS: &[(&str, &str)] = &[")?;
+        for tag in &swagger.tags {
+            let description = tag.description.as_deref().unwrap_or_default();
+            writeln!(writer, "        ({:?}, {description:?}),", tag.name)?;
+        }
+        writeln!(writer, "    ];\n")?;
 
-        self.print_derives(&schema, writer)?;
-        self.print_description(&schema, writer)?;
-        writeln!(writer, "pub enum {type_name} {{")?;
-        for enum_value in &schema.enum_ {
-            if let Some(val) = enum_value.as_str() {
-                writeln!(writer, "    #[serde(rename = \"{val}\")]")?;
-                writeln!(writer, "{},", format_enum_value_name(val))?;
+        writeln!(
+            writer,
+            "    /// External documentation of tags as `(name, url)`."
+        )?;
+        writeln!(writer, "    pub const TAG_DOCS: &[(&str, &str)] = &[")?;
+        for tag in &swagger.tags {
+            if let Some(docs) = &tag.external_docs {
+                writeln!(writer, "        ({:?}, {:?}),", tag.name, docs.url)?;
             }
         }
-        writeln!(writer, "}}\n")?;
+        writeln!(writer, "    ];\n")?;
 
-        // implement AsRef<str>
-        writeln!(writer, "impl AsRef<str> for {type_name} {{")?;
-        writeln!(writer, "    fn as_ref(&self) -> &str {{")?;
-        writeln!(writer, "        match self {{")?;
-        for enum_value in &schema.enum_ {
-            if let Some(val) = enum_value.as_str() {
-                writeln!(
-                    writer,
-                    "            {type_name}::{} => \"{val}\",",
-                    format_enum_value_name(val)
-                )?;
-            }
+        writeln!(
+            writer,
+            "    /// Operations of the spec as `(method, path, operation_id, summary)`."
+        )?;
+        writeln!(
+            writer,
+            "    pub const OPERATIONS: &[(&str, &str, &str, &str)] = &["
+        )?;
+        for (path, method, op) in &operations {
+            let operation_id = op.operation_id.as_deref().unwrap_or_default();
+            let summary = op.summary.as_deref().unwrap_or_default();
+            writeln!(
+                writer,
+                "        ({method:?}, {path:?}, {operation_id:?}, {summary:?}),"
+            )?;
         }
-        writeln!(writer, "        }}\n    }}\n}}\n")?;
+        writeln!(writer, "    ];\n")?;
 
-        // implement Display
         writeln!(
             writer,
-            r#"impl std::fmt::Display for {type_name} {{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {{
-        write!(f, "{{}}", self.as_ref())
-    }}
-}}
-"#
+            "    /// External documentation of operations as `(operation_id, url)`."
         )?;
-        self.generated_models.push(type_name);
-        Ok(())
+        writeln!(writer, "    pub const OPERATION_DOCS: &[(&str, &str)] = &[")?;
+        for (_, _, op) in &operations {
+            if let (Some(operation_id), Some(docs)) = (&op.operation_id, &op.external_docs) {
+                writeln!(writer, "        ({operation_id:?}, {:?}),", docs.url)?;
+            }
+        }
+        writeln!(writer, "    ];")?;
+        writeln!(writer, "}}\n")
     }
 
+    /// Writes the derives of the model `type_name`, followed by the `extra_derives` of its
+    /// `x-rust-derive` extension. Comparison derives are left out with a warning unless the model
+    /// is totally ordered, `Clone` and `Copy` unless it is `is_clone`.
+    #[allow(clippy::too_many_arguments)]
     fn print_derives(
         &self,
-        _schema: &Schema,
+        type_name: &str,
+        is_totally_ordered: bool,
+        is_clone: bool,
+        is_map_key: bool,
+        manual_eq: bool,
+        extra_derives: &[String],
         writer: &mut Box<dyn std::io::Write>,
     ) -> std::io::Result<()> {
-        const DEFAULT_DERIVES: &str = "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]";
-        writeln!(writer, "{DEFAULT_DERIVES}")
+        const DEFAULT_DERIVES: &[&str] =
+            &["Debug", "Clone", "PartialEq", "Serialize", "Deserialize"];
+        const ORD_DERIVES: &[&str] = &[
+            "Debug",
+            "Clone",
+            "PartialEq",
+            "Eq",
+            "Hash",
+            "PartialOrd",
+            "Ord",
+            "Serialize",
+            "Deserialize",
+        ];
+        const KEY_DERIVES: &[&str] = &[
+            "Debug",
+            "Clone",
+            "PartialEq",
+            "Eq",
+            "Hash",
+            "Serialize",
+            "Deserialize",
+        ];
+        let mut derives = if self.ordered_floats() && is_totally_ordered {
+            ORD_DERIVES.to_vec()
+        } else if is_map_key {
+            KEY_DERIVES.to_vec()
+        } else {
+            DEFAULT_DERIVES.to_vec()
+        };
+        if !is_clone {
+            derives.retain(|derive| *derive != "Clone");
+        }
+        if manual_eq {
+            derives.retain(|derive| *derive != "PartialEq");
+        }
+        for derive in extra_derives {
+            let derive = derive.as_str();
+            if !is_clone && matches!(derive, "Clone" | "Copy") {
+                log::warn!(
+                    "not deriving `{derive}` for `{type_name}`, it is marked not to be cloned"
+                );
+                continue;
+            }
+            let requires = match derive {
+                "Ord" => &["PartialOrd", "Eq", "Ord"][..],
+                "Eq" | "Hash" | "PartialOrd" => &[derive][..],
+                _ => {
+                    if !derives.contains(&derive) {
+                        derives.push(derive);
+                    }
+                    continue;
+                }
+            };
+            if !is_totally_ordered {
+                log::warn!(
+                    "not deriving `{derive}` for `{type_name}`, it contains floats, maps or untyped values"
+                );
+                continue;
+            }
+            for derive in requires {
+                if !derives.contains(derive) {
+                    derives.push(derive);
+                }
+            }
+        }
+        writeln!(writer, "#[derive({})]", derives.join(", "))?;
+        if self.opts.non_exhaustive {
+            writeln!(writer, "#[non_exhaustive]")?;
+        }
+        Ok(())
     }
 
     fn print_description(
@@ -361,6 +4232,17 @@ impl Codegen {
         if let Some(description) = &schema.description {
             self.print_doc_comment(description, None, writer)?;
         }
+        if !self.content_types.is_empty() {
+            if schema.description.is_some() {
+                writeln!(writer, "///")?;
+            }
+            let label = if self.content_types.len() == 1 {
+                "Content type"
+            } else {
+                "Content types"
+            };
+            writeln!(writer, "/// {label}: {}", self.content_types.join(", "))?;
+        }
         Ok(())
     }
 
@@ -373,9 +4255,1654 @@ impl Codegen {
         let indentation = indentation
             .map(|i| " ".repeat(i.into()))
             .unwrap_or_default();
-        for line in comment.as_ref().lines() {
+        for line in doc_lines(
+            comment.as_ref(),
+            self.opts.doc_width,
+            self.opts.trim_description_whitespace,
+        ) {
             writeln!(writer, "{indentation}/// {line}")?;
         }
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{enum_rename_all, Codegen, CodegenOptions, PrimitiveNames};
+    use crate::v2::{
+        builder::{SchemaBuilder, SwaggerBuilder},
+        codegen::{
+            backend::{
+                test_util::{generate, generate_built},
+                FieldOrder,
+            },
+            CodeGenerator,
+        },
+        validation::Severity,
+    };
+
+    #[test]
+    fn access_flags_from_all_of() {
+        let out = generate(
+            Codegen::default(),
+            r##"
+swagger: "2.0"
+definitions:
+  Base:
+    type: object
+    readOnly: true
+    properties:
+      Id:
+        type: string
+  User:
+    allOf:
+      - $ref: "#/definitions/Base"
+      - type: object
+        required: [Password, Nickname]
+        properties:
+          Password:
+            type: string
+            writeOnly: true
+          Nickname:
+            type: string
+            x-nullable: true
+"##,
+        );
+        assert!(out.contains(
+            "pub struct User {\n    #[serde(rename = \"Id\", skip_serializing)]\n    pub id: Option<String>,"
+        ));
+        assert!(
+            out.contains("    #[serde(rename = \"Nickname\")]\n    pub nickname: Option<String>,")
+        );
+        assert!(out.contains(
+            "    #[serde(rename = \"Password\", skip_deserializing)]\n    pub password: String,"
+        ));
+    }
+
+    #[test]
+    fn required_write_only_without_default() {
+        let out = generate(
+            Codegen::default(),
+            r##"
+swagger: "2.0"
+definitions:
+  Login:
+    type: object
+    required: [Credentials]
+    properties:
+      Credentials:
+        type: object
+        writeOnly: true
+        properties:
+          Token:
+            type: string
+"##,
+        );
+        // generated types don't derive `Default`, there is nothing to skip to
+        assert!(out.contains(
+            "    #[serde(rename = \"Credentials\", skip_deserializing, skip_serializing_if = \"Option::is_none\")]\n    pub credentials: Option<LoginCredentialsInlineItem>,"
+        ));
+    }
+
+    #[test]
+    fn capture_unknown_fields() {
+        let spec = r#"
+swagger: "2.0"
+definitions:
+  Container:
+    type: object
+    properties:
+      Extra:
+        type: string
+"#;
+        let out = generate(Codegen::default(), spec);
+        assert!(!out.contains("#[serde(flatten)]"));
+
+        let opts = CodegenOptions {
+            capture_unknown: true,
+            ..Default::default()
+        };
+        let out = generate(Codegen::new(opts), spec);
+        assert!(out.contains(
+            "    pub extra: Option<String>,\n    #[serde(flatten)]\n    pub extra_: HashMap<String, Value>,\n}"
+        ));
+    }
+
+    #[test]
+    fn array_query_params() {
+        let spec = r#"
+swagger: "2.0"
+paths:
+  /containers/json:
+    get:
+      operationId: ContainerList
+      parameters:
+        - name: id
+          in: query
+          type: array
+          items:
+            type: integer
+            format: int64
+          collectionFormat: multi
+        - name: status
+          in: query
+          required: true
+          type: array
+          items:
+            type: string
+        - name: label
+          in: query
+          type: array
+          items:
+            type: string
+          collectionFormat: pipes
+        - name: limit
+          in: query
+          type: integer
+      responses:
+        200:
+          description: no error
+"#;
+        // only generated on request
+        assert!(!generate(Codegen::default(), spec).contains("ContainerListParams"));
+        let opts = CodegenOptions {
+            query_params_models: true,
+            ..Default::default()
+        };
+        let out = generate(Codegen::new(opts), spec);
+        assert!(out.contains("fn serialize_pipes<S: serde::Serializer, T: std::fmt::Display>("));
+        assert!(out.contains("fn deserialize_pipes<'de, D, C, T>(d: D) -> Result<C, D::Error>"));
+        assert!(out.contains("/// Query parameters of the `ContainerList` operation.\npub struct ContainerListParams {"));
+        assert!(out.contains("    #[serde(skip_serializing_if = \"Option::is_none\")]\n    pub id: Option<Vec<i64>>,"));
+        assert!(out.contains(
+            "    #[serde(default, deserialize_with = \"deserialize_pipes\", serialize_with = \"serialize_pipes\", skip_serializing_if = \"Option::is_none\")]\n    pub label: Option<Vec<String>>,"
+        ));
+        assert!(out.contains("    #[serde(skip_serializing_if = \"Option::is_none\")]\n    pub limit: Option<isize>,"));
+        assert!(out.contains(
+            "    #[serde(default, deserialize_with = \"deserialize_csv\", serialize_with = \"serialize_csv\")]\n    pub status: Vec<String>,"
+        ));
+    }
+
+    #[test]
+    fn open_objects() {
+        let out = generate(
+            Codegen::default(),
+            r#"
+swagger: "2.0"
+definitions:
+  Labels:
+    type: object
+    required: [name, version]
+  Container:
+    type: object
+    properties:
+      Meta:
+        type: object
+        required: [created]
+"#,
+        );
+        assert!(out.contains(
+            "pub struct Labels {\n    pub name: Value,\n    pub version: Value,\n    #[serde(flatten)]\n    pub extra: HashMap<String, Value>,\n}"
+        ));
+        assert!(out.contains("    pub meta: Option<ContainerMetaInlineItem>,"));
+        assert!(out.contains(
+            "pub struct ContainerMetaInlineItem {\n    pub created: Value,\n    #[serde(flatten)]\n    pub extra: HashMap<String, Value>,\n}"
+        ));
+    }
+
+    #[test]
+    fn map_default() {
+        let out = generate(
+            Codegen::default(),
+            r#"
+swagger: "2.0"
+definitions:
+  HostConfig:
+    type: object
+    properties:
+      Labels:
+        type: object
+        additionalProperties:
+          type: string
+        default:
+          com.example: "true"
+"#,
+        );
+        assert!(out.contains("    #[serde(rename = \"Labels\", default = \"default_host_config_labels\", skip_serializing_if = \"Option::is_none\")]\n"));
+        assert!(out.contains(
+            "fn default_host_config_labels() -> Option<HashMap<String, String>> {\n    Some(HashMap::from([(\"com.example\".to_string(), \"true\".to_string())]))\n}"
+        ));
+    }
+
+    #[test]
+    fn array_default() {
+        let out = generate(
+            Codegen::default(),
+            r#"
+swagger: "2.0"
+definitions:
+  Healthcheck:
+    type: object
+    required: [Test]
+    properties:
+      Test:
+        type: array
+        items:
+          type: string
+        default: ["CMD", "true"]
+      Retries:
+        type: integer
+        format: int32
+        default: 3
+"#,
+        );
+        assert!(out.contains(
+            "    #[serde(rename = \"Test\", default = \"default_healthcheck_test\", deserialize_with = \"deserialize_nonoptional_vec\")]\n    pub test: Vec<String>,"
+        ));
+        assert!(out.contains(
+            "fn default_healthcheck_test() -> Vec<String> {\n    vec![\"CMD\".to_string(), \"true\".to_string()]\n}"
+        ));
+        assert!(out.contains("fn default_healthcheck_retries() -> Option<i32> {\n    Some(3)\n}"));
+    }
+
+    #[test]
+    fn nested_object_default() {
+        let out = generate(
+            Codegen::default(),
+            r#"
+swagger: "2.0"
+definitions:
+  HostConfig:
+    type: object
+    properties:
+      LogConfig:
+        type: object
+        title: LogConfig
+        properties:
+          Type:
+            type: string
+            enum: ["json-file", "syslog"]
+          Config:
+            type: object
+            additionalProperties:
+              type: string
+        default:
+          Type: json-file
+      Created:
+        type: string
+        format: date-time
+        default: "2022-01-01T00:00:00Z"
+"#,
+        );
+        assert!(out.contains(
+            "fn default_host_config_log_config() -> Option<LogConfig> {\n    Some(LogConfig { config: None, type_: Some(\"json-file\".to_string()) })\n}"
+        ));
+        assert!(out.contains(
+            "fn default_host_config_created() -> Option<DateTime<Utc>> {\n    serde_json::from_str(r#\"\"2022-01-01T00:00:00Z\"\"#).unwrap()\n}"
+        ));
+    }
+
+    #[test]
+    fn api_meta_tables() {
+        let spec = r##"
+swagger: "2.0"
+tags:
+  - name: pets
+    description: Everything about pets
+    externalDocs:
+      url: https://example.com/pets
+  - name: store
+paths:
+  /pets:
+    get:
+      tags: [pets]
+      operationId: listPets
+      summary: List all pets
+      responses: {}
+    post:
+      tags: [pets]
+      operationId: createPet
+      externalDocs:
+        url: https://example.com/create
+      responses: {}
+  /store/inventory:
+    get:
+      tags: [store]
+      operationId: getInventory
+      summary: "Returns \"pet\" inventories"
+      responses: {}
+"##;
+        let opts = CodegenOptions {
+            api_meta: true,
+            ..Default::default()
+        };
+        let out = generate(Codegen::new(opts), spec);
+        assert!(out.contains(
+            "    pub const TAGS: &[(&str, &str)] = &[\n        (\"pets\", \"Everything about pets\"),\n        (\"store\", \"\"),\n    ];"
+        ));
+        assert!(out.contains(
+            "    pub const TAG_DOCS: &[(&str, &str)] = &[\n        (\"pets\", \"https://example.com/pets\"),\n    ];"
+        ));
+        assert!(out.contains(
+            r#"    pub const OPERATIONS: &[(&str, &str, &str, &str)] = &[
+        ("get", "/pets", "listPets", "List all pets"),
+        ("post", "/pets", "createPet", ""),
+        ("get", "/store/inventory", "getInventory", "Returns \"pet\" inventories"),
+    ];"#
+        ));
+        assert!(out.contains(
+            "    pub const OPERATION_DOCS: &[(&str, &str)] = &[\n        (\"createPet\", \"https://example.com/create\"),\n    ];"
+        ));
+
+        let out = generate(Codegen::default(), spec);
+        assert!(!out.contains("api_meta"));
+    }
+
+    #[test]
+    fn operation_consts() {
+        let spec = r##"
+swagger: "2.0"
+paths:
+  /users/{id}:
+    get:
+      operationId: GetUser
+      responses: {}
+    delete:
+      responses: {}
+  /users:
+    post:
+      operationId: create_user
+      responses: {}
+    put:
+      operationId: CreateUser
+      responses: {}
+"##;
+        let opts = CodegenOptions {
+            operation_consts: true,
+            ..Default::default()
+        };
+        let out = generate(Codegen::new(opts), spec);
+        assert!(out.contains(
+            r#"pub mod operations {
+    pub const CREATE_USER: (&str, &str) = ("PUT", "/users");
+    pub const GET_USER: (&str, &str) = ("GET", "/users/{id}");
+    pub const DELETE_USERS_ID: (&str, &str) = ("DELETE", "/users/{id}");
+}"#
+        ));
+
+        let out = generate(Codegen::default(), spec);
+        assert!(!out.contains("mod operations"));
+    }
+
+    #[test]
+    fn case_variant_definitions() {
+        let out = generate(
+            Codegen::default(),
+            r##"
+swagger: "2.0"
+definitions:
+  networkSettings:
+    type: object
+    properties:
+      Bridge:
+        type: string
+  NetworkSettings:
+    type: object
+    properties:
+      Ports:
+        type: integer
+  Container:
+    type: object
+    properties:
+      Lower:
+        $ref: "#/definitions/networkSettings"
+      Upper:
+        $ref: "#/definitions/NetworkSettings"
+"##,
+        );
+        assert!(out.contains("pub struct NetworkSettings {\n    #[serde(rename = \"Ports\", skip_serializing_if = \"Option::is_none\")]"));
+        assert!(out.contains("pub struct NetworkSettings2 {\n    #[serde(rename = \"Bridge\", skip_serializing_if = \"Option::is_none\")]"));
+        assert!(out.contains("    pub lower: Option<NetworkSettings2>,"));
+        assert!(out.contains("    pub upper: Option<NetworkSettings>,"));
+    }
+
+    #[test]
+    fn time_format() {
+        let swagger = SwaggerBuilder::new()
+            .definition(
+                "Schedule",
+                SchemaBuilder::object()
+                    .prop("Start", SchemaBuilder::string().format("time").required())
+                    .prop(
+                        "Breaks",
+                        SchemaBuilder::array(SchemaBuilder::string().format("Time")),
+                    ),
+            )
+            .build();
+        let out = generate_built(Codegen::default(), swagger);
+        assert!(out.contains("    pub start: NaiveTime,"));
+        assert!(out.contains("    pub breaks: Option<Vec<NaiveTime>>,"));
+        assert!(out.contains("use chrono::NaiveTime;"));
+
+        let swagger = SwaggerBuilder::new()
+            .definition("Name", SchemaBuilder::string())
+            .build();
+        let out = generate_built(Codegen::default(), swagger);
+        assert!(!out.contains("NaiveTime"));
+    }
+
+    #[test]
+    fn doc_width() {
+        let swagger = || {
+            SwaggerBuilder::new()
+                .definition(
+                    "Mount",
+                    SchemaBuilder::object()
+                        .description(
+                            "Mount options of a container, applied in the order they are listed.",
+                        )
+                        .prop("Target", SchemaBuilder::string()),
+                )
+                .build()
+        };
+        let opts = CodegenOptions {
+            doc_width: Some(30),
+            ..Default::default()
+        };
+        let out = generate_built(Codegen::new(opts), swagger());
+        assert!(out.contains(
+            "/// Mount options of a container,\n/// applied in the order they are\n/// listed.\n"
+        ));
+
+        let out = generate_built(Codegen::default(), swagger());
+        assert!(out
+            .contains("/// Mount options of a container, applied in the order they are listed.\n"));
+    }
+
+    #[test]
+    fn titled_aliases() {
+        let swagger = SwaggerBuilder::new()
+            .definition(
+                "Container",
+                SchemaBuilder::object()
+                    .title("Box")
+                    .prop("Mounts", SchemaBuilder::reference("MountList")),
+            )
+            .definition("Id", SchemaBuilder::string().title("ContainerId"))
+            .definition(
+                "MountList",
+                SchemaBuilder::array(SchemaBuilder::string()).title("Mounts"),
+            )
+            .build();
+        let out = generate_built(Codegen::default(), swagger);
+        assert!(out.contains("pub struct Container {"));
+        assert!(out.contains("pub type ContainerId = String;"));
+        assert!(out.contains("pub type Mounts = Vec<String>;"));
+        assert!(out.contains("    pub mounts: Option<Mounts>,"));
+        assert!(!out.contains("MountList"));
+    }
+
+    #[cfg(feature = "ordered-float")]
+    #[test]
+    fn ordered_floats() {
+        let spec = r##"
+swagger: "2.0"
+definitions:
+  Point:
+    type: object
+    required: [X]
+    properties:
+      X:
+        type: number
+        format: double
+      Weights:
+        type: array
+        items:
+          type: number
+          format: float
+      Kind:
+        $ref: "#/definitions/Kind"
+  Kind:
+    type: string
+    enum: [a, b]
+  Tagged:
+    type: object
+    properties:
+      Labels:
+        type: object
+        additionalProperties:
+          type: string
+"##;
+        let opts = CodegenOptions {
+            float_eq: super::FloatEq::Ordered,
+            ..Default::default()
+        };
+        let out = generate(Codegen::new(opts), spec);
+        const ORD: &str =
+            "#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]";
+        assert!(out.contains(&format!("{ORD}\npub struct Point {{")));
+        assert!(out.contains("    pub x: ordered_float::OrderedFloat<f64>,"));
+        assert!(out.contains("    pub weights: Option<Vec<ordered_float::OrderedFloat<f32>>>,"));
+        assert!(out.contains(&format!("{ORD}\npub enum Kind {{")));
+        assert!(out.contains(
+            "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]\npub struct Tagged {"
+        ));
+
+        let out = generate(Codegen::default(), spec);
+        assert!(!out.contains("OrderedFloat"));
+        assert!(!out.contains("Eq, Hash"));
+        assert!(out.contains("    pub x: f64,"));
+    }
+
+    #[test]
+    fn big_ints() {
+        let spec = r##"
+swagger: "2.0"
+definitions:
+  Account:
+    type: object
+    required: [Balance]
+    properties:
+      Balance:
+        type: integer
+        format: bigint
+      Limit:
+        type: integer
+        format: biginteger
+        default: 100
+      History:
+        type: array
+        items:
+          type: integer
+          format: bigint
+"##;
+        let out = generate(Codegen::default(), spec);
+        assert!(out.contains("    pub balance: i128,"));
+        assert!(out.contains("    pub history: Option<Vec<i128>>,"));
+        assert!(out.contains("fn default_account_limit() -> Option<i128> {\n    Some(100)\n}"));
+        assert!(!out.contains("big_int_serde"));
+
+        #[cfg(feature = "num-bigint")]
+        {
+            let opts = CodegenOptions {
+                big_int: super::BigInt::NumBigInt,
+                ..Default::default()
+            };
+            let out = generate(Codegen::new(opts), spec);
+            assert!(out.contains("mod big_int_serde {"));
+            assert!(out.contains(
+                "    #[serde(rename = \"Balance\", with = \"big_int_serde\")]\n    pub balance: num_bigint::BigInt,"
+            ));
+            assert!(out.contains(
+                "    #[serde(rename = \"Limit\", default = \"default_account_limit\", with = \"big_int_serde::option\", skip_serializing_if = \"Option::is_none\")]"
+            ));
+            assert!(out.contains("    Some(\"100\".parse::<num_bigint::BigInt>().unwrap())"));
+        }
+    }
+
+    #[test]
+    fn double_option() {
+        let spec = r##"
+swagger: "2.0"
+definitions:
+  ContainerUpdate:
+    type: object
+    required: [Id]
+    properties:
+      Id:
+        type: string
+      Memory:
+        type: integer
+        format: int64
+        x-explicit-null: true
+      CpuShares:
+        type: integer
+        x-nullable: true
+      Name:
+        type: string
+"##;
+        #[cfg(not(feature = "serde-with"))]
+        {
+            let opts = CodegenOptions {
+                double_option: true,
+                ..Default::default()
+            };
+            let out = generate(Codegen::new(opts), spec);
+            assert!(!out.contains("double_option"));
+            assert!(out.contains("    pub memory: Option<i64>,"));
+        }
+
+        #[cfg(feature = "serde-with")]
+        {
+            let out = generate(Codegen::default(), spec);
+            assert!(out.contains(
+                "    #[serde(rename = \"Memory\", default, with = \"::serde_with::rust::double_option\", skip_serializing_if = \"Option::is_none\")]\n    pub memory: Option<Option<i64>>,"
+            ));
+            assert!(out.contains("    pub cpu_shares: Option<isize>,"));
+
+            let opts = CodegenOptions {
+                double_option: true,
+                ..Default::default()
+            };
+            let out = generate(Codegen::new(opts), spec);
+            assert!(out.contains("    pub cpu_shares: Option<Option<isize>>,"));
+            assert!(out.contains("    pub name: Option<String>,"));
+            assert!(out.contains("    pub id: String,"));
+        }
+    }
+
+    #[test]
+    fn wire_formats() {
+        let spec = r##"
+swagger: "2.0"
+definitions:
+  Blob:
+    type: object
+    required: [Data]
+    properties:
+      Data:
+        type: string
+        format: binary
+      Chunks:
+        type: array
+        items:
+          type: string
+          format: binary
+      Values:
+        type: array
+        items:
+          type: integer
+          format: uint8
+      Compressed:
+        type: boolean
+        x-lenient-bool: true
+  Payload:
+    type: string
+    format: binary
+"##;
+        let generate_for = |wire_format| {
+            let opts = CodegenOptions {
+                wire_format,
+                // leaves out the note about required collections, which mentions the flag
+                lenient_required_collections: false,
+                ..Default::default()
+            };
+            generate(Codegen::new(opts), spec)
+        };
+
+        let out = generate_for(super::WireFormat::MsgPack);
+        assert!(out.contains("    pub data: serde_bytes::ByteBuf,"));
+        assert!(out.contains("    pub chunks: Option<Vec<serde_bytes::ByteBuf>>,"));
+        assert!(out.contains("    pub values: Option<Vec<u8>>,"));
+        assert!(out.contains("pub type Payload = serde_bytes::ByteBuf;"));
+        assert!(out.contains("deserialize_with = \"deserialize_lenient_option_bool\""));
+
+        let out = generate_for(super::WireFormat::Any);
+        assert!(out.contains("    pub data: Vec<u8>,"));
+        assert!(!out.contains("lenient"));
+        assert!(out.contains(
+            "    #[serde(rename = \"Compressed\", skip_serializing_if = \"Option::is_none\")]\n    pub compressed: Option<bool>,"
+        ));
+    }
+
+    #[test]
+    fn lenient_bools() {
+        let spec = r##"
+swagger: "2.0"
+definitions:
+  Port:
+    type: object
+    required: [Published]
+    properties:
+      Published:
+        type: boolean
+      Ipv6:
+        type: boolean
+      Name:
+        type: string
+"##;
+        let out = generate(Codegen::default(), spec);
+        assert!(!out.contains("lenient"));
+
+        let opts = CodegenOptions {
+            lenient_bools: true,
+            ..Default::default()
+        };
+        let out = generate(Codegen::new(opts), spec);
+        assert!(out.contains("fn deserialize_lenient_bool<'de"));
+        assert!(out.contains(
+            "    #[serde(rename = \"Published\", deserialize_with = \"deserialize_lenient_bool\")]\n    pub published: bool,"
+        ));
+        assert!(out.contains(
+            "    #[serde(rename = \"Ipv6\", default, deserialize_with = \"deserialize_lenient_option_bool\", skip_serializing_if = \"Option::is_none\")]\n    pub ipv_6: Option<bool>,"
+        ));
+        assert!(out.contains(
+            "    #[serde(rename = \"Name\", skip_serializing_if = \"Option::is_none\")]\n    pub name: Option<String>,"
+        ));
+    }
+
+    #[test]
+    fn schema_derives() {
+        let spec = r##"
+swagger: "2.0"
+definitions:
+  Label:
+    type: object
+    x-rust-derive: [Hash, Eq, Default]
+    properties:
+      Name:
+        type: string
+  Usage:
+    type: object
+    x-rust-derive: [Ord, Default]
+    properties:
+      Ratio:
+        type: number
+        format: double
+  State:
+    type: string
+    x-rust-derive: [Hash, Copy]
+    enum: [running, exited]
+"##;
+        let out = generate(Codegen::default(), spec);
+        assert!(out.contains(
+            "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Hash, Eq, Default)]\npub struct Label {"
+        ));
+        // floats can't be ordered
+        assert!(out.contains(
+            "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]\npub struct Usage {"
+        ));
+        assert!(out.contains(
+            "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Hash, Copy)]\npub enum State {"
+        ));
+
+        #[cfg(feature = "ordered-float")]
+        {
+            let opts = CodegenOptions {
+                float_eq: super::FloatEq::Ordered,
+                ..Default::default()
+            };
+            let out = generate(Codegen::new(opts), spec);
+            assert!(out.contains(
+                "#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize, Default)]\npub struct Usage {"
+            ));
+        }
+    }
+
+    #[test]
+    fn collection_defaults() {
+        let spec = r##"
+swagger: "2.0"
+definitions:
+  Container:
+    type: object
+    required: [Names, Ports]
+    properties:
+      Names:
+        type: array
+        items:
+          type: string
+      Ports:
+        type: array
+        items:
+          type: integer
+        default: [80]
+      Mounts:
+        type: array
+        items:
+          type: string
+      Volumes:
+        type: array
+        items:
+          type: string
+        default: []
+"##;
+        let note = "// Required array and map fields are empty when missing";
+        let out = generate(Codegen::default(), spec);
+        assert!(out.starts_with(note));
+        // required without a default
+        assert!(out.contains(
+            "    #[serde(rename = \"Names\", default, deserialize_with = \"deserialize_nonoptional_vec\")]\n    pub names: Vec<String>,"
+        ));
+        // required with a default
+        assert!(out.contains(
+            "    #[serde(rename = \"Ports\", default = \"default_container_ports\", deserialize_with = \"deserialize_nonoptional_vec\")]\n    pub ports: Vec<isize>,"
+        ));
+        // optional without a default
+        assert!(out.contains(
+            "    #[serde(rename = \"Mounts\", skip_serializing_if = \"Option::is_none\")]\n    pub mounts: Option<Vec<String>>,"
+        ));
+        // optional with a default
+        assert!(out.contains(
+            "    #[serde(rename = \"Volumes\", default = \"default_container_volumes\", skip_serializing_if = \"Option::is_none\")]\n    pub volumes: Option<Vec<String>>,"
+        ));
+
+        let opts = CodegenOptions {
+            lenient_required_collections: false,
+            ..Default::default()
+        };
+        let strict = generate(Codegen::new(opts), spec);
+        assert!(!strict.contains(note));
+        assert!(strict.contains(
+            "    #[serde(rename = \"Names\", deserialize_with = \"deserialize_nonoptional_vec\")]\n    pub names: Vec<String>,"
+        ));
+        // the other fields don't change
+        for field in ["Ports", "Mounts", "Volumes"] {
+            let line = |out: &str| {
+                out.lines()
+                    .find(|line| line.contains(&format!("rename = \"{field}\"")))
+                    .unwrap()
+                    .to_string()
+            };
+            assert_eq!(line(&out), line(&strict));
+        }
+
+        // `Vec<u8>` binary strings and the models of required query parameters get the note too
+        let binary = r##"
+swagger: "2.0"
+definitions:
+  Blob:
+    type: object
+    required: [Data]
+    properties:
+      Data:
+        type: string
+        format: binary
+"##;
+        assert!(generate(Codegen::default(), binary).starts_with(note));
+        let opts = CodegenOptions {
+            wire_format: super::WireFormat::MsgPack,
+            ..Default::default()
+        };
+        assert!(!generate(Codegen::new(opts), binary).contains(note));
+        let query = r##"
+swagger: "2.0"
+paths:
+  /containers/json:
+    get:
+      operationId: ContainerList
+      parameters:
+        - name: ids
+          in: query
+          required: true
+          type: array
+          items:
+            type: string
+      responses: {}
+"##;
+        assert!(generate(Codegen::default(), query).starts_with(note));
+    }
+
+    #[test]
+    fn no_clone() {
+        let spec = r##"
+swagger: "2.0"
+definitions:
+  Layer:
+    type: object
+    x-no-clone: true
+    x-rust-derive: [Clone, Default]
+    properties:
+      Data:
+        type: string
+        format: binary
+  Image:
+    type: object
+    properties:
+      Layers:
+        type: array
+        items:
+          $ref: "#/definitions/Layer"
+      State:
+        $ref: "#/definitions/State"
+  Tag:
+    type: object
+    properties:
+      Name:
+        type: string
+  State:
+    type: string
+    enum: [pulled, missing]
+"##;
+        let derives = |out: &str, item: &str| {
+            let end = out.find(&format!("\npub {item} {{")).unwrap();
+            out[..end].lines().last().unwrap().trim().to_string()
+        };
+        let out = generate(Codegen::default(), spec);
+        assert_eq!(
+            derives(&out, "struct Layer"),
+            "#[derive(Debug, PartialEq, Serialize, Deserialize, Default)]"
+        );
+        // holds a `Layer`
+        assert_eq!(
+            derives(&out, "struct Image"),
+            "#[derive(Debug, PartialEq, Serialize, Deserialize)]"
+        );
+        assert_eq!(
+            derives(&out, "struct Tag"),
+            "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]"
+        );
+
+        let opts = CodegenOptions {
+            no_clone: true,
+            ..Default::default()
+        };
+        let out = generate(Codegen::new(opts), spec);
+        assert_eq!(
+            derives(&out, "struct Tag"),
+            "#[derive(Debug, PartialEq, Serialize, Deserialize)]"
+        );
+        // enums are only unit variants
+        assert_eq!(
+            derives(&out, "enum State"),
+            "#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]"
+        );
+    }
+
+    #[test]
+    fn enum_variants() {
+        let spec = r##"
+swagger: "2.0"
+definitions:
+  Status:
+    type: string
+    enum: [up, UP, down, Up, up]
+"##;
+        let out = generate(Codegen::default(), spec);
+        assert!(out.contains(
+            "pub enum Status {\n    #[serde(rename = \"up\")]\nUp,\n    #[serde(rename = \"UP\")]\nUp2,\n    #[serde(rename = \"down\")]\nDown,\n    #[serde(rename = \"Up\")]\nUp3,\n}"
+        ));
+        assert!(out.contains("            Status::Up2 => \"UP\","));
+        assert!(out.contains(
+            "    pub const VARIANTS: &[Self] = &[\n        Status::Up,\n        Status::Up2,\n        Status::Down,\n        Status::Up3,\n    ];"
+        ));
+
+        // no rule maps both `Up` and `Up2` back
+        let opts = CodegenOptions {
+            enum_rename_all: true,
+            ..Default::default()
+        };
+        let out = generate(Codegen::new(opts), spec);
+        assert!(!out.contains("rename_all"));
+
+        let out = generate(
+            Codegen::default(),
+            r##"
+swagger: "2.0"
+definitions:
+  Mode:
+    type: string
+    enum: [foo-bar, foo.bar, foo_bar]
+"##,
+        );
+        assert!(out.contains(
+            "pub enum Mode {\n    #[serde(rename = \"foo-bar\")]\nFooBar,\n    #[serde(rename = \"foo.bar\")]\nFooBar2,\n    #[serde(rename = \"foo_bar\")]\nFooBar3,\n}"
+        ));
+        assert!(out.contains("            Mode::FooBar2 => \"foo.bar\","));
+    }
+
+    #[test]
+    fn recursive_fields() {
+        let spec = r##"
+swagger: "2.0"
+definitions:
+  Node:
+    type: object
+    properties:
+      Parent:
+        $ref: "#/definitions/Node"
+      Meta:
+        type: object
+        properties:
+          Owner:
+            $ref: "#/definitions/Node"
+      Children:
+        type: object
+        additionalProperties:
+          $ref: "#/definitions/Node"
+"##;
+        let opts = CodegenOptions {
+            accessors: true,
+            ..Default::default()
+        };
+        let out = generate(Codegen::new(opts), spec);
+        assert!(out.contains("    pub parent: Option<Box<Node>>,"));
+        assert!(out.contains("    pub meta: Option<Box<NodeMetaInlineItem>>,"));
+        assert!(out.contains("    pub owner: Option<Box<Node>>,"));
+        assert!(out.contains("    pub children: Option<HashMap<String, Node>>,"));
+        assert!(out.contains(
+            "    pub fn parent(&self) -> Option<&Node> {\n        self.parent.as_deref()\n    }"
+        ));
+    }
+
+    #[test]
+    fn edition_keywords() {
+        let spec = r##"
+swagger: "2.0"
+definitions:
+  Task:
+    type: object
+    properties:
+      gen:
+        type: integer
+      async:
+        type: boolean
+      try:
+        type: integer
+"##;
+        let out = generate(Codegen::default(), spec);
+        assert!(out.contains("    pub gen: Option<isize>,"));
+        assert!(out.contains("    #[serde(rename = \"async\", skip_serializing_if = \"Option::is_none\")]\n    pub async_: Option<bool>,"));
+        assert!(out.contains("    pub try_: Option<isize>,"));
+
+        let opts = CodegenOptions {
+            edition: crate::v2::codegen::backend::rust::Edition::E2024,
+            ..Default::default()
+        };
+        let out = generate(Codegen::new(opts), spec);
+        assert!(out.contains("    #[serde(rename = \"gen\", skip_serializing_if = \"Option::is_none\")]\n    pub gen_: Option<isize>,"));
+        assert!(out.contains("    pub async_: Option<bool>,"));
+    }
+
+    #[test]
+    fn strong_components_of_cycles() {
+        let component = super::strong_components(&[vec![1], vec![0, 2], vec![2, 3], vec![]]);
+        assert_eq!(component[0], component[1]);
+        assert_ne!(component[1], component[2]);
+        assert_ne!(component[2], component[3]);
+        assert_ne!(component[0], component[3]);
+    }
+
+    #[test]
+    fn always_rename() {
+        let spec = r##"
+swagger: "2.0"
+definitions:
+  Task:
+    type: object
+    required: [id]
+    properties:
+      id:
+        type: string
+      Name:
+        type: string
+      owner:
+        $ref: "#/definitions/User"
+  User:
+    type: string
+"##;
+        let out = generate(Codegen::default(), spec);
+        assert!(out.contains("    pub id: String,"));
+        assert!(out.contains("    #[serde(rename = \"Name\", skip_serializing_if = \"Option::is_none\")]\n    pub name: Option<String>,"));
+
+        let opts = CodegenOptions {
+            always_rename: true,
+            ..Default::default()
+        };
+        let out = generate(Codegen::new(opts), spec);
+        assert!(out.contains("    #[serde(rename = \"id\")]\n    pub id: String,"));
+        assert!(out.contains("    #[serde(rename = \"Name\", skip_serializing_if = \"Option::is_none\")]\n    pub name: Option<String>,"));
+        assert!(out.contains("    #[serde(rename = \"owner\")]\n    pub owner: Option<String>,"));
+    }
+
+    #[test]
+    fn enum_variant_cases() {
+        use crate::v2::codegen::backend::EnumVariantCase;
+
+        let swagger = SwaggerBuilder::new()
+            .definition(
+                "Status",
+                SchemaBuilder::string_enum(["not-found", "404", "UP", ""]),
+            )
+            .build();
+        let opts = CodegenOptions {
+            enum_variant_case: EnumVariantCase::ScreamingSnake,
+            ..Default::default()
+        };
+        let out = generate_built(Codegen::new(opts), swagger);
+        assert!(out.contains("#[allow(non_camel_case_types)]\npub enum Status {"));
+        for (value, variant) in [
+            ("not-found", "NOT_FOUND"),
+            ("404", "VALUE_404"),
+            ("UP", "UP"),
+            ("", "EMPTY"),
+        ] {
+            assert!(out.contains(&format!("    #[serde(rename = \"{value}\")]\n{variant},")));
+            assert!(out.contains(&format!("Status::{variant} => \"{value}\",")));
+        }
+
+        // variants named like the values need no renames
+        let rename_all = |case| enum_rename_all(["UP", "Down"], case);
+        assert_eq!(rename_all(EnumVariantCase::Verbatim), Some("PascalCase"));
+        assert_eq!(rename_all(EnumVariantCase::Default), None);
+    }
+
+    #[test]
+    fn enum_rename_all_rules() {
+        use crate::v2::codegen::backend::EnumVariantCase;
+
+        assert_eq!(
+            enum_rename_all(["Running", "Paused"], EnumVariantCase::Default),
+            Some("PascalCase")
+        );
+        assert_eq!(
+            enum_rename_all(["running", "paused"], EnumVariantCase::Default),
+            Some("lowercase")
+        );
+        assert_eq!(
+            enum_rename_all(["onFailure", "always"], EnumVariantCase::Default),
+            Some("camelCase")
+        );
+        assert_eq!(
+            enum_rename_all(["on_failure", "always"], EnumVariantCase::Default),
+            Some("snake_case")
+        );
+        assert_eq!(
+            enum_rename_all(["OUT_OF_MEMORY", "RUNNING"], EnumVariantCase::Default),
+            Some("SCREAMING_SNAKE_CASE")
+        );
+        assert_eq!(
+            enum_rename_all(["on-failure", "no"], EnumVariantCase::Default),
+            Some("kebab-case")
+        );
+        assert_eq!(
+            enum_rename_all(["ON-FAILURE", "NO"], EnumVariantCase::Default),
+            Some("SCREAMING-KEBAB-CASE")
+        );
+        // mixed conventions, keywords and digits need per-variant renames
+        assert_eq!(
+            enum_rename_all(["on-failure", "on_failure"], EnumVariantCase::Default),
+            None
+        );
+        assert_eq!(
+            enum_rename_all(["self", "other"], EnumVariantCase::Default),
+            None
+        );
+        assert_eq!(
+            enum_rename_all(["2xx", "4xx"], EnumVariantCase::Default),
+            None
+        );
+        assert_eq!(enum_rename_all([], EnumVariantCase::Default), None);
+    }
+
+    #[test]
+    fn parallel_matches_serial() {
+        let mut spec = String::from("swagger: \"2.0\"\ndefinitions:\n");
+        for i in 0..100 {
+            spec.push_str(&format!(
+                r##"
+  Model{i}:
+    type: object
+    properties:
+      Start:
+        type: string
+        format: time
+      Kind:
+        type: string
+        enum: [a, b]
+      Next:
+        $ref: "#/definitions/Model{}"
+  Model{i}List:
+    type: array
+    items:
+      $ref: "#/definitions/Model{i}"
+"##,
+                (i + 1) % 100
+            ));
+        }
+        spec.push_str(
+            r##"
+responses:
+  Model0:
+    description: Clashes with the definition and is skipped.
+    schema:
+      $ref: "#/definitions/Model1"
+"##,
+        );
+
+        let render = |parallel| {
+            let opts = CodegenOptions {
+                parallel,
+                ..Default::default()
+            };
+            generate(Codegen::new(opts), &spec)
+        };
+        let serial = render(false);
+        assert!(serial.contains("pub struct Model99 {"));
+        assert!(serial.contains("pub type Model99List = Vec<Model99>;"));
+        assert!(serial.contains("use chrono::NaiveTime;"));
+        assert!(!serial.contains("pub type Model0 ="));
+        assert_eq!(serial, render(true));
+    }
+
+    #[test]
+    fn dedup_identical_enums() {
+        let spec = r##"
+swagger: "2.0"
+definitions:
+  Container:
+    type: object
+    properties:
+      State:
+        type: object
+        properties:
+          Status:
+            type: string
+            enum: [running, stopped, paused]
+      Status:
+        type: string
+        enum: [paused, running, stopped]
+  Task:
+    type: object
+    properties:
+      Status:
+        type: string
+        enum: [running, stopped, paused]
+      Health:
+        type: string
+        description: Health of the task.
+        enum: [running, stopped, paused]
+"##;
+        let out = generate(Codegen::default(), spec);
+        assert_eq!(out.matches("pub enum").count(), 2);
+        assert!(out.contains("pub enum ContainerStateInlineItemStatusInlineItem {"));
+        assert!(out.contains("pub enum TaskHealthInlineItem {"));
+
+        let opts = CodegenOptions {
+            dedup_ignore_docs: true,
+            ..Default::default()
+        };
+        let out = generate(Codegen::new(opts), spec);
+        assert_eq!(out.matches("pub enum").count(), 1);
+
+        let swagger = serde_yaml::from_str(spec).unwrap();
+        let codegen = CodeGenerator::new(swagger, Box::new(Codegen::default()));
+        let diagnostics = codegen.diagnostics();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Info);
+        assert_eq!(
+            diagnostics[0].locations,
+            vec![
+                "ContainerStateInlineItemStatusInlineItem",
+                "ContainerStatusInlineItem",
+                "TaskStatusInlineItem"
+            ]
+        );
+
+        let titled = spec.replace(
+            "      Status:\n        type: string\n        enum: [running, stopped, paused]",
+            "      Status:\n        type: string\n        title: TaskStatus\n        enum: [running, stopped, paused]",
+        );
+        let out = generate(Codegen::default(), &titled);
+        assert!(out.contains("pub enum TaskStatus {"));
+        assert!(!out.contains("pub enum ContainerStateInlineItemStatusInlineItem {"));
+    }
+
+    #[test]
+    fn default_response_name() {
+        let out = generate(
+            Codegen::default(),
+            r##"
+swagger: "2.0"
+paths:
+  /pets:
+    get:
+      operationId: listPets
+      responses:
+        200:
+          description: A list of pets.
+          schema:
+            type: object
+            properties:
+              Names:
+                type: array
+                items:
+                  type: string
+        default:
+          description: Unexpected error.
+          schema:
+            type: object
+            properties:
+              Message:
+                type: string
+"##,
+        );
+        assert!(out.contains("pub struct ListPets200Response {"));
+        assert!(out.contains("/// Unexpected error.\npub struct ListPetsDefaultResponse {"));
+    }
+
+    #[test]
+    fn value_conversions_of_borrowing_models() {
+        let opts = CodegenOptions {
+            value_conversions: true,
+            borrowed: true,
+            ..Default::default()
+        };
+        let out = generate(
+            Codegen::new(opts),
+            r##"
+swagger: "2.0"
+definitions:
+  Named:
+    type: object
+    properties:
+      Name:
+        type: string
+  Sized:
+    type: object
+    properties:
+      Size:
+        type: integer
+"##,
+        );
+        assert!(!out.contains("impl TryFrom<serde_json::Value> for Named"));
+        assert!(out.contains("impl<'a> Named<'a> {\n    pub fn to_value(&self)"));
+        assert!(out.contains("impl TryFrom<serde_json::Value> for Sized {"));
+        assert!(out.contains("impl Sized {\n    pub fn to_value(&self)"));
+    }
+
+    #[test]
+    fn variant_conversions() {
+        let out = generate(
+            Codegen::default(),
+            r##"
+swagger: "2.0"
+definitions:
+  Event:
+    type: object
+    x-variants:
+      field: Type
+      mapping:
+        image: ImageEvent
+        pull: ImageEvent
+        container: ContainerEvent
+  ImageEvent:
+    type: object
+    properties:
+      Tag:
+        type: string
+  ContainerEvent:
+    type: object
+    properties:
+      ID:
+        type: string
+"##,
+        );
+        assert!(out.contains(
+            "impl From<ContainerEvent> for Event {\n    fn from(value: ContainerEvent) -> Self {\n        Self::Container(value)"
+        ));
+        assert!(!out.contains("impl From<ImageEvent> for Event"));
+    }
+
+    #[test]
+    fn with_constructors() {
+        let spec = r##"
+swagger: "2.0"
+definitions:
+  Volume:
+    type: object
+    required: [Name, Driver, Options]
+    properties:
+      Name:
+        type: string
+      Driver:
+        type: string
+      Options:
+        type: object
+        additionalProperties:
+          type: string
+      Labels:
+        type: object
+        additionalProperties:
+          type: string
+      Scope:
+        type: string
+"##;
+        let opts = CodegenOptions {
+            with_constructors: true,
+            ..Default::default()
+        };
+        let out = generate(Codegen::new(opts), spec);
+        assert!(out.contains(
+            r#"impl Volume {
+    pub fn new(driver: String, name: String, options: HashMap<String, String>) -> Self {
+        Self {
+            driver,
+            labels: None,
+            name,
+            options,
+            scope: None,
+        }
+    }
+}"#
+        ));
+        assert!(!out.contains("#[non_exhaustive]"));
+
+        let out = generate(Codegen::default(), spec);
+        assert!(!out.contains("pub fn new("));
+    }
+
+    #[test]
+    fn content_types() {
+        let out = generate(
+            Codegen::default(),
+            r##"
+swagger: "2.0"
+consumes: [application/json]
+produces: [application/json]
+paths:
+  /build:
+    post:
+      operationId: build
+      consumes: [application/x-tar, application/octet-stream]
+      parameters:
+        - name: context
+          in: body
+          schema:
+            type: object
+            properties:
+              Dockerfile:
+                type: string
+      responses:
+        200:
+          description: Build output.
+          schema:
+            type: object
+            properties:
+              Stream:
+                type: string
+"##,
+        );
+        assert!(out.contains(
+            "/// Content types: application/x-tar, application/octet-stream\npub struct BuildContextParam {"
+        ));
+        assert!(out.contains(
+            "/// Build output.\n///\n/// Content type: application/json\npub struct Build200Response {"
+        ));
+    }
+
+    #[test]
+    fn flatten_all_of() {
+        let spec = r##"
+swagger: "2.0"
+definitions:
+  Base:
+    type: object
+    required: [Id]
+    properties:
+      Id:
+        type: string
+  Container:
+    allOf:
+      - $ref: "#/definitions/Base"
+      - type: object
+        required: [Image]
+        properties:
+          Image:
+            type: string
+  Alias:
+    allOf:
+      - $ref: "#/definitions/Base"
+"##;
+        let opts = CodegenOptions {
+            flatten_all_of: true,
+            ..Default::default()
+        };
+        let out = generate(Codegen::new(opts), spec);
+        assert!(out.contains(
+            "pub struct Container {\n    #[serde(flatten)]\n    pub base: Base,\n    #[serde(rename = \"Image\")]\n    pub image: String,\n}"
+        ));
+        // a lone reference has nothing to flatten next to it
+        assert!(out
+            .contains("pub struct Alias {\n    #[serde(rename = \"Id\")]\n    pub id: String,\n}"));
+
+        let out = generate(Codegen::default(), spec);
+        assert!(!out.contains("#[serde(flatten)]"));
+        assert!(out.contains("pub struct Container {\n    #[serde(rename = \"Id\")]\n    pub id: String,\n    #[serde(rename = \"Image\")]\n    pub image: String,\n}"));
+    }
+
+    #[test]
+    fn field_order() {
+        let spec = r#"
+swagger: "2.0"
+definitions:
+  Point:
+    type: object
+    properties:
+      Y:
+        type: integer
+      X:
+        type: integer
+      Label:
+        type: string
+"#;
+        let field_names = |out: &str| -> Vec<String> {
+            out.lines()
+                .filter_map(|line| line.trim().strip_prefix("pub "))
+                .filter_map(|line| line.split_once(':'))
+                .map(|(name, _)| name.to_string())
+                .collect()
+        };
+
+        let out = generate(Codegen::default(), spec);
+        assert_eq!(field_names(&out), ["label", "x", "y"]);
+
+        let opts = CodegenOptions {
+            field_order: FieldOrder::Spec,
+            ..Default::default()
+        };
+        let out = generate(Codegen::new(opts), spec);
+        assert_eq!(field_names(&out), ["y", "x", "label"]);
+    }
+
+    #[test]
+    fn array_of_response_reference() {
+        let out = generate(
+            Codegen::default(),
+            r##"
+swagger: "2.0"
+definitions:
+  TagList:
+    type: array
+    items:
+      $ref: "#/responses/Tag"
+  Holder:
+    type: object
+    properties:
+      Tags:
+        type: array
+        items:
+          $ref: "#/responses/Tag"
+responses:
+  Tag:
+    description: a tag
+    schema:
+      type: object
+      properties:
+        Name:
+          type: string
+"##,
+        );
+        assert!(out.contains("pub struct Tag {"));
+        assert!(out.contains("pub type TagList = Vec<Tag>;"));
+        assert!(out.contains("    pub tags: Option<Vec<Tag>>,"));
+    }
+
+    #[test]
+    fn primitive_names() {
+        let spec = r##"
+swagger: "2.0"
+definitions:
+  String:
+    type: string
+  String2:
+    type: integer
+    format: int32
+  Named:
+    type: object
+    required: [Name]
+    properties:
+      Name:
+        $ref: "#/definitions/String"
+      Aliases:
+        type: array
+        items:
+          $ref: "#/definitions/String"
+"##;
+        let generate_with = |primitive_names| {
+            let opts = CodegenOptions {
+                primitive_names,
+                ..Default::default()
+            };
+            generate(Codegen::new(opts), spec)
+        };
+
+        let out = generate_with(PrimitiveNames::Skip);
+        assert!(!out.contains("pub type String ="));
+        assert!(out.contains("pub name: String,"));
+        assert!(out.contains("pub aliases: Option<Vec<String>>,"));
+
+        let out = generate_with(PrimitiveNames::Newtype);
+        assert!(out.contains("#[serde(transparent)]\npub struct String_(pub String);"));
+        assert!(out.contains("pub name: String_,"));
+        assert!(out.contains("pub aliases: Option<Vec<String_>>,"));
+
+        // `String2` is taken by another definition
+        let out = generate_with(PrimitiveNames::Rename);
+        assert!(out.contains("pub type String3 = String;"));
+        assert!(out.contains("pub type String2 = i32;"));
+        assert!(out.contains("pub name: String,"));
+    }
+
+    #[test]
+    fn newtypes() {
+        let spec = r##"
+swagger: "2.0"
+definitions:
+  ApiKey:
+    type: string
+  Created:
+    type: string
+    format: date-time
+  Client:
+    type: object
+    properties:
+      Key:
+        $ref: "#/definitions/ApiKey"
+"##;
+        let opts = CodegenOptions {
+            newtypes: true,
+            ..Default::default()
+        };
+        let out = generate(Codegen::new(opts), spec);
+        assert!(out.contains("#[serde(transparent)]\npub struct ApiKey(pub String);"));
+        assert!(out.contains("impl std::str::FromStr for ApiKey {"));
+        assert!(out.contains("    pub key: Option<ApiKey>,"));
+        // only scalars that can be displayed are wrapped
+        assert!(out.contains("pub type Created = DateTime<Utc>;"));
+
+        let out = generate(Codegen::default(), spec);
+        assert!(out.contains("pub type ApiKey = String;"));
+        assert!(out.contains("    pub key: Option<String>,"));
+    }
+}
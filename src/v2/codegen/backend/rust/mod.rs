@@ -1,18 +1,41 @@
 mod backend;
 mod types;
 
-pub use backend::Codegen;
+pub use backend::{
+    BigInt, Codegen, CodegenOptions, FloatEq, FloatHash, PrimitiveNames, StringType, WireFormat,
+};
 pub use types::Type;
 
+use crate::v2::codegen::backend::{
+    finish_identifier, is_identifier, sanitize_identifier, EnumVariantCase,
+};
 use crate::{Case, Casing};
 
+/// Keywords and reserved words of the 2018 and 2021 editions.
 pub const KEYWORDS: &[&str] = &[
-    "as", "break", "const", "continue", "crate", "else", "enum", "extern", "false", "fn", "for",
-    "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub", "ref", "return",
-    "self", "Self", "static", "struct", "super", "trait", "true", "type", "unsafe", "use", "where",
-    "while",
+    "abstract", "as", "async", "await", "become", "box", "break", "const", "continue", "crate",
+    "do", "dyn", "else", "enum", "extern", "false", "final", "fn", "for", "if", "impl", "in",
+    "let", "loop", "macro", "match", "mod", "move", "mut", "override", "priv", "pub", "ref",
+    "return", "self", "Self", "static", "struct", "super", "trait", "true", "try", "type",
+    "typeof", "unsafe", "unsized", "use", "virtual", "where", "while", "yield",
 ];
 
+/// Words reserved starting with the 2024 edition.
+pub const KEYWORDS_2024: &[&str] = &["gen"];
+
+/// Standard library types scalar models map to. A name formatting to one of them keeps a
+/// trailing underscore, see `PrimitiveNames::Newtype`.
+const STD_TYPE_NAMES: &[&str] = &["String"];
+
+/// Rust edition generated code is compiled with.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Edition {
+    E2018,
+    #[default]
+    E2021,
+    E2024,
+}
+
 pub fn is_keyword(word: &str) -> bool {
     KEYWORDS.contains(&word)
 }
@@ -24,39 +47,166 @@ pub fn fix_name_if_keyword(name: &mut String) {
     }
 }
 
+/// Like `fix_name_if_keyword`, also escaping the words reserved by `edition`.
+pub fn fix_name_if_keyword_in(name: &mut String, edition: Edition) {
+    if edition >= Edition::E2024 && KEYWORDS_2024.contains(&name.as_str()) {
+        name.push('_');
+    } else {
+        fix_name_if_keyword(name);
+    }
+}
+
 pub fn format_type_name(name: &str) -> String {
-    let mut name = name.to_case(Case::UpperCamel);
-    fix_name_if_keyword(&mut name);
-    name
+    let mut ident = sanitize_identifier(name).to_case(Case::UpperCamel);
+    fix_name_if_keyword(&mut ident);
+    if name.ends_with('_') && STD_TYPE_NAMES.contains(&ident.as_str()) {
+        ident.push('_');
+    }
+    finish_identifier(ident, name, "Type")
 }
 
 pub fn format_var_name(name: &str) -> String {
-    let name = name.replace('-', "_");
-    let name = name.replace('.', "_");
-    let name = name.replace('/', "_");
-    let mut name = name.to_case(Case::Snake);
-    fix_name_if_keyword(&mut name);
+    format_var_name_in(name, Edition::default())
+}
+
+/// Formats a field name that is valid in `edition`.
+pub fn format_var_name_in(name: &str, edition: Edition) -> String {
+    let mut ident = sanitize_identifier(name).to_case(Case::Snake);
+    fix_name_if_keyword_in(&mut ident, edition);
+    finish_identifier(ident, name, "field_")
+}
+
+/// Formats a segment of a Go package path like `github.com` or `go-units` as a module name.
+pub fn format_module_name(name: &str, edition: Edition) -> String {
+    let mut name: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() {
+                c.to_ascii_lowercase()
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if name.is_empty() || name.starts_with(|c: char| c.is_ascii_digit()) {
+        name.insert(0, '_');
+    }
+    fix_name_if_keyword_in(&mut name, edition);
     name
 }
 
 pub fn format_enum_value_name(name: &str) -> String {
-    let name = name.replace('-', " ");
-    let name = name.replace('.', " ");
-    let name = name.replace('/', " ");
-    let mut name = name.to_case(Case::UpperCamel);
+    format_enum_value_name_with(name, EnumVariantCase::Default)
+}
+
+/// Formats the name of the variant of the enum value `name` in `case`.
+pub fn format_enum_value_name_with(name: &str, case: EnumVariantCase) -> String {
+    let screaming = case == EnumVariantCase::ScreamingSnake;
+    let mut name = match case {
+        EnumVariantCase::Verbatim if is_identifier(name) => name.to_string(),
+        EnumVariantCase::ScreamingSnake => sanitize_identifier(name).to_case(Case::UpperSnake),
+        _ => sanitize_identifier(name).to_case(Case::UpperCamel),
+    };
     name = name.replace(' ', "");
     fix_name_if_keyword(&mut name);
 
     if name.is_empty() {
-        "Empty".into()
+        if screaming { "EMPTY" } else { "Empty" }.into()
     } else if name
         .chars()
         .next()
         .map(|c| c.is_numeric())
         .unwrap_or_default()
     {
-        format!("Value{name}")
+        if screaming {
+            format!("VALUE_{name}")
+        } else {
+            format!("Value{name}")
+        }
     } else {
         name
     }
 }
+
+/// Names the variants of an enum of `values` in order, leaving out repeated values. Values whose
+/// names collide, like `up` and `UP`, are told apart by a numeric suffix, `Up` and `Up2`.
+pub fn enum_variant_names<'a>(
+    values: impl IntoIterator<Item = &'a str>,
+    case: EnumVariantCase,
+) -> Vec<(&'a str, String)> {
+    let mut variants: Vec<(&str, String)> = vec![];
+    for value in values {
+        if variants.iter().any(|(v, _)| *v == value) {
+            continue;
+        }
+        let name = format_enum_value_name_with(value, case);
+        let mut variant = name.clone();
+        let mut n = 2;
+        while variants.iter().any(|(_, v)| *v == variant) {
+            variant = format!("{name}{n}");
+            n += 1;
+        }
+        variants.push((value, variant));
+    }
+    variants
+}
+
+/// Serde `rename_all` rules in the order they are tried by `enum_rename_all`.
+const RENAME_ALL_RULES: &[&str] = &[
+    "PascalCase",
+    "lowercase",
+    "UPPERCASE",
+    "camelCase",
+    "snake_case",
+    "SCREAMING_SNAKE_CASE",
+    "kebab-case",
+    "SCREAMING-KEBAB-CASE",
+];
+
+/// Renames an enum variant the way serde does for `#[serde(rename_all = "{rule}")]`.
+fn rename_variant(rule: &str, variant: &str) -> String {
+    let snake = || {
+        let mut snake = String::new();
+        for (i, c) in variant.char_indices() {
+            if i > 0 && c.is_uppercase() {
+                snake.push('_');
+            }
+            snake.push(c.to_ascii_lowercase());
+        }
+        snake
+    };
+    match rule {
+        "lowercase" => variant.to_ascii_lowercase(),
+        "UPPERCASE" => variant.to_ascii_uppercase(),
+        "camelCase" => {
+            let mut chars = variant.chars();
+            chars
+                .next()
+                .map(|c| c.to_ascii_lowercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        }
+        "snake_case" => snake(),
+        "SCREAMING_SNAKE_CASE" => snake().to_ascii_uppercase(),
+        "kebab-case" => snake().replace('_', "-"),
+        "SCREAMING-KEBAB-CASE" => snake().to_ascii_uppercase().replace('_', "-"),
+        _ => variant.to_string(),
+    }
+}
+
+/// Finds a serde `rename_all` rule mapping the variant names generated for every value of an
+/// enum back to the value. `PascalCase` means the variant names already are the values.
+pub fn enum_rename_all<'a>(
+    values: impl IntoIterator<Item = &'a str>,
+    case: EnumVariantCase,
+) -> Option<&'static str> {
+    let values: Vec<_> = values.into_iter().collect();
+    if values.is_empty() {
+        return None;
+    }
+    let variants = enum_variant_names(values, case);
+    RENAME_ALL_RULES.iter().copied().find(|rule| {
+        variants
+            .iter()
+            .all(|(value, variant)| rename_variant(rule, variant) == *value)
+    })
+}
@@ -1,8 +1,8 @@
 use crate::v2::codegen::backend::rust::format_type_name;
 use crate::v2::{trim_reference, Schema, Swagger};
 
-use log::trace;
-use std::fmt;
+use log::{trace, warn};
+use std::{collections::HashSet, fmt};
 
 #[derive(Clone)]
 pub enum Type {
@@ -14,18 +14,42 @@ pub enum Type {
     U32,
     I64,
     U64,
+    I128,
+    /// An arbitrary precision `num_bigint::BigInt`.
+    #[cfg(feature = "num-bigint")]
+    BigInt,
     ISize,
     USize,
     F32,
     F64,
+    /// A float wrapped in `ordered_float::OrderedFloat`.
+    #[cfg(feature = "ordered-float")]
+    OrderedFloat(Box<Type>),
     String,
+    /// A `Box<str>`, see `StringType::BoxStr`.
+    BoxStr,
     DateTime,
+    /// A `chrono::NaiveTime`, imported by the generated models when they use it.
+    Time,
     Bool,
+    /// A `serde_bytes::ByteBuf`.
+    ByteBuf,
     Vec(Box<Type>),
     Object(Box<Type>),
+    /// A map keyed by a generated enum, `(key, value)`.
+    Map(Box<Type>, Box<Type>),
     Option(Box<Type>),
+    /// A `Box` giving a field of a recursive model a known size.
+    Boxed(Box<Type>),
     Custom(String),
+    /// A `Cow<'a, str>` borrowing from the deserialized input where possible.
+    Cow,
+    /// A generated type with a lifetime parameter, as it has fields borrowing from the
+    /// deserialized input.
+    Borrowed(String),
     Value,
+    /// The `EmptyObject` helper, an inline object that can't have any keys.
+    EmptyObject,
 }
 
 impl fmt::Display for Type {
@@ -40,23 +64,141 @@ impl fmt::Display for Type {
             U32 => write!(f, "u32"),
             I64 => write!(f, "i64"),
             U64 => write!(f, "u64"),
+            I128 => write!(f, "i128"),
+            #[cfg(feature = "num-bigint")]
+            BigInt => write!(f, "num_bigint::BigInt"),
             F32 => write!(f, "f32"),
             F64 => write!(f, "f64"),
+            #[cfg(feature = "ordered-float")]
+            OrderedFloat(ty) => write!(f, "ordered_float::OrderedFloat<{ty}>"),
             ISize => write!(f, "isize"),
             USize => write!(f, "usize"),
             String => write!(f, "String"),
+            BoxStr => write!(f, "Box<str>"),
             DateTime => write!(f, "DateTime<Utc>"),
+            Time => write!(f, "NaiveTime"),
             Bool => write!(f, "bool"),
+            ByteBuf => write!(f, "serde_bytes::ByteBuf"),
             Vec(ty) => write!(f, "Vec<{ty}>"),
             Object(ty) => write!(f, "HashMap<String, {ty}>"),
+            Map(key, ty) => write!(f, "HashMap<{key}, {ty}>"),
             Option(ty) => write!(f, "Option<{ty}>"),
+            Boxed(ty) => write!(f, "Box<{ty}>"),
             Custom(ty) => write!(f, "{}", format_type_name(ty)),
+            Cow => write!(f, "std::borrow::Cow<'a, str>"),
+            Borrowed(ty) => write!(f, "{}<'a>", format_type_name(ty)),
             Value => write!(f, "Value"),
+            EmptyObject => write!(f, "EmptyObject"),
         }
     }
 }
 
 impl Type {
+    /// Names of the generated types this type is composed of.
+    pub fn custom_types(&self) -> Vec<&str> {
+        match self {
+            Type::Custom(name) | Type::Borrowed(name) => vec![name],
+            #[cfg(feature = "ordered-float")]
+            Type::OrderedFloat(ty) => ty.custom_types(),
+            Type::Vec(ty) | Type::Object(ty) | Type::Option(ty) | Type::Boxed(ty) => {
+                ty.custom_types()
+            }
+            Type::Map(key, ty) => {
+                let mut types = key.custom_types();
+                types.extend(ty.custom_types());
+                types
+            }
+            _ => vec![],
+        }
+    }
+
+    /// Wraps every float this type is composed of in `OrderedFloat`.
+    #[cfg(feature = "ordered-float")]
+    pub fn with_ordered_floats(self) -> Self {
+        match self {
+            Type::F32 | Type::F64 => Type::OrderedFloat(Box::new(self)),
+            Type::Vec(ty) => Type::Vec(Box::new(ty.with_ordered_floats())),
+            Type::Object(ty) => Type::Object(Box::new(ty.with_ordered_floats())),
+            Type::Map(key, ty) => Type::Map(key, Box::new(ty.with_ordered_floats())),
+            Type::Option(ty) => Type::Option(Box::new(ty.with_ordered_floats())),
+            ty => ty,
+        }
+    }
+
+    /// Replaces every `i128` this type is composed of with `num_bigint::BigInt`.
+    #[cfg(feature = "num-bigint")]
+    pub fn with_num_big_ints(self) -> Self {
+        match self {
+            Type::I128 => Type::BigInt,
+            Type::Vec(ty) => Type::Vec(Box::new(ty.with_num_big_ints())),
+            Type::Object(ty) => Type::Object(Box::new(ty.with_num_big_ints())),
+            Type::Map(key, ty) => Type::Map(key, Box::new(ty.with_num_big_ints())),
+            Type::Option(ty) => Type::Option(Box::new(ty.with_num_big_ints())),
+            ty => ty,
+        }
+    }
+
+    /// Whether this type implements `Default`, generated types don't derive it.
+    pub fn is_default(&self) -> bool {
+        match self {
+            Type::Custom(_) | Type::Borrowed(_) => false,
+            Type::Boxed(ty) => ty.is_default(),
+            _ => true,
+        }
+    }
+
+    /// Whether this type or any type it is composed of has the lifetime `'a`.
+    pub fn has_lifetime(&self) -> bool {
+        match self {
+            Type::Cow | Type::Borrowed(_) => true,
+            Type::Vec(ty) | Type::Object(ty) | Type::Option(ty) | Type::Boxed(ty) => {
+                ty.has_lifetime()
+            }
+            Type::Map(_, ty) => ty.has_lifetime(),
+            _ => false,
+        }
+    }
+
+    /// Replaces every `String` this type is composed of, except for map keys, with a `Cow` and
+    /// every generated type named in `borrowing` with its borrowing variant.
+    pub fn borrowed(self, borrowing: &HashSet<String>) -> Self {
+        match self {
+            Type::String => Type::Cow,
+            Type::Custom(name) if borrowing.contains(&format_type_name(&name)) => {
+                Type::Borrowed(name)
+            }
+            Type::Vec(ty) => Type::Vec(Box::new(ty.borrowed(borrowing))),
+            Type::Object(ty) => Type::Object(Box::new(ty.borrowed(borrowing))),
+            Type::Map(key, ty) => Type::Map(key, Box::new(ty.borrowed(borrowing))),
+            Type::Option(ty) => Type::Option(Box::new(ty.borrowed(borrowing))),
+            Type::Boxed(ty) => Type::Boxed(Box::new(ty.borrowed(borrowing))),
+            ty => ty,
+        }
+    }
+
+    /// Replaces every `String` this type is composed of, except for map keys, with a `Box<str>`.
+    pub fn with_boxed_strs(self) -> Self {
+        match self {
+            Type::String => Type::BoxStr,
+            Type::Vec(ty) => Type::Vec(Box::new(ty.with_boxed_strs())),
+            Type::Object(ty) => Type::Object(Box::new(ty.with_boxed_strs())),
+            Type::Map(key, ty) => Type::Map(key, Box::new(ty.with_boxed_strs())),
+            Type::Option(ty) => Type::Option(Box::new(ty.with_boxed_strs())),
+            Type::Boxed(ty) => Type::Boxed(Box::new(ty.with_boxed_strs())),
+            ty => ty,
+        }
+    }
+
+    /// Replaces every `Vec<u8>` this type is composed of with `serde_bytes::ByteBuf`.
+    pub fn with_byte_bufs(self) -> Self {
+        match self {
+            Type::Vec(ty) if matches!(*ty, Type::U8) => Type::ByteBuf,
+            Type::Vec(ty) => Type::Vec(Box::new(ty.with_byte_bufs())),
+            Type::Option(ty) => Type::Option(Box::new(ty.with_byte_bufs())),
+            ty => ty,
+        }
+    }
+
     pub fn from_integer_format(format: &str) -> Option<Self> {
         let ty = match format {
             "int" => Type::ISize,
@@ -69,6 +211,7 @@ impl Type {
             "uint16" => Type::U16,
             "int8" => Type::I8,
             "uint8" => Type::U8,
+            "bigint" | "biginteger" => Type::I128,
             _ => return None,
         };
 
@@ -88,6 +231,14 @@ impl crate::v2::Type for Type {
         parent_name: Option<&str>,
         swagger: &Swagger<Self>,
     ) -> Option<Self> {
+        if schema.is_mixed_enum() {
+            let ty = Type::Value;
+            return Some(if is_required {
+                ty
+            } else {
+                Type::Option(Box::new(ty))
+            });
+        }
         let ty = schema.type_()?;
         trace!(
             "mapping schema type, type: {ty}, ref: {ref_:?}, required: {is_required}, parent: {parent_name:?}"
@@ -105,9 +256,12 @@ impl crate::v2::Type for Type {
                 .as_deref()
             {
                 Some("date-time") | Some("datetime") | Some("date time") => Type::DateTime,
+                Some("time") => Type::Time,
                 Some("binary") => Type::Vec(Box::new(Type::U8)),
                 _ => Type::String,
             },
+            // the content of an uploaded or downloaded file
+            "file" => Type::Vec(Box::new(Type::U8)),
             "boolean" => Type::Bool,
             "array" => {
                 let ty = if let Some(ref_) = ref_ {
@@ -127,9 +281,23 @@ impl crate::v2::Type for Type {
             "object" => {
                 let ty = if let Some(ref_) = ref_ {
                     Type::Custom(trim_reference(ref_).to_string())
-                } else if let Some(item) = &schema.additional_properties {
+                } else if schema.is_empty_object() {
+                    Type::EmptyObject
+                } else if let Some(item) = &schema.map_values() {
                     if let Some(ty) = Self::map_item_type(item, true, parent_name, swagger) {
-                        Type::Object(Box::new(ty))
+                        match schema.x_key_type.as_deref() {
+                            Some(key) => match swagger.get_ref_schema(key) {
+                                Some(key_schema) if key_schema.is_string_enum() => {
+                                    let key = swagger.type_names().get(key).to_string();
+                                    Type::Map(Box::new(Type::Custom(key)), Box::new(ty))
+                                }
+                                _ => {
+                                    warn!("`x-key-type` `{key}` is not a string enum, keeping string keys");
+                                    Type::Object(Box::new(ty))
+                                }
+                            },
+                            None => Type::Object(Box::new(ty)),
+                        }
                     } else {
                         return None;
                     }
@@ -139,7 +307,7 @@ impl crate::v2::Type for Type {
                     } else {
                         return None;
                     }
-                } else if schema.properties.is_some() {
+                } else if schema.properties.is_some() || schema.is_open_object() {
                     if let Some(name) = schema.name() {
                         Type::Custom(name)
                     } else if let Some(parent_name) = &parent_name {
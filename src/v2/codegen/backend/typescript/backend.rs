@@ -0,0 +1,270 @@
+use crate::v2::codegen::{
+    backend::{
+        typescript::{self, format_type_name, format_var_name},
+        CodegenBackend, FieldOrder,
+    },
+    ModelKind, ModelPrototype, PrototypeOptions,
+};
+use crate::v2::{Item, Schema, Swagger};
+
+use log::{debug, error, trace};
+
+#[derive(Debug, Default, Clone)]
+pub struct CodegenOptions {
+    /// Order of the properties of an interface.
+    pub field_order: FieldOrder,
+    /// Only generate models of these kinds, or all of them if empty, see
+    /// `PrototypeOptions::only`.
+    pub only: Vec<ModelKind>,
+}
+
+#[derive(Default)]
+pub struct Codegen {
+    generated_models: Vec<String>,
+    opts: CodegenOptions,
+}
+
+impl CodegenBackend<typescript::Type> for Codegen {
+    fn generate_model(
+        &mut self,
+        model: ModelPrototype,
+        swagger: &Swagger<typescript::Type>,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        trace!("generating {} `{}`", model.schema.type_(), &model.name);
+        match &model.schema {
+            Item::Reference(ref_) => {
+                self.generate_reference_model(ref_, &model, swagger, writer)?
+            }
+            Item::Object(schema) => self.generate_object_model(schema, &model, swagger, writer)?,
+        }
+        Ok(())
+    }
+
+    fn prototype_options(&self) -> PrototypeOptions {
+        PrototypeOptions {
+            only: self.opts.only.clone(),
+            ..Default::default()
+        }
+    }
+
+    fn generate_helpers(
+        &mut self,
+        _swagger: &Swagger<typescript::Type>,
+        _writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Codegen {
+    pub fn new(opts: CodegenOptions) -> Self {
+        Self {
+            generated_models: vec![],
+            opts,
+        }
+    }
+
+    fn generate_reference_model(
+        &mut self,
+        ref_: &str,
+        model: &ModelPrototype,
+        swagger: &Swagger<typescript::Type>,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        if let Some(schema) = swagger.get_ref_schema(ref_) {
+            let schema = swagger.merge_all_of_schema(schema);
+            if !schema.is_object() {
+                return Ok(());
+            }
+            if let Some(ty) = swagger.map_reference_type(ref_, true, Some(&model.name)) {
+                let type_name = format_type_name(&model.name);
+                self.generate_alias(&type_name, &ty, &schema, writer)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn generate_object_model(
+        &mut self,
+        schema: &Schema,
+        model: &ModelPrototype,
+        swagger: &Swagger<typescript::Type>,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        let schema = swagger.merge_all_of_schema(schema);
+        self.generate_schema(
+            &model.name,
+            model.parent_name.as_deref(),
+            &schema,
+            swagger,
+            writer,
+        )
+    }
+
+    fn generate_schema(
+        &mut self,
+        name: &str,
+        parent_name: Option<&str>,
+        schema: &Schema,
+        swagger: &Swagger<typescript::Type>,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        debug!("handling schema {name}, parent: {parent_name:?}");
+        trace!("{schema:?}");
+        let name = if name.is_empty() {
+            schema.name().unwrap_or(
+                parent_name
+                    .map(|parent_name| format!("{}InlineItem", parent_name))
+                    .unwrap_or(name.to_string()),
+            )
+        } else {
+            name.to_string()
+        };
+        let type_name = format_type_name(&name);
+        trace!("mapped name: {name}, type name: {type_name}");
+
+        if schema.properties.is_some() {
+            self.generate_props_schema(&name, schema, swagger, writer)?
+        } else if schema.is_string_enum() {
+            self.generate_enum_schema(&name, schema, writer)?
+        } else if let Some(ref_) = schema.ref_.as_deref() {
+            error!("got unhandled reference schema {ref_}");
+        } else if let Some(ty) = swagger.map_schema_type(schema, None, true, Some(&name)) {
+            debug!("handling basic type schema {type_name} = {ty}");
+            self.generate_alias(&type_name, &ty, schema, writer)?;
+        } else {
+            error!("unhandled schema {schema:?}");
+        }
+
+        Ok(())
+    }
+
+    fn generate_alias(
+        &mut self,
+        type_name: &str,
+        ty: &typescript::Type,
+        schema: &Schema,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        let ty_str = ty.to_string();
+        if type_name == ty_str {
+            log::warn!("skipping type alias with same name `{type_name} == {ty_str}`");
+            return Ok(());
+        }
+        if self.generated_models.iter().any(|m| m == type_name) {
+            log::warn!(
+                "skipping type alias `{type_name}`, a type with the same name already exists"
+            );
+            return Ok(());
+        }
+        self.print_description(schema, None, writer)?;
+        writeln!(writer, "export type {type_name} = {ty_str};\n")?;
+        self.generated_models.push(type_name.to_string());
+        Ok(())
+    }
+
+    fn generate_props_schema(
+        &mut self,
+        name: &str,
+        schema: &Schema,
+        swagger: &Swagger<typescript::Type>,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        debug!("handling property schema `{name}`");
+        let props = schema.properties.as_ref().unwrap();
+        let type_name = format_type_name(name);
+        self.print_description(schema, None, writer)?;
+
+        writeln!(writer, "export interface {type_name} {{")?;
+        let props = self.opts.field_order.props(props);
+        for (prop, item) in &props {
+            let is_required = schema.is_required(prop, item);
+            debug!("handling property `{prop}`, required: {is_required}");
+
+            let ty = match item {
+                Item::Reference(ref_) => swagger.map_reference_type(ref_, is_required, Some(prop)),
+                Item::Object(_) => {
+                    let prop_ty_name = format!("{type_name}{prop}");
+                    swagger.map_item_type(item, is_required, Some(&prop_ty_name))
+                }
+            }
+            .unwrap_or(typescript::Type::Value);
+
+            if let Item::Object(schema) = item {
+                self.print_description(schema, Some(4), writer)?;
+            }
+            let (optional, ty) = match ty {
+                typescript::Type::Optional(ty) => ("?", *ty),
+                ty => ("", ty),
+            };
+            let nullable = match item {
+                Item::Object(schema) if schema.x_nullable => " | null",
+                _ => "",
+            };
+            let readonly = match item {
+                Item::Object(schema) if schema.read_only => "readonly ",
+                _ => "",
+            };
+            writeln!(
+                writer,
+                "    {readonly}{}{optional}: {ty}{nullable};",
+                format_var_name(prop)
+            )?;
+        }
+        self.generated_models.push(type_name);
+        writeln!(writer, "}}\n")
+    }
+
+    fn generate_enum_schema(
+        &mut self,
+        name: &str,
+        schema: &Schema,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        debug!("handling enum schema `{name}`");
+        let type_name = format_type_name(name);
+        let variants: Vec<_> = schema
+            .enum_
+            .iter()
+            .filter_map(|value| value.as_str())
+            .map(|value| format!("{value:?}"))
+            .collect();
+        self.print_description(schema, None, writer)?;
+        writeln!(
+            writer,
+            "export type {type_name} = {};\n",
+            variants.join(" | ")
+        )?;
+        self.generated_models.push(type_name);
+        Ok(())
+    }
+
+    fn print_description(
+        &self,
+        schema: &Schema,
+        indentation: Option<u8>,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        if let Some(description) = &schema.description {
+            self.print_doc_comment(description, indentation, writer)?;
+        }
+        Ok(())
+    }
+
+    fn print_doc_comment(
+        &self,
+        comment: impl AsRef<str>,
+        indentation: Option<u8>,
+        writer: &mut Box<dyn std::io::Write>,
+    ) -> std::io::Result<()> {
+        let indentation = indentation
+            .map(|i| " ".repeat(i.into()))
+            .unwrap_or_default();
+        writeln!(writer, "{indentation}/**")?;
+        for line in comment.as_ref().lines() {
+            writeln!(writer, "{indentation} * {}", line.replace("*/", "*\\/"))?;
+        }
+        writeln!(writer, "{indentation} */")
+    }
+}
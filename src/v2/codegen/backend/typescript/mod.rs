@@ -0,0 +1,90 @@
+mod backend;
+mod types;
+
+pub use backend::{Codegen, CodegenOptions};
+pub use types::Type;
+
+use crate::v2::codegen::backend::{finish_identifier, sanitize_identifier};
+use crate::{Case, Casing};
+
+pub const KEYWORDS: &[&str] = &[
+    "break",
+    "case",
+    "catch",
+    "class",
+    "const",
+    "continue",
+    "debugger",
+    "default",
+    "delete",
+    "do",
+    "else",
+    "enum",
+    "export",
+    "extends",
+    "false",
+    "finally",
+    "for",
+    "function",
+    "if",
+    "import",
+    "in",
+    "instanceof",
+    "new",
+    "null",
+    "return",
+    "super",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "var",
+    "void",
+    "while",
+    "with",
+    "any",
+    "boolean",
+    "number",
+    "string",
+    "symbol",
+    "unknown",
+    "never",
+    "object",
+];
+
+pub fn is_keyword(word: &str) -> bool {
+    KEYWORDS.contains(&word)
+}
+
+pub fn fix_name_if_keyword(name: &mut String) {
+    let is_keyword = is_keyword(name.as_str());
+    if is_keyword {
+        name.push('_');
+    }
+}
+
+pub fn format_type_name(name: &str) -> String {
+    let mut ident = sanitize_identifier(name).to_case(Case::UpperCamel);
+    fix_name_if_keyword(&mut ident);
+    finish_identifier(ident, name, "Type")
+}
+
+/// Property names are kept as they appear on the wire, quoting the ones that aren't valid
+/// identifiers.
+pub fn format_var_name(name: &str) -> String {
+    let is_identifier = name
+        .chars()
+        .next()
+        .map(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        .unwrap_or_default()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if is_identifier {
+        name.to_string()
+    } else {
+        format!("{name:?}")
+    }
+}
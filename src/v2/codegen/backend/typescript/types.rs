@@ -0,0 +1,107 @@
+use crate::v2::codegen::backend::typescript::format_type_name;
+use crate::v2::{trim_reference, Schema, Swagger};
+
+use log::trace;
+use std::fmt;
+
+#[derive(Clone)]
+pub enum Type {
+    String,
+    Number,
+    Boolean,
+    Array(Box<Type>),
+    Record(Box<Type>),
+    Optional(Box<Type>),
+    Custom(String),
+    Value,
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use Type::*;
+        match self {
+            String => write!(f, "string"),
+            Number => write!(f, "number"),
+            Boolean => write!(f, "boolean"),
+            Array(ty) => write!(f, "Array<{ty}>"),
+            Record(ty) => write!(f, "Record<string, {ty}>"),
+            Optional(ty) => write!(f, "{ty} | undefined"),
+            Custom(ty) => write!(f, "{}", format_type_name(ty)),
+            Value => write!(f, "unknown"),
+        }
+    }
+}
+
+impl crate::v2::Type for Type {
+    fn format_name(name: &str) -> String {
+        format_type_name(name)
+    }
+
+    fn map_schema_type(
+        schema: &Schema,
+        ref_: Option<&str>,
+        is_required: bool,
+        parent_name: Option<&str>,
+        swagger: &Swagger<Self>,
+    ) -> Option<Self> {
+        let ty = schema.type_()?;
+        trace!(
+            "mapping schema type, type: {ty}, ref: {ref_:?}, required: {is_required}, parent: {parent_name:?}"
+        );
+        let mut ty = match ty {
+            "integer" | "number" => Type::Number,
+            // files are strings like binary strings are
+            "string" | "file" => Type::String,
+            "boolean" => Type::Boolean,
+            "array" => {
+                if let Some(ref_) = ref_ {
+                    Type::Custom(trim_reference(ref_).to_string())
+                } else if let Some(item) = &schema.items {
+                    Type::Array(Box::new(Self::map_item_type(
+                        item,
+                        true,
+                        parent_name,
+                        swagger,
+                    )?))
+                } else {
+                    return None;
+                }
+            }
+            "object" => {
+                if let Some(ref_) = ref_ {
+                    Type::Custom(trim_reference(ref_).to_string())
+                } else if let Some(item) = &schema.map_values() {
+                    Type::Record(Box::new(Self::map_item_type(
+                        item,
+                        true,
+                        parent_name,
+                        swagger,
+                    )?))
+                } else if let Some(item) = &schema.items {
+                    Type::Record(Box::new(Self::map_item_type(
+                        item,
+                        true,
+                        parent_name,
+                        swagger,
+                    )?))
+                } else if schema.properties.is_some() {
+                    if let Some(name) = schema.name() {
+                        Type::Custom(name)
+                    } else if let Some(parent_name) = &parent_name {
+                        Type::Custom(format!("{parent_name}InlineItem"))
+                    } else {
+                        Type::Value
+                    }
+                } else {
+                    Type::Value
+                }
+            }
+            _ => return None,
+        };
+        if !is_required {
+            ty = Type::Optional(Box::new(ty));
+        }
+        trace!("mapped to {ty}");
+        Some(ty)
+    }
+}
@@ -0,0 +1,183 @@
+//! Line diffs of generated code, to check that code generated earlier is up to date.
+
+/// Lines of unchanged context around every change.
+const CONTEXT: usize = 3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Op {
+    Equal,
+    Delete,
+    Insert,
+}
+
+/// A unified diff turning `old` into `new`, or `None` if they are the same.
+pub fn unified_diff(old: &str, new: &str, old_name: &str, new_name: &str) -> Option<String> {
+    if old == new {
+        return None;
+    }
+    // splitting on every newline keeps a missing newline at the end of a file visible
+    let old_lines: Vec<_> = old.split('\n').collect();
+    let new_lines: Vec<_> = new.split('\n').collect();
+
+    // every operation along with the index of the old and the new line it applies to
+    let mut lines = vec![];
+    let (mut i, mut j) = (0, 0);
+    for op in diff_ops(&old_lines, &new_lines) {
+        lines.push((op, i, j));
+        match op {
+            Op::Equal => (i, j) = (i + 1, j + 1),
+            Op::Delete => i += 1,
+            Op::Insert => j += 1,
+        }
+    }
+
+    let changes: Vec<_> = (0..lines.len())
+        .filter(|&n| lines[n].0 != Op::Equal)
+        .collect();
+    let mut out = format!("--- {old_name}\n+++ {new_name}\n");
+    let mut start = 0;
+    while start < changes.len() {
+        // changes closer than twice the context share a hunk
+        let mut end = start;
+        while end + 1 < changes.len() && changes[end + 1] - changes[end] <= 2 * CONTEXT {
+            end += 1;
+        }
+        let from = changes[start].saturating_sub(CONTEXT);
+        let to = (changes[end] + CONTEXT + 1).min(lines.len());
+        let hunk = &lines[from..to];
+        let old_count = hunk.iter().filter(|(op, _, _)| *op != Op::Insert).count();
+        let new_count = hunk.iter().filter(|(op, _, _)| *op != Op::Delete).count();
+        let (_, old_start, new_start) = hunk[0];
+        // an empty range starts at the line before it
+        let old_start = old_start + usize::from(old_count > 0);
+        let new_start = new_start + usize::from(new_count > 0);
+        out.push_str(&format!(
+            "@@ -{old_start},{old_count} +{new_start},{new_count} @@\n"
+        ));
+        for (op, i, j) in hunk {
+            let line = match op {
+                Op::Equal => format!(" {}", old_lines[*i]),
+                Op::Delete => format!("-{}", old_lines[*i]),
+                Op::Insert => format!("+{}", new_lines[*j]),
+            };
+            out.push_str(&line);
+            out.push('\n');
+        }
+        start = end + 1;
+    }
+    Some(out)
+}
+
+/// The shortest edit script turning `old` into `new`, found with Myers' algorithm.
+fn diff_ops(old: &[&str], new: &[&str]) -> Vec<Op> {
+    let (n, m) = (old.len() as isize, new.len() as isize);
+    let max = n + m;
+    // the furthest x reached on every diagonal `k = x - y`, offset by `max`
+    let mut v = vec![0; 2 * max as usize + 2];
+    let at = |k: isize| (k + max) as usize;
+    // the diagonals `-d..=d` of `v` before every step `d`, to walk the path back
+    let mut trace: Vec<Vec<isize>> = vec![];
+    'search: for d in 0..=max {
+        trace.push(v[at(-d)..=at(d)].to_vec());
+        for k in (-d..=d).step_by(2) {
+            let mut x = if k == -d || (k != d && v[at(k - 1)] < v[at(k + 1)]) {
+                v[at(k + 1)]
+            } else {
+                v[at(k - 1)] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && old[x as usize] == new[y as usize] {
+                (x, y) = (x + 1, y + 1);
+            }
+            v[at(k)] = x;
+            if x >= n && y >= m {
+                break 'search;
+            }
+        }
+    }
+
+    let mut ops = vec![];
+    let (mut x, mut y) = (n, m);
+    for (d, v) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let get = |k: isize| v.get((k + d) as usize).copied().unwrap_or(0);
+        let k = x - y;
+        let prev_k = if k == -d || (k != d && get(k - 1) < get(k + 1)) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let prev_x = get(prev_k);
+        let prev_y = prev_x - prev_k;
+        while x > prev_x && y > prev_y {
+            ops.push(Op::Equal);
+            (x, y) = (x - 1, y - 1);
+        }
+        if d > 0 {
+            ops.push(if x == prev_x { Op::Insert } else { Op::Delete });
+        }
+        (x, y) = (prev_x, prev_y);
+    }
+    ops.reverse();
+    ops
+}
+
+#[cfg(test)]
+mod test {
+    use super::unified_diff;
+
+    #[test]
+    fn same() {
+        assert_eq!(unified_diff("a\nb\n", "a\nb\n", "old", "new"), None);
+    }
+
+    #[test]
+    fn hunks() {
+        let old: String = (1..=20).map(|n| format!("{n}\n")).collect();
+        let new: String = (1..=20)
+            .filter_map(|n| match n {
+                2 => Some("two\n".to_string()),
+                4 => None,
+                18 => Some("18\neighteen and a half\n".to_string()),
+                n => Some(format!("{n}\n")),
+            })
+            .collect();
+        assert_eq!(
+            unified_diff(&old, &new, "models.rs", "generated").unwrap(),
+            "--- models.rs
++++ generated
+@@ -1,7 +1,6 @@
+ 1
+-2
++two
+ 3
+-4
+ 5
+ 6
+ 7
+@@ -16,6 +15,7 @@
+ 16
+ 17
+ 18
++eighteen and a half
+ 19
+ 20\n \n"
+        );
+    }
+
+    #[test]
+    fn edges() {
+        assert_eq!(
+            unified_diff("", "a\n", "old", "new").unwrap(),
+            "--- old\n+++ new\n@@ -1,1 +1,2 @@\n+a\n \n"
+        );
+        assert_eq!(
+            unified_diff("a\nb", "a\nb\n", "old", "new").unwrap(),
+            "--- old\n+++ new\n@@ -1,2 +1,3 @@\n a\n b\n+\n"
+        );
+        assert_eq!(
+            unified_diff("a\nb\nc\n", "x\n", "old", "new").unwrap(),
+            "--- old\n+++ new\n@@ -1,4 +1,2 @@\n-a\n-b\n-c\n+x\n \n"
+        );
+    }
+}
@@ -0,0 +1,206 @@
+/// Reflows `text` so that no line is longer than `width` characters where possible.
+///
+/// Paragraphs separated by blank lines are kept apart, list items keep their bullet and get a
+/// hanging indent, while fenced code blocks, indented code, headings and tables are left
+/// untouched. Words longer than `width` are never split.
+pub fn reflow(text: &str, width: usize) -> Vec<String> {
+    let mut out = vec![];
+    let mut paragraph: Option<Paragraph> = None;
+    let mut in_fence = false;
+
+    for line in text.lines() {
+        let trimmed = line.trim_start();
+        if in_fence || trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            flush(&mut paragraph, width, &mut out);
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_fence = !in_fence;
+            }
+            out.push(line.to_string());
+            continue;
+        }
+        if trimmed.is_empty() {
+            flush(&mut paragraph, width, &mut out);
+            out.push(String::new());
+            continue;
+        }
+        if let Some(bullet) = bullet(line) {
+            flush(&mut paragraph, width, &mut out);
+            paragraph = Some(Paragraph {
+                indent: " ".repeat(bullet.len()),
+                first_prefix: bullet.to_string(),
+                words: line[bullet.len()..].to_string(),
+            });
+            continue;
+        }
+        let indented = line.len() - trimmed.len();
+        match &mut paragraph {
+            Some(p) if !p.first_prefix.is_empty() && indented > 0 => {
+                p.words.push(' ');
+                p.words.push_str(trimmed);
+            }
+            _ if indented >= 4 || trimmed.starts_with('#') || trimmed.starts_with('|') => {
+                flush(&mut paragraph, width, &mut out);
+                out.push(line.to_string());
+            }
+            Some(p) if p.first_prefix.is_empty() => {
+                p.words.push(' ');
+                p.words.push_str(trimmed);
+            }
+            _ => {
+                flush(&mut paragraph, width, &mut out);
+                paragraph = Some(Paragraph {
+                    indent: String::new(),
+                    first_prefix: String::new(),
+                    words: trimmed.to_string(),
+                });
+            }
+        }
+    }
+    flush(&mut paragraph, width, &mut out);
+    out
+}
+
+/// Removes trailing whitespace from every line, leading and trailing blank lines and the
+/// leading spaces and tabs shared by all non-blank lines. Other leading whitespace, like the
+/// ideographic space indenting CJK text, is kept.
+pub fn normalize_whitespace(text: &str) -> String {
+    let lines: Vec<_> = text.lines().map(str::trim_end).collect();
+    // spaces and tabs are a single byte, so the indent is a char boundary of every line
+    let indent = lines
+        .iter()
+        .filter(|line| !line.is_empty())
+        .map(|line| {
+            line.bytes()
+                .take_while(|b| matches!(b, b' ' | b'\t'))
+                .count()
+        })
+        .min()
+        .unwrap_or_default();
+    let start = lines.iter().position(|line| !line.is_empty());
+    let end = lines.iter().rposition(|line| !line.is_empty());
+    let (Some(start), Some(end)) = (start, end) else {
+        return String::new();
+    };
+    lines[start..=end]
+        .iter()
+        .map(|line| line.get(indent..).unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+struct Paragraph {
+    /// Prefix of the first line, the bullet of a list item or empty for plain text.
+    first_prefix: String,
+    /// Prefix of every following line.
+    indent: String,
+    words: String,
+}
+
+/// Returns the bullet of a list item line including its leading whitespace and the space after
+/// it, e.g. `"  - "` or `"1. "`.
+fn bullet(line: &str) -> Option<&str> {
+    let trimmed = line.trim_start();
+    let leading = line.len() - trimmed.len();
+    let marker_len = if ["- ", "* ", "+ "].iter().any(|b| trimmed.starts_with(b)) {
+        1
+    } else {
+        let digits = trimmed.chars().take_while(char::is_ascii_digit).count();
+        if digits == 0 || !trimmed[digits..].starts_with(". ") {
+            return None;
+        }
+        digits + 1
+    };
+    let spaces = trimmed[marker_len..]
+        .chars()
+        .take_while(|c| *c == ' ')
+        .count();
+    Some(&line[..leading + marker_len + spaces])
+}
+
+fn flush(paragraph: &mut Option<Paragraph>, width: usize, out: &mut Vec<String>) {
+    let Some(p) = paragraph.take() else {
+        return;
+    };
+    let mut line = p.first_prefix.clone();
+    let mut has_words = false;
+    for word in p.words.split_whitespace() {
+        if has_words && line.chars().count() + 1 + word.chars().count() > width {
+            out.push(line);
+            line = p.indent.clone();
+            has_words = false;
+        }
+        if has_words {
+            line.push(' ');
+        }
+        line.push_str(word);
+        has_words = true;
+    }
+    out.push(line);
+}
+
+#[cfg(test)]
+mod test {
+    use super::{normalize_whitespace, reflow};
+
+    #[test]
+    fn normalizes_whitespace() {
+        let text = "\n    First line.  \n\n      Indented more.\t\n    Last line.\n   \n";
+        assert_eq!(
+            normalize_whitespace(text),
+            "First line.\n\n  Indented more.\nLast line."
+        );
+        assert_eq!(normalize_whitespace(" \n\t"), "");
+        assert_eq!(normalize_whitespace("  a\n\u{3000}b"), "  a\n\u{3000}b");
+        assert_eq!(normalize_whitespace("  a\n  \u{3000}b"), "a\n\u{3000}b");
+    }
+
+    #[test]
+    fn reflows_paragraphs() {
+        let text = "The quick brown fox jumps over the lazy dog and keeps on\nrunning.\n\nSecond paragraph.";
+        assert_eq!(
+            reflow(text, 20),
+            vec![
+                "The quick brown fox",
+                "jumps over the lazy",
+                "dog and keeps on",
+                "running.",
+                "",
+                "Second paragraph.",
+            ]
+        );
+    }
+
+    #[test]
+    fn preserves_code_fences() {
+        let text = "Run the following command to start it:\n\n```\ndocker run --rm -it alpine:latest sh -c 'echo hello'\n```\nDone.";
+        assert_eq!(
+            reflow(text, 20),
+            vec![
+                "Run the following",
+                "command to start it:",
+                "",
+                "```",
+                "docker run --rm -it alpine:latest sh -c 'echo hello'",
+                "```",
+                "Done.",
+            ]
+        );
+    }
+
+    #[test]
+    fn preserves_bullet_lists() {
+        let text = "Possible values:\n- `none` disables networking for the container\n- `host` uses the host network\n  stack\n10. numbered item";
+        assert_eq!(
+            reflow(text, 24),
+            vec![
+                "Possible values:",
+                "- `none` disables",
+                "  networking for the",
+                "  container",
+                "- `host` uses the host",
+                "  network stack",
+                "10. numbered item",
+            ]
+        );
+    }
+}
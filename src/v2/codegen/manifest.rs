@@ -0,0 +1,135 @@
+use crate::v2::{
+    codegen::{backend::CodegenBackend, ModelPrototype, PrototypeSource},
+    validation::Severity,
+    Item, Swagger, Type, DEFINITIONS_REF,
+};
+
+use serde::Serialize;
+use std::{
+    collections::{HashMap, HashSet},
+    io,
+};
+
+/// What was generated for a spec, for tools building on the generated code.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Manifest {
+    /// Every generated model.
+    pub models: Vec<ManifestModel>,
+    /// Models of the spec that are not generated on their own.
+    pub skipped: Vec<SkippedModel>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ManifestModel {
+    /// Part of the spec the model comes from, `definition`, `response` or `operation`.
+    pub source: String,
+    /// The key of a definition, or the name the model was derived under from the spec.
+    pub spec_name: String,
+    /// Type name of the generated model.
+    pub name: String,
+    /// `object`, `enum` or `alias`.
+    pub kind: String,
+    /// Type name of the model this one is a type alias of, see `dedupe_identical_schemas`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alias_of: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SkippedModel {
+    pub spec_name: String,
+    pub reason: String,
+}
+
+impl Manifest {
+    /// Lists the models `backend` generates for `swagger`. Names from diagnostics of deriving
+    /// the models that aren't generated, like merged enums or too deeply nested schemas, are
+    /// skipped for the reason the diagnostic gives.
+    pub fn new<T: Type>(swagger: &Swagger<T>, backend: &dyn CodegenBackend<T>) -> Self {
+        let (prototypes, diagnostics) = backend.prototypes_with_diagnostics(swagger);
+        // definitions are derived under the names of `Swagger::type_names`
+        let keys: HashMap<_, _> = swagger
+            .definitions
+            .iter()
+            .flat_map(|definitions| definitions.0.keys())
+            .map(|key| {
+                let ref_ = format!("{DEFINITIONS_REF}{key}");
+                (swagger.type_names().get(&ref_).to_string(), key.clone())
+            })
+            .collect();
+
+        let models: Vec<_> = prototypes
+            .iter()
+            .map(|prototype| {
+                let spec_name = match prototype.source {
+                    PrototypeSource::Definition if prototype.parent_name.is_none() => {
+                        keys.get(&prototype.name).unwrap_or(&prototype.name)
+                    }
+                    _ => &prototype.name,
+                };
+                ManifestModel {
+                    source: source_name(prototype.source).to_string(),
+                    spec_name: spec_name.clone(),
+                    name: T::format_name(&prototype.name),
+                    kind: kind(prototype).to_string(),
+                    alias_of: prototype.alias_of.as_deref().map(T::format_name),
+                }
+            })
+            .collect();
+
+        let generated: HashSet<_> = prototypes
+            .iter()
+            .map(|prototype| prototype.name.as_str())
+            .collect();
+        let mut skipped = vec![];
+        for diagnostic in &diagnostics {
+            if diagnostic.severity == Severity::Warning {
+                continue;
+            }
+            for location in &diagnostic.locations {
+                if !generated.contains(location.as_str()) {
+                    skipped.push(SkippedModel {
+                        spec_name: location.clone(),
+                        reason: diagnostic.message.clone(),
+                    });
+                }
+            }
+        }
+        if backend.prototype_options().inline_threshold > 0 {
+            let mut inlined: Vec<_> = keys
+                .iter()
+                .filter(|(name, _)| !generated.contains(name.as_str()))
+                .map(|(_, key)| key)
+                .collect();
+            inlined.sort_unstable();
+            skipped.extend(inlined.into_iter().map(|key| SkippedModel {
+                spec_name: key.clone(),
+                reason: "inlined into the model using it".to_string(),
+            }));
+        }
+
+        Self { models, skipped }
+    }
+
+    /// Writes the manifest as pretty printed JSON.
+    pub fn write_json(&self, writer: &mut dyn io::Write) -> io::Result<()> {
+        serde_json::to_writer_pretty(&mut *writer, self)?;
+        writeln!(writer)
+    }
+}
+
+fn source_name(source: PrototypeSource) -> &'static str {
+    match source {
+        PrototypeSource::Definition => "definition",
+        PrototypeSource::Response => "response",
+        PrototypeSource::Operation => "operation",
+    }
+}
+
+fn kind(prototype: &ModelPrototype) -> &'static str {
+    match &prototype.schema {
+        _ if prototype.alias_of.is_some() => "alias",
+        Item::Object(schema) if schema.x_variants.is_some() || schema.is_string_enum() => "enum",
+        Item::Object(schema) if schema.properties.is_some() => "object",
+        _ => "alias",
+    }
+}
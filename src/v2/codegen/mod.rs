@@ -1,9 +1,20 @@
 pub mod backend;
+pub mod diff;
+mod doc;
+pub mod manifest;
+pub mod operations;
 mod prototyper;
 
-use crate::v2::{Swagger, Type};
+use crate::v2::{validation::Diagnostic, Swagger, Type};
 use backend::CodegenBackend;
-use prototyper::{ModelPrototype, Prototyper};
+use manifest::Manifest;
+
+pub use prototyper::{
+    BodyNames, ModelKind, ModelPrototype, PrototypeOptions, PrototypeSource, Prototyper,
+    DEFAULT_MAX_DEPTH,
+};
+
+use std::{cell::RefCell, io, rc::Rc};
 
 pub struct CodeGenerator<T: Type> {
     swagger: Swagger<T>,
@@ -18,4 +29,47 @@ impl<T: Type> CodeGenerator<T> {
     pub fn generate_models(&mut self, writer: &mut Box<dyn std::io::Write>) -> std::io::Result<()> {
         self.backend.generate(&self.swagger, writer)
     }
+
+    /// Problems found in the spec together with notes on how models were derived from it.
+    pub fn diagnostics(&self) -> Vec<Diagnostic> {
+        let mut diagnostics = self.swagger.validate();
+        diagnostics.extend(self.backend.prototypes_with_diagnostics(&self.swagger).1);
+        diagnostics
+    }
+
+    /// The models generated for the spec and the ones skipped, see `Manifest::new`.
+    pub fn manifest(&self) -> Manifest {
+        Manifest::new(&self.swagger, self.backend.as_ref())
+    }
+
+    /// Generates the models into memory and returns them as a string.
+    pub fn generate_models_to_string(&mut self) -> std::io::Result<String> {
+        let out = render_to_vec(|writer| self.generate_models(writer))?;
+        String::from_utf8(out).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+}
+
+/// Runs `render` with a writer backed by memory and returns everything written to it.
+pub(crate) fn render_to_vec(
+    render: impl FnOnce(&mut Box<dyn std::io::Write>) -> io::Result<()>,
+) -> io::Result<Vec<u8>> {
+    let buf = SharedBuf::default();
+    let mut writer = Box::new(buf.clone()) as Box<dyn std::io::Write>;
+    render(&mut writer)?;
+    drop(writer);
+    Ok(buf.0.take())
+}
+
+/// A writer whose output stays accessible after handing a boxed clone of it to a backend.
+#[derive(Clone, Default)]
+struct SharedBuf(Rc<RefCell<Vec<u8>>>);
+
+impl io::Write for SharedBuf {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.borrow_mut().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
 }
@@ -0,0 +1,330 @@
+use crate::v2::{
+    codegen::{
+        backend::rust::{self, format_var_name},
+        prototyper::{
+            body_model_names, form_model_name, query_params_model_name, response_model_name,
+        },
+        BodyNames,
+    },
+    operation::Operation,
+    parameter::Parameter,
+    responses::Response,
+    Swagger, Type, Value,
+};
+
+use serde::Serialize;
+use std::{collections::HashSet, io};
+
+/// A parameter of an operation in the operations manifest.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ParamDef {
+    pub name: String,
+    /// Where the parameter goes, `path`, `query`, `header`, `body` or `formData`.
+    pub location: String,
+    pub required: bool,
+    /// Type name of the generated model of a body parameter.
+    pub model: Option<String>,
+}
+
+/// An operation of the spec together with the type names of the models generated for it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OperationDef {
+    pub method: String,
+    pub path: String,
+    pub operation_id: Option<String>,
+    pub params: Vec<ParamDef>,
+    /// Type name of the model generated for the query parameters of the operation.
+    pub query_model: Option<String>,
+    /// `(status, type name)` of every response with a body.
+    pub response_models: Vec<(u16, String)>,
+    /// Type name of the model of the `default` response.
+    pub default_response: Option<String>,
+}
+
+/// Lists every operation of the spec sorted by path, the same order models are derived in. Body
+/// models are named the way `body_names` names them for the backends, query parameters only have
+/// a model with `query_params_models`.
+pub fn operation_defs<T: Type>(
+    swagger: &Swagger<T>,
+    body_names: BodyNames,
+    query_params_models: bool,
+) -> Vec<OperationDef> {
+    let body_names = body_model_names(swagger, body_names);
+    swagger
+        .operations()
+        .map(|(path, method, op)| {
+            let params = op
+                .parameters
+                .iter()
+                .filter_map(|param| {
+                    let (name, location, required, model) = match param {
+                        Parameter::Path(param) => (param.name.clone(), "path", true, None),
+                        Parameter::Query(param) => {
+                            (param.name.clone(), "query", param.required, None)
+                        }
+                        Parameter::Body(param) => {
+                            let model = &body_names[&(path, method, param.name.as_str())];
+                            let model = Some(T::format_name(model));
+                            (param.name.clone(), "body", param.required, model)
+                        }
+                        Parameter::FormData(param) => {
+                            let model =
+                                Some(T::format_name(&form_model_name::<T>(path, method, op)));
+                            (param.name.clone(), "formData", param.required, model)
+                        }
+                        Parameter::Other(map) => {
+                            let field = |key: &str| map.get(&Value::String(key.into()));
+                            let name = field("name")?.as_str()?.to_string();
+                            let location = field("in")?.as_str()?;
+                            let required = field("required")
+                                .and_then(Value::as_bool)
+                                .unwrap_or_default();
+                            return Some(ParamDef {
+                                name,
+                                location: location.to_string(),
+                                required,
+                                model: None,
+                            });
+                        }
+                    };
+                    Some(ParamDef {
+                        name,
+                        location: location.to_string(),
+                        required,
+                        model,
+                    })
+                })
+                .collect();
+            let query_model = (query_params_models
+                && op
+                    .parameters
+                    .iter()
+                    .any(|param| matches!(param, Parameter::Query(_))))
+            .then(|| T::format_name(&query_params_model_name::<T>(path, method, op)));
+
+            let mut response_models = vec![];
+            let mut default_response = None;
+            for (code, response) in &op.responses.0 {
+                let model = match response {
+                    Response::Object(response) if response.schema.is_some() => {
+                        response_model_name::<T>(path, method, op, code)
+                    }
+                    Response::Object(_) => continue,
+                    // the body is a referenced definition, no model is generated for it
+                    Response::Reference(ref_, _) => swagger.type_names().get(ref_).to_string(),
+                };
+                let model = T::format_name(&model);
+                if code == "default" {
+                    default_response = Some(model);
+                } else if let Ok(status) = code.parse() {
+                    response_models.push((status, model));
+                } else {
+                    log::warn!("skipping response `{code}` of `{method} {path}`");
+                }
+            }
+            response_models.sort_unstable();
+
+            OperationDef {
+                method: method.to_string(),
+                path: path.to_string(),
+                operation_id: op.operation_id.clone(),
+                params,
+                query_model,
+                response_models,
+                default_response,
+            }
+        })
+        .collect()
+}
+
+/// Writes the operations as a Rust `OPERATIONS` table along with the definitions of its types.
+pub fn write_rust(operations: &[OperationDef], writer: &mut dyn io::Write) -> io::Result<()> {
+    write!(
+        writer,
+        r#"/// A parameter of an operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParamDef {{
+    pub name: &'static str,
+    /// Where the parameter goes, `path`, `query`, `header`, `body` or `formData`.
+    pub location: &'static str,
+    pub required: bool,
+    /// Type name of the generated model of a body parameter.
+    pub model: Option<&'static str>,
+}}
+
+/// An operation of the API together with the type names of the models generated for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OperationDef {{
+    pub method: &'static str,
+    pub path: &'static str,
+    pub operation_id: Option<&'static str>,
+    pub params: &'static [ParamDef],
+    /// Type name of the model generated for the query parameters of the operation.
+    pub query_model: Option<&'static str>,
+    /// `(status, type name)` of every response with a body.
+    pub response_models: &'static [(u16, &'static str)],
+    /// Type name of the model of the `default` response.
+    pub default_response: Option<&'static str>,
+}}
+
+pub const OPERATIONS: &[OperationDef] = &[
+"#
+    )?;
+    for op in operations {
+        writeln!(writer, "    OperationDef {{")?;
+        writeln!(writer, "        method: {:?},", op.method)?;
+        writeln!(writer, "        path: {:?},", op.path)?;
+        writeln!(writer, "        operation_id: {:?},", op.operation_id)?;
+        writeln!(writer, "        params: &[")?;
+        for param in &op.params {
+            writeln!(
+                writer,
+                "            ParamDef {{ name: {:?}, location: {:?}, required: {}, model: {:?} }},",
+                param.name, param.location, param.required, param.model
+            )?;
+        }
+        writeln!(writer, "        ],")?;
+        writeln!(writer, "        query_model: {:?},", op.query_model)?;
+        let responses: Vec<_> = op
+            .response_models
+            .iter()
+            .map(|(status, model)| format!("({status}, {model:?})"))
+            .collect();
+        writeln!(
+            writer,
+            "        response_models: &[{}],",
+            responses.join(", ")
+        )?;
+        writeln!(
+            writer,
+            "        default_response: {:?},",
+            op.default_response
+        )?;
+        writeln!(writer, "    }},")?;
+    }
+    writeln!(writer, "];")
+}
+
+/// Writes the operations as a pretty printed JSON array.
+pub fn write_json(operations: &[OperationDef], writer: &mut dyn io::Write) -> io::Result<()> {
+    serde_json::to_writer_pretty(&mut *writer, operations)?;
+    writeln!(writer)
+}
+
+/// Names generated items of an operation in snake case after its id, or after its method and
+/// path if it has none.
+pub fn operation_ident(path: &str, method: &str, op: &Operation) -> String {
+    let name = match &op.operation_id {
+        Some(operation_id) => format_var_name(operation_id),
+        None => format_var_name(&format!("{method} {path}")),
+    };
+    name.trim_end_matches('_').to_string()
+}
+
+/// Percent-encodes everything but the unreserved characters of RFC 3986 so that a value always
+/// ends up in a single path segment.
+const ENCODE_PATH_SEGMENT: &str = r#"    fn encode_path_segment(segment: &str) -> String {
+        let mut encoded = String::with_capacity(segment.len());
+        for byte in segment.bytes() {
+            match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                    encoded.push(byte as char)
+                }
+                _ => encoded.push_str(&format!("%{byte:02X}")),
+            }
+        }
+        encoded
+    }
+"#;
+
+/// Writes a `paths` module with the path template of every operation as a constant, along with
+/// a function building the path from its parameters.
+///
+/// Items are named after the operation id, or after the method and path of operations without
+/// one. Path parameters mapped to numbers or booleans are taken by value, any other parameter is
+/// taken as `&str`.
+pub fn write_rust_paths(
+    swagger: &Swagger<rust::Type>,
+    writer: &mut dyn io::Write,
+) -> io::Result<()> {
+    writeln!(writer, "pub mod paths {{")?;
+    write!(writer, "{ENCODE_PATH_SEGMENT}")?;
+    let mut names = HashSet::new();
+    for (path, method, op) in swagger.operations() {
+        let name = operation_ident(path, method, op);
+        if !names.insert(name.clone()) {
+            log::warn!("skipping path of `{method} {path}`, `{name}` is already used");
+            continue;
+        }
+
+        let mut template = String::new();
+        let mut args = vec![];
+        let mut values = vec![];
+        let mut rest = path;
+        while let Some((head, tail)) = rest.split_once('{') {
+            let Some((param, tail)) = tail.split_once('}') else {
+                break;
+            };
+            template.push_str(head);
+            template.push_str("{}");
+            rest = tail;
+
+            let var = format_var_name(param);
+            let ty = op.parameters.iter().find_map(|p| match p {
+                Parameter::Path(p) if p.name == param => {
+                    rust::Type::map_schema_type(&p.to_schema(), None, true, None, swagger)
+                }
+                _ => None,
+            });
+            match ty {
+                Some(
+                    ty @ (rust::Type::I8
+                    | rust::Type::U8
+                    | rust::Type::I16
+                    | rust::Type::U16
+                    | rust::Type::I32
+                    | rust::Type::U32
+                    | rust::Type::I64
+                    | rust::Type::U64
+                    | rust::Type::I128
+                    | rust::Type::ISize
+                    | rust::Type::USize
+                    | rust::Type::F32
+                    | rust::Type::F64
+                    | rust::Type::Bool),
+                ) => {
+                    args.push(format!("{var}: {ty}"));
+                    values.push(format!("encode_path_segment(&{var}.to_string())"));
+                }
+                _ => {
+                    args.push(format!("{var}: &str"));
+                    values.push(format!("encode_path_segment({var})"));
+                }
+            }
+        }
+        template.push_str(rest);
+
+        writeln!(writer, "\n    /// `{method} {path}`")?;
+        writeln!(
+            writer,
+            "    pub const {}: &str = {path:?};\n",
+            name.to_uppercase()
+        )?;
+        writeln!(
+            writer,
+            "    pub fn {name}_path({}) -> String {{",
+            args.join(", ")
+        )?;
+        if values.is_empty() {
+            writeln!(writer, "        {}.to_string()", name.to_uppercase())?;
+        } else {
+            writeln!(
+                writer,
+                "        format!({template:?}, {})",
+                values.join(", ")
+            )?;
+        }
+        writeln!(writer, "    }}")?;
+    }
+    writeln!(writer, "}}")
+}
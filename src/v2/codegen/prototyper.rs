@@ -1,39 +1,498 @@
 use crate::v2::{
-    items::Item, parameter::Parameter, path::Path, responses::Response, schema::Schema, Swagger,
-    Type,
+    items::{Item, Items},
+    operation::Operation,
+    parameter::{Parameter, PathParameter},
+    responses::Response,
+    schema::Schema,
+    validation::Diagnostic,
+    Swagger, Type, DEFINITIONS_REF, RESPONSES_REF,
 };
 
 use log::{debug, error, trace};
-use std::marker::PhantomData;
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt,
+    marker::PhantomData,
+    sync::Arc,
+};
+
+/// How deeply inline schemas may be nested by default, see `PrototypeOptions::max_depth`.
+pub const DEFAULT_MAX_DEPTH: usize = 128;
+
+#[derive(Debug, Clone)]
+pub struct PrototypeOptions {
+    /// Merge identical enums even if their descriptions differ.
+    pub dedup_ignore_docs: bool,
+    /// Generate a single model for inline objects with the same structure and type aliases of
+    /// it for the others, see `dedup_schemas`.
+    pub dedupe_identical_schemas: bool,
+    /// Keep a single `allOf` reference next to inline members instead of merging it, see
+    /// `Swagger::flatten_all_of_schema`.
+    pub flatten_all_of: bool,
+    /// Inline definitions referenced by a single property of a single model instead of
+    /// generating them on their own, if they have at most this many properties. Objects become a
+    /// model named after the property and its parent, scalar definitions are replaced by their
+    /// type. `0` disables inlining.
+    pub inline_threshold: usize,
+    pub body_names: BodyNames,
+    /// Names of inline objects by their `property_signature`. Every inline object of the spec
+    /// with a listed signature shares a single model of that name, unless it has a title or
+    /// `x-go-name` of its own. Top level schemas keep their names.
+    pub common_inline_names: HashMap<String, String>,
+    /// How many levels of inline objects, arrays and maps a schema may nest. Deeper schemas are
+    /// skipped with an error diagnostic, together with the models nested in them.
+    pub max_depth: usize,
+    /// Enums are ordered by the order their values are declared in, merging enums that declare
+    /// the same values in different orders is an error, see `enum_order_conflicts`.
+    pub ordered_enums: bool,
+    /// Only generate models of these kinds, or all of them if empty, see `filter_kinds`.
+    pub only: Vec<ModelKind>,
+    /// Inline objects listing `required` keys without `properties` get a model of their own,
+    /// for backends generating them as structs, see `Schema::is_open_object`.
+    pub open_objects: bool,
+    /// Generate a `{OperationId}Params` model of the query parameters of every operation that
+    /// has some.
+    pub query_params_models: bool,
+}
+
+impl Default for PrototypeOptions {
+    fn default() -> Self {
+        Self {
+            dedup_ignore_docs: false,
+            dedupe_identical_schemas: false,
+            flatten_all_of: false,
+            inline_threshold: 0,
+            body_names: BodyNames::default(),
+            common_inline_names: HashMap::new(),
+            max_depth: DEFAULT_MAX_DEPTH,
+            ordered_enums: false,
+            only: vec![],
+            open_objects: false,
+            query_params_models: false,
+        }
+    }
+}
+
+/// How the models of body parameters are named, see `body_model_names`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum BodyNames {
+    /// `{OperationId}{ParamName}Param`.
+    #[default]
+    Operation,
+    /// After the definition the body references or the title or `x-go-name` of an inline
+    /// body, falling back to `Operation` for bodies without either.
+    Schema,
+}
+
+/// Part of the spec a model prototype was derived from, ordered the way the spec lists them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PrototypeSource {
+    /// An entry of `definitions`.
+    Definition,
+    /// An entry of the top level `responses`.
+    Response,
+    /// A response, body parameter or the query parameters of an operation.
+    Operation,
+}
 
+/// What a model is generated as, see `ModelPrototype::kind`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ModelKind {
+    /// A struct, dataclass or interface of an object.
+    Struct,
+    /// An enum of string values, of values of mixed types or of `x-variants`.
+    Enum,
+    /// A type alias of an array, map, scalar or other model.
+    Alias,
+}
+
+/// A model to generate, along with where it comes from in the spec.
+///
+/// Names are not formatted for any language yet, they are the names of `Swagger::type_names`
+/// or derived from them and from the spec. Whether the `allOf` of `schema` is already merged
+/// depends on `Prototyper::merge_all_of`.
 #[derive(Debug)]
 pub struct ModelPrototype {
     pub name: String,
+    /// Name of the model whose property this model was derived from.
     pub parent_name: Option<String>,
     pub schema: Item,
+    pub source: PrototypeSource,
+    /// Content types of the request body or response this model was derived from.
+    pub content_types: Vec<String>,
+    /// References of properties, array items and map values used by this model whose types
+    /// have to be generated too.
+    pub dependencies: Vec<String>,
+    /// Reference to a definition whose properties this model contains unchanged, see
+    /// `Prototyper::matching_definition`.
+    pub base: Option<String>,
+    /// Name of an identical model this model is generated as a type alias of, see
+    /// `dedup_schemas`.
+    pub alias_of: Option<String>,
 }
 
-#[derive(Debug)]
+impl ModelPrototype {
+    /// What this model is generated as, before backends with options generating some schemas
+    /// differently refine it with `CodegenBackend::model_kind`.
+    pub fn kind(&self) -> ModelKind {
+        match &self.schema {
+            _ if self.alias_of.is_some() => ModelKind::Alias,
+            Item::Reference(_) => ModelKind::Alias,
+            Item::Object(schema) => schema_kind(schema).unwrap_or(ModelKind::Alias),
+        }
+    }
+}
+
+/// The kind of the model generated for `schema`, `None` for schemas that are aliases at the top
+/// level and no model of their own inline.
+fn schema_kind(schema: &Schema) -> Option<ModelKind> {
+    if schema.x_variants.is_some() {
+        Some(ModelKind::Enum)
+    } else if schema.is_empty_object() || schema.properties.is_some() {
+        Some(ModelKind::Struct)
+    } else if schema.is_array() {
+        None
+    } else if schema.is_mixed_enum() || schema.is_string_enum() {
+        Some(ModelKind::Enum)
+    } else {
+        None
+    }
+}
+
+type PrototypeFilter = dyn Fn(&ModelPrototype) -> bool;
+
+/// Collects the models to generate for a spec.
+///
+/// Every part of the spec is walked by default, the builder methods narrow that down:
+///
+/// ```
+/// use swagger_gen::v2::{
+///     codegen::{backend::rust, PrototypeSource, Prototyper},
+///     Swagger,
+/// };
+///
+/// let swagger: Swagger<rust::Type> = serde_yaml::from_str(
+///     r#"
+/// swagger: "2.0"
+/// definitions:
+///   Container:
+///     type: object
+///     properties:
+///       Id:
+///         type: string
+///   Image:
+///     type: object
+///     properties:
+///       Id:
+///         type: string
+/// "#,
+/// )
+/// .unwrap();
+///
+/// let prototypes = Prototyper::new()
+///     .include_definitions(true)
+///     .include_paths(false)
+///     .filter(|prototype| prototype.name != "Image")
+///     .generate_prototypes(&swagger);
+/// assert_eq!(prototypes.len(), 1);
+/// assert_eq!(prototypes[0].name, "Container");
+/// assert_eq!(prototypes[0].source, PrototypeSource::Definition);
+/// ```
 pub struct Prototyper<T: Type> {
     prototypes: Vec<ModelPrototype>,
+    opts: PrototypeOptions,
+    include_definitions: bool,
+    include_responses: bool,
+    include_paths: bool,
+    merge_all_of: bool,
+    filter: Option<Box<PrototypeFilter>>,
+    /// Source of the prototypes currently being added.
+    source: PrototypeSource,
+    /// Properties, items and map values taken from the top level schema to the one being added.
+    path: Vec<String>,
+    /// The path at which the schema being added exceeded `PrototypeOptions::max_depth`.
+    too_deep: Option<Vec<String>>,
+    diagnostics: Vec<Diagnostic>,
     _data: PhantomData<T>,
 }
 
+impl<T: Type> fmt::Debug for Prototyper<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Prototyper")
+            .field("prototypes", &self.prototypes)
+            .field("opts", &self.opts)
+            .field("include_definitions", &self.include_definitions)
+            .field("include_responses", &self.include_responses)
+            .field("include_paths", &self.include_paths)
+            .field("merge_all_of", &self.merge_all_of)
+            .field("filter", &self.filter.is_some())
+            .finish()
+    }
+}
+
 impl<T: Type> Default for Prototyper<T> {
     fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Type> Prototyper<T> {
+    pub fn new() -> Self {
         Self {
             prototypes: vec![],
+            opts: PrototypeOptions::default(),
+            include_definitions: true,
+            include_responses: true,
+            include_paths: true,
+            merge_all_of: true,
+            filter: None,
+            source: PrototypeSource::Definition,
+            path: vec![],
+            too_deep: None,
+            diagnostics: vec![],
             _data: PhantomData,
         }
     }
-}
 
-impl<T: Type> Prototyper<T> {
-    pub fn generate_prototypes(mut self, swagger: &Swagger<T>) -> Vec<ModelPrototype> {
-        self.add_definition_models(swagger);
-        self.add_responses_models(swagger);
-        self.add_paths_models(swagger);
-        self.prototypes
+    pub fn options(mut self, opts: PrototypeOptions) -> Self {
+        self.opts = opts;
+        self
+    }
+
+    /// Collect models of `definitions`.
+    pub fn include_definitions(mut self, include: bool) -> Self {
+        self.include_definitions = include;
+        self
+    }
+
+    /// Collect models of the top level `responses`.
+    pub fn include_responses(mut self, include: bool) -> Self {
+        self.include_responses = include;
+        self
+    }
+
+    /// Collect models of the responses, body and query parameters of operations.
+    pub fn include_paths(mut self, include: bool) -> Self {
+        self.include_paths = include;
+        self
+    }
+
+    /// Merge the `allOf` of every schema, or flatten it with `PrototypeOptions::flatten_all_of`.
+    /// Without it schemas are kept as they are declared in the spec.
+    pub fn merge_all_of(mut self, merge: bool) -> Self {
+        self.merge_all_of = merge;
+        self
+    }
+
+    /// Only keep the prototypes `filter` returns `true` for. Models of the other ones are
+    /// dropped even if a kept model refers to them.
+    pub fn filter(mut self, filter: impl Fn(&ModelPrototype) -> bool + 'static) -> Self {
+        self.filter = Some(Box::new(filter));
+        self
+    }
+
+    /// Resolves the `allOf` of `schema`, merging or flattening it depending on the options.
+    fn compose<'a>(&self, swagger: &Swagger<T>, schema: &'a Schema) -> Cow<'a, Schema> {
+        if !self.merge_all_of {
+            Cow::Borrowed(schema)
+        } else if self.opts.flatten_all_of {
+            swagger.flatten_all_of_schema(schema)
+        } else {
+            swagger.merge_all_of_schema(schema)
+        }
+    }
+
+    /// Like `compose` for a schema that is already owned, avoiding a copy when there is nothing
+    /// to compose.
+    fn compose_owned(&self, swagger: &Swagger<T>, schema: Schema) -> Arc<Schema> {
+        if schema.all_of.is_empty() {
+            Arc::new(schema)
+        } else {
+            Arc::new(self.compose(swagger, &schema).into_owned())
+        }
+    }
+
+    pub fn generate_prototypes(self, swagger: &Swagger<T>) -> Vec<ModelPrototype> {
+        let (prototypes, diagnostics) = self.generate_prototypes_with_diagnostics(swagger);
+        for diagnostic in diagnostics {
+            error!("{diagnostic}");
+        }
+        prototypes
+    }
+
+    /// Like `generate_prototypes`, also returning the schemas that were skipped for being
+    /// nested too deeply.
+    pub fn generate_prototypes_with_diagnostics(
+        mut self,
+        swagger: &Swagger<T>,
+    ) -> (Vec<ModelPrototype>, Vec<Diagnostic>) {
+        if self.include_definitions {
+            self.add_definition_models(swagger);
+        }
+        if self.include_responses {
+            self.add_responses_models(swagger);
+        }
+        if self.include_paths {
+            self.add_paths_models(swagger);
+        }
+        self.add_missing_dependencies(swagger);
+        if self.opts.inline_threshold > 0 {
+            self.inline_single_use_definitions(swagger);
+        }
+        if let Some(filter) = &self.filter {
+            self.prototypes.retain(|prototype| filter(prototype));
+        }
+        (self.prototypes, self.diagnostics)
+    }
+
+    /// Adds a model for every referenced array item type that no prototype was generated for
+    /// yet, so that no model refers to an undefined type. References to a part of the spec that
+    /// isn't included are left alone.
+    fn add_missing_dependencies(&mut self, swagger: &Swagger<T>) {
+        let mut missing: Vec<_> = self
+            .prototypes
+            .iter()
+            .flat_map(|prototype| &prototype.dependencies)
+            .filter(|ref_| {
+                let name = swagger.type_names().get(ref_);
+                !self
+                    .prototypes
+                    .iter()
+                    .any(|prototype| prototype.name == name)
+            })
+            .cloned()
+            .collect();
+        missing.sort_unstable();
+        missing.dedup();
+        for ref_ in missing {
+            self.source = if ref_.starts_with(RESPONSES_REF) {
+                PrototypeSource::Response
+            } else {
+                PrototypeSource::Definition
+            };
+            let included = match self.source {
+                PrototypeSource::Response => self.include_responses,
+                _ => self.include_definitions,
+            };
+            if !included {
+                continue;
+            }
+            let Some(schema) = swagger.get_ref_schema(&ref_) else {
+                error!("`{ref_}` used by a model doesn't resolve to a schema");
+                continue;
+            };
+            debug!("adding model of referenced array item `{ref_}`");
+            let schema = Arc::new(self.compose(swagger, schema).into_owned());
+            let name = swagger.type_names().get(&ref_).to_string();
+            self.add_schema_prototype(name, None, schema);
+        }
+    }
+
+    /// Inlines definitions with at most `PrototypeOptions::inline_threshold` properties, or
+    /// scalar ones, that are used by exactly one property of one model and nowhere else.
+    fn inline_single_use_definitions(&mut self, swagger: &Swagger<T>) {
+        // every place a reference is used, with the model and property for references of a
+        // property that could be inlined
+        let mut sites: HashMap<String, Vec<Option<(usize, String)>>> = HashMap::new();
+        for (i, prototype) in self.prototypes.iter().enumerate() {
+            let mut add = |ref_: &str, prop: Option<&str>| {
+                let site = prop.map(|prop| (i, prop.to_string()));
+                sites.entry(ref_.to_string()).or_default().push(site);
+            };
+            if let Some(base) = &prototype.base {
+                add(base, None);
+            }
+            match &prototype.schema {
+                Item::Reference(ref_) => add(ref_, None),
+                Item::Object(schema) => visit_prototype_refs(schema, &mut add),
+            }
+        }
+        for (_, _, op) in swagger.operations() {
+            for response in op.responses.0.values() {
+                if let Response::Reference(ref_, _) = response {
+                    sites.entry(ref_.clone()).or_default().push(None);
+                }
+            }
+        }
+
+        let mut sites: Vec<_> = sites.into_iter().collect();
+        sites.sort_unstable();
+        let mut removed = HashSet::new();
+        for (ref_, sites) in sites {
+            let [Some((parent, prop))] = sites.as_slice() else {
+                continue;
+            };
+            let name = swagger.type_names().get(&ref_);
+            let Some(i) = self.prototypes.iter().position(|prototype| {
+                prototype.name == name && prototype.source == PrototypeSource::Definition
+            }) else {
+                continue;
+            };
+            let Item::Object(schema) = &self.prototypes[i].schema else {
+                continue;
+            };
+            if i == *parent || !schema.all_of.is_empty() {
+                continue;
+            }
+            let is_scalar = schema.properties.is_none()
+                && schema.enum_.is_empty()
+                && matches!(
+                    schema.type_(),
+                    Some("string" | "integer" | "number" | "boolean")
+                );
+            let inlined = if is_scalar {
+                debug!(
+                    "inlining scalar `{ref_}` into `{}`",
+                    self.prototypes[*parent].name
+                );
+                removed.insert(i);
+                schema.clone()
+            } else {
+                let is_small = schema.is_object()
+                    && schema.map_values().is_none()
+                    && schema
+                        .properties
+                        .as_ref()
+                        .is_some_and(|props| props.0.len() <= self.opts.inline_threshold);
+                // models nested in the definition are named after it
+                let has_nested = self
+                    .prototypes
+                    .iter()
+                    .any(|prototype| prototype.parent_name.as_deref() == Some(name));
+                let new_name = format!("{}{}", self.prototypes[*parent].name, upper_first(prop));
+                if !is_small
+                    || has_nested
+                    || self
+                        .prototypes
+                        .iter()
+                        .any(|prototype| prototype.name == new_name)
+                {
+                    continue;
+                }
+                debug!("inlining `{ref_}` as `{new_name}`");
+                let inlined = Arc::new(Schema {
+                    title: Some(new_name.clone()),
+                    ..(**schema).clone()
+                });
+                self.prototypes[i].name = new_name;
+                self.prototypes[i].parent_name = Some(self.prototypes[*parent].name.clone());
+                inlined
+            };
+
+            let parent = &mut self.prototypes[*parent];
+            parent.dependencies.retain(|dependency| *dependency != ref_);
+            if let Item::Object(parent_schema) = &mut parent.schema {
+                if let Some(props) = &mut Arc::make_mut(parent_schema).properties {
+                    props.0.insert(prop.clone(), Item::Object(inlined));
+                }
+            }
+        }
+        let mut i = 0;
+        self.prototypes.retain(|_| {
+            i += 1;
+            !removed.contains(&(i - 1))
+        });
     }
 
     fn add_ref_prototype(
@@ -46,6 +505,11 @@ impl<T: Type> Prototyper<T> {
             name: name.into(),
             parent_name,
             schema: Item::Reference(ref_),
+            source: self.source,
+            content_types: vec![],
+            dependencies: vec![],
+            base: None,
+            alias_of: None,
         };
         trace!("adding reference {prototype:?}");
         self.prototypes.push(prototype);
@@ -55,7 +519,59 @@ impl<T: Type> Prototyper<T> {
         &mut self,
         name: impl Into<String>,
         parent_name: Option<String>,
-        schema: &Schema,
+        schema: Arc<Schema>,
+    ) {
+        let schema = if self.opts.common_inline_names.is_empty() {
+            schema
+        } else {
+            Arc::new(name_common_inline_schemas(
+                &self.opts.common_inline_names,
+                &schema,
+                false,
+            ))
+        };
+        let name = name.into();
+        let added = self.prototypes.len();
+        self.path = vec![name.clone()];
+        self.insert_schema_prototype(name.clone(), parent_name, schema);
+        if let Some(path) = self.too_deep.take() {
+            self.prototypes.truncate(added);
+            self.diagnostics.push(Diagnostic::error(
+                format!(
+                    "schema of `{name}` is nested deeper than {} levels, skipping its models",
+                    self.opts.max_depth
+                ),
+                vec![path.join(".")],
+            ));
+        }
+    }
+
+    /// Adds the prototype of a schema nested in the one being added, reached through `step`.
+    fn insert_nested_prototype(
+        &mut self,
+        step: &str,
+        name: String,
+        parent_name: Option<String>,
+        schema: Arc<Schema>,
+    ) {
+        if self.too_deep.is_some() {
+            return;
+        }
+        self.path.push(step.to_string());
+        // the path starts with the name of the top level schema
+        if self.path.len() - 1 > self.opts.max_depth {
+            self.too_deep = Some(self.path.clone());
+        } else {
+            self.insert_schema_prototype(name, parent_name, schema);
+        }
+        self.path.pop();
+    }
+
+    fn insert_schema_prototype(
+        &mut self,
+        name: impl Into<String>,
+        parent_name: Option<String>,
+        schema: Arc<Schema>,
     ) {
         let mut name = name.into();
         if name.ends_with("InlineItem") {
@@ -63,78 +579,164 @@ impl<T: Type> Prototyper<T> {
                 name = schema_name;
             }
         }
+        if self
+            .opts
+            .common_inline_names
+            .values()
+            .any(|common| *common == name)
+            && self
+                .prototypes
+                .iter()
+                .any(|prototype| prototype.name == name)
+        {
+            trace!("common inline schema `{name}` already added");
+            return;
+        }
         trace!("adding schema prototype `{name}`, parent: `{parent_name:?}`");
+        if self.path.len() - 1 + collection_depth(&schema) > self.opts.max_depth {
+            self.too_deep = Some(self.path.clone());
+            return;
+        }
         if let Some(ref_) = &schema.ref_ {
             self.add_ref_prototype(name, parent_name, ref_.to_string());
             return;
         }
 
+        let mut dependencies = vec![];
+        if let Some(variants) = &schema.x_variants {
+            dependencies.extend(variants.refs().map(|(_, ref_)| ref_));
+        }
         if let Some(items) = &schema.items {
             match items {
-                Item::Object(child_schema) => {
-                    if child_schema.is_object() {
-                        let name = child_schema.name().unwrap_or(format!("{name}InlineItem"));
-                        trace!("handling child schema `{name}` {child_schema:?}");
-                        self.add_schema_prototype(name, parent_name.clone(), &child_schema)
-                    }
+                Item::Object(child_schema)
+                    if child_schema.is_object() && !child_schema.is_empty_object() =>
+                {
+                    let child_name = child_schema.name().unwrap_or(format!("{name}InlineItem"));
+                    trace!("handling child schema `{child_name}` {child_schema:?}");
+                    // items of the arrays of operations are inline in the operation's model,
+                    // so identical ones of different operations can be deduplicated
+                    let item_parent = match &parent_name {
+                        None if self.source == PrototypeSource::Operation => Some(name.clone()),
+                        parent_name => parent_name.clone(),
+                    };
+                    self.insert_nested_prototype(
+                        "items",
+                        child_name,
+                        item_parent,
+                        child_schema.clone(),
+                    )
                 }
+                Item::Reference(ref_) => dependencies.push(ref_.clone()),
                 _ => {}
             }
         }
 
+        if let Some(values) = inline_object_values(&schema) {
+            let name = values.name().unwrap_or(format!("{name}InlineItem"));
+            trace!("handling map values schema `{name}` {values:?}");
+            self.insert_nested_prototype("additionalProperties", name, parent_name.clone(), values)
+        } else if let Some(Item::Reference(ref_)) = schema.map_values() {
+            dependencies.push(ref_);
+        }
+
         if let Some(props) = &schema.properties {
             for (prop_name, prop_schema) in props.0.iter() {
                 trace!("handling property {prop_name}, parent: {:?}", &parent_name);
-                match prop_schema {
-                    Item::Object(prop_schema) => {
-                        let mut chars = prop_name.chars();
-                        let prop_name = format!(
-                            "{}{}",
-                            chars.next().unwrap_or_default().to_uppercase(),
-                            chars.as_str()
-                        );
-                        let prop_name = prop_schema
-                            .name()
-                            .unwrap_or(format!("{name}{prop_name}InlineItem"));
-                        trace!("Item::Object property {prop_name}");
-                        if prop_schema.is_object() && prop_schema.properties.is_some() {
-                            trace!("adding object schema {prop_name}");
-                            self.add_schema_prototype(prop_name, Some(name.clone()), &prop_schema)
-                        } else if prop_schema.is_array() {
-                            if let Some(items) = &prop_schema.items {
-                                trace!("adding array schema {prop_name}");
-                                match items {
-                                    Item::Object(prop_schema) if prop_schema.is_object() => self
-                                        .add_schema_prototype(
-                                            prop_name.clone(),
-                                            Some(name.clone()),
-                                            &prop_schema,
-                                        ),
-                                    _ => {}
+                if let Item::Reference(ref_) = prop_schema {
+                    dependencies.push(ref_.clone());
+                }
+                if let Item::Object(prop_schema) = prop_schema {
+                    if let Some(Item::Reference(ref_)) = prop_schema.map_values() {
+                        dependencies.push(ref_);
+                    }
+                    let step = prop_name.as_str();
+                    if self.path.len() + collection_depth(prop_schema) > self.opts.max_depth {
+                        let mut path = self.path.clone();
+                        path.push(step.to_string());
+                        self.too_deep = Some(path);
+                        return;
+                    }
+                    let prop_name = upper_first(prop_name);
+                    let prop_name = prop_schema
+                        .name()
+                        .unwrap_or(format!("{name}{prop_name}InlineItem"));
+                    trace!("Item::Object property {prop_name}");
+                    if prop_schema.is_empty_object() {
+                        // mapped to the `EmptyObject` helper of the backend
+                    } else if prop_schema.is_object()
+                        && (prop_schema.properties.is_some()
+                            || self.opts.open_objects && prop_schema.is_open_object())
+                    {
+                        trace!("adding object schema {prop_name}");
+                        self.insert_nested_prototype(
+                            step,
+                            prop_name,
+                            Some(name.clone()),
+                            prop_schema.clone(),
+                        )
+                    } else if prop_schema.is_array() {
+                        if let Some(items) = &prop_schema.items {
+                            trace!("adding array schema {prop_name}");
+                            match items {
+                                Item::Object(prop_schema)
+                                    if prop_schema.is_object()
+                                        && !prop_schema.is_empty_object() =>
+                                {
+                                    self.insert_nested_prototype(
+                                        step,
+                                        prop_name.clone(),
+                                        Some(name.clone()),
+                                        prop_schema.clone(),
+                                    )
                                 }
+                                Item::Reference(ref_) => dependencies.push(ref_.clone()),
+                                _ => {}
                             }
-                            error!("skipping {prop_name} {prop_schema:?}")
-                        } else if prop_schema.is_string_enum() {
-                            trace!("adding enum schema {prop_name}");
-                            self.add_schema_prototype(prop_name, Some(name.clone()), &prop_schema)
                         }
+                        error!("skipping {prop_name} {prop_schema:?}")
+                    } else if let Some(values) = inline_object_values(prop_schema) {
+                        trace!("adding map values schema {prop_name}");
+                        self.insert_nested_prototype(step, prop_name, Some(name.clone()), values)
+                    } else if prop_schema.is_string_enum() {
+                        trace!("adding enum schema {prop_name}");
+                        self.insert_nested_prototype(
+                            step,
+                            prop_name,
+                            Some(name.clone()),
+                            prop_schema.clone(),
+                        )
                     }
-                    _ => {}
                 }
             }
         }
 
+        dependencies.sort_unstable();
+        dependencies.dedup();
         let prototype = ModelPrototype {
-            name: name.into(),
+            name,
             parent_name,
-            schema: Item::Object(Box::new(schema.clone())),
+            schema: Item::Object(schema),
+            source: self.source,
+            content_types: vec![],
+            dependencies,
+            base: None,
+            alias_of: None,
         };
         trace!("adding object {prototype:?}");
         self.prototypes.push(prototype);
     }
 
+    /// Sets the content types of the most recently added prototype. Models nested in a schema
+    /// are added before the model of the schema itself, so this is the top level model.
+    fn set_content_types(&mut self, content_types: &[String]) {
+        if let Some(prototype) = self.prototypes.last_mut() {
+            prototype.content_types = content_types.to_vec();
+        }
+    }
+
     fn add_definition_models(&mut self, swagger: &Swagger<T>) {
         debug!("adding definition models");
+        self.source = PrototypeSource::Definition;
         if let Some(definitions) = &swagger.definitions {
             trace!("definitions found");
             let mut definitions: Vec<_> = definitions.0.iter().collect();
@@ -143,8 +745,12 @@ impl<T: Type> Prototyper<T> {
 
             for (name, schema) in definitions {
                 trace!("processing definition `{name}`");
-                let schema = swagger.merge_all_of_schema(schema.clone());
-                self.add_schema_prototype(name, None, &schema);
+                let schema = Arc::new(self.compose(swagger, schema).into_owned());
+                let name = swagger
+                    .type_names()
+                    .get(&format!("{DEFINITIONS_REF}{name}"))
+                    .to_string();
+                self.add_schema_prototype(name, None, schema);
             }
         } else {
             trace!("no definitions to process");
@@ -153,6 +759,7 @@ impl<T: Type> Prototyper<T> {
 
     fn add_responses_models(&mut self, swagger: &Swagger<T>) {
         debug!("adding responses models");
+        self.source = PrototypeSource::Response;
         if let Some(responses) = &swagger.responses {
             trace!("responses found");
             let mut responses: Vec<_> = responses.0.iter().collect();
@@ -166,11 +773,23 @@ impl<T: Type> Prototyper<T> {
                         if let Some(schema) = &response.schema {
                             let mut schema = schema.clone();
                             schema.description = response.description.clone();
-                            let schema = swagger.merge_all_of_schema(schema.clone());
-                            self.add_schema_prototype(name, None, &schema);
+                            let schema = self.compose_owned(swagger, schema);
+                            match self.matching_definition(swagger, &schema) {
+                                Some((ref_, true)) => {
+                                    debug!("response `{name}` is identical to `{ref_}`");
+                                    self.add_ref_prototype(name, None, ref_);
+                                }
+                                Some((ref_, false)) => {
+                                    self.add_schema_prototype(name, None, schema);
+                                    if let Some(prototype) = self.prototypes.last_mut() {
+                                        prototype.base = Some(ref_);
+                                    }
+                                }
+                                None => self.add_schema_prototype(name, None, schema),
+                            }
                         }
                     }
-                    Response::Reference(ref_) => {
+                    Response::Reference(ref_, _) => {
                         self.add_ref_prototype(name, None, ref_.to_string())
                     }
                 }
@@ -180,77 +799,1503 @@ impl<T: Type> Prototyper<T> {
         }
     }
 
+    /// Finds the definition a composed response schema duplicates. Returns the reference to it
+    /// and whether the schema is identical to it apart from its documentation, or only adds
+    /// properties to the ones of the definition. Of several candidates the one sharing the
+    /// most properties is picked.
+    fn matching_definition(&self, swagger: &Swagger<T>, schema: &Schema) -> Option<(String, bool)> {
+        // the shape of an object schema, without its documentation
+        fn shape(schema: &Schema) -> Option<Schema> {
+            schema.properties.as_ref()?;
+            let mut required = schema.required.clone();
+            required.sort_unstable();
+            Some(Schema {
+                title: None,
+                description: None,
+                x_go_name: None,
+                x_go_package: None,
+                required,
+                ..schema.clone()
+            })
+        }
+
+        if !schema.all_of.is_empty() {
+            return None;
+        }
+        let schema = shape(schema)?;
+        let props = &schema.properties.as_ref()?.0;
+        let definitions = swagger.definitions.as_ref()?;
+        let mut keys: Vec<_> = definitions.0.keys().collect();
+        keys.sort_unstable();
+
+        let mut best: Option<(&String, usize)> = None;
+        for key in keys {
+            let definition = self.compose(swagger, &definitions.0[key]);
+            if !definition.all_of.is_empty() {
+                continue;
+            }
+            let Some(definition) = shape(&definition) else {
+                continue;
+            };
+            if definition == schema {
+                return Some((format!("{DEFINITIONS_REF}{key}"), true));
+            }
+            let def_props = &definition.properties.as_ref()?.0;
+            let is_extension = def_props.len() < props.len()
+                && (definition.type_.is_none() || definition.type_ == schema.type_)
+                && definition.additional_properties == schema.additional_properties
+                && definition.pattern_properties == schema.pattern_properties
+                && def_props.iter().all(|(name, prop)| {
+                    props.get(name) == Some(prop)
+                        && definition.required.contains(name) == schema.required.contains(name)
+                });
+            if is_extension && best.is_none_or(|(_, shared)| def_props.len() > shared) {
+                best = Some((key, def_props.len()));
+            }
+        }
+        best.map(|(key, _)| (format!("{DEFINITIONS_REF}{key}"), false))
+    }
+
     fn add_paths_models(&mut self, swagger: &Swagger<T>) {
         debug!("adding paths models");
-        if let Some(paths) = &swagger.paths {
-            debug!("paths found");
-            let mut paths: Vec<_> = paths.0.iter().collect();
-            trace!("sorting paths alphabetically by name");
-            paths.sort_unstable_by_key(|(k, _)| *k);
-
-            macro_rules! handle_method {
-                ($path:ident, $method:ident) => {
-                    if let Some(op) = $path.$method.as_ref() {
-                        for (code, response) in &op.responses.0 {
-                            match response {
-                                Response::Object(response) => {
-                                    if let Some(schema) = &response.schema {
-                                        let mut schema = schema.clone();
-                                        schema.description = response.description.clone();
-                                        let schema = swagger.merge_all_of_schema(schema.clone());
-                                        self.add_schema_prototype(
-                                            &format!(
-                                                "{}{code}Response",
-                                                op.operation_id
-                                                    .as_deref()
-                                                    .unwrap_or("InlineResponse")
-                                            ),
-                                            None,
-                                            &schema,
-                                        );
-                                    }
-                                }
-                                _ => {}
-                            }
-                        }
-
-                        for param in &op.parameters {
-                            match param {
-                                Parameter::Body(param) => {
-                                    let name = format!(
-                                        "{}{}Param",
-                                        T::format_name(
-                                            op.operation_id.as_deref().unwrap_or("InlineResponse")
-                                        ),
-                                        T::format_name(&param.name)
-                                    );
-                                    let schema = swagger.merge_all_of_schema(param.schema.clone());
-                                    self.add_schema_prototype(&name, None, &schema)
-                                }
-                                _ => {}
-                            }
-                        }
+        self.source = PrototypeSource::Operation;
+        let body_names = body_model_names(swagger, self.opts.body_names);
+        // with `BodyNames::Schema` a body referencing a definition is generated as that
+        // definition and bodies sharing a title share one model
+        let mut bodies: HashSet<_> = match self.opts.body_names {
+            BodyNames::Operation => HashSet::new(),
+            BodyNames::Schema => swagger
+                .definitions
+                .iter()
+                .flat_map(|defs| defs.0.keys())
+                .map(|key| {
+                    T::format_name(swagger.type_names().get(&format!("{DEFINITIONS_REF}{key}")))
+                })
+                .collect(),
+        };
+        for (path, method, op) in swagger.operations() {
+            trace!("processing operation `{method} {path}`");
+            let consumes = swagger.consumes_of(op);
+            let produces = swagger.produces_of(op);
+            for (code, response) in &op.responses.0 {
+                if let Response::Object(response) = response {
+                    if let Some(schema) = &response.schema {
+                        let mut schema = schema.clone();
+                        schema.description = response.description.clone();
+                        let schema = self.compose_owned(swagger, schema);
+                        self.add_schema_prototype(
+                            response_model_name::<T>(path, method, op, code),
+                            None,
+                            schema,
+                        );
+                        self.set_content_types(produces);
                     }
-                };
+                }
             }
 
-            for (name, path) in paths {
-                trace!("processing path `{name}`");
-                match path {
-                    Path::Item(path) => {
-                        handle_method!(path, get);
-                        handle_method!(path, put);
-                        handle_method!(path, post);
-                        handle_method!(path, delete);
-                        handle_method!(path, options);
-                        handle_method!(path, head);
-                        handle_method!(path, patch);
+            for param in &op.parameters {
+                if let Parameter::Body(param) = param {
+                    let name = &body_names[&(path, method, param.name.as_str())];
+                    if self.opts.body_names == BodyNames::Schema
+                        && !bodies.insert(T::format_name(name))
+                    {
+                        continue;
                     }
-                    Path::Extension(ext) => eprintln!("{:?}", ext),
+                    let schema = self.compose(swagger, &param.schema);
+                    let schema = Arc::new(schema.into_owned());
+                    self.add_schema_prototype(name, None, schema);
+                    self.set_content_types(consumes);
                 }
             }
+
+            let query_params: Vec<_> = op
+                .parameters
+                .iter()
+                .filter_map(|param| match param {
+                    Parameter::Query(param) => Some(param),
+                    _ => None,
+                })
+                .collect();
+            if self.opts.query_params_models && !query_params.is_empty() {
+                let name = query_params_model_name::<T>(path, method, op);
+                let schema = params_schema(path, method, op, "Query", &query_params);
+                self.add_schema_prototype(&name, None, Arc::new(schema))
+            }
+
+            let form_params: Vec<_> = op
+                .parameters
+                .iter()
+                .filter_map(|param| match param {
+                    Parameter::FormData(param) => Some(param),
+                    _ => None,
+                })
+                .collect();
+            if !form_params.is_empty() {
+                let name = form_model_name::<T>(path, method, op);
+                let schema = params_schema(path, method, op, "Form", &form_params);
+                self.add_schema_prototype(&name, None, Arc::new(schema));
+                self.set_content_types(consumes);
+            }
+        }
+    }
+}
+
+/// An object schema with a property for each of the `kind` parameters `params` of `op`.
+fn params_schema(
+    path: &str,
+    method: &str,
+    op: &Operation,
+    kind: &str,
+    params: &[&PathParameter],
+) -> Schema {
+    let operation = operation_model_base(path, method, op);
+    Schema {
+        description: Some(format!("{kind} parameters of the `{operation}` operation.")),
+        type_: Some("object".into()),
+        required: params
+            .iter()
+            .filter(|param| param.required)
+            .map(|param| param.name.clone())
+            .collect(),
+        properties: Some(Items(
+            params
+                .iter()
+                .map(|param| {
+                    let schema = Arc::new(param.to_schema());
+                    (param.name.clone(), Item::Object(schema))
+                })
+                .collect(),
+        )),
+        ..Default::default()
+    }
+}
+
+/// The values of the map `schema` if they are an inline object that gets a model of its own.
+/// The sorted `name:type` pairs of the properties of `schema` joined by commas, like
+/// `code:integer,message:string`. Properties referencing a definition have the reference as
+/// their type. `None` for schemas without properties.
+pub fn property_signature(schema: &Schema) -> Option<String> {
+    let props = schema.properties.as_ref()?;
+    let mut pairs: Vec<_> = props
+        .0
+        .iter()
+        .map(|(name, item)| {
+            let type_ = match item {
+                Item::Reference(ref_) => ref_.as_str(),
+                Item::Object(schema) => schema
+                    .ref_
+                    .as_deref()
+                    .or(schema.type_())
+                    .unwrap_or("object"),
+            };
+            format!("{name}:{type_}")
+        })
+        .collect();
+    pairs.sort_unstable();
+    Some(pairs.join(","))
+}
+
+/// Titles the inline objects nested in `schema` that have a signature listed in `names`, so the
+/// models and the field types of all of them use the common name.
+fn name_common_inline_schemas(
+    names: &HashMap<String, String>,
+    schema: &Schema,
+    nested: bool,
+) -> Schema {
+    let mut schema = schema.clone();
+    let rename = |item: &mut Item| {
+        if let Item::Object(child) = item {
+            *child = Arc::new(name_common_inline_schemas(names, child, true));
+        }
+    };
+    if let Some(props) = &mut schema.properties {
+        props.0.values_mut().for_each(rename);
+    }
+    if let Some(items) = &mut schema.items {
+        rename(items);
+    }
+    if let Some(values) = &mut schema.additional_properties {
+        rename(values);
+    }
+    if nested && schema.name().is_none() {
+        if let Some(name) = property_signature(&schema).and_then(|sig| names.get(&sig)) {
+            schema.title = Some(name.clone());
+        }
+    }
+    schema
+}
+
+/// How many arrays and maps are nested in `schema`, following only their items and values.
+fn collection_depth(schema: &Schema) -> usize {
+    let mut depth = 0;
+    let mut schema = schema;
+    while let Some(Item::Object(inner)) = schema
+        .items
+        .as_ref()
+        .or(schema.additional_properties.as_ref())
+    {
+        depth += 1;
+        schema = inner;
+    }
+    depth
+}
+
+fn inline_object_values(schema: &Schema) -> Option<Arc<Schema>> {
+    match schema.map_values()? {
+        Item::Object(values)
+            if values.is_object() && values.properties.is_some() && !values.is_empty_object() =>
+        {
+            Some(values)
+        }
+        _ => None,
+    }
+}
+
+fn upper_first(s: &str) -> String {
+    let mut chars = s.chars();
+    format!(
+        "{}{}",
+        chars.next().unwrap_or_default().to_uppercase(),
+        chars.as_str()
+    )
+}
+
+/// Calls `f` with every reference used by the model of `schema`, along with the property for
+/// references that are a property of the model. Inline objects and array items that get a model
+/// of their own are skipped, their references are visited with that model.
+fn visit_prototype_refs(schema: &Schema, f: &mut impl FnMut(&str, Option<&str>)) {
+    fn visit_item(item: &Item, f: &mut impl FnMut(&str, Option<&str>)) {
+        match item {
+            Item::Reference(ref_) => f(ref_, None),
+            Item::Object(schema) => visit_all(schema, f),
+        }
+    }
+
+    fn visit_all(schema: &Schema, f: &mut impl FnMut(&str, Option<&str>)) {
+        if let Some(ref_) = &schema.ref_ {
+            f(ref_, None);
+        }
+        let props = schema.properties.iter().flat_map(|props| props.0.values());
+        for item in props.chain(&schema.items) {
+            visit_item(item, f);
+        }
+        if let Some(item) = &schema.map_values() {
+            visit_item(item, f);
+        }
+        for member in &schema.all_of {
+            visit_all(member, f);
+        }
+    }
+
+    // everything but the items and properties
+    fn visit_rest(schema: &Schema, f: &mut impl FnMut(&str, Option<&str>)) {
+        if let Some(ref_) = &schema.ref_ {
+            f(ref_, None);
+        }
+        match schema.map_values() {
+            Some(Item::Object(values)) if values.is_object() && values.properties.is_some() => {}
+            Some(item) => visit_item(&item, f),
+            None => {}
+        }
+        for member in &schema.all_of {
+            visit_all(member, f);
+        }
+        match &schema.items {
+            Some(Item::Object(items)) if items.is_object() => {}
+            Some(item) => visit_item(item, f),
+            None => {}
+        }
+    }
+
+    visit_rest(schema, f);
+    for (_, ref_) in schema
+        .x_variants
+        .iter()
+        .flat_map(|variants| variants.refs())
+    {
+        f(&ref_, None);
+    }
+    for (name, prop) in schema.properties.iter().flat_map(|props| &props.0) {
+        match prop {
+            Item::Reference(ref_) => f(ref_, Some(name)),
+            Item::Object(prop) if prop.is_object() && prop.properties.is_some() => {}
+            Item::Object(prop) if prop.is_array() || prop.map_values().is_some() => {
+                visit_rest(prop, f)
+            }
+            Item::Object(prop) => visit_all(prop, f),
+        }
+    }
+}
+
+/// Name of the model generated for the response of `op` with status `code`, named after
+/// `operation_model_base` like the parameter models.
+pub fn response_model_name<T: Type>(
+    path: &str,
+    method: &str,
+    op: &Operation,
+    code: &str,
+) -> String {
+    // the `default` response usually describes the error envelope, give it a properly cased
+    // name
+    let code = if code == "default" { "Default" } else { code };
+    let operation = operation_model_base(path, method, op);
+    format!("{}{code}Response", T::format_name(&operation))
+}
+
+/// What the parameter models of `op` are named after, its `operationId` or without one its
+/// method and path, like `get /containers/{id}/json`, so that models of different operations
+/// don't share a name.
+pub fn operation_model_base(path: &str, method: &str, op: &Operation) -> String {
+    match &op.operation_id {
+        Some(operation_id) => operation_id.clone(),
+        None => format!("{method} {path}"),
+    }
+}
+
+/// Name of the model generated for the body parameter `param_name` of `op`.
+pub fn body_param_model_name<T: Type>(
+    path: &str,
+    method: &str,
+    op: &Operation,
+    param_name: &str,
+) -> String {
+    format!(
+        "{}{}Param",
+        T::format_name(&operation_model_base(path, method, op)),
+        T::format_name(param_name)
+    )
+}
+
+/// Name of the model of every body parameter by path, method and parameter name.
+///
+/// With `BodyNames::Schema` a body referencing a definition is named by the type of the
+/// definition. An inline body is named by its title or `x-go-name` unless that is the name of a
+/// definition or of another inline body with a different schema, then it falls back to
+/// `body_param_model_name`.
+pub fn body_model_names<T: Type>(
+    swagger: &Swagger<T>,
+    naming: BodyNames,
+) -> HashMap<(&str, &'static str, &str), String> {
+    let bodies: Vec<_> = swagger
+        .operations()
+        .flat_map(|(path, method, op)| {
+            op.parameters.iter().filter_map(move |param| match param {
+                Parameter::Body(param) => Some((path, method, op, param)),
+                _ => None,
+            })
+        })
+        .collect();
+
+    // formatted titles of inline bodies and the schema using them, `None` if they can't be used
+    let mut titles: HashMap<String, Option<&Schema>> = HashMap::new();
+    if naming == BodyNames::Schema {
+        let definitions: HashSet<_> = swagger
+            .definitions
+            .iter()
+            .flat_map(|defs| defs.0.keys())
+            .map(|key| T::format_name(swagger.type_names().get(&format!("{DEFINITIONS_REF}{key}"))))
+            .collect();
+        for (_, _, _, param) in &bodies {
+            let Some(title) = param.schema.name().filter(|_| param.schema.ref_.is_none()) else {
+                continue;
+            };
+            let type_name = T::format_name(&title);
+            let taken = definitions.contains(&type_name);
+            let shared = titles.entry(type_name).or_insert(Some(&param.schema));
+            if taken || shared.is_some_and(|schema| *schema != param.schema) {
+                *shared = None;
+            }
+        }
+    }
+
+    bodies
+        .into_iter()
+        .map(|(path, method, op, param)| {
+            let name = match &param.schema.ref_ {
+                _ if naming == BodyNames::Operation => None,
+                Some(ref_) if ref_.starts_with(DEFINITIONS_REF) => {
+                    Some(swagger.type_names().get(ref_).to_string())
+                }
+                Some(_) => None,
+                None => param
+                    .schema
+                    .name()
+                    .filter(|title| titles[&T::format_name(title)].is_some()),
+            };
+            let name =
+                name.unwrap_or_else(|| body_param_model_name::<T>(path, method, op, &param.name));
+            ((path, method, param.name.as_str()), name)
+        })
+        .collect()
+}
+
+/// Name of the model generated for the query parameters of `op`.
+pub fn query_params_model_name<T: Type>(path: &str, method: &str, op: &Operation) -> String {
+    let operation = operation_model_base(path, method, op);
+    format!("{}Params", T::format_name(&operation))
+}
+
+/// Name of the model generated for the form parameters of `op`.
+pub fn form_model_name<T: Type>(path: &str, method: &str, op: &Operation) -> String {
+    let operation = operation_model_base(path, method, op);
+    format!("{}Form", T::format_name(&operation))
+}
+
+/// The type, sorted values and description of an inline enum, see `enum_key`.
+pub type EnumKey = (Option<String>, Vec<String>, Option<String>);
+
+/// What `dedup_enums` tells inline enums apart by, the description is left out with
+/// `PrototypeOptions::dedup_ignore_docs`.
+pub fn enum_key(schema: &Schema, opts: &PrototypeOptions) -> EnumKey {
+    let mut values: Vec<_> = schema.enum_.iter().map(|v| format!("{v:?}")).collect();
+    values.sort_unstable();
+    let description = if opts.dedup_ignore_docs {
+        None
+    } else {
+        schema.description.clone()
+    };
+    (schema.type_.clone(), values, description)
+}
+
+/// Indices of the inline enums `dedup_enums` merges, by group.
+fn enum_groups(prototypes: &[ModelPrototype], opts: &PrototypeOptions) -> Vec<Vec<usize>> {
+    let mut groups: Vec<Vec<usize>> = vec![];
+    // index into `groups` by `enum_key`
+    let mut group_of_key: HashMap<EnumKey, usize> = HashMap::new();
+    for (i, prototype) in prototypes.iter().enumerate() {
+        let Item::Object(schema) = &prototype.schema else {
+            continue;
+        };
+        if prototype.parent_name.is_none() || !schema.is_string_enum() {
+            continue;
+        }
+        let key = enum_key(schema, opts);
+        match group_of_key.get(&key) {
+            Some(group) => groups[*group].push(i),
+            None => {
+                group_of_key.insert(key, groups.len());
+                groups.push(vec![i]);
+            }
+        }
+    }
+    groups.retain(|group| group.len() > 1);
+    groups
+}
+
+/// Whether the enums of `group` declare their values in different orders.
+fn has_mixed_orders(prototypes: &[ModelPrototype], group: &[usize]) -> bool {
+    let mut orders = group.iter().map(|i| match &prototypes[*i].schema {
+        Item::Object(schema) => Some(&schema.enum_),
+        Item::Reference(_) => None,
+    });
+    let first = orders.next().flatten();
+    orders.any(|order| order != first)
+}
+
+/// Names of the inline enums of every group `dedup_enums` would merge although they declare
+/// their values in different orders, which leaves the merged enum without an order to follow.
+pub fn enum_order_conflicts(
+    prototypes: &[ModelPrototype],
+    opts: &PrototypeOptions,
+) -> Vec<Vec<String>> {
+    enum_groups(prototypes, opts)
+        .into_iter()
+        .filter(|group| has_mixed_orders(prototypes, group))
+        .map(|group| {
+            group
+                .into_iter()
+                .map(|i| prototypes[i].name.clone())
+                .collect()
+        })
+        .collect()
+}
+
+/// Collapses inline enums with the same set of values into one prototype. The kept prototype is
+/// the first one of each group, or the first one with a title if any of them has one, and is
+/// renamed to the title. Returns a diagnostic for every merged group, an error one with
+/// `PrototypeOptions::ordered_enums` if the merged enums declare their values in different
+/// orders.
+pub fn dedup_enums(
+    prototypes: &mut Vec<ModelPrototype>,
+    opts: &PrototypeOptions,
+) -> Vec<Diagnostic> {
+    let mut diagnostics = vec![];
+    let mut removed = vec![];
+    let mut renamed = vec![];
+    for group in enum_groups(prototypes, opts) {
+        let kept = group
+            .iter()
+            .copied()
+            .find(|i| match &prototypes[*i].schema {
+                Item::Object(schema) => schema.title.is_some(),
+                Item::Reference(_) => false,
+            })
+            .unwrap_or(group[0]);
+        let name = match &prototypes[kept].schema {
+            Item::Object(schema) => schema.title.clone(),
+            Item::Reference(_) => None,
+        }
+        .unwrap_or_else(|| prototypes[kept].name.clone());
+        let names = group.iter().map(|i| prototypes[*i].name.clone()).collect();
+        diagnostics.push(
+            if opts.ordered_enums && has_mixed_orders(prototypes, &group) {
+                Diagnostic::error(
+                    format!(
+                        "merged {} enums into `{name}` that declare their values in different orders, it can't be ordered",
+                        group.len()
+                    ),
+                    names,
+                )
+            } else {
+                Diagnostic::info(
+                    format!("merged {} identical enums into `{name}`", group.len()),
+                    names,
+                )
+            },
+        );
+        removed.extend(group.into_iter().filter(|i| *i != kept));
+        renamed.push((kept, name));
+    }
+    for (i, name) in renamed {
+        prototypes[i].name = name;
+    }
+    let mut i = 0;
+    prototypes.retain(|_| {
+        i += 1;
+        !removed.contains(&(i - 1))
+    });
+    diagnostics
+}
+
+/// Turns inline objects with the same structure as an earlier one into type aliases of it, or
+/// of the first one with a title or `x-go-name` if any of them has one. Items of a top level
+/// array aren't aliased, the array uses the kept model as its items instead. Descriptions,
+/// titles and the order of properties don't count. Returns a diagnostic for every group of
+/// identical objects.
+pub fn dedup_schemas(prototypes: &mut Vec<ModelPrototype>) -> Vec<Diagnostic> {
+    let mut groups: Vec<Vec<usize>> = vec![];
+    let mut group_of_key: HashMap<String, usize> = HashMap::new();
+    for (i, prototype) in prototypes.iter().enumerate() {
+        let Item::Object(schema) = &prototype.schema else {
+            continue;
+        };
+        if prototype.parent_name.is_none() || schema.properties.is_none() {
+            continue;
+        }
+        let key = structural_key(schema);
+        match group_of_key.get(&key) {
+            Some(group) => groups[*group].push(i),
+            None => {
+                group_of_key.insert(key, groups.len());
+                groups.push(vec![i]);
+            }
+        }
+    }
+
+    let mut diagnostics = vec![];
+    let mut removed = vec![];
+    for group in groups.into_iter().filter(|group| group.len() > 1) {
+        let kept = group
+            .iter()
+            .copied()
+            .find(|i| match &prototypes[*i].schema {
+                Item::Object(schema) => schema.name().is_some(),
+                Item::Reference(_) => false,
+            })
+            .unwrap_or(group[0]);
+        let name = prototypes[kept].name.clone();
+        diagnostics.push(Diagnostic::info(
+            format!(
+                "generated {} identical schemas as `{name}` and type aliases of it",
+                group.len()
+            ),
+            group.iter().map(|i| prototypes[*i].name.clone()).collect(),
+        ));
+        for i in group.into_iter().filter(|i| *i != kept) {
+            match array_of_items(prototypes, i) {
+                Some(array) => {
+                    retitle_items(&mut prototypes[array], &name);
+                    removed.push(i);
+                }
+                None => prototypes[i].alias_of = Some(name.clone()),
+            }
+        }
+    }
+    let mut i = 0;
+    prototypes.retain(|_| {
+        i += 1;
+        !removed.contains(&(i - 1))
+    });
+    diagnostics
+}
+
+/// Drops the prototypes whose kind in `kinds` isn't one of `only`. The kept models use what the
+/// dropped ones are made of instead of them: the strings of enums, untyped values for structs and
+/// the aliased type of aliases. Returns a warning for every dropped model that a kept one used.
+pub fn filter_kinds<T: Type>(
+    prototypes: &mut Vec<ModelPrototype>,
+    kinds: &[ModelKind],
+    only: &[ModelKind],
+    swagger: &Swagger<T>,
+) -> Vec<Diagnostic> {
+    let dropped: Vec<_> = prototypes
+        .iter()
+        .zip(kinds)
+        .filter(|(_, kind)| !only.contains(kind))
+        .map(|(prototype, _)| (prototype.name.clone(), prototype.parent_name.clone()))
+        .collect();
+    let mut i = 0;
+    prototypes.retain(|_| {
+        i += 1;
+        only.contains(&kinds[i - 1])
+    });
+    let degrader = Degrader {
+        swagger,
+        dropped: dropped.iter().map(|(name, _)| name.as_str()).collect(),
+        only,
+    };
+
+    let mut users: BTreeMap<String, Vec<String>> = BTreeMap::new();
+    for prototype in prototypes.iter_mut() {
+        let mut used = vec![];
+        if let Some(target) = prototype.alias_of.take() {
+            if degrader.dropped.contains(target.as_str()) {
+                used.push(target);
+                prototype.schema = Item::Object(Arc::new(untyped(&Schema::default())));
+            } else {
+                prototype.alias_of = Some(target);
+            }
         } else {
-            trace!("no paths to process");
+            prototype.schema = match &prototype.schema {
+                Item::Reference(_) => degrader.item(&prototype.schema, &mut used, &mut vec![]),
+                Item::Object(schema) => {
+                    Item::Object(Arc::new(degrader.children(schema, &mut used, &mut vec![])))
+                }
+            };
+        }
+        if let Some(base) = &prototype.base {
+            if degrader.dropped.contains(swagger.type_names().get(base)) {
+                prototype.base = None;
+            }
         }
+        for name in used {
+            users.entry(name).or_default().push(prototype.name.clone());
+        }
+    }
+    // inline models are used by their parent
+    for (name, parent) in &dropped {
+        if let Some(parent) = parent {
+            if prototypes.iter().any(|prototype| &prototype.name == parent) {
+                users.entry(name.clone()).or_default().push(parent.clone());
+            }
+        }
+    }
+
+    users
+        .into_iter()
+        .map(|(name, mut users)| {
+            users.sort_unstable();
+            users.dedup();
+            Diagnostic::warning(
+                format!("`{name}` isn't generated, the models using it use the type it is made of"),
+                users,
+            )
+        })
+        .collect()
+}
+
+/// Replaces the models dropped by `filter_kinds` in the schemas of the kept ones.
+struct Degrader<'a, T: Type> {
+    swagger: &'a Swagger<T>,
+    dropped: HashSet<&'a str>,
+    only: &'a [ModelKind],
+}
+
+impl<T: Type> Degrader<'_, T> {
+    /// `schema` with the models used by its properties, items and values replaced.
+    fn children(&self, schema: &Schema, used: &mut Vec<String>, refs: &mut Vec<String>) -> Schema {
+        let mut schema = schema.clone();
+        for props in [&mut schema.properties, &mut schema.pattern_properties]
+            .into_iter()
+            .flatten()
+        {
+            for item in props.0.values_mut() {
+                *item = self.item(item, used, refs);
+            }
+        }
+        for item in [&mut schema.items, &mut schema.additional_properties]
+            .into_iter()
+            .flatten()
+        {
+            *item = self.item(item, used, refs);
+        }
+        schema
+    }
+
+    fn item(&self, item: &Item, used: &mut Vec<String>, refs: &mut Vec<String>) -> Item {
+        match item {
+            Item::Reference(ref_) => match self.reference(ref_, used, refs) {
+                Some(schema) => Item::Object(Arc::new(schema)),
+                None => item.clone(),
+            },
+            Item::Object(schema) => Item::Object(Arc::new(self.inline(schema, used, refs))),
+        }
+    }
+
+    /// What the model `ref_` is made of if it was dropped. References back to a model being
+    /// replaced become untyped values.
+    fn reference(
+        &self,
+        ref_: &str,
+        used: &mut Vec<String>,
+        refs: &mut Vec<String>,
+    ) -> Option<Schema> {
+        let name = self.swagger.type_names().get(ref_);
+        if !self.dropped.contains(name) {
+            return None;
+        }
+        let schema = self.swagger.get_ref_schema(ref_)?;
+        used.push(name.to_string());
+        if refs.iter().any(|visited| visited == ref_) {
+            return Some(untyped(schema));
+        }
+        refs.push(ref_.to_string());
+        let schema = self.swagger.merge_all_of_schema(schema);
+        let schema = match schema_kind(&schema) {
+            Some(kind) if !self.only.contains(&kind) => self.made_of(&schema, kind),
+            _ => self.children(&schema, used, refs),
+        };
+        refs.pop();
+        Some(schema)
+    }
+
+    /// An inline `schema`, replaced if the model it would get is of a dropped kind.
+    fn inline(&self, schema: &Schema, used: &mut Vec<String>, refs: &mut Vec<String>) -> Schema {
+        if let Some(ref_) = &schema.ref_ {
+            if let Some(schema) = self.reference(ref_, used, refs) {
+                return schema;
+            }
+        }
+        match schema_kind(schema) {
+            Some(kind) if !self.only.contains(&kind) && !schema.is_empty_object() => {
+                self.made_of(schema, kind)
+            }
+            _ => self.children(schema, used, refs),
+        }
+    }
+
+    fn made_of(&self, schema: &Schema, kind: ModelKind) -> Schema {
+        match kind {
+            ModelKind::Enum if schema.is_string_enum() => Schema {
+                enum_: vec![],
+                ..schema.clone()
+            },
+            _ => untyped(schema),
+        }
+    }
+}
+
+/// An object of any value, documented and required like `schema`.
+fn untyped(schema: &Schema) -> Schema {
+    Schema {
+        type_: Some("object".to_string()),
+        description: schema.description.clone(),
+        x_nullable: schema.x_nullable,
+        self_required: schema.self_required,
+        ..Default::default()
+    }
+}
+
+/// The index of the top level array whose items are the model `i`.
+fn array_of_items(prototypes: &[ModelPrototype], i: usize) -> Option<usize> {
+    let parent = prototypes[i].parent_name.as_deref()?;
+    prototypes.iter().position(|prototype| {
+        prototype.name == parent
+            && prototype.parent_name.is_none()
+            && matches!(
+                &prototype.schema,
+                Item::Object(schema) if matches!(schema.items, Some(Item::Object(_)))
+            )
+    })
+}
+
+/// Titles the inline items of the array `prototype` with `name`, so they are the model `name`.
+fn retitle_items(prototype: &mut ModelPrototype, name: &str) {
+    let Item::Object(schema) = &mut prototype.schema else {
+        return;
+    };
+    let schema = Arc::make_mut(schema);
+    if let Some(Item::Object(items)) = &mut schema.items {
+        let items = Arc::make_mut(items);
+        items.title = Some(name.to_string());
+        items.x_go_name = None;
+    }
+}
+
+/// A string that is equal for schemas of the same structure, see `without_docs`.
+fn structural_key(schema: &Schema) -> String {
+    // maps of `serde_json` are sorted, the order of properties doesn't matter
+    serde_json::to_value(without_docs(schema))
+        .map(|value| value.to_string())
+        .unwrap_or_default()
+}
+
+/// `schema` and every schema nested in it without titles and descriptions, with `required`
+/// sorted.
+fn without_docs(schema: &Schema) -> Schema {
+    let mut schema = schema.clone();
+    schema.title = None;
+    schema.description = None;
+    schema.required.sort_unstable();
+    let strip = |item: &mut Item| {
+        if let Item::Object(child) = item {
+            *child = Arc::new(without_docs(child));
+        }
+    };
+    for props in [&mut schema.properties, &mut schema.pattern_properties]
+        .into_iter()
+        .flatten()
+    {
+        props.0.values_mut().for_each(strip);
+    }
+    if let Some(items) = &mut schema.items {
+        strip(items);
+    }
+    if let Some(values) = &mut schema.additional_properties {
+        strip(values);
+    }
+    for member in &mut schema.all_of {
+        *member = without_docs(member);
+    }
+    schema
+}
+
+#[cfg(test)]
+mod test {
+    use super::{
+        body_model_names, property_signature, BodyNames, PrototypeOptions, PrototypeSource,
+        Prototyper,
+    };
+    use crate::v2::{codegen::backend::rust, Item, Swagger};
+    use std::sync::Arc;
+
+    const SOURCES_SPEC: &str = r##"
+swagger: "2.0"
+definitions:
+  Base:
+    type: object
+    properties:
+      Id:
+        type: string
+  Derived:
+    allOf:
+      - $ref: "#/definitions/Base"
+      - type: object
+        properties:
+          Name:
+            type: string
+responses:
+  Error:
+    description: an error
+    schema:
+      type: object
+      properties:
+        Message:
+          type: string
+paths:
+  /items:
+    get:
+      operationId: ListItems
+      responses:
+        200:
+          description: the items
+          schema:
+            type: object
+            properties:
+              Total:
+                type: integer
+"##;
+
+    fn sources(prototyper: Prototyper<rust::Type>) -> Vec<(String, PrototypeSource)> {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(SOURCES_SPEC).unwrap();
+        prototyper
+            .generate_prototypes(&swagger)
+            .into_iter()
+            .map(|prototype| (prototype.name, prototype.source))
+            .collect()
+    }
+
+    #[test]
+    fn builder_includes_everything_by_default() {
+        let names: Vec<_> = sources(Prototyper::new())
+            .into_iter()
+            .map(|(name, source)| format!("{name}:{source:?}"))
+            .collect();
+        assert_eq!(
+            names,
+            [
+                "Base:Definition",
+                "Derived:Definition",
+                "Error:Response",
+                "ListItems200Response:Operation"
+            ]
+        );
+    }
+
+    #[test]
+    fn builder_include_toggles() {
+        let only = |prototyper: Prototyper<rust::Type>| -> Vec<PrototypeSource> {
+            let mut sources: Vec<_> = sources(prototyper).into_iter().map(|(_, s)| s).collect();
+            sources.dedup();
+            sources
+        };
+        assert_eq!(
+            only(
+                Prototyper::new()
+                    .include_responses(false)
+                    .include_paths(false)
+            ),
+            [PrototypeSource::Definition]
+        );
+        assert_eq!(
+            only(
+                Prototyper::new()
+                    .include_definitions(false)
+                    .include_paths(false)
+            ),
+            [PrototypeSource::Response]
+        );
+        assert_eq!(
+            only(
+                Prototyper::new()
+                    .include_definitions(false)
+                    .include_responses(false)
+            ),
+            [PrototypeSource::Operation]
+        );
+    }
+
+    #[test]
+    fn builder_filter() {
+        let names: Vec<_> = sources(
+            Prototyper::new().filter(|prototype| prototype.source != PrototypeSource::Definition),
+        )
+        .into_iter()
+        .map(|(name, _)| name)
+        .collect();
+        assert_eq!(names, ["Error", "ListItems200Response"]);
+    }
+
+    #[test]
+    fn builder_merge_all_of() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(SOURCES_SPEC).unwrap();
+        let derived = |merge: bool| {
+            let prototypes = Prototyper::new()
+                .merge_all_of(merge)
+                .filter(|prototype| prototype.name == "Derived")
+                .generate_prototypes(&swagger);
+            match &prototypes[0].schema {
+                Item::Object(schema) => schema.clone(),
+                Item::Reference(_) => panic!("Derived is a reference"),
+            }
+        };
+        let merged = derived(true);
+        assert!(merged.all_of.is_empty());
+        assert_eq!(merged.properties.as_ref().unwrap().0.len(), 2);
+        let declared = derived(false);
+        assert_eq!(declared.all_of.len(), 2);
+        assert!(declared.properties.is_none());
+    }
+
+    #[test]
+    fn nested_prototypes_share_schemas() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(
+            r#"
+swagger: "2.0"
+definitions:
+  Container:
+    type: object
+    properties:
+      State:
+        type: object
+        properties:
+          Running:
+            type: boolean
+"#,
+        )
+        .unwrap();
+        let prototypes = Prototyper::default().generate_prototypes(&swagger);
+        let schema = |name: &str| match prototypes.iter().find(|p| p.name == name) {
+            Some(prototype) => match &prototype.schema {
+                Item::Object(schema) => schema.clone(),
+                Item::Reference(_) => panic!("{name} is a reference"),
+            },
+            None => panic!("missing prototype {name}"),
+        };
+        let container = schema("Container");
+        let Some(Item::Object(state)) = container.properties.as_ref().unwrap().0.get("State")
+        else {
+            panic!("missing State property");
+        };
+        assert!(Arc::ptr_eq(state, &schema("ContainerStateInlineItem")));
+    }
+
+    #[test]
+    fn array_item_references_are_dependencies() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(
+            r##"
+swagger: "2.0"
+definitions:
+  TagList:
+    type: array
+    items:
+      $ref: "#/responses/Tag"
+responses:
+  Tag:
+    description: a tag
+    schema:
+      type: object
+      properties:
+        Name:
+          type: string
+"##,
+        )
+        .unwrap();
+        let prototypes = Prototyper::default().generate_prototypes(&swagger);
+        let tag_list = prototypes.iter().find(|p| p.name == "TagList").unwrap();
+        assert_eq!(tag_list.dependencies, ["#/responses/Tag"]);
+        assert_eq!(prototypes.iter().filter(|p| p.name == "Tag").count(), 1);
+    }
+
+    #[test]
+    fn referenced_properties_are_dependencies() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(
+            r##"
+swagger: "2.0"
+definitions:
+  Outer:
+    type: object
+    properties:
+      Inner:
+        $ref: "#/definitions/Inner"
+      Labels:
+        type: object
+        additionalProperties:
+          $ref: "#/responses/Label"
+  Inner:
+    type: object
+    properties:
+      Nested:
+        type: object
+        properties:
+          Deep:
+            type: string
+responses:
+  Label:
+    description: a label
+    schema:
+      type: object
+      properties:
+        Child:
+          type: object
+          properties:
+            Value:
+              type: string
+"##,
+        )
+        .unwrap();
+        let prototypes = Prototyper::default().generate_prototypes(&swagger);
+        let outer = prototypes.iter().find(|p| p.name == "Outer").unwrap();
+        assert_eq!(
+            outer.dependencies,
+            ["#/definitions/Inner", "#/responses/Label"]
+        );
+        let mut names: Vec<_> = prototypes.iter().map(|p| p.name.as_str()).collect();
+        names.sort_unstable();
+        assert_eq!(
+            names,
+            [
+                "Inner",
+                "InnerNestedInlineItem",
+                "Label",
+                "LabelChildInlineItem",
+                "Outer"
+            ]
+        );
+    }
+
+    const INLINE_SPEC: &str = r##"
+swagger: "2.0"
+definitions:
+  Container:
+    type: object
+    properties:
+      Id:
+        $ref: "#/definitions/ContainerId"
+      Health:
+        $ref: "#/definitions/Health"
+      Mounts:
+        type: array
+        items:
+          $ref: "#/definitions/Mount"
+  Image:
+    type: object
+    properties:
+      Mount:
+        $ref: "#/definitions/Mount"
+      Config:
+        $ref: "#/definitions/Config"
+  ContainerId:
+    type: string
+  Health:
+    type: object
+    properties:
+      Status:
+        type: string
+  Mount:
+    type: object
+    properties:
+      Source:
+        type: string
+  Config:
+    type: object
+    properties:
+      User:
+        type: string
+      Env:
+        type: string
+"##;
+
+    fn inlined(threshold: usize) -> Vec<(String, Option<String>)> {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(INLINE_SPEC).unwrap();
+        let mut prototypes: Vec<_> = Prototyper::new()
+            .options(PrototypeOptions {
+                inline_threshold: threshold,
+                ..Default::default()
+            })
+            .generate_prototypes(&swagger)
+            .into_iter()
+            .map(|prototype| (prototype.name, prototype.parent_name))
+            .collect();
+        prototypes.sort_unstable();
+        prototypes
+    }
+
+    #[test]
+    fn inline_threshold() {
+        let top_level = |names: &[&str]| -> Vec<(String, Option<String>)> {
+            names.iter().map(|name| (name.to_string(), None)).collect()
+        };
+        assert_eq!(
+            inlined(0),
+            top_level(&[
+                "Config",
+                "Container",
+                "ContainerId",
+                "Health",
+                "Image",
+                "Mount"
+            ])
+        );
+
+        let mut expected = top_level(&["Config", "Container", "Image", "Mount"]);
+        expected.insert(2, ("ContainerHealth".into(), Some("Container".into())));
+        assert_eq!(inlined(1), expected);
+
+        // `Config` has two properties and `Mount` is used twice
+        let mut expected = top_level(&["Container", "Image", "Mount"]);
+        expected.insert(1, ("ContainerHealth".into(), Some("Container".into())));
+        expected.insert(3, ("ImageConfig".into(), Some("Image".into())));
+        assert_eq!(inlined(2), expected);
+    }
+
+    #[test]
+    fn inlined_property_types() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(INLINE_SPEC).unwrap();
+        let prototypes = Prototyper::new()
+            .options(PrototypeOptions {
+                inline_threshold: 1,
+                ..Default::default()
+            })
+            .generate_prototypes(&swagger);
+        let container = prototypes.iter().find(|p| p.name == "Container").unwrap();
+        assert_eq!(container.dependencies, ["#/definitions/Mount"]);
+        let Item::Object(schema) = &container.schema else {
+            panic!("expected an object");
+        };
+        let prop = |name: &str| match &schema.properties.as_ref().unwrap().0[name] {
+            Item::Object(prop) => prop.clone(),
+            Item::Reference(ref_) => panic!("`{name}` still refers to `{ref_}`"),
+        };
+        assert_eq!(prop("Id").type_(), Some("string"));
+        assert_eq!(prop("Health").name().as_deref(), Some("ContainerHealth"));
+    }
+
+    #[test]
+    fn responses_matching_definitions() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(
+            r##"
+swagger: "2.0"
+definitions:
+  Error:
+    type: object
+    properties:
+      Message:
+        type: string
+responses:
+  Copy:
+    description: same as Error
+    schema:
+      type: object
+      properties:
+        Message:
+          type: string
+  Extended:
+    description: Error with a code
+    schema:
+      type: object
+      properties:
+        Message:
+          type: string
+        Code:
+          type: integer
+  Other:
+    description: a different message type
+    schema:
+      type: object
+      properties:
+        Message:
+          type: integer
+        Code:
+          type: integer
+"##,
+        )
+        .unwrap();
+        let prototypes = Prototyper::default().generate_prototypes(&swagger);
+        let prototype = |name: &str| prototypes.iter().find(|p| p.name == name).unwrap();
+        assert!(
+            matches!(&prototype("Copy").schema, Item::Reference(ref_) if ref_ == "#/definitions/Error")
+        );
+        assert_eq!(
+            prototype("Extended").base.as_deref(),
+            Some("#/definitions/Error")
+        );
+        assert!(prototype("Extended").schema.is_object());
+        assert_eq!(prototype("Other").base, None);
+    }
+
+    #[test]
+    fn param_models_of_operations_without_id() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(
+            r##"
+swagger: "2.0"
+paths:
+  /containers/{id}/update:
+    post:
+      parameters:
+        - name: force
+          in: query
+          type: boolean
+        - name: update
+          in: body
+          schema:
+            type: object
+            properties:
+              Memory:
+                type: integer
+      responses: {}
+  /images/create:
+    post:
+      parameters:
+        - name: fromImage
+          in: query
+          type: string
+        - name: update
+          in: body
+          schema:
+            type: object
+            properties:
+              Tag:
+                type: string
+      responses: {}
+"##,
+        )
+        .unwrap();
+        let mut names: Vec<_> = Prototyper::new()
+            .options(PrototypeOptions {
+                query_params_models: true,
+                ..Default::default()
+            })
+            .generate_prototypes(&swagger)
+            .into_iter()
+            .map(|prototype| prototype.name)
+            .collect();
+        names.sort_unstable();
+        assert_eq!(
+            names,
+            [
+                "PostContainersIdUpdateParams",
+                "PostContainersIdUpdateUpdateParam",
+                "PostImagesCreateParams",
+                "PostImagesCreateUpdateParam",
+            ]
+        );
+    }
+
+    #[test]
+    fn response_models_of_operations_without_id() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(
+            r##"
+swagger: "2.0"
+paths:
+  /a:
+    get:
+      responses:
+        200:
+          description: a
+          schema:
+            type: object
+            properties:
+              A:
+                type: string
+  /b:
+    get:
+      responses:
+        200:
+          description: b
+          schema:
+            type: object
+            properties:
+              B:
+                type: string
+"##,
+        )
+        .unwrap();
+        let mut names: Vec<_> = Prototyper::new()
+            .generate_prototypes(&swagger)
+            .into_iter()
+            .map(|prototype| prototype.name)
+            .collect();
+        names.sort_unstable();
+        assert_eq!(names, ["GetA200Response", "GetB200Response"]);
+    }
+
+    #[test]
+    fn body_names_of_differing_titles() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(
+            r##"
+swagger: "2.0"
+paths:
+  /a:
+    post:
+      operationId: A
+      parameters:
+        - name: body
+          in: body
+          schema:
+            title: Options
+            type: object
+            properties:
+              Force:
+                type: boolean
+      responses: {}
+  /b:
+    post:
+      operationId: B
+      parameters:
+        - name: body
+          in: body
+          schema:
+            title: Options
+            type: object
+            properties:
+              Timeout:
+                type: integer
+      responses: {}
+  /c:
+    post:
+      operationId: C
+      parameters:
+        - name: body
+          in: body
+          schema:
+            x-go-name: COptions
+            type: object
+      responses: {}
+"##,
+        )
+        .unwrap();
+        let names = body_model_names(&swagger, BodyNames::Schema);
+        assert_eq!(names[&("/a", "post", "body")], "ABodyParam");
+        assert_eq!(names[&("/b", "post", "body")], "BBodyParam");
+        assert_eq!(names[&("/c", "post", "body")], "COptions");
+
+        let names = body_model_names(&swagger, BodyNames::Operation);
+        assert_eq!(names[&("/c", "post", "body")], "CBodyParam");
+    }
+
+    #[test]
+    fn common_inline_names() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(
+            r##"
+swagger: "2.0"
+definitions:
+  Error:
+    type: object
+    properties:
+      message:
+        type: string
+      code:
+        type: integer
+  Task:
+    type: object
+    properties:
+      Error:
+        type: object
+        properties:
+          message:
+            type: string
+          code:
+            type: integer
+      Titled:
+        type: object
+        title: TaskError
+        properties:
+          message:
+            type: string
+          code:
+            type: integer
+      Status:
+        type: object
+        properties:
+          code:
+            type: integer
+"##,
+        )
+        .unwrap();
+        let error = swagger.definitions.as_ref().unwrap().get("Error").unwrap();
+        assert_eq!(
+            property_signature(error).as_deref(),
+            Some("code:integer,message:string")
+        );
+
+        let prototypes = Prototyper::new()
+            .options(PrototypeOptions {
+                common_inline_names: [("code:integer,message:string".into(), "Failure".into())]
+                    .into(),
+                ..Default::default()
+            })
+            .generate_prototypes(&swagger);
+        let names: Vec<_> = prototypes.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(
+            names,
+            [
+                "Error",
+                "Failure",
+                "TaskError",
+                "TaskStatusInlineItem",
+                "Task"
+            ]
+        );
     }
 }
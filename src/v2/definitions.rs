@@ -1,14 +1,88 @@
 use crate::v2::{schema::Schema, DEFINITIONS_REF};
 
-use serde::Deserialize;
-use std::collections::HashMap;
+use serde::{de, ser};
+use std::{collections::HashMap, fmt};
 
-#[derive(Debug, Clone, Deserialize)]
-pub struct Definitions(pub HashMap<String, Schema>);
+/// A definition key that was declared more than once. Only the last declaration is kept.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateKey {
+    pub key: String,
+    /// Zero-based position of the earlier entry in the `definitions` object.
+    pub first: usize,
+    /// Zero-based position of the entry that replaced it.
+    pub second: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Definitions(pub HashMap<String, Schema>, Vec<DuplicateKey>);
+
+impl From<HashMap<String, Schema>> for Definitions {
+    fn from(definitions: HashMap<String, Schema>) -> Self {
+        Definitions(definitions, vec![])
+    }
+}
 
 impl Definitions {
     pub fn get(&self, key: impl AsRef<str>) -> Option<&Schema> {
         let key = key.as_ref().trim_start_matches(DEFINITIONS_REF);
         self.0.get(key)
     }
+
+    /// The keys declared more than once in the parsed spec.
+    pub fn duplicates(&self) -> &[DuplicateKey] {
+        &self.1
+    }
+}
+
+impl<'de> de::Deserialize<'de> for Definitions {
+    fn deserialize<D>(deserializer: D) -> Result<Definitions, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        struct DefinitionsVisitor;
+
+        impl<'de> de::Visitor<'de> for DefinitionsVisitor {
+            type Value = Definitions;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                write!(f, "a mapping of definition names to schemas")
+            }
+
+            fn visit_map<A>(self, mut map: A) -> Result<Definitions, A::Error>
+            where
+                A: de::MapAccess<'de>,
+            {
+                let mut definitions = HashMap::new();
+                let mut positions = HashMap::new();
+                let mut duplicates = vec![];
+                let mut position = 0;
+                while let Some((key, schema)) = map.next_entry::<String, Schema>()? {
+                    if let Some(first) = positions.insert(key.clone(), position) {
+                        log::warn!(
+                            "definition `{key}` is declared more than once, keeping the last one"
+                        );
+                        duplicates.push(DuplicateKey {
+                            key: key.clone(),
+                            first,
+                            second: position,
+                        });
+                    }
+                    definitions.insert(key, schema);
+                    position += 1;
+                }
+                Ok(Definitions(definitions, duplicates))
+            }
+        }
+
+        deserializer.deserialize_map(DefinitionsVisitor)
+    }
+}
+
+impl ser::Serialize for Definitions {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
 }
@@ -1,12 +1,13 @@
 use crate::v2::schema::Schema;
 
-use serde::{de, Deserialize};
-use std::collections::HashMap;
+use indexmap::IndexMap;
+use serde::{de, ser, Deserialize, Serialize};
+use std::sync::Arc;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum Item {
     Reference(String),
-    Object(Box<Schema>),
+    Object(Arc<Schema>),
 }
 
 impl Item {
@@ -34,6 +35,7 @@ impl<'de> de::Deserialize<'de> for Item {
         let v: serde_yaml::Value = de::Deserialize::deserialize(deserializer)?;
 
         let ref_key = "$ref".into();
+        let required_key = "required".into();
         match v {
             serde_yaml::Value::String(s) => Ok(Item::Reference(s)),
             serde_yaml::Value::Mapping(map) if map.contains_key(&ref_key) => {
@@ -44,12 +46,54 @@ impl<'de> de::Deserialize<'de> for Item {
                     Err(de::Error::custom(format!("invalid reference `{:?}`", ref_)))
                 }
             }
-            v => serde_yaml::from_value(v.clone())
-                .map(|schema: Schema| Item::Object(Box::new(schema)))
+            // non-standard `required: true` on the property itself instead of the parent's
+            // `required` list
+            serde_yaml::Value::Mapping(mut map)
+                if map.get(&required_key).is_some_and(|v| v.is_bool()) =>
+            {
+                let self_required = map.remove(&required_key).and_then(|v| v.as_bool());
+                serde_yaml::from_value(serde_yaml::Value::Mapping(map))
+                    .map(|schema: Schema| {
+                        Item::Object(Arc::new(Schema {
+                            self_required,
+                            ..schema
+                        }))
+                    })
+                    .map_err(|e| de::Error::custom(e.to_string()))
+            }
+            v => serde_yaml::from_value(v)
+                .map(|schema: Schema| Item::Object(Arc::new(schema)))
                 .map_err(|e| de::Error::custom(e.to_string())),
         }
     }
 }
 
-#[derive(Debug, Default, Clone, Deserialize)]
-pub struct Items(pub HashMap<String, Item>);
+impl ser::Serialize for Item {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        use ser::SerializeMap;
+        match self {
+            Item::Reference(ref_) => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("$ref", ref_)?;
+                map.end()
+            }
+            Item::Object(schema) => match schema.self_required {
+                Some(required) => {
+                    let mut v = serde_yaml::to_value(&**schema).map_err(ser::Error::custom)?;
+                    if let serde_yaml::Value::Mapping(map) = &mut v {
+                        map.insert("required".into(), required.into());
+                    }
+                    v.serialize(serializer)
+                }
+                None => (**schema).serialize(serializer),
+            },
+        }
+    }
+}
+
+/// Properties of a schema in the order they are declared in the spec.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Items(pub IndexMap<String, Item>);
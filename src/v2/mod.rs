@@ -1,12 +1,18 @@
+pub mod builder;
 pub mod codegen;
 pub mod definitions;
 pub mod items;
+pub mod names;
+pub mod openapi3;
 pub mod operation;
 pub mod parameter;
 pub mod path;
 pub mod responses;
 pub mod schema;
+pub mod stats;
+pub mod tag;
 pub mod types;
+pub mod validation;
 
 pub const DEFINITIONS_REF: &str = "#/definitions/";
 pub const RESPONSES_REF: &str = "#/responses/";
@@ -16,8 +22,13 @@ pub use responses::Response;
 pub use schema::Schema;
 pub use types::Type;
 
-use serde::Deserialize;
-use std::marker::PhantomData;
+use serde::{Deserialize, Serialize};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, HashSet},
+    marker::PhantomData,
+    sync::{Arc, OnceLock},
+};
 
 pub(crate) use serde_yaml::Value;
 
@@ -26,17 +37,99 @@ fn trim_reference(ref_: &str) -> &str {
         .trim_start_matches(RESPONSES_REF)
 }
 
-#[derive(Debug, Deserialize)]
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
+#[derive(Debug, Deserialize, Serialize)]
 pub struct Swagger<T: Type> {
     pub swagger: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub definitions: Option<definitions::Definitions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub paths: Option<path::Paths>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub responses: Option<responses::Responses>,
-    #[serde(skip_deserializing)]
+    /// Content types operations consume unless they declare their own.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub consumes: Vec<String>,
+    /// Content types operations produce unless they declare their own.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub produces: Vec<String>,
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<tag::Tag>,
+    /// Top level fields that are not used for generating code, like `info` or `host`.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+    #[serde(skip)]
+    type_names: OnceLock<names::TypeNames>,
+    #[serde(skip)]
+    map_key_types: OnceLock<HashSet<String>>,
+    #[serde(skip)]
     _data: PhantomData<T>,
 }
 
 impl<T: Type> Swagger<T> {
+    /// Returns every operation of the spec as `(path, method, operation)`, sorted by path.
+    pub fn operations(&self) -> impl Iterator<Item = (&str, &'static str, &operation::Operation)> {
+        let mut paths: Vec<_> = self
+            .paths
+            .iter()
+            .flat_map(|paths| &paths.0)
+            .filter_map(|(name, path)| match path {
+                path::Path::Item(item) => Some((name.as_str(), item.as_ref())),
+                path::Path::Extension(_) => None,
+            })
+            .collect();
+        paths.sort_unstable_by_key(|(name, _)| *name);
+        paths.into_iter().flat_map(|(name, item)| {
+            item.operations()
+                .map(move |(method, op)| (name, method, op))
+        })
+    }
+
+    /// Content types `op` accepts, its own `consumes` or the ones of the spec if it has none.
+    pub fn consumes_of<'a>(&'a self, op: &'a operation::Operation) -> &'a [String] {
+        if op.consumes.is_empty() {
+            &self.consumes
+        } else {
+            &op.consumes
+        }
+    }
+
+    /// Content types `op` responds with, its own `produces` or the ones of the spec if it has
+    /// none.
+    pub fn produces_of<'a>(&'a self, op: &'a operation::Operation) -> &'a [String] {
+        if op.produces.is_empty() {
+            &self.produces
+        } else {
+            &op.produces
+        }
+    }
+
+    /// Names that types of referenced definitions are generated under.
+    pub fn type_names(&self) -> &names::TypeNames {
+        self.type_names.get_or_init(|| names::TypeNames::new(self))
+    }
+
+    /// References of every definition used as the key type of a map with `x-key-type`.
+    pub fn map_key_types(&self) -> &HashSet<String> {
+        self.map_key_types.get_or_init(|| {
+            let mut refs = HashSet::new();
+            self.visit_schemas(|schema, _| {
+                if let Some(ref_) = &schema.x_key_type {
+                    refs.insert(ref_.clone());
+                }
+            });
+            refs
+        })
+    }
+
+    /// Resolves a reference to a definition or to the schema of a top level response, as it is
+    /// declared in the spec. Use `merge_all_of_schema` for its merged form.
     pub fn get_ref_schema(&self, ref_: &str) -> Option<&Schema> {
         log::debug!("getting schema for reference `{ref_}`");
         if ref_.starts_with(DEFINITIONS_REF) {
@@ -45,10 +138,10 @@ impl<T: Type> Swagger<T> {
             }
         } else if ref_.starts_with(RESPONSES_REF) {
             if let Some(responses) = &self.responses {
-                let response = responses.0.get(ref_)?;
+                let response = responses.0.get(ref_.trim_start_matches(RESPONSES_REF))?;
                 match response {
                     Response::Object(response) => return response.schema.as_ref(),
-                    Response::Reference(ref_) => return self.get_ref_schema(&ref_),
+                    Response::Reference(ref_, _) => return self.get_ref_schema(ref_),
                 }
             }
         }
@@ -56,56 +149,177 @@ impl<T: Type> Swagger<T> {
         None
     }
 
-    pub fn merge_all_of_schema(&self, schema: Schema) -> Schema {
-        if !schema.all_of.is_empty() {
-            let base_schema = Schema {
-                description: schema.description.clone(),
-                title: schema.title.clone(),
-                properties: Some(Items::default()),
-                ..Default::default()
-            };
-            schema
-                .all_of
-                .into_iter()
-                .fold(base_schema, |mut acc, schema| {
-                    let mut schema = if let Some(ref_) = &schema.ref_ {
-                        self.get_ref_schema(ref_)
-                            .map(|s| s.clone())
-                            .unwrap_or(schema)
-                    } else {
-                        schema
-                    };
-                    if let Some(props) = &mut acc.properties {
-                        if let Some(new_props) = &schema.properties {
-                            props
-                                .0
-                                .extend(new_props.0.iter().map(|(k, v)| (k.clone(), v.clone())));
+    /// The definition `name`, a key of `definitions` or a full reference, with its `allOf`
+    /// merged by `merge_all_of_schema`.
+    pub fn merged_definition(&self, name: &str) -> Option<Schema> {
+        let schema = self.definitions.as_ref()?.get(name)?;
+        Some(self.merge_all_of_schema(schema).into_owned())
+    }
+
+    /// Merges the `allOf` subschemas of `schema` into a single schema, starting out with
+    /// everything declared on `schema` itself. Members are merged first, so properties of
+    /// references to schemas composed with `allOf` themselves are included, a reference back to
+    /// a schema being merged is taken as declared. Properties of later members replace the ones
+    /// of earlier members of the same name, while `readOnly`, `writeOnly` and `x-nullable` of
+    /// them accumulate. `type`, `format`, `title`, `description`, `maxLength` and `enum` are
+    /// taken from the first member declaring them unless `schema` does. A merged scalar or
+    /// array without properties isn't an object. Schemas without `allOf` are returned as is
+    /// without copying them.
+    pub fn merge_all_of_schema<'a>(&self, schema: &'a Schema) -> Cow<'a, Schema> {
+        self.merge_all_of_schema_in(schema, &mut vec![])
+    }
+
+    /// `merge_all_of_schema` inside of the merges of the references in `merging`.
+    fn merge_all_of_schema_in<'a>(
+        &self,
+        schema: &'a Schema,
+        merging: &mut Vec<String>,
+    ) -> Cow<'a, Schema> {
+        if schema.all_of.is_empty() {
+            return Cow::Borrowed(schema);
+        }
+        // the schema's own fields win over the ones of its members, a `$ref` next to `allOf`
+        // is ignored
+        let mut acc = Schema {
+            ref_: None,
+            properties: Some(schema.properties.clone().unwrap_or_default()),
+            all_of: vec![],
+            ..schema.clone()
+        };
+        for member in &schema.all_of {
+            let schema = match member.ref_.as_deref() {
+                Some(ref_) if !merging.iter().any(|merged| merged == ref_) => {
+                    match self.get_ref_schema(ref_) {
+                        Some(base) => {
+                            merging.push(ref_.to_string());
+                            let base = self.merge_all_of_schema_in(base, merging);
+                            merging.pop();
+                            base
                         }
+                        None => Cow::Borrowed(member),
                     }
-                    macro_rules! add_if_not_set {
-                    ($($field:ident),+) => {
-                        $(
-                        if acc.$field.is_none() && schema.$field.is_some() {
-                            acc.$field = schema.$field;
+                }
+                Some(_) => Cow::Borrowed(member),
+                None => self.merge_all_of_schema_in(member, merging),
+            };
+            if let Some(props) = &mut acc.properties {
+                if let Some(new_props) = &schema.properties {
+                    for (name, prop) in &new_props.0 {
+                        let mut prop = prop.clone();
+                        if let Item::Object(prop_schema) = &mut prop {
+                            let mut read_only = prop_schema.read_only | schema.read_only;
+                            let mut write_only = prop_schema.write_only | schema.write_only;
+                            let mut x_nullable = prop_schema.x_nullable;
+                            if let Some(Item::Object(existing)) = props.0.get(name) {
+                                read_only |= existing.read_only;
+                                write_only |= existing.write_only;
+                                x_nullable |= existing.x_nullable;
+                            }
+                            // only copy the property schema if a flag actually changes
+                            if (read_only, write_only, x_nullable)
+                                != (
+                                    prop_schema.read_only,
+                                    prop_schema.write_only,
+                                    prop_schema.x_nullable,
+                                )
+                            {
+                                let prop_schema = Arc::make_mut(prop_schema);
+                                prop_schema.read_only = read_only;
+                                prop_schema.write_only = write_only;
+                                prop_schema.x_nullable = x_nullable;
+                            }
                         }
-                        )+
-                    };
+                        props.0.insert(name.clone(), prop);
+                    }
                 }
-                    add_if_not_set!(format, title, description, type_);
-
-                    if acc.required.is_empty() && !schema.required.is_empty() {
-                        acc.required.append(&mut schema.required);
+            }
+            macro_rules! add_if_not_set {
+                ($($field:ident),+) => {
+                    $(
+                    if acc.$field.is_none() && schema.$field.is_some() {
+                        acc.$field = schema.$field.clone();
                     }
+                    )+
+                };
+            }
+            add_if_not_set!(format, title, description, type_, max_length);
 
-                    if acc.enum_.is_empty() && !schema.enum_.is_empty() {
-                        acc.enum_.append(&mut schema.enum_);
-                    }
+            for required in &schema.required {
+                if !acc.required.contains(required) {
+                    acc.required.push(required.clone());
+                }
+            }
 
-                    acc
-                })
-        } else {
-            schema
+            if acc.enum_.is_empty() && !schema.enum_.is_empty() {
+                acc.enum_ = schema.enum_.clone();
+            }
+        }
+        // scalars and arrays composed with `allOf` aren't objects without properties
+        let is_object = !matches!(
+            acc.type_.as_deref(),
+            Some("string" | "integer" | "number" | "boolean" | "array")
+        );
+        if !is_object
+            && acc
+                .properties
+                .as_ref()
+                .is_some_and(|props| props.0.is_empty())
+        {
+            acc.properties = None;
+        }
+        Cow::Owned(acc)
+    }
+
+    /// Composes a schema of a single `allOf` reference to an object and inline members by
+    /// merging only the inline members and keeping the reference as the sole `allOf` entry, so
+    /// that it can be flattened into the model instead of copying its properties. Any other
+    /// schema is merged with `merge_all_of_schema`.
+    pub fn flatten_all_of_schema<'a>(&self, schema: &'a Schema) -> Cow<'a, Schema> {
+        let mut refs = schema.all_of.iter().filter(|member| member.ref_.is_some());
+        let (Some(reference), None) = (refs.next(), refs.next()) else {
+            return self.merge_all_of_schema(schema);
+        };
+        let is_object = reference
+            .ref_
+            .as_deref()
+            .and_then(|ref_| self.get_ref_schema(ref_))
+            .map(|base| self.merge_all_of_schema(base))
+            .is_some_and(|base| base.properties.is_some());
+        if !is_object {
+            return self.merge_all_of_schema(schema);
         }
+        if schema.all_of.len() == 1 {
+            // already flattened, a lone reference without properties is merged as an alias
+            return if schema.properties.is_some() {
+                Cow::Borrowed(schema)
+            } else {
+                self.merge_all_of_schema(schema)
+            };
+        }
+        let inline = Schema {
+            all_of: schema
+                .all_of
+                .iter()
+                .filter(|member| member.ref_.is_none())
+                .cloned()
+                .collect(),
+            ..schema.clone()
+        };
+        let mut flattened = self.merge_all_of_schema(&inline).into_owned();
+        flattened.all_of = vec![reference.clone()];
+        Cow::Owned(flattened)
+    }
+
+    /// Serializes the spec as pretty printed JSON with sorted keys. Fields that are not used
+    /// for generating code but were kept while parsing, like vendor extensions, are included.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string_pretty(&serde_json::to_value(self)?)
+    }
+
+    /// Serializes the spec as YAML with sorted keys, see `to_json`.
+    pub fn to_yaml(&self) -> Result<String, serde_yaml::Error> {
+        let value = serde_json::to_value(self).map_err(serde::ser::Error::custom)?;
+        serde_yaml::to_string(&value)
     }
 
     pub fn map_reference_type(
@@ -114,7 +328,7 @@ impl<T: Type> Swagger<T> {
         is_required: bool,
         parent_name: Option<&str>,
     ) -> Option<T> {
-        T::map_reference_type(ref_, is_required, parent_name, &self)
+        T::map_reference_type(ref_, is_required, parent_name, self)
     }
 
     pub fn map_item_type(
@@ -123,7 +337,7 @@ impl<T: Type> Swagger<T> {
         is_required: bool,
         parent_name: Option<&str>,
     ) -> Option<T> {
-        T::map_item_type(item, is_required, parent_name, &self)
+        T::map_item_type(item, is_required, parent_name, self)
     }
 
     pub fn map_schema_type(
@@ -133,6 +347,322 @@ impl<T: Type> Swagger<T> {
         is_required: bool,
         parent_name: Option<&str>,
     ) -> Option<T> {
-        T::map_schema_type(schema, ref_, is_required, parent_name, &self)
+        T::map_schema_type(schema, ref_, is_required, parent_name, self)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Item, Schema, Swagger};
+    use crate::v2::codegen::backend::rust;
+    use std::borrow::Cow;
+
+    #[test]
+    fn operations() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(
+            r#"
+swagger: "2.0"
+consumes: [application/json]
+produces: [application/json]
+paths:
+  x-internal:
+    owner: engine
+  /images/{name}:
+    delete:
+      responses: {}
+    get:
+      produces: [application/x-tar]
+      responses: {}
+  /containers:
+    post:
+      consumes: [application/x-www-form-urlencoded]
+      responses: {}
+    get:
+      responses: {}
+"#,
+        )
+        .unwrap();
+        let operations: Vec<_> = swagger
+            .operations()
+            .map(|(path, method, op)| {
+                (
+                    path,
+                    method,
+                    swagger.consumes_of(op)[0].as_str(),
+                    swagger.produces_of(op)[0].as_str(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            operations,
+            [
+                ("/containers", "get", "application/json", "application/json"),
+                (
+                    "/containers",
+                    "post",
+                    "application/x-www-form-urlencoded",
+                    "application/json"
+                ),
+                (
+                    "/images/{name}",
+                    "get",
+                    "application/json",
+                    "application/x-tar"
+                ),
+                (
+                    "/images/{name}",
+                    "delete",
+                    "application/json",
+                    "application/json"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn merged_definition() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(
+            r##"
+swagger: "2.0"
+definitions:
+  Base:
+    type: object
+    required: [Id]
+    properties:
+      Id:
+        type: string
+  Named:
+    allOf:
+      - $ref: "#/definitions/Base"
+      - type: object
+        properties:
+          Name:
+            type: string
+  Sized:
+    description: A named object with a size.
+    allOf:
+      - $ref: "#/definitions/Named"
+      - type: object
+        properties:
+          Size:
+            type: integer
+  Loop:
+    allOf:
+      - $ref: "#/definitions/Back"
+      - properties:
+          Front:
+            type: string
+  Back:
+    allOf:
+      - $ref: "#/definitions/Loop"
+      - properties:
+          Back:
+            type: string
+"##,
+        )
+        .unwrap();
+        let props = |name: &str| -> Vec<String> {
+            let schema = swagger.merged_definition(name).unwrap();
+            let mut props: Vec<_> = schema.properties.unwrap().0.into_keys().collect();
+            props.sort_unstable();
+            props
+        };
+        assert_eq!(props("Sized"), ["Id", "Name", "Size"]);
+        assert_eq!(props("#/definitions/Named"), ["Id", "Name"]);
+        assert_eq!(props("Loop"), ["Back", "Front"]);
+
+        let sized = swagger.merged_definition("Sized").unwrap();
+        assert_eq!(sized.required, ["Id"]);
+        assert_eq!(sized.type_.as_deref(), Some("object"));
+        assert_eq!(
+            sized.description.as_deref(),
+            Some("A named object with a size.")
+        );
+        assert!(swagger.merged_definition("Missing").is_none());
+    }
+
+    #[test]
+    fn merge_all_of_unites_required() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(
+            r##"
+swagger: "2.0"
+definitions:
+  Base:
+    type: object
+    required: [Id]
+    properties:
+      Id:
+        type: string
+  Pet:
+    allOf:
+      - $ref: "#/definitions/Base"
+      - type: object
+        required: [Name, Id]
+        properties:
+          Name:
+            type: string
+"##,
+        )
+        .unwrap();
+        let pet = swagger.merged_definition("Pet").unwrap();
+        assert_eq!(pet.required, ["Id", "Name"]);
+    }
+
+    #[test]
+    fn map_reference_type_merges_all_of() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(
+            r##"
+swagger: "2.0"
+definitions:
+  Base:
+    type: object
+    properties:
+      Id:
+        type: string
+  Pet:
+    allOf:
+      - $ref: "#/definitions/Base"
+"##,
+        )
+        .unwrap();
+        // only composed of `allOf`, a struct once merged rather than an untyped value
+        assert_eq!(
+            swagger
+                .map_reference_type("#/definitions/Pet", true, None)
+                .map(|ty| ty.to_string()),
+            Some("Pet".to_string())
+        );
+    }
+
+    #[test]
+    fn merge_all_of_keeps_property_access_flags() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(
+            r#"
+swagger: "2.0"
+definitions:
+  Base:
+    type: object
+    readOnly: true
+    properties:
+      Id:
+        type: string
+      Name:
+        type: string
+        x-nullable: true
+"#,
+        )
+        .unwrap();
+        let schema: Schema = serde_yaml::from_str(
+            r##"
+allOf:
+  - $ref: "#/definitions/Base"
+  - type: object
+    properties:
+      Name:
+        type: string
+      Secret:
+        type: string
+        writeOnly: true
+"##,
+        )
+        .unwrap();
+
+        let merged = swagger.merge_all_of_schema(&schema);
+        let props = merged.properties.as_ref().unwrap();
+        let prop = |name: &str| match props.0.get(name) {
+            Some(Item::Object(schema)) => schema.clone(),
+            _ => panic!("missing property {name}"),
+        };
+        assert!(prop("Id").read_only);
+        assert!(prop("Name").read_only);
+        assert!(prop("Name").x_nullable);
+        assert!(!prop("Secret").read_only);
+        assert!(prop("Secret").write_only);
+    }
+
+    #[test]
+    fn merge_all_of_borrows_plain_schema() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str("swagger: \"2.0\"").unwrap();
+        let schema: Schema = serde_yaml::from_str("type: object").unwrap();
+        assert!(matches!(
+            swagger.merge_all_of_schema(&schema),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn merge_all_of_keeps_own_properties() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str("swagger: \"2.0\"").unwrap();
+        let schema: Schema = serde_yaml::from_str(
+            r#"
+required: [Own]
+properties:
+  Own:
+    type: string
+allOf:
+  - type: object
+    properties:
+      Member:
+        type: string
+"#,
+        )
+        .unwrap();
+        let merged = swagger.merge_all_of_schema(&schema);
+        let props = merged.properties.as_ref().unwrap();
+        assert!(props.0.contains_key("Own"));
+        assert!(props.0.contains_key("Member"));
+        assert_eq!(merged.required, vec!["Own".to_string()]);
+    }
+
+    #[test]
+    fn merge_all_of_keeps_own_constraints() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(
+            r##"
+swagger: "2.0"
+definitions:
+  Level:
+    type: string
+    format: level
+    description: Severity of an event.
+    enum: [debug, info, warn, error]
+  Alert:
+    type: string
+    enum: [warn, error]
+    allOf:
+      - $ref: "#/definitions/Level"
+"##,
+        )
+        .unwrap();
+        let merged = swagger.merged_definition("Alert").unwrap();
+        assert_eq!(merged.type_.as_deref(), Some("string"));
+        assert_eq!(merged.enum_.len(), 2);
+        assert!(merged.all_of.is_empty());
+        // taken from the member, `Alert` doesn't declare them
+        assert_eq!(merged.format.as_deref(), Some("level"));
+        assert_eq!(merged.description.as_deref(), Some("Severity of an event."));
+
+        let out =
+            crate::v2::codegen::CodeGenerator::new(swagger, Box::new(rust::Codegen::default()))
+                .generate_models_to_string()
+                .unwrap();
+        assert!(out.contains("pub enum Alert {"));
+    }
+
+    #[test]
+    fn properties_keep_declaration_order() {
+        let schema: Schema = serde_yaml::from_str(
+            r#"
+properties:
+  Zeta:
+    type: string
+  Alpha:
+    type: string
+  Mu:
+    type: string
+"#,
+        )
+        .unwrap();
+        let names: Vec<_> = schema.properties.unwrap().0.into_keys().collect();
+        assert_eq!(names, ["Zeta", "Alpha", "Mu"]);
     }
 }
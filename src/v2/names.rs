@@ -0,0 +1,195 @@
+use crate::v2::{Schema, Swagger, Type, DEFINITIONS_REF};
+
+use std::collections::{HashMap, HashSet};
+
+/// A definition whose formatted type name collides with the one of an earlier definition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TypeNameCollision {
+    /// The type name both definitions format to.
+    pub type_name: String,
+    /// Key of the definition that keeps the name.
+    pub kept: String,
+    /// Key of the definition that is renamed.
+    pub renamed: String,
+    /// The name the renamed definition is generated under.
+    pub new_name: String,
+}
+
+/// Maps references to the name their type is generated under when it differs from the
+/// referenced key.
+#[derive(Debug, Default, Clone)]
+pub struct TypeNames {
+    renames: HashMap<String, String>,
+    collisions: Vec<TypeNameCollision>,
+}
+
+impl TypeNames {
+    /// Finds definitions whose names format to the same type name, e.g. `networkSettings` and
+    /// `NetworkSettings`. Keys are visited in sorted order and every key after the first one
+    /// gets the lowest numeric suffix that doesn't collide with any other type name.
+    ///
+    /// A definition is named by its key, except for array and alias definitions with a title
+    /// or `x-go-name` which are named by that instead, unless it is the name of another key.
+    pub fn new<T: Type>(swagger: &Swagger<T>) -> Self {
+        let mut names = Self::default();
+        let Some(definitions) = &swagger.definitions else {
+            return names;
+        };
+        let mut keys: Vec<_> = definitions.0.keys().collect();
+        keys.sort_unstable();
+        let key_names: HashSet<_> = keys.iter().map(|key| T::format_name(key)).collect();
+        let preferred: Vec<(&String, String)> = keys
+            .into_iter()
+            .map(|key| {
+                let name = Some(&definitions.0[key])
+                    .filter(|schema| is_alias(schema))
+                    .and_then(Schema::name)
+                    .filter(|title| !key_names.contains(&T::format_name(title)))
+                    .unwrap_or_else(|| key.clone());
+                (key, name)
+            })
+            .collect();
+
+        let mut taken: HashSet<_> = preferred
+            .iter()
+            .map(|(_, name)| T::format_name(name))
+            .collect();
+        let mut owners: HashMap<String, &str> = HashMap::new();
+        for (key, name) in &preferred {
+            let type_name = T::format_name(name);
+            let Some(kept) = owners.get(&type_name) else {
+                owners.insert(type_name, key);
+                if name != *key {
+                    names
+                        .renames
+                        .insert(format!("{DEFINITIONS_REF}{key}"), name.clone());
+                }
+                continue;
+            };
+            let new_name = (2..)
+                .map(|n| format!("{name}{n}"))
+                .find(|candidate| !taken.contains(&T::format_name(candidate)))
+                .unwrap();
+            taken.insert(T::format_name(&new_name));
+            names
+                .renames
+                .insert(format!("{DEFINITIONS_REF}{key}"), new_name.clone());
+            names.collisions.push(TypeNameCollision {
+                type_name,
+                kept: kept.to_string(),
+                renamed: key.to_string(),
+                new_name,
+            });
+        }
+        names
+    }
+
+    /// Returns the unformatted name the type of `ref_` is generated under.
+    pub fn get<'a>(&'a self, ref_: &'a str) -> &'a str {
+        self.renames
+            .get(ref_)
+            .map(String::as_str)
+            .unwrap_or_else(|| crate::v2::trim_reference(ref_))
+    }
+
+    pub fn collisions(&self) -> &[TypeNameCollision] {
+        &self.collisions
+    }
+}
+
+/// Whether `schema` is an array, a map or an alias of another type rather than an object or
+/// an enum.
+fn is_alias(schema: &Schema) -> bool {
+    schema.properties.is_none()
+        && schema.all_of.is_empty()
+        && schema.x_variants.is_none()
+        && !schema.is_string_enum()
+        && (!schema.is_object() || schema.map_values().is_some())
+}
+
+#[cfg(test)]
+mod test {
+    use crate::v2::{codegen::backend::rust, Swagger};
+
+    #[test]
+    fn case_variant_definitions() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(
+            r#"
+swagger: "2.0"
+definitions:
+  networkSettings:
+    type: object
+  NetworkSettings:
+    type: object
+  NetworkSettings2:
+    type: object
+  Other:
+    type: object
+"#,
+        )
+        .unwrap();
+        let names = swagger.type_names();
+        assert_eq!(
+            names.get("#/definitions/NetworkSettings"),
+            "NetworkSettings"
+        );
+        assert_eq!(
+            names.get("#/definitions/networkSettings"),
+            "networkSettings3"
+        );
+        assert_eq!(names.get("#/definitions/Other"), "Other");
+        assert_eq!(names.collisions().len(), 1);
+        assert_eq!(names.collisions()[0].kept, "NetworkSettings");
+        assert_eq!(names.collisions()[0].renamed, "networkSettings");
+
+        let diagnostics = swagger.validate();
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0]
+            .message
+            .contains("the latter is generated as `NetworkSettings3`"));
+    }
+
+    #[test]
+    fn titled_aliases() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(
+            r#"
+swagger: "2.0"
+definitions:
+  ContainerSummary:
+    type: array
+    title: Containers
+    items:
+      type: string
+  Containers:
+    type: object
+    properties:
+      Id:
+        type: string
+  Name:
+    type: string
+    x-go-name: ContainerName
+  Labels:
+    type: object
+    title: LabelMap
+    additionalProperties:
+      type: string
+  State:
+    type: string
+    title: ContainerState
+    enum: [running]
+"#,
+        )
+        .unwrap();
+        let names = swagger.type_names();
+        assert_eq!(names.get("#/definitions/Name"), "ContainerName");
+        assert_eq!(names.get("#/definitions/Labels"), "LabelMap");
+        assert_eq!(names.get("#/definitions/State"), "State");
+        // keys take precedence over titles
+        assert_eq!(names.get("#/definitions/Containers"), "Containers");
+        assert_eq!(
+            names.get("#/definitions/ContainerSummary"),
+            "ContainerSummary"
+        );
+        assert!(names.collisions().is_empty());
+    }
+}
@@ -0,0 +1,574 @@
+//! Down-conversion of OpenAPI 3.x documents to the Swagger 2.0 model.
+//!
+//! Only the parts of a document that have a v2 equivalent are converted, everything else is
+//! reported as a warning and dropped.
+use crate::v2::{validation::Diagnostic, Value, DEFINITIONS_REF, RESPONSES_REF};
+
+use serde_yaml::Mapping;
+
+const SCHEMAS_REF: &str = "#/components/schemas/";
+const COMPONENT_RESPONSES_REF: &str = "#/components/responses/";
+const PARAMETERS_REF: &str = "#/components/parameters/";
+const REQUEST_BODIES_REF: &str = "#/components/requestBodies/";
+
+const METHODS: &[&str] = &["get", "put", "post", "delete", "options", "head", "patch"];
+
+/// Returns true if `doc` declares an `openapi: 3.x` version.
+pub fn is_openapi3(doc: &Value) -> bool {
+    doc.get("openapi")
+        .and_then(|version| version.as_str())
+        .map(|version| version.starts_with("3."))
+        .unwrap_or_default()
+}
+
+/// Converts an OpenAPI 3.x document into a Swagger 2.0 document that can be deserialized as
+/// [`Swagger`](crate::v2::Swagger). Features without a v2 equivalent are skipped and reported in
+/// the returned diagnostics.
+pub fn convert(doc: Value) -> (Value, Vec<Diagnostic>) {
+    let mut converter = Converter {
+        components: doc.get("components").cloned().unwrap_or(Value::Null),
+        diagnostics: vec![],
+    };
+    let doc = converter.convert_document(doc);
+    (doc, converter.diagnostics)
+}
+
+fn key(key: &str) -> Value {
+    Value::String(key.to_string())
+}
+
+struct Converter {
+    components: Value,
+    diagnostics: Vec<Diagnostic>,
+}
+
+impl Converter {
+    fn warn(&mut self, message: impl Into<String>, location: impl Into<String>) {
+        self.diagnostics
+            .push(Diagnostic::warning(message, vec![location.into()]));
+    }
+
+    fn convert_document(&mut self, doc: Value) -> Value {
+        let mut out = Mapping::new();
+        out.insert(key("swagger"), key("2.0"));
+        for field in ["info", "tags", "externalDocs"] {
+            if let Some(value) = doc.get(field) {
+                out.insert(key(field), value.clone());
+            }
+        }
+
+        if let Some(Value::Mapping(schemas)) = self.components.get("schemas").cloned() {
+            let definitions = schemas
+                .into_iter()
+                .map(|(name, schema)| (name, self.convert_schema(schema)))
+                .collect();
+            out.insert(key("definitions"), Value::Mapping(definitions));
+        }
+
+        if let Some(Value::Mapping(responses)) = self.components.get("responses").cloned() {
+            let mut converted = Mapping::new();
+            for (name, response) in responses {
+                let location = format!(
+                    "{COMPONENT_RESPONSES_REF}{}",
+                    name.as_str().unwrap_or_default()
+                );
+                let response = self.convert_response(response, &location);
+                converted.insert(name, response);
+            }
+            out.insert(key("responses"), Value::Mapping(converted));
+        }
+
+        if let Some(Value::Mapping(paths)) = doc.get("paths").cloned() {
+            let mut converted = Mapping::new();
+            for (path, item) in paths {
+                let path_str = path
+                    .as_str()
+                    .unwrap_or_default()
+                    .replace('~', "~0")
+                    .replace('/', "~1");
+                let item = self.convert_path_item(item, &path_str);
+                converted.insert(path, item);
+            }
+            out.insert(key("paths"), Value::Mapping(converted));
+        }
+
+        for unsupported in [
+            "callbacks",
+            "links",
+            "securitySchemes",
+            "examples",
+            "headers",
+        ] {
+            if self.components.get(unsupported).is_some() {
+                self.warn(
+                    format!(
+                        "components `{unsupported}` have no Swagger 2.0 equivalent and are skipped"
+                    ),
+                    format!("#/components/{unsupported}"),
+                );
+            }
+        }
+
+        Value::Mapping(out)
+    }
+
+    fn convert_path_item(&mut self, item: Value, path: &str) -> Value {
+        let Value::Mapping(item) = item else {
+            return item;
+        };
+        let mut out = Mapping::new();
+        for (name, value) in item {
+            let name_str = name.as_str().unwrap_or_default().to_string();
+            if METHODS.contains(&name_str.as_str()) {
+                let location = format!("#/paths/{path}/{name_str}");
+                let op = self.convert_operation(value, &location);
+                out.insert(name, op);
+            } else if name_str == "parameters" {
+                let location = format!("#/paths/{path}/parameters");
+                let params = self.convert_parameters(value, &location);
+                out.insert(name, params);
+            } else if name_str == "servers" || name_str == "trace" {
+                self.warn(
+                    format!(
+                        "path item field `{name_str}` has no Swagger 2.0 equivalent and is skipped"
+                    ),
+                    format!("#/paths/{path}/{name_str}"),
+                );
+            } else {
+                out.insert(name, value);
+            }
+        }
+        Value::Mapping(out)
+    }
+
+    fn convert_operation(&mut self, op: Value, location: &str) -> Value {
+        let Value::Mapping(op) = op else {
+            return op;
+        };
+        let mut out = Mapping::new();
+        let mut parameters = vec![];
+        let mut produces: Vec<String> = vec![];
+        for (name, value) in op {
+            match name.as_str().unwrap_or_default() {
+                "parameters" => {
+                    if let Value::Sequence(params) =
+                        self.convert_parameters(value, &format!("{location}/parameters"))
+                    {
+                        parameters.extend(params);
+                    }
+                }
+                "requestBody" => {
+                    let location = format!("{location}/requestBody");
+                    if let Some((param, consumes)) = self.convert_request_body(value, &location) {
+                        parameters.push(param);
+                        out.insert(
+                            key("consumes"),
+                            Value::Sequence(consumes.into_iter().map(Value::String).collect()),
+                        );
+                    }
+                }
+                "responses" => {
+                    let mut responses = Mapping::new();
+                    if let Value::Mapping(value) = value {
+                        for (code, response) in value {
+                            let code_str = match &code {
+                                Value::Number(n) => n.to_string(),
+                                code => code.as_str().unwrap_or_default().to_string(),
+                            };
+                            let location = format!("{location}/responses/{code_str}");
+                            if let Some(content) =
+                                response.get("content").and_then(|c| c.as_mapping())
+                            {
+                                for content_type in content.iter().filter_map(|(k, _)| k.as_str()) {
+                                    if !produces.iter().any(|p| p == content_type) {
+                                        produces.push(content_type.to_string());
+                                    }
+                                }
+                            }
+                            let response = self.convert_response(response, &location);
+                            responses.insert(code, response);
+                        }
+                    }
+                    out.insert(name, Value::Mapping(responses));
+                }
+                "callbacks" => self.warn(
+                    "callbacks have no Swagger 2.0 equivalent and are skipped",
+                    format!("{location}/callbacks"),
+                ),
+                "servers" => self.warn(
+                    "operation servers have no Swagger 2.0 equivalent and are skipped",
+                    format!("{location}/servers"),
+                ),
+                _ => {
+                    out.insert(name, value);
+                }
+            }
+        }
+        if !parameters.is_empty() {
+            out.insert(key("parameters"), Value::Sequence(parameters));
+        }
+        if !produces.is_empty() {
+            out.insert(
+                key("produces"),
+                Value::Sequence(produces.into_iter().map(Value::String).collect()),
+            );
+        }
+        Value::Mapping(out)
+    }
+
+    fn resolve_component(&self, value: Value, prefix: &str, kind: &str) -> Value {
+        match value.get("$ref").and_then(|r| r.as_str()) {
+            Some(ref_) if ref_.starts_with(prefix) => self
+                .components
+                .get(kind)
+                .and_then(|components| components.get(ref_.trim_start_matches(prefix)))
+                .cloned()
+                .unwrap_or(value),
+            _ => value,
+        }
+    }
+
+    fn convert_parameters(&mut self, params: Value, location: &str) -> Value {
+        let Value::Sequence(params) = params else {
+            return params;
+        };
+        let params = params
+            .into_iter()
+            .enumerate()
+            .filter_map(|(i, param)| {
+                let param = self.resolve_component(param, PARAMETERS_REF, "parameters");
+                self.convert_parameter(param, &format!("{location}/{i}"))
+            })
+            .collect();
+        Value::Sequence(params)
+    }
+
+    fn convert_parameter(&mut self, param: Value, location: &str) -> Option<Value> {
+        let Value::Mapping(mut param) = param else {
+            return Some(param);
+        };
+        if param.get(&key("in")).and_then(|in_| in_.as_str()) == Some("cookie") {
+            self.warn(
+                "cookie parameters have no Swagger 2.0 equivalent and are skipped",
+                location,
+            );
+            return None;
+        }
+        if param.remove(&key("content")).is_some() {
+            self.warn(
+                "parameter content types are not supported, falling back to a string",
+                location,
+            );
+            param.insert(key("type"), key("string"));
+        }
+        if let Some(Value::Mapping(schema)) = param.remove(&key("schema")) {
+            let Value::Mapping(schema) = self.convert_schema(Value::Mapping(schema)) else {
+                unreachable!()
+            };
+            for (name, value) in schema {
+                if name.as_str() == Some("$ref") {
+                    self.warn(
+                        "parameter schema references are not supported, falling back to a string",
+                        location,
+                    );
+                    param.insert(key("type"), key("string"));
+                } else {
+                    param.entry(name).or_insert(value);
+                }
+            }
+        }
+        param.remove(&key("style"));
+        param.remove(&key("explode"));
+        param.remove(&key("example"));
+        param.remove(&key("examples"));
+        Some(Value::Mapping(param))
+    }
+
+    fn convert_request_body(
+        &mut self,
+        body: Value,
+        location: &str,
+    ) -> Option<(Value, Vec<String>)> {
+        let body = self.resolve_component(body, REQUEST_BODIES_REF, "requestBodies");
+        let content = body.get("content").and_then(|c| c.as_mapping())?;
+        let consumes: Vec<String> = content
+            .iter()
+            .filter_map(|(k, _)| k.as_str().map(str::to_string))
+            .collect();
+        let Some(schema) = self.select_content_schema(content, location) else {
+            self.warn("request body has no JSON content and is skipped", location);
+            return None;
+        };
+
+        let mut param = Mapping::new();
+        param.insert(key("in"), key("body"));
+        param.insert(
+            key("name"),
+            body.get("x-codegen-request-body-name")
+                .cloned()
+                .unwrap_or_else(|| key("body")),
+        );
+        if let Some(description) = body.get("description") {
+            param.insert(key("description"), description.clone());
+        }
+        if let Some(required) = body.get("required") {
+            param.insert(key("required"), required.clone());
+        }
+        param.insert(key("schema"), schema);
+        Some((Value::Mapping(param), consumes))
+    }
+
+    fn convert_response(&mut self, response: Value, location: &str) -> Value {
+        let Value::Mapping(response) = response else {
+            return response;
+        };
+        let mut out = Mapping::new();
+        for (name, value) in response {
+            match name.as_str().unwrap_or_default() {
+                "$ref" => {
+                    let ref_ = value.as_str().unwrap_or_default();
+                    out.insert(name, key(&rewrite_ref(ref_)));
+                }
+                "content" => {
+                    if let Value::Mapping(content) = &value {
+                        match self.select_content_schema(content, location) {
+                            Some(schema) => {
+                                out.insert(key("schema"), schema);
+                            }
+                            None if !content.is_empty() => self.warn(
+                                "response has no JSON content and its schema is skipped",
+                                format!("{location}/content"),
+                            ),
+                            None => {}
+                        }
+                    }
+                }
+                "links" => self.warn(
+                    "response links have no Swagger 2.0 equivalent and are skipped",
+                    format!("{location}/links"),
+                ),
+                "headers" => {
+                    let headers = self.convert_headers(value);
+                    out.insert(name, headers);
+                }
+                _ => {
+                    out.insert(name, value);
+                }
+            }
+        }
+        Value::Mapping(out)
+    }
+
+    fn convert_headers(&mut self, headers: Value) -> Value {
+        let Value::Mapping(headers) = headers else {
+            return headers;
+        };
+        headers
+            .into_iter()
+            .map(|(name, header)| {
+                let mut header = header.as_mapping().cloned().unwrap_or_default();
+                if let Some(Value::Mapping(schema)) = header.remove(&key("schema")) {
+                    for (k, v) in schema {
+                        header.entry(k).or_insert(v);
+                    }
+                }
+                (name, Value::Mapping(header))
+            })
+            .collect::<Mapping>()
+            .into()
+    }
+
+    /// Picks the schema of the JSON media type out of a `content` map, warning about the media
+    /// types that get dropped. Content without a JSON media type has no schema, the models are
+    /// only ever (de)serialized as JSON.
+    fn select_content_schema(&mut self, content: &Mapping, location: &str) -> Option<Value> {
+        let media_types: Vec<&str> = content.iter().filter_map(|(k, _)| k.as_str()).collect();
+        let selected = media_types
+            .iter()
+            .find(|ty| **ty == "application/json")
+            .or_else(|| media_types.iter().find(|ty| is_json(ty)))
+            .copied()?;
+        if media_types.len() > 1 {
+            self.warn(
+                format!(
+                    "multiple content types are not supported, using `{selected}` and skipping {}",
+                    media_types
+                        .iter()
+                        .filter(|ty| **ty != selected)
+                        .map(|ty| format!("`{ty}`"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                format!("{location}/content"),
+            );
+        }
+        let schema = content.get(&key(selected))?.get("schema")?.clone();
+        Some(self.convert_schema(schema))
+    }
+
+    fn convert_schema(&mut self, schema: Value) -> Value {
+        match schema {
+            Value::Mapping(schema) => {
+                let mut out = Mapping::new();
+                for (name, value) in schema {
+                    match name.as_str().unwrap_or_default() {
+                        "$ref" => {
+                            let ref_ = value.as_str().unwrap_or_default();
+                            out.insert(name, key(&rewrite_ref(ref_)));
+                        }
+                        "nullable" => {
+                            out.insert(key("x-nullable"), value);
+                        }
+                        "example" | "default" | "enum" => {
+                            out.insert(name, value);
+                        }
+                        "properties" => {
+                            let properties = match value {
+                                Value::Mapping(properties) => properties
+                                    .into_iter()
+                                    .map(|(prop, schema)| (prop, self.convert_schema(schema)))
+                                    .collect::<Mapping>()
+                                    .into(),
+                                value => value,
+                            };
+                            out.insert(name, properties);
+                        }
+                        _ => {
+                            let value = self.convert_schema(value);
+                            out.insert(name, value);
+                        }
+                    }
+                }
+                Value::Mapping(out)
+            }
+            Value::Sequence(seq) => {
+                Value::Sequence(seq.into_iter().map(|s| self.convert_schema(s)).collect())
+            }
+            schema => schema,
+        }
+    }
+}
+
+/// Whether `media_type` is JSON, like `application/json`, `application/problem+json` or
+/// `text/json; charset=utf-8`.
+fn is_json(media_type: &str) -> bool {
+    let essence = media_type.split(';').next().unwrap_or_default().trim();
+    essence.ends_with("/json") || essence.ends_with("+json")
+}
+
+fn rewrite_ref(ref_: &str) -> String {
+    if let Some(name) = ref_.strip_prefix(SCHEMAS_REF) {
+        format!("{DEFINITIONS_REF}{name}")
+    } else if let Some(name) = ref_.strip_prefix(COMPONENT_RESPONSES_REF) {
+        format!("{RESPONSES_REF}{name}")
+    } else if let Some(name) = ref_.strip_prefix(PARAMETERS_REF) {
+        format!("#/parameters/{name}")
+    } else {
+        ref_.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{convert, is_openapi3};
+    use crate::v2::{
+        codegen::backend::{rust, test_util::generate},
+        Value,
+    };
+
+    const PETSTORE: &str = include_str!("../../tests/fixtures/petstore-v3.yaml");
+    const PETSTORE_RS: &str = include_str!("../../tests/fixtures/petstore-v3.rs");
+
+    fn convert_str(spec: &str) -> (String, Vec<String>) {
+        let doc: Value = serde_yaml::from_str(spec).unwrap();
+        assert!(is_openapi3(&doc));
+        let (doc, diagnostics) = convert(doc);
+        (
+            serde_yaml::to_string(&doc).unwrap(),
+            diagnostics.into_iter().map(|d| d.message).collect(),
+        )
+    }
+
+    #[test]
+    fn converts_components_and_bodies() {
+        let (doc, diagnostics) = convert_str(PETSTORE);
+        let doc: Value = serde_yaml::from_str(&doc).unwrap();
+        assert_eq!(doc["swagger"].as_str(), Some("2.0"));
+        assert!(doc["definitions"]["Pet"].is_mapping());
+        assert_eq!(
+            doc["definitions"]["Pets"]["items"]["$ref"].as_str(),
+            Some("#/definitions/Pet")
+        );
+        assert_eq!(
+            doc["definitions"]["NewPet"]["properties"]["tag"]["x-nullable"].as_bool(),
+            Some(true)
+        );
+
+        let post = &doc["paths"]["/pets"]["post"];
+        assert_eq!(post["parameters"][0]["in"].as_str(), Some("body"));
+        assert_eq!(
+            post["parameters"][0]["schema"]["$ref"].as_str(),
+            Some("#/definitions/NewPet")
+        );
+        let limit = &doc["paths"]["/pets"]["get"]["parameters"][0];
+        assert_eq!(limit["type"].as_str(), Some("integer"));
+        assert_eq!(limit["format"].as_str(), Some("int32"));
+        assert_eq!(
+            doc["paths"]["/pets/{petId}"]["get"]["responses"]["default"]["$ref"].as_str(),
+            Some("#/responses/UnexpectedError")
+        );
+
+        assert_eq!(diagnostics.len(), 2);
+        assert!(diagnostics[0].contains("using `application/json` and skipping `application/xml`"));
+        assert!(diagnostics[1].contains("callbacks"));
+    }
+
+    #[test]
+    fn skips_content_without_json() {
+        let (doc, diagnostics) = convert_str(
+            r##"
+openapi: 3.0.0
+paths:
+  /upload:
+    post:
+      requestBody:
+        content:
+          application/x-www-form-urlencoded:
+            schema:
+              type: object
+      responses:
+        "200":
+          description: ok
+          content:
+            text/plain:
+              schema:
+                type: string
+        "400":
+          description: bad request
+          content:
+            application/problem+json; charset=utf-8:
+              schema:
+                type: object
+"##,
+        );
+        let doc: Value = serde_yaml::from_str(&doc).unwrap();
+        let post = &doc["paths"]["/upload"]["post"];
+        assert!(post.get("parameters").is_none());
+        assert!(post["responses"]["200"].get("schema").is_none());
+        assert!(post["responses"]["400"]["schema"].is_mapping());
+        assert_eq!(
+            diagnostics,
+            [
+                "request body has no JSON content and is skipped",
+                "response has no JSON content and its schema is skipped"
+            ]
+        );
+    }
+
+    #[test]
+    fn petstore_golden() {
+        let (doc, _) = convert_str(PETSTORE);
+        let out = generate(rust::Codegen::default(), &doc);
+        assert_eq!(out, PETSTORE_RS);
+    }
+}
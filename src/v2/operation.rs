@@ -1,22 +1,37 @@
-use crate::v2::{parameter::Parameter, responses::Responses};
+use crate::v2::{is_false, parameter::Parameter, responses::Responses, tag::ExternalDocs, Value};
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct Operation {
     #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub tags: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     #[serde(rename = "operationId")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub operation_id: Option<String>,
     #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub consumes: Vec<String>,
+    #[serde(rename = "externalDocs")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_docs: Option<ExternalDocs>,
     #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub produces: Vec<String>,
     pub responses: Responses,
     #[serde(default)]
+    #[serde(skip_serializing_if = "is_false")]
     pub depracated: bool,
     #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub parameters: Vec<Parameter>,
+    /// Fields that are not used for generating code, like vendor extensions.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
@@ -1,13 +1,16 @@
-use crate::v2::{items::Item, schema::Schema};
+use crate::v2::{is_false, items::Item, schema::Schema};
 
-use serde::{de, Deserialize};
+use serde::{de, ser, Deserialize, Serialize};
 use serde_yaml::Value;
+use std::collections::HashMap;
 
 #[derive(Debug, Clone)]
+#[allow(clippy::large_enum_variant)]
 pub enum Parameter {
     Path(PathParameter),
     Query(QueryParameter),
     Body(BodyParameter),
+    FormData(FormDataParameter),
     Other(serde_yaml::Mapping),
 }
 
@@ -22,7 +25,7 @@ impl<'de> de::Deserialize<'de> for Parameter {
             Value::Mapping(map) => {
                 if let Some(in_) = map.get(&Value::String("in".into())) {
                     if !in_.is_string() {
-                        return Err(de::Error::custom(format!("invalid `in` - {in_:?}")));
+                        Err(de::Error::custom(format!("invalid `in` - {in_:?}")))
                     } else {
                         let in_ = in_.as_str().unwrap();
                         match in_ {
@@ -35,6 +38,9 @@ impl<'de> de::Deserialize<'de> for Parameter {
                             "body" => serde_yaml::from_value(Value::Mapping(map))
                                 .map(|param: BodyParameter| Parameter::Body(param))
                                 .map_err(|e| de::Error::custom(e.to_string())),
+                            "formData" => serde_yaml::from_value(Value::Mapping(map))
+                                .map(|param: FormDataParameter| Parameter::FormData(param))
+                                .map_err(|e| de::Error::custom(e.to_string())),
                             _ => Ok(Parameter::Other(map)),
                         }
                     }
@@ -50,24 +56,107 @@ impl<'de> de::Deserialize<'de> for Parameter {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+impl ser::Serialize for Parameter {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        let (in_, param) = match self {
+            Parameter::Path(param) => ("path", serde_yaml::to_value(param)),
+            Parameter::Query(param) => ("query", serde_yaml::to_value(param)),
+            Parameter::Body(param) => ("body", serde_yaml::to_value(param)),
+            Parameter::FormData(param) => ("formData", serde_yaml::to_value(param)),
+            Parameter::Other(map) => return map.serialize(serializer),
+        };
+        let mut param = param.map_err(ser::Error::custom)?;
+        if let Value::Mapping(map) = &mut param {
+            map.insert("in".into(), in_.into());
+        }
+        param.serialize(serializer)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PathParameter {
     pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     #[serde(rename = "type")]
     pub type_: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
     #[serde(default)]
+    #[serde(skip_serializing_if = "is_false")]
     pub required: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub items: Option<Item>,
+    #[serde(rename = "collectionFormat")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+    #[serde(rename = "enum")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub enum_: Vec<Value>,
+    /// A query or form parameter that can be sent with an empty value, as its name alone.
+    #[serde(rename = "allowEmptyValue")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "is_false")]
+    pub allow_empty_value: bool,
+    /// Fields that are not used for generating code, like `minimum` or vendor extensions.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+impl PathParameter {
+    pub fn is_array(&self) -> bool {
+        self.type_ == "array"
+    }
+
+    /// Describes this parameter as a schema so that it can be mapped like any other property.
+    /// Array parameters always carry their collection format, defaulting to `csv`. Enum
+    /// parameters get an enum model like inline enum properties do.
+    pub fn to_schema(&self) -> Schema {
+        Schema {
+            description: self.description.clone(),
+            type_: Some(self.type_.clone()),
+            format: self.format.clone(),
+            items: self.items.clone(),
+            collection_format: if self.is_array() {
+                Some(
+                    self.collection_format
+                        .as_deref()
+                        .unwrap_or("csv")
+                        .to_string(),
+                )
+            } else {
+                None
+            },
+            enum_: self.enum_.clone(),
+            default: self.default.clone(),
+            allow_empty_value: self.allow_empty_value,
+            ..Default::default()
+        }
+    }
 }
 
 pub type QueryParameter = PathParameter;
 
-#[derive(Debug, Clone, Deserialize)]
+/// A parameter sent in the `multipart/form-data` or `application/x-www-form-urlencoded` body of
+/// a request, `type` can be `file`.
+pub type FormDataParameter = PathParameter;
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct BodyParameter {
     pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     #[serde(default)]
+    #[serde(skip_serializing_if = "is_false")]
     pub required: bool,
     pub schema: Schema,
+    /// Fields that are not used for generating code, like vendor extensions.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
@@ -1,6 +1,6 @@
 use crate::v2::{operation::Operation, Value};
 
-use serde::{de, Deserialize};
+use serde::{de, ser, Deserialize, Serialize};
 use std::collections::HashMap;
 
 #[derive(Debug, Clone)]
@@ -9,6 +9,18 @@ pub enum Path {
     Extension(serde_yaml::Value),
 }
 
+impl ser::Serialize for Path {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        match self {
+            Path::Item(item) => item.serialize(serializer),
+            Path::Extension(value) => value.serialize(serializer),
+        }
+    }
+}
+
 impl<'de> de::Deserialize<'de> for Paths {
     fn deserialize<D>(deserializer: D) -> Result<Paths, D::Error>
     where
@@ -45,18 +57,47 @@ impl<'de> de::Deserialize<'de> for Paths {
     }
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct PathItemObject {
     #[serde(rename = "$ref")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ref_: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub get: Option<Operation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub put: Option<Operation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub post: Option<Operation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub delete: Option<Operation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub options: Option<Operation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub head: Option<Operation>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub patch: Option<Operation>,
+    /// Fields that are not used for generating code, like shared `parameters` or vendor
+    /// extensions.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
 }
 
-#[derive(Debug, Clone)]
+impl PathItemObject {
+    /// Returns all operations defined on this path together with their HTTP method.
+    pub fn operations(&self) -> impl Iterator<Item = (&'static str, &Operation)> {
+        [
+            ("get", &self.get),
+            ("put", &self.put),
+            ("post", &self.post),
+            ("delete", &self.delete),
+            ("options", &self.options),
+            ("head", &self.head),
+            ("patch", &self.patch),
+        ]
+        .into_iter()
+        .filter_map(|(method, op)| op.as_ref().map(|op| (method, op)))
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct Paths(pub HashMap<String, Path>);
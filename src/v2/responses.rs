@@ -1,18 +1,46 @@
 use crate::v2::{schema::Schema, Value};
 
-use serde::{de, Deserialize};
+use serde::{de, ser, Deserialize, Serialize};
 use std::collections::HashMap;
 
 #[derive(Debug, Clone)]
 pub enum Response {
-    Reference(String),
+    /// A response whose schema is a reference, along with the rest of the response.
+    Reference(String, Box<ResponseObject>),
     Object(Box<ResponseObject>),
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ResponseObject {
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub schema: Option<Schema>,
+    /// Example payloads of the response keyed by content type.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    pub examples: HashMap<String, Value>,
+    /// Fields that are not used for generating code, like `headers` or vendor extensions.
+    #[serde(flatten)]
+    pub extra: HashMap<String, Value>,
+}
+
+impl ResponseObject {
+    /// The example of a JSON content type, `application/json` or one ending in `+json`.
+    pub fn json_example(&self) -> Option<&Value> {
+        let mut content_types: Vec<_> = self
+            .examples
+            .keys()
+            .filter(|ty| is_json_content_type(ty))
+            .collect();
+        content_types.sort_unstable_by_key(|ty| (*ty != "application/json", *ty));
+        content_types.first().map(|ty| &self.examples[*ty])
+    }
+}
+
+/// Whether bodies of content type `ty` are JSON, `application/json` or a type ending in `+json`.
+pub fn is_json_content_type(ty: &str) -> bool {
+    ty == "application/json" || ty.ends_with("+json")
 }
 
 impl<'de> de::Deserialize<'de> for Response {
@@ -28,12 +56,15 @@ impl<'de> de::Deserialize<'de> for Response {
             Value::Mapping(map) if map.contains_key(&schema_key) => {
                 let schema = map.get(&schema_key).unwrap();
                 if schema.is_mapping() {
-                    if let Some(Value::String(ref_)) = schema.as_mapping().unwrap().get(&ref_key) {
-                        Ok(Response::Reference(ref_.to_string()))
-                    } else {
-                        serde_yaml::from_value(Value::Mapping(map))
-                            .map(|resp: ResponseObject| Response::Object(Box::new(resp)))
-                            .map_err(|e| de::Error::custom(e.to_string()))
+                    let ref_ = match schema.as_mapping().unwrap().get(&ref_key) {
+                        Some(Value::String(ref_)) => Some(ref_.to_string()),
+                        _ => None,
+                    };
+                    let resp: ResponseObject = serde_yaml::from_value(Value::Mapping(map))
+                        .map_err(|e| de::Error::custom(e.to_string()))?;
+                    match ref_ {
+                        Some(ref_) => Ok(Response::Reference(ref_, Box::new(resp))),
+                        None => Ok(Response::Object(Box::new(resp))),
                     }
                 } else {
                     Err(de::Error::custom("invalid schema, expected mapping"))
@@ -46,7 +77,18 @@ impl<'de> de::Deserialize<'de> for Response {
     }
 }
 
-#[derive(Debug, Clone)]
+impl ser::Serialize for Response {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        match self {
+            Response::Reference(_, resp) | Response::Object(resp) => resp.serialize(serializer),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
 pub struct Responses(pub HashMap<String, Response>);
 
 impl<'de> de::Deserialize<'de> for Responses {
@@ -1,38 +1,130 @@
 use crate::v2::{
+    is_false,
     items::{Item, Items},
-    Value,
+    Value, DEFINITIONS_REF,
 };
 
-use serde::Deserialize;
+use indexmap::IndexMap;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
 
-#[derive(Debug, Default, Clone, Deserialize)]
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
 pub struct Schema {
     #[serde(rename = "$ref")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub ref_: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub format: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub description: Option<String>,
     #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub required: Vec<String>,
     #[serde(rename = "type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub type_: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub items: Option<Item>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub properties: Option<Items>,
     #[serde(rename = "additionalProperties")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub additional_properties: Option<Item>,
+    /// Schemas of the values of properties whose names match a regular expression.
+    #[serde(rename = "patternProperties")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pattern_properties: Option<Items>,
     #[serde(rename = "enum")]
     #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub enum_: Vec<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+    /// An example instance of this schema.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub example: Option<Value>,
+    #[serde(rename = "readOnly")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "is_false")]
+    pub read_only: bool,
+    #[serde(rename = "writeOnly")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "is_false")]
+    pub write_only: bool,
+    #[serde(rename = "collectionFormat")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection_format: Option<String>,
+    #[serde(rename = "maxLength")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<usize>,
+    #[serde(rename = "maxProperties")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_properties: Option<usize>,
+    #[serde(rename = "minProperties")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_properties: Option<usize>,
 
     #[serde(rename = "allOf")]
     #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
     pub all_of: Vec<Schema>,
 
     // Extensions
     #[serde(rename = "x-go-name")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub x_go_name: Option<String>,
     #[serde(rename = "x-go-package")]
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub x_go_package: Option<String>,
+    #[serde(rename = "x-nullable")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "is_false")]
+    pub x_nullable: bool,
+    /// Former names of a property that are still accepted when deserializing.
+    #[serde(rename = "x-aliases")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub x_aliases: Vec<String>,
+    /// Reference to a string enum definition whose values are the keys of this map.
+    #[serde(rename = "x-key-type")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub x_key_type: Option<String>,
+    /// Accept strings like `"true"` or `"0"` and numbers for this boolean property.
+    #[serde(rename = "x-lenient-bool")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "is_false")]
+    pub x_lenient_bool: bool,
+    /// Tell an explicit `null` of this optional property apart from a missing one, like a PATCH
+    /// body clearing a field.
+    #[serde(rename = "x-explicit-null")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "is_false")]
+    pub x_explicit_null: bool,
+    /// Derives added to the model generated for this schema, like `Hash` or `Default`.
+    #[serde(rename = "x-rust-derive")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub x_rust_derive: Vec<String>,
+    /// Leave `Clone` out of the derives of the model of this schema and of every model holding
+    /// it, e.g. for models of large binary payloads.
+    #[serde(rename = "x-no-clone")]
+    #[serde(default)]
+    #[serde(skip_serializing_if = "is_false")]
+    pub x_no_clone: bool,
+    /// Definitions this schema is one of, told apart by the value of a field.
+    #[serde(rename = "x-variants")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub x_variants: Option<Variants>,
+
+    /// A non-standard `required: true/false` declared on a property schema itself rather than
+    /// in the `required` list of its parent.
+    #[serde(skip)]
+    pub self_required: Option<bool>,
+    /// Set on the schema of an `allowEmptyValue` parameter, see `PathParameter::to_schema`.
+    #[serde(skip)]
+    pub allow_empty_value: bool,
 }
 
 impl Schema {
@@ -52,24 +144,166 @@ impl Schema {
         self.is_of_type("array")
     }
 
+    /// Whether this is a `file` or a string of the `binary` format, which are raw bytes.
+    pub fn is_binary(&self) -> bool {
+        self.is_of_type("file")
+            || self.is_of_type("string") && self.format.as_deref() == Some("binary")
+    }
+
     pub fn is_string_enum(&self) -> bool {
-        self.is_of_type("string") && !self.enum_.is_empty()
+        self.is_of_type("string") && !self.enum_.is_empty() && !self.is_mixed_enum()
+    }
+
+    /// The kinds of the values of `enum`, without duplicates and ignoring `null`.
+    pub fn enum_kinds(&self) -> Vec<EnumKind> {
+        let mut kinds: Vec<_> = self
+            .enum_
+            .iter()
+            .filter_map(|value| match value {
+                Value::Number(n) if n.is_f64() => Some(EnumKind::Number),
+                Value::Number(_) => Some(EnumKind::Integer),
+                Value::String(_) => Some(EnumKind::String),
+                Value::Bool(_) => Some(EnumKind::Boolean),
+                _ => None,
+            })
+            .collect();
+        kinds.sort_unstable();
+        kinds.dedup();
+        kinds
+    }
+
+    /// Whether `enum` has values of more than one kind, like `[1, "auto", true]`.
+    pub fn is_mixed_enum(&self) -> bool {
+        self.enum_kinds().len() > 1
+    }
+
+    /// Whether the property `name` of this schema is required, either by being listed in
+    /// `required` or by declaring `required: true` itself.
+    pub fn is_required(&self, name: &str, prop: &Item) -> bool {
+        self.required.iter().any(|required| required == name)
+            || matches!(prop, Item::Object(schema) if schema.self_required == Some(true))
+    }
+
+    /// The values of this schema if it is a map, either `additionalProperties` or the values of
+    /// `patternProperties` when there are no plain properties. Patterns with different values
+    /// make a map of untyped objects.
+    pub fn map_values(&self) -> Option<Item> {
+        if let Some(values) = &self.additional_properties {
+            return Some(values.clone());
+        }
+        if self.properties.is_some() {
+            return None;
+        }
+        let mut patterns = self
+            .pattern_properties
+            .iter()
+            .flat_map(|props| props.0.values());
+        let values = patterns.next()?;
+        if patterns.all(|other| other == values) {
+            Some(values.clone())
+        } else {
+            Some(Item::Object(Arc::new(Schema {
+                type_: Some("object".into()),
+                ..Default::default()
+            })))
+        }
+    }
+
+    /// Whether this is an object listing `required` keys without declaring any `properties`.
+    /// The Rust backend generates it as a model of the required keys holding any value that
+    /// keeps the other keys as well, instead of an untyped object.
+    pub fn is_open_object(&self) -> bool {
+        self.is_object()
+            && self.properties.is_none()
+            && !self.required.is_empty()
+            && self.map_values().is_none()
+            && self.x_variants.is_none()
+    }
+
+    /// Whether this is an object that can't have any keys, with `maxProperties: 0` or empty
+    /// `properties` and no values of other keys. It is generated as a unit struct serialized
+    /// as `{}` instead of an untyped object.
+    pub fn is_empty_object(&self) -> bool {
+        if !self.is_object() || self.x_variants.is_some() || !self.all_of.is_empty() {
+            return false;
+        }
+        self.max_properties == Some(0)
+            || (self
+                .properties
+                .as_ref()
+                .is_some_and(|props| props.0.is_empty())
+                && self.required.is_empty()
+                && self.map_values().is_none())
+    }
+
+    /// The required keys of an open object as properties of any value, see `is_open_object`.
+    pub fn open_object_properties(&self) -> Items {
+        Items(
+            self.required
+                .iter()
+                .map(|key| (key.clone(), Item::Object(Arc::new(Schema::default()))))
+                .collect(),
+        )
+    }
+
+    /// Patterns the keys of this schema match if it is a map of `patternProperties`.
+    pub fn key_patterns(&self) -> Vec<&str> {
+        if self.properties.is_some() || self.additional_properties.is_some() {
+            return vec![];
+        }
+        self.pattern_properties
+            .iter()
+            .flat_map(|props| props.0.keys())
+            .map(String::as_str)
+            .collect()
     }
 
     pub fn name(&self) -> Option<String> {
         if let Some(title) = &self.x_go_name {
             Some(title.to_string())
-        } else if let Some(title) = &self.title {
-            Some(title.to_string())
         } else {
-            None
+            self.title.as_ref().map(|title| title.to_string())
         }
     }
 }
 
+/// The kind of a value of an `enum`. Kinds are ordered so that integers are tried before other
+/// numbers when deserializing a value of a mixed enum.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum EnumKind {
+    Integer,
+    Number,
+    String,
+    Boolean,
+}
+
+/// The `x-variants` extension of a schema that is one of several definitions. The value of
+/// `field` in a payload is a key of `mapping` which names the definition of the payload.
+#[derive(Debug, Default, Clone, PartialEq, Deserialize, Serialize)]
+pub struct Variants {
+    pub field: String,
+    pub mapping: IndexMap<String, String>,
+}
+
+impl Variants {
+    /// The tags of the mapping with references to their definitions. Targets can be given as a
+    /// definition name or a full reference.
+    pub fn refs(&self) -> impl Iterator<Item = (&str, String)> {
+        self.mapping.iter().map(|(tag, target)| {
+            let ref_ = if target.starts_with("#/") {
+                target.clone()
+            } else {
+                format!("{DEFINITIONS_REF}{target}")
+            };
+            (tag.as_str(), ref_)
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::Schema;
+    use super::{EnumKind, Item, Schema};
+    use crate::v2::builder::SchemaBuilder;
 
     #[test]
     fn schema_types() {
@@ -77,17 +311,122 @@ mod test {
         assert_eq!(s.type_(), None);
         assert!(!s.is_array());
         assert!(!s.is_object());
-        let s = Schema {
-            type_: Some("array".into()),
-            ..Default::default()
-        };
+        let s = SchemaBuilder::array(SchemaBuilder::string()).build();
         assert!(s.is_array());
         assert!(!s.is_object());
+        let s = SchemaBuilder::object().build();
+        assert!(!s.is_array());
+        assert!(s.is_object());
+        assert!(SchemaBuilder::string_enum(["a"]).build().is_string_enum());
+        assert!(!SchemaBuilder::string().build().is_string_enum());
+    }
+
+    #[test]
+    fn enum_kinds() {
+        let s: Schema = serde_yaml::from_str("enum: [true, auto, 1, 0.5, 2, null]").unwrap();
+        assert_eq!(
+            s.enum_kinds(),
+            [
+                EnumKind::Integer,
+                EnumKind::Number,
+                EnumKind::String,
+                EnumKind::Boolean
+            ]
+        );
+        assert!(s.is_mixed_enum());
+
+        let s: Schema = serde_yaml::from_str("{type: string, enum: [a, b, null]}").unwrap();
+        assert!(!s.is_mixed_enum());
+        assert!(s.is_string_enum());
+        let s: Schema = serde_yaml::from_str("{type: string, enum: [a, 1]}").unwrap();
+        assert!(s.is_mixed_enum());
+        assert!(!s.is_string_enum());
+    }
+
+    #[test]
+    fn required_properties() {
+        let s = SchemaBuilder::object()
+            .prop("Id", SchemaBuilder::string().required())
+            .prop("Name", SchemaBuilder::string())
+            .build();
+        let props = &s.properties.as_ref().unwrap().0;
+        assert!(s.is_required("Id", &props["Id"]));
+        assert!(!s.is_required("Name", &props["Name"]));
+    }
+
+    #[test]
+    fn empty_objects() {
+        let empty = |yaml: &str| {
+            serde_yaml::from_str::<Schema>(yaml)
+                .unwrap()
+                .is_empty_object()
+        };
+        assert!(empty("{type: object, properties: {}}"));
+        assert!(empty("{type: object, maxProperties: 0}"));
+        assert!(empty(
+            "{type: object, maxProperties: 0, additionalProperties: {type: string}}"
+        ));
+        assert!(!empty("{type: object}"));
+        assert!(!empty("{type: object, maxProperties: 2}"));
+        assert!(!empty("{properties: {}}"));
+        assert!(!empty("{type: object, properties: {}, required: [id]}"));
+        assert!(!empty(
+            "{type: object, properties: {}, additionalProperties: {type: string}}"
+        ));
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(SchemaBuilder::object().build().name(), None);
+        let s = SchemaBuilder::object().title("Mount").build();
+        assert_eq!(s.name().as_deref(), Some("Mount"));
         let s = Schema {
-            type_: Some("object".into()),
+            x_go_name: Some("MountPoint".into()),
+            ..s
+        };
+        assert_eq!(s.name().as_deref(), Some("MountPoint"));
+    }
+
+    #[test]
+    fn pattern_properties() {
+        let single: Schema = serde_yaml::from_str(
+            r#"
+type: object
+patternProperties:
+  "^\\d+/(tcp|udp)$":
+    type: object
+"#,
+        )
+        .unwrap();
+        let Some(Item::Object(values)) = single.map_values() else {
+            panic!("expected inline values");
+        };
+        assert!(values.is_object());
+        assert_eq!(single.key_patterns(), [r"^\d+/(tcp|udp)$"]);
+
+        let multi: Schema = serde_yaml::from_str(
+            r#"
+type: object
+patternProperties:
+  "^x-":
+    type: string
+  "^[a-z]+$":
+    type: integer
+"#,
+        )
+        .unwrap();
+        let Some(Item::Object(values)) = multi.map_values() else {
+            panic!("expected inline values");
+        };
+        assert!(values.is_object() && values.properties.is_none());
+        assert_eq!(multi.key_patterns(), ["^x-", "^[a-z]+$"]);
+
+        let with_props = Schema {
+            properties: Some(Default::default()),
+            pattern_properties: multi.pattern_properties.clone(),
             ..Default::default()
         };
-        assert!(!s.is_array());
-        assert!(s.is_object());
+        assert!(with_props.map_values().is_none());
+        assert!(with_props.key_patterns().is_empty());
     }
 }
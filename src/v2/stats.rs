@@ -0,0 +1,160 @@
+use crate::v2::{codegen::Prototyper, Item, Schema, Swagger, Type};
+
+use serde::Serialize;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt,
+};
+
+/// How many definitions the largest schemas are listed for.
+const LARGEST_SCHEMAS: usize = 10;
+
+/// Counts and size metrics of a spec, to estimate the size of the generated code and spot
+/// unusual parts of the spec before generating anything.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Stats {
+    pub definitions: usize,
+    /// Number of operations by HTTP method.
+    pub operations: BTreeMap<String, usize>,
+    /// Number of definitions by how deeply their inline schemas are nested. A schema without
+    /// inline objects or arrays has a depth of 1, references don't add to it.
+    pub depths: BTreeMap<usize, usize>,
+    /// Number of models generated for inline schemas that have no definition of their own.
+    pub inline_schemas: usize,
+    /// Number of distinct references used by every definition.
+    pub refs: BTreeMap<String, usize>,
+    /// The definitions with the most properties, `allOf` members included.
+    pub largest: Vec<SchemaSize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct SchemaSize {
+    pub name: String,
+    pub properties: usize,
+}
+
+impl<T: Type> Swagger<T> {
+    pub fn stats(&self) -> Stats {
+        let mut operations = BTreeMap::new();
+        for (_, method, _) in self.operations() {
+            *operations.entry(method.to_string()).or_default() += 1;
+        }
+
+        let inline_schemas = Prototyper::new()
+            .generate_prototypes(self)
+            .iter()
+            .filter(|prototype| prototype.parent_name.is_some())
+            .count();
+
+        let mut depths = BTreeMap::new();
+        let mut refs = BTreeMap::new();
+        let mut largest = vec![];
+        for (name, schema) in self.definitions.iter().flat_map(|defs| &defs.0) {
+            *depths.entry(depth(schema)).or_default() += 1;
+            let mut found = BTreeSet::new();
+            collect_refs(schema, &mut found);
+            refs.insert(name.clone(), found.len());
+            let properties = self
+                .merge_all_of_schema(schema)
+                .properties
+                .as_ref()
+                .map_or(0, |props| props.0.len());
+            largest.push(SchemaSize {
+                name: name.clone(),
+                properties,
+            });
+        }
+        largest.sort_unstable_by(|a, b| {
+            b.properties
+                .cmp(&a.properties)
+                .then_with(|| a.name.cmp(&b.name))
+        });
+        largest.retain(|size| size.properties > 0);
+        largest.truncate(LARGEST_SCHEMAS);
+
+        Stats {
+            definitions: refs.len(),
+            operations,
+            depths,
+            inline_schemas,
+            refs,
+            largest,
+        }
+    }
+}
+
+fn depth(schema: &Schema) -> usize {
+    let members = schema.all_of.iter().map(depth).max().unwrap_or(1);
+    let values = schema.map_values();
+    let nested = schema
+        .properties
+        .iter()
+        .flat_map(|props| props.0.values())
+        .chain(&schema.items)
+        .chain(&values)
+        .filter_map(|item| match item {
+            Item::Object(schema)
+                if schema.is_object() || schema.is_array() || schema.properties.is_some() =>
+            {
+                Some(depth(schema) + 1)
+            }
+            _ => None,
+        })
+        .max()
+        .unwrap_or(1);
+    members.max(nested)
+}
+
+fn collect_refs(schema: &Schema, found: &mut BTreeSet<String>) {
+    if let Some(ref_) = &schema.ref_ {
+        found.insert(ref_.clone());
+    }
+    for member in &schema.all_of {
+        collect_refs(member, found);
+    }
+    let values = schema.map_values();
+    let items = schema
+        .properties
+        .iter()
+        .flat_map(|props| props.0.values())
+        .chain(&schema.items)
+        .chain(&values);
+    for item in items {
+        match item {
+            Item::Reference(ref_) => {
+                found.insert(ref_.clone());
+            }
+            Item::Object(schema) => collect_refs(schema, found),
+        }
+    }
+}
+
+impl fmt::Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "definitions: {}", self.definitions)?;
+        writeln!(f, "operations: {}", self.operations.values().sum::<usize>())?;
+        for (method, count) in &self.operations {
+            writeln!(f, "  {method}: {count}")?;
+        }
+        writeln!(f, "schema depth:")?;
+        for (depth, count) in &self.depths {
+            writeln!(f, "  {depth}: {count}")?;
+        }
+        writeln!(f, "inline schemas: {}", self.inline_schemas)?;
+        if let Some((name, max)) = self.refs.iter().rev().max_by_key(|(_, count)| **count) {
+            let average = self.refs.values().sum::<usize>() as f64 / self.refs.len() as f64;
+            write!(f, "refs per definition: {average:.2} on average")?;
+            if *max > 0 {
+                write!(f, ", at most {max} (`{name}`)")?;
+            }
+            writeln!(f)?;
+        }
+        if !self.largest.is_empty() {
+            writeln!(f, "largest schemas:")?;
+            for size in &self.largest {
+                writeln!(f, "  {}: {} properties", size.name, size.properties)?;
+            }
+        }
+        Ok(())
+    }
+}
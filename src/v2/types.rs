@@ -1,4 +1,4 @@
-use crate::v2::{schema::Schema, Item, Swagger, DEFINITIONS_REF, RESPONSES_REF};
+use crate::v2::{schema::Schema, Item, Swagger};
 use log::{debug, trace};
 
 pub trait Type: std::fmt::Display + Sized {
@@ -35,11 +35,9 @@ pub trait Type: std::fmt::Display + Sized {
         swagger: &Swagger<Self>,
     ) -> Option<Self> {
         debug!("mapping reference `{ref_}`, required: {is_required}, parent: {parent_name:?}");
-        let schema = swagger.get_ref_schema(ref_)?;
+        let schema = swagger.merge_all_of_schema(swagger.get_ref_schema(ref_)?);
         trace!("got schema {schema:?}");
-        let ref_ = ref_
-            .trim_start_matches(RESPONSES_REF)
-            .trim_start_matches(DEFINITIONS_REF);
-        Self::map_schema_type(schema, Some(ref_), is_required, parent_name, swagger)
+        let name = swagger.type_names().get(ref_);
+        Self::map_schema_type(&schema, Some(name), is_required, parent_name, swagger)
     }
 }
@@ -0,0 +1,910 @@
+use crate::v2::{
+    parameter::Parameter, path::Path, responses::is_json_content_type, Item, Response, Schema,
+    Swagger, Type, DEFINITIONS_REF, RESPONSES_REF,
+};
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Info => write!(f, "info"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Error => write!(f, "error"),
+        }
+    }
+}
+
+/// A problem found in a spec, pointing at every location involved in it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    pub locations: Vec<String>,
+}
+
+impl Diagnostic {
+    pub fn info(message: impl Into<String>, locations: Vec<String>) -> Self {
+        Self {
+            severity: Severity::Info,
+            message: message.into(),
+            locations,
+        }
+    }
+
+    pub fn warning(message: impl Into<String>, locations: Vec<String>) -> Self {
+        Self {
+            severity: Severity::Warning,
+            message: message.into(),
+            locations,
+        }
+    }
+
+    pub fn error(message: impl Into<String>, locations: Vec<String>) -> Self {
+        Self {
+            severity: Severity::Error,
+            message: message.into(),
+            locations,
+        }
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.severity, self.message)?;
+        for location in &self.locations {
+            write!(f, "\n  --> {location}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Type> Swagger<T> {
+    /// Checks the spec for problems that would silently affect the generated code.
+    pub fn validate(&self) -> Vec<Diagnostic> {
+        let mut diagnostics = vec![];
+        self.validate_definition_keys(&mut diagnostics);
+        self.validate_definition_titles(&mut diagnostics);
+        self.validate_type_names(&mut diagnostics);
+        self.validate_property_required(&mut diagnostics);
+        self.validate_property_aliases(&mut diagnostics);
+        self.validate_references(&mut diagnostics);
+        self.validate_required_properties(&mut diagnostics);
+        self.validate_pattern_properties(&mut diagnostics);
+        self.validate_response_content_types(&mut diagnostics);
+        self.validate_definition_types(&mut diagnostics);
+        self.validate_variants(&mut diagnostics);
+        self.validate_files(&mut diagnostics);
+        diagnostics
+    }
+
+    fn validate_definition_keys(&self, diagnostics: &mut Vec<Diagnostic>) {
+        let Some(definitions) = &self.definitions else {
+            return;
+        };
+        for duplicate in definitions.duplicates() {
+            diagnostics.push(Diagnostic::warning(
+                format!(
+                    "definition `{}` is declared more than once, only the last declaration is used",
+                    duplicate.key
+                ),
+                vec![
+                    format!(
+                        "{DEFINITIONS_REF}{} (entry {})",
+                        duplicate.key,
+                        duplicate.first + 1
+                    ),
+                    format!(
+                        "{DEFINITIONS_REF}{} (entry {})",
+                        duplicate.key,
+                        duplicate.second + 1
+                    ),
+                ],
+            ));
+        }
+    }
+
+    fn validate_definition_titles(&self, diagnostics: &mut Vec<Diagnostic>) {
+        let Some(definitions) = &self.definitions else {
+            return;
+        };
+        let mut titles: BTreeMap<String, Vec<&str>> = BTreeMap::new();
+        for (key, schema) in &definitions.0 {
+            if let Some(title) = schema.name() {
+                titles.entry(T::format_name(&title)).or_default().push(key);
+            }
+        }
+        for (title, mut keys) in titles {
+            if keys.len() < 2 {
+                continue;
+            }
+            keys.sort_unstable();
+            diagnostics.push(Diagnostic::warning(
+                format!("definitions share the title `{title}` and may generate the same name"),
+                keys.into_iter()
+                    .map(|key| format!("{DEFINITIONS_REF}{key}"))
+                    .collect(),
+            ));
+        }
+    }
+
+    /// Calls `f` with every schema of the spec, including nested ones, together with its
+    /// location.
+    pub(crate) fn visit_schemas(&self, mut f: impl FnMut(&Schema, &str)) {
+        // a worklist instead of recursion, so deeply nested schemas can't overflow the stack
+        fn visit(schema: &Schema, location: &str, f: &mut impl FnMut(&Schema, &str)) {
+            let mut stack = vec![(schema, location.to_string())];
+            while let Some((schema, location)) = stack.pop() {
+                f(schema, &location);
+                let mut children = vec![];
+                for (i, member) in schema.all_of.iter().enumerate() {
+                    children.push((member, format!("{location}/allOf/{i}")));
+                }
+                if let Some(Item::Object(items)) = &schema.items {
+                    children.push((&**items, format!("{location}/items")));
+                }
+                if let Some(Item::Object(values)) = &schema.additional_properties {
+                    children.push((&**values, format!("{location}/additionalProperties")));
+                }
+                for (pattern, values) in schema.pattern_properties.iter().flat_map(|props| &props.0)
+                {
+                    if let Item::Object(values) = values {
+                        children
+                            .push((&**values, format!("{location}/patternProperties/{pattern}")));
+                    }
+                }
+                for (name, prop) in schema.properties.iter().flat_map(|props| &props.0) {
+                    if let Item::Object(prop) = prop {
+                        children.push((&**prop, format!("{location}/properties/{name}")));
+                    }
+                }
+                // visited in the order they were found
+                stack.extend(children.into_iter().rev());
+            }
+        }
+
+        if let Some(definitions) = &self.definitions {
+            for (key, schema) in &definitions.0 {
+                visit(schema, &format!("{DEFINITIONS_REF}{key}"), &mut f);
+            }
+        }
+        if let Some(responses) = &self.responses {
+            for (key, response) in &responses.0 {
+                if let Response::Object(response) = response {
+                    if let Some(schema) = &response.schema {
+                        visit(schema, &format!("{RESPONSES_REF}{key}/schema"), &mut f);
+                    }
+                }
+            }
+        }
+        for (path, item) in self.paths.iter().flat_map(|paths| &paths.0) {
+            let Path::Item(item) = item else {
+                continue;
+            };
+            // escaped as a JSON pointer segment
+            let path = path.replace('~', "~0").replace('/', "~1");
+            for (method, op) in item.operations() {
+                let location = format!("#/paths/{path}/{method}");
+                for (code, response) in &op.responses.0 {
+                    if let Response::Object(response) = response {
+                        if let Some(schema) = &response.schema {
+                            visit(
+                                schema,
+                                &format!("{location}/responses/{code}/schema"),
+                                &mut f,
+                            );
+                        }
+                    }
+                }
+                for (i, param) in op.parameters.iter().enumerate() {
+                    if let Parameter::Body(param) = param {
+                        visit(
+                            &param.schema,
+                            &format!("{location}/parameters/{i}/schema"),
+                            &mut f,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn validate_property_required(&self, diagnostics: &mut Vec<Diagnostic>) {
+        let mut locations = vec![];
+        self.visit_schemas(|schema, location| {
+            if schema.self_required.is_some() {
+                locations.push(location.to_string());
+            }
+        });
+        if !locations.is_empty() {
+            locations.sort_unstable();
+            diagnostics.push(Diagnostic::warning(
+                "properties declare `required` as a boolean instead of being listed in the `required` array of their parent",
+                locations,
+            ));
+        }
+    }
+
+    fn validate_property_aliases(&self, diagnostics: &mut Vec<Diagnostic>) {
+        let mut found = vec![];
+        self.visit_schemas(|schema, location| {
+            let Some(props) = &schema.properties else {
+                return;
+            };
+            // wire name -> property it belongs to
+            let mut owners: HashMap<&str, &str> =
+                props.0.keys().map(|name| (name.as_str(), name.as_str())).collect();
+            for (name, prop) in &props.0 {
+                let Item::Object(prop) = prop else {
+                    continue;
+                };
+                for alias in &prop.x_aliases {
+                    match owners.get(alias.as_str()) {
+                        Some(owner) if owner != name => found.push(Diagnostic::warning(
+                            format!("alias `{alias}` of property `{name}` is also a name of property `{owner}`"),
+                            vec![
+                                format!("{location}/properties/{name}"),
+                                format!("{location}/properties/{owner}"),
+                            ],
+                        )),
+                        Some(_) => {}
+                        None => {
+                            owners.insert(alias, name);
+                        }
+                    }
+                }
+            }
+        });
+        found.sort_unstable_by(|a, b| a.locations.cmp(&b.locations));
+        diagnostics.extend(found);
+    }
+
+    fn validate_references(&self, diagnostics: &mut Vec<Diagnostic>) {
+        let resolves = |ref_: &str| {
+            if let Some(key) = ref_.strip_prefix(DEFINITIONS_REF) {
+                self.definitions
+                    .as_ref()
+                    .is_some_and(|definitions| definitions.0.contains_key(key))
+            } else if let Some(key) = ref_.strip_prefix(RESPONSES_REF) {
+                self.responses
+                    .as_ref()
+                    .is_some_and(|responses| responses.0.contains_key(key))
+            } else {
+                false
+            }
+        };
+        let mut dangling: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        let mut check = |ref_: &str, location: String| {
+            if !resolves(ref_) {
+                dangling.entry(ref_.to_string()).or_default().push(location);
+            }
+        };
+        self.visit_schemas(|schema, location| {
+            if let Some(ref_) = &schema.ref_ {
+                check(ref_, location.to_string());
+            }
+            if let Some(Item::Reference(ref_)) = &schema.items {
+                check(ref_, format!("{location}/items"));
+            }
+            if let Some(Item::Reference(ref_)) = &schema.additional_properties {
+                check(ref_, format!("{location}/additionalProperties"));
+            }
+            for (pattern, values) in schema.pattern_properties.iter().flat_map(|props| &props.0) {
+                if let Item::Reference(ref_) = values {
+                    check(ref_, format!("{location}/patternProperties/{pattern}"));
+                }
+            }
+            for (name, prop) in schema.properties.iter().flat_map(|props| &props.0) {
+                if let Item::Reference(ref_) = prop {
+                    check(ref_, format!("{location}/properties/{name}"));
+                }
+            }
+        });
+        let responses = self.responses.iter().flat_map(|responses| {
+            responses
+                .0
+                .iter()
+                .map(|(key, response)| (format!("{RESPONSES_REF}{key}"), response))
+        });
+        for (location, response) in responses {
+            if let Response::Reference(ref_, _) = response {
+                check(ref_, format!("{location}/schema"));
+            }
+        }
+        for (path, method, op) in self.operations() {
+            let path = path.replace('~', "~0").replace('/', "~1");
+            for (code, response) in &op.responses.0 {
+                if let Response::Reference(ref_, _) = response {
+                    check(
+                        ref_,
+                        format!("#/paths/{path}/{method}/responses/{code}/schema"),
+                    );
+                }
+            }
+        }
+
+        for (ref_, mut locations) in dangling {
+            locations.sort_unstable();
+            diagnostics.push(Diagnostic::error(
+                format!("reference `{ref_}` doesn't point to a definition or response of the spec"),
+                locations,
+            ));
+        }
+    }
+
+    fn validate_required_properties(&self, diagnostics: &mut Vec<Diagnostic>) {
+        let mut found = vec![];
+        self.visit_schemas(|schema, location| {
+            // members are checked merged with the rest of their `allOf`
+            if location.rsplit('/').nth(1) == Some("allOf") || schema.required.is_empty() {
+                return;
+            }
+            let merged = self.merge_all_of_schema(schema);
+            // the required keys of an open object are all it declares
+            if merged.is_open_object() {
+                return;
+            }
+            let props = merged.properties.as_ref();
+            for required in &merged.required {
+                if !props.is_some_and(|props| props.0.contains_key(required)) {
+                    found.push(Diagnostic::warning(
+                        format!("required property `{required}` is not declared"),
+                        vec![location.to_string()],
+                    ));
+                }
+            }
+        });
+        found.sort_unstable_by(|a, b| a.locations.cmp(&b.locations));
+        diagnostics.extend(found);
+    }
+
+    fn validate_pattern_properties(&self, diagnostics: &mut Vec<Diagnostic>) {
+        let mut found = vec![];
+        self.visit_schemas(|schema, location| {
+            if schema.key_patterns().len() < 2 {
+                return;
+            }
+            let mut values = schema
+                .pattern_properties
+                .iter()
+                .flat_map(|props| props.0.values());
+            let Some(first) = values.next() else {
+                return;
+            };
+            if values.any(|other| other != first) {
+                found.push(Diagnostic::warning(
+                    "patternProperties have different value schemas, values are generated as untyped JSON",
+                    vec![location.to_string()],
+                ));
+            }
+        });
+        found.sort_unstable_by(|a, b| a.locations.cmp(&b.locations));
+        diagnostics.extend(found);
+    }
+
+    fn validate_response_content_types(&self, diagnostics: &mut Vec<Diagnostic>) {
+        for (path, method, op) in self.operations() {
+            let produces = self.produces_of(op);
+            if produces.is_empty()
+                || produces
+                    .iter()
+                    .any(|ty| ty == "*/*" || is_json_content_type(ty))
+            {
+                continue;
+            }
+            let path = path.replace('~', "~0").replace('/', "~1");
+            for (code, response) in &op.responses.0 {
+                let schema = match response {
+                    Response::Reference(ref_, _) => self.get_ref_schema(ref_),
+                    Response::Object(response) => response.schema.as_ref(),
+                };
+                let Some(schema) = schema.map(|schema| self.merge_all_of_schema(schema)) else {
+                    continue;
+                };
+                if schema.is_object()
+                    || schema.is_array()
+                    || schema.properties.is_some()
+                    || schema.x_variants.is_some()
+                {
+                    diagnostics.push(Diagnostic::warning(
+                        format!(
+                            "response `{code}` has a JSON schema but the operation only produces {}",
+                            produces.join(", ")
+                        ),
+                        vec![format!("#/paths/{path}/{method}/responses/{code}")],
+                    ));
+                }
+            }
+        }
+    }
+
+    fn validate_definition_types(&self, diagnostics: &mut Vec<Diagnostic>) {
+        let Some(definitions) = &self.definitions else {
+            return;
+        };
+        let mut keys: Vec<_> = definitions.0.keys().collect();
+        keys.sort_unstable();
+        for key in keys {
+            let schema = self.merge_all_of_schema(&definitions.0[key]);
+            if schema.ref_.is_some()
+                || schema.properties.is_some()
+                || schema.is_string_enum()
+                || schema.is_mixed_enum()
+                || schema.x_variants.is_some()
+            {
+                continue;
+            }
+            if self
+                .map_schema_type(&schema, None, true, Some(key))
+                .is_none()
+            {
+                diagnostics.push(Diagnostic::warning(
+                    format!("no type can be generated for definition `{key}`, it is skipped"),
+                    vec![format!("{DEFINITIONS_REF}{key}")],
+                ));
+            }
+        }
+    }
+
+    fn validate_variants(&self, diagnostics: &mut Vec<Diagnostic>) {
+        let Some(definitions) = &self.definitions else {
+            return;
+        };
+        let mut keys: Vec<_> = definitions.0.keys().collect();
+        keys.sort_unstable();
+        for key in keys {
+            let Some(variants) = &definitions.0[key].x_variants else {
+                continue;
+            };
+            for (tag, ref_) in variants.refs() {
+                let location = format!("{DEFINITIONS_REF}{key}/x-variants/mapping/{tag}");
+                let Some(target) = self.get_ref_schema(&ref_) else {
+                    diagnostics.push(Diagnostic::error(
+                        format!("variant `{tag}` maps to `{ref_}` which is not a definition, it is skipped"),
+                        vec![location],
+                    ));
+                    continue;
+                };
+                // the tag is taken out of the payload before the variant is deserialized
+                let target = self.merge_all_of_schema(target);
+                if target.required.contains(&variants.field) {
+                    diagnostics.push(Diagnostic::warning(
+                        format!(
+                            "variant `{tag}` requires the tag field `{}`, which it never receives when deserialized",
+                            variants.field
+                        ),
+                        vec![location, ref_],
+                    ));
+                }
+            }
+        }
+    }
+
+    /// `file` parameters and schemas are generated as raw bytes.
+    fn validate_files(&self, diagnostics: &mut Vec<Diagnostic>) {
+        let mut locations = vec![];
+        self.visit_schemas(|schema, location| {
+            if schema.is_of_type("file") {
+                locations.push(location.to_string());
+            }
+        });
+        for (path, method, op) in self.operations() {
+            let path = path.replace('~', "~0").replace('/', "~1");
+            for (i, param) in op.parameters.iter().enumerate() {
+                if matches!(param, Parameter::FormData(param) if param.type_ == "file") {
+                    locations.push(format!("#/paths/{path}/{method}/parameters/{i}"));
+                }
+            }
+        }
+        if locations.is_empty() {
+            return;
+        }
+        locations.sort_unstable();
+        diagnostics.push(Diagnostic::warning(
+            "files are generated as raw bytes, their file names and content types aren't kept",
+            locations,
+        ));
+    }
+
+    fn validate_type_names(&self, diagnostics: &mut Vec<Diagnostic>) {
+        for collision in self.type_names().collisions() {
+            diagnostics.push(Diagnostic::warning(
+                format!(
+                    "definitions `{}` and `{}` both generate the type `{}`, the latter is generated as `{}`",
+                    collision.kept,
+                    collision.renamed,
+                    collision.type_name,
+                    T::format_name(&collision.new_name)
+                ),
+                vec![
+                    format!("{DEFINITIONS_REF}{}", collision.kept),
+                    format!("{DEFINITIONS_REF}{}", collision.renamed),
+                ],
+            ));
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Severity;
+    use crate::v2::{codegen::backend::rust, Swagger};
+
+    #[test]
+    fn duplicate_definition_key() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(
+            r#"
+swagger: "2.0"
+definitions:
+  Foo:
+    type: string
+  Bar:
+    type: integer
+  Foo:
+    type: boolean
+"#,
+        )
+        .unwrap();
+        let definitions = swagger.definitions.as_ref().unwrap();
+        assert_eq!(definitions.get("Foo").unwrap().type_(), Some("boolean"));
+
+        let diagnostics = swagger.validate();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert_eq!(
+            diagnostics[0].locations,
+            vec![
+                "#/definitions/Foo (entry 1)".to_string(),
+                "#/definitions/Foo (entry 3)".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn conflicting_titles() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(
+            r#"
+swagger: "2.0"
+definitions:
+  ContainerConfig:
+    type: object
+    title: Config
+  ImageConfig:
+    type: object
+    title: config
+  Other:
+    type: object
+    title: Other
+"#,
+        )
+        .unwrap();
+        let diagnostics = swagger.validate();
+        assert_eq!(diagnostics.len(), 1);
+        assert!(diagnostics[0].message.contains("`Config`"));
+        assert_eq!(
+            diagnostics[0].locations,
+            vec![
+                "#/definitions/ContainerConfig".to_string(),
+                "#/definitions/ImageConfig".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn property_required_flag() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(
+            r#"
+swagger: "2.0"
+definitions:
+  Listed:
+    type: object
+    required: [Id]
+    properties:
+      Id:
+        type: string
+  Flagged:
+    type: object
+    properties:
+      Id:
+        type: string
+        required: true
+      Nested:
+        type: object
+        properties:
+          Name:
+            type: string
+            required: false
+"#,
+        )
+        .unwrap();
+        let diagnostics = swagger.validate();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert_eq!(
+            diagnostics[0].locations,
+            vec![
+                "#/definitions/Flagged/properties/Id".to_string(),
+                "#/definitions/Flagged/properties/Nested/properties/Name".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn alias_collisions() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(
+            r#"
+swagger: "2.0"
+definitions:
+  Container:
+    type: object
+    properties:
+      Image:
+        type: string
+        x-aliases: [ImageName, Name]
+      Name:
+        type: string
+      Tag:
+        type: string
+        x-aliases: [ImageName, Version]
+"#,
+        )
+        .unwrap();
+        let diagnostics = swagger.validate();
+        let messages: Vec<_> = diagnostics.iter().map(|d| d.message.as_str()).collect();
+        assert_eq!(
+            messages,
+            [
+                "alias `Name` of property `Image` is also a name of property `Name`",
+                "alias `ImageName` of property `Tag` is also a name of property `Image`",
+            ]
+        );
+        assert_eq!(
+            diagnostics[1].locations,
+            vec![
+                "#/definitions/Container/properties/Tag".to_string(),
+                "#/definitions/Container/properties/Image".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn dangling_references() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(
+            r##"
+swagger: "2.0"
+definitions:
+  Pet:
+    type: object
+    properties:
+      Owner:
+        $ref: "#/definitions/Owner"
+      Tags:
+        type: array
+        items:
+          $ref: "#/definitions/Tag"
+  Tag:
+    type: string
+paths:
+  /pets:
+    get:
+      responses:
+        200:
+          description: ok
+          schema:
+            $ref: "#/definitions/Owner"
+"##,
+        )
+        .unwrap();
+        let diagnostics = swagger.validate();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0].severity, Severity::Error);
+        assert!(diagnostics[0].message.contains("`#/definitions/Owner`"));
+        assert_eq!(
+            diagnostics[0].locations,
+            vec![
+                "#/definitions/Pet/properties/Owner".to_string(),
+                "#/paths/~1pets/get/responses/200/schema".to_string()
+            ]
+        );
+    }
+
+    #[test]
+    fn undeclared_required_properties() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(
+            r##"
+swagger: "2.0"
+definitions:
+  Base:
+    type: object
+    properties:
+      Id:
+        type: string
+  Pet:
+    required: [Id, Name, Age]
+    allOf:
+      - $ref: "#/definitions/Base"
+      - type: object
+        required: [Name]
+        properties:
+          Name:
+            type: string
+  Config:
+    type: object
+    required: [Image]
+"##,
+        )
+        .unwrap();
+        let diagnostics = swagger.validate();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "required property `Age` is not declared"
+        );
+        assert_eq!(
+            diagnostics[0].locations,
+            vec!["#/definitions/Pet".to_string()]
+        );
+    }
+
+    #[test]
+    fn mixed_pattern_properties() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(
+            r##"
+swagger: "2.0"
+definitions:
+  Labels:
+    type: object
+    patternProperties:
+      "^x-":
+        type: string
+      "^[a-z]+$":
+        type: string
+  Settings:
+    type: object
+    patternProperties:
+      "^s_":
+        type: string
+      "^i_":
+        type: integer
+"##,
+        )
+        .unwrap();
+        let diagnostics = swagger.validate();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].locations,
+            vec!["#/definitions/Settings".to_string()]
+        );
+    }
+
+    #[test]
+    fn response_content_types() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(
+            r##"
+swagger: "2.0"
+produces: [application/json]
+definitions:
+  Info:
+    type: object
+    properties:
+      Name:
+        type: string
+paths:
+  /_ping:
+    get:
+      produces: [text/plain]
+      responses:
+        200:
+          description: OK
+          schema:
+            type: string
+        500:
+          description: server error
+          schema:
+            $ref: "#/definitions/Info"
+  /containers/{id}/export:
+    get:
+      produces: [application/octet-stream]
+      responses:
+        200:
+          description: the tarball
+          schema:
+            type: string
+            format: binary
+  /info:
+    get:
+      responses:
+        200:
+          description: OK
+          schema:
+            $ref: "#/definitions/Info"
+"##,
+        )
+        .unwrap();
+        let diagnostics = swagger.validate();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(
+            diagnostics[0].message,
+            "response `500` has a JSON schema but the operation only produces text/plain"
+        );
+        assert_eq!(
+            diagnostics[0].locations,
+            vec!["#/paths/~1_ping/get/responses/500".to_string()]
+        );
+    }
+
+    #[test]
+    fn unmappable_definitions() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(
+            r#"
+swagger: "2.0"
+definitions:
+  Amount:
+    type: number
+  Price:
+    type: number
+    format: double
+  Anything: {}
+"#,
+        )
+        .unwrap();
+        let diagnostics = swagger.validate();
+        let locations: Vec<_> = diagnostics.iter().flat_map(|d| &d.locations).collect();
+        assert_eq!(
+            locations,
+            ["#/definitions/Amount", "#/definitions/Anything"]
+        );
+    }
+
+    #[test]
+    fn variants() {
+        let swagger: Swagger<rust::Type> = serde_yaml::from_str(
+            r##"
+swagger: "2.0"
+definitions:
+  Event:
+    type: object
+    x-variants:
+      field: type
+      mapping:
+        image: ImageEvent
+        volume: "#/definitions/VolumeEvent"
+  ImageEvent:
+    type: object
+    required: [type]
+    properties:
+      type:
+        type: string
+"##,
+        )
+        .unwrap();
+        let diagnostics = swagger.validate();
+        assert_eq!(diagnostics.len(), 2);
+        assert_eq!(diagnostics[0].severity, Severity::Warning);
+        assert_eq!(
+            diagnostics[0].locations,
+            [
+                "#/definitions/Event/x-variants/mapping/image",
+                "#/definitions/ImageEvent"
+            ]
+        );
+        assert_eq!(diagnostics[1].severity, Severity::Error);
+        assert!(diagnostics[1]
+            .message
+            .contains("`#/definitions/VolumeEvent` which is not a definition"));
+        assert_eq!(
+            diagnostics[1].locations,
+            ["#/definitions/Event/x-variants/mapping/volume"]
+        );
+    }
+}
@@ -0,0 +1,35 @@
+//! Compiles `tests/fixtures/accessors.rs`, the output of the Rust backend with `accessors`
+//! enabled, and exercises the generated accessors.
+mod common;
+
+common::include_models!(generated, "accessors");
+
+#[test]
+fn accessors() {
+    let container: generated::Container = serde_json::from_str(
+        r#"{
+            "Env": {"PATH": "/bin"},
+            "HostConfig": {"Memory": 512},
+            "Labels": {"app": "web"},
+            "Names": ["/web"],
+            "get_labels": "literal"
+        }"#,
+    )
+    .unwrap();
+
+    let memory = container
+        .host_config()
+        .and_then(generated::HostConfig::memory);
+    assert_eq!(memory, Some(&512));
+    assert_eq!(container.get_env("PATH").map(String::as_str), Some("/bin"));
+    assert_eq!(container.get_env("HOME"), None);
+    assert_eq!(
+        container.get_labels_ref("app").map(String::as_str),
+        Some("web")
+    );
+    assert_eq!(container.get_labels().map(String::as_str), Some("literal"));
+    assert_eq!(container.names_iter().collect::<Vec<_>>(), ["/web"]);
+    assert_eq!(container.mounts_iter().count(), 0);
+    assert_eq!(container.mounts(), None);
+    assert_eq!(container.type_(), None);
+}
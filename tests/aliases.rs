@@ -0,0 +1,23 @@
+//! Compiles `tests/fixtures/aliases.rs`, generated for properties with `x-aliases`, and makes
+//! sure the generated model accepts every alias.
+mod common;
+
+common::include_models!(generated, "aliases");
+
+#[test]
+fn deserialize_from_aliases() {
+    for key in ["Image", "ImageName", "image_ref"] {
+        let json = format!(r#"{{"{key}": "nginx"}}"#);
+        let container: generated::Container = serde_json::from_str(&json).unwrap();
+        assert_eq!(container.image, "nginx");
+    }
+
+    let container = generated::Container {
+        image: "nginx".into(),
+        labels: None,
+    };
+    assert_eq!(
+        serde_json::to_string(&container).unwrap(),
+        r#"{"Image":"nginx"}"#
+    );
+}
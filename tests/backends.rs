@@ -0,0 +1,263 @@
+//! Snapshot tests feeding a shared set of schema fixtures through every codegen backend, and the
+//! Rust fixtures of individual options through the Rust backend.
+//!
+//! Snapshots live in `tests/snapshots/<backend>/<fixture>.<ext>` and `tests/fixtures/<output>.rs`
+//! respectively, set `UPDATE_SNAPSHOTS=1` to regenerate them after an intended output change.
+mod common;
+
+use swagger_gen::v2::{
+    codegen::{
+        backend::{python, rust, typescript, CodegenBackend},
+        BodyNames, CodeGenerator, ModelKind,
+    },
+    Type,
+};
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+const FIXTURES: &[&str] = &[
+    "scalar",
+    "array",
+    "nested_object",
+    "enum",
+    "all_of",
+    "nullable",
+    "property_required",
+];
+
+/// A spec from `tests/fixtures` generated with the given options into `tests/fixtures/<output>.rs`.
+/// The dedicated test of each fixture includes that output to check that it compiles and behaves.
+struct RustFixture {
+    spec: &'static str,
+    output: &'static str,
+    options: fn() -> rust::CodegenOptions,
+}
+
+const fn fixture(
+    spec: &'static str,
+    output: &'static str,
+    options: fn() -> rust::CodegenOptions,
+) -> RustFixture {
+    RustFixture {
+        spec,
+        output,
+        options,
+    }
+}
+
+const RUST_FIXTURES: &[RustFixture] = &[
+    fixture("accessors", "accessors", || rust::CodegenOptions {
+        accessors: true,
+        with_constructors: true,
+        ..Default::default()
+    }),
+    fixture("aliases", "aliases", Default::default),
+    fixture("big-int", "big-int", Default::default),
+    fixture("body-names", "body-names", || rust::CodegenOptions {
+        body_names: BodyNames::Operation,
+        ..Default::default()
+    }),
+    fixture("body-names", "body-names-schema", || rust::CodegenOptions {
+        body_names: BodyNames::Schema,
+        ..Default::default()
+    }),
+    fixture("borrowed", "borrowed", || rust::CodegenOptions {
+        borrowed: true,
+        ..Default::default()
+    }),
+    fixture("borrowed", "borrowed", || rust::CodegenOptions {
+        string_type: rust::StringType::Cow,
+        ..Default::default()
+    }),
+    fixture("borrowed", "box-str", || rust::CodegenOptions {
+        string_type: rust::StringType::BoxStr,
+        ..Default::default()
+    }),
+    fixture("bounded-strings", "bounded-strings", || {
+        rust::CodegenOptions {
+            bounded_strings: true,
+            ..Default::default()
+        }
+    }),
+    fixture("common-inline-names", "common-inline-names", || {
+        rust::CodegenOptions {
+            common_inline_names: HashMap::from([("message:string".into(), "ErrorDetail".into())]),
+            ..Default::default()
+        }
+    }),
+    fixture("date-times", "date-times", Default::default),
+    fixture(
+        "dedupe-identical-schemas",
+        "dedupe-identical-schemas",
+        || rust::CodegenOptions {
+            dedupe_identical_schemas: true,
+            ..Default::default()
+        },
+    ),
+    fixture("dynamic-maps", "dynamic-maps", || rust::CodegenOptions {
+        dynamic_maps: true,
+        ..Default::default()
+    }),
+    fixture("empty-objects", "empty-objects", Default::default),
+    fixture("enum-rename-all", "enum-rename-all", || {
+        rust::CodegenOptions {
+            enum_rename_all: true,
+            ..Default::default()
+        }
+    }),
+    fixture("enum-round-trips", "enum-round-trips", || {
+        rust::CodegenOptions {
+            enum_round_trip_tests: true,
+            enum_rename_all: true,
+            ..Default::default()
+        }
+    }),
+    fixture("examples", "examples", || rust::CodegenOptions {
+        examples_as_tests: true,
+        ..Default::default()
+    }),
+    fixture("files", "files", Default::default),
+    fixture("float-hash", "float-hash", || rust::CodegenOptions {
+        float_hash: rust::FloatHash::Bits,
+        ..Default::default()
+    }),
+    fixture("form-data", "form-data", Default::default),
+    fixture("go-packages", "go-packages", || rust::CodegenOptions {
+        use_go_packages: true,
+        ..Default::default()
+    }),
+    fixture("inline", "inline", || rust::CodegenOptions {
+        inline_threshold: 1,
+        ..Default::default()
+    }),
+    fixture("inline-module", "inline-module", || rust::CodegenOptions {
+        inline_module_threshold: 2,
+        ..Default::default()
+    }),
+    fixture("lenient-bools", "lenient-bools", Default::default),
+    fixture("map-keys", "map-keys", Default::default),
+    fixture("mixed-enums", "mixed-enums", Default::default),
+    fixture("newtypes", "newtypes", || rust::CodegenOptions {
+        newtypes: true,
+        ..Default::default()
+    }),
+    fixture("non-exhaustive", "non-exhaustive", || {
+        rust::CodegenOptions {
+            non_exhaustive: true,
+            ..Default::default()
+        }
+    }),
+    fixture("only-kinds", "only-kinds", || rust::CodegenOptions {
+        only: vec![ModelKind::Enum],
+        ..Default::default()
+    }),
+    fixture("ordered-enums", "ordered-enums", || rust::CodegenOptions {
+        ordered_enums: true,
+        ..Default::default()
+    }),
+    fixture("pattern-properties", "pattern-properties", Default::default),
+    fixture("query-params", "query-params", || rust::CodegenOptions {
+        query_params_models: true,
+        ..Default::default()
+    }),
+    fixture("recursive", "recursive", Default::default),
+    fixture("response-aliases", "response-aliases", Default::default),
+    fixture("response-array-items", "response-array-items", || {
+        rust::CodegenOptions {
+            dedupe_identical_schemas: true,
+            ..Default::default()
+        }
+    }),
+    fixture("schema-hashes", "schema-hashes", || rust::CodegenOptions {
+        schema_hashes: true,
+        ..Default::default()
+    }),
+    fixture("sections", "sections", || rust::CodegenOptions {
+        section_banners: true,
+        parallel: true,
+        query_params_models: true,
+        ..Default::default()
+    }),
+    fixture("sections", "sections", || rust::CodegenOptions {
+        section_banners: true,
+        parallel: false,
+        query_params_models: true,
+        ..Default::default()
+    }),
+    fixture("value-conversions", "value-conversions", || {
+        rust::CodegenOptions {
+            value_conversions: true,
+            wire_format: rust::WireFormat::Json,
+            ..Default::default()
+        }
+    }),
+    fixture("variants", "variants", Default::default),
+    fixture("wire-format", "wire-format", || rust::CodegenOptions {
+        wire_format: rust::WireFormat::Any,
+        ..Default::default()
+    }),
+];
+
+fn generate<T: Type>(fixture: &str, backend: Box<dyn CodegenBackend<T>>) -> String {
+    CodeGenerator::new(common::load(fixture), backend)
+        .generate_models_to_string()
+        .unwrap()
+}
+
+/// Compares each output with its snapshot, or rewrites the snapshots with `UPDATE_SNAPSHOTS`.
+fn check_snapshots(outputs: impl IntoIterator<Item = (PathBuf, String)>) {
+    let update = std::env::var_os("UPDATE_SNAPSHOTS").is_some();
+    let mut mismatches = vec![];
+
+    for (snapshot, out) in outputs {
+        if update {
+            std::fs::create_dir_all(snapshot.parent().unwrap()).unwrap();
+            std::fs::write(&snapshot, &out).unwrap();
+        } else if std::fs::read_to_string(&snapshot).ok().as_deref() != Some(out.as_str()) {
+            mismatches.push(format!("{}:\n{out}", snapshot.display()));
+        }
+    }
+
+    assert!(
+        mismatches.is_empty(),
+        "output differs from snapshots:\n{}",
+        mismatches.join("\n")
+    );
+}
+
+fn check_backend<T: Type>(name: &str, ext: &str, backend: impl Fn() -> Box<dyn CodegenBackend<T>>) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/snapshots");
+    check_snapshots(FIXTURES.iter().map(|fixture| {
+        let out = generate(&format!("harness/{fixture}"), backend());
+        (root.join(format!("{name}/{fixture}.{ext}")), out)
+    }));
+}
+
+#[test]
+fn rust_backend() {
+    check_backend::<rust::Type>("rust", "rs", || Box::new(rust::Codegen::default()));
+}
+
+#[test]
+fn python_backend() {
+    check_backend::<python::Type>("python", "py", || Box::new(python::Codegen::default()));
+}
+
+#[test]
+fn typescript_backend() {
+    check_backend::<typescript::Type>("typescript", "ts", || {
+        Box::new(typescript::Codegen::default())
+    });
+}
+
+#[test]
+fn rust_fixtures() {
+    check_snapshots(RUST_FIXTURES.iter().map(|fixture| {
+        let out = common::generate(fixture.spec, (fixture.options)());
+        (
+            common::fixtures().join(format!("{}.rs", fixture.output)),
+            out,
+        )
+    }));
+}
@@ -0,0 +1,15 @@
+//! Compiles `tests/fixtures/big-int.rs`, generated for `bigint` integers, and makes sure values
+//! beyond `i64` survive a round trip.
+mod common;
+
+common::include_models!(generated, "big-int");
+
+#[test]
+fn values_beyond_i64_round_trip() {
+    let json =
+        r#"{"Balance":170141183460469231731687303715884105727,"Limit":-9223372036854775809}"#;
+    let account: generated::Account = serde_json::from_str(json).unwrap();
+    assert_eq!(account.balance, i128::MAX);
+    assert_eq!(account.limit, Some(i64::MIN as i128 - 1));
+    assert_eq!(serde_json::to_string(&account).unwrap(), json);
+}
@@ -0,0 +1,44 @@
+//! Compiles the models of the body parameters of `tests/fixtures/body-names.yaml` named after the
+//! operation and after the schema, `tests/fixtures/body-names.rs` and
+//! `tests/fixtures/body-names-schema.rs`, and checks the models the operations table points at.
+mod common;
+
+use swagger_gen::v2::{
+    codegen::{backend::rust, operations, BodyNames},
+    Swagger,
+};
+
+common::include_models!(by_operation, "body-names");
+
+common::include_models!(by_schema, "body-names-schema");
+
+fn load() -> Swagger<rust::Type> {
+    common::load("body-names")
+}
+
+#[test]
+fn operations_name_body_models() {
+    let defs = operations::operation_defs(&load(), BodyNames::Schema, false);
+    let models: Vec<_> = defs
+        .iter()
+        .flat_map(|op| &op.params)
+        .filter_map(|param| param.model.as_deref())
+        .collect();
+    assert_eq!(
+        models,
+        [
+            "ContainerConfig",
+            "Container",
+            "ContainerConfig",
+            "ContainerRenameBodyParam",
+            "ContainerWaitBodyParam"
+        ]
+    );
+
+    let config = by_schema::ContainerConfig {
+        cmd: None,
+        image: "alpine".into(),
+    };
+    let _: by_operation::ContainerCreateBodyParam =
+        serde_json::from_value(serde_json::to_value(config).unwrap()).unwrap();
+}
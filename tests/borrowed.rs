@@ -0,0 +1,32 @@
+//! Compiles `tests/fixtures/borrowed.rs`, the output of the Rust backend with `borrowed`, and
+//! checks that the models borrow from the input they are deserialized from.
+mod common;
+
+use std::borrow::Cow;
+
+common::include_models!(generated, "borrowed");
+
+use generated::{Container, Containers, Event};
+
+#[test]
+fn borrows_from_input() {
+    let input =
+        r#"[{"Id": "abc", "Names": ["web"], "Mounts": [{"Source": "/data"}], "Size": {"Rw": 1}}]"#;
+    let containers: Containers = serde_json::from_str(input).unwrap();
+    let container: &Container = &containers[0];
+    assert!(matches!(container.id, Cow::Borrowed("abc")));
+    assert_eq!(container.names, ["web"]);
+    assert_eq!(container.driver.as_deref(), Some("overlay2"));
+    let source = &container.mounts.as_ref().unwrap()[0].source;
+    assert!(matches!(source, Some(Cow::Borrowed("/data"))));
+
+    // escaped strings can't be borrowed
+    let container: Container = serde_json::from_str(r#"{"Id": "a\"b", "Names": []}"#).unwrap();
+    assert!(matches!(container.id, Cow::Owned(_)));
+
+    let event: Event = serde_json::from_str(r#"{"Type": "mount", "Source": "/data"}"#).unwrap();
+    let Event::Mount(mount) = event else {
+        panic!("expected a mount event, got {event:?}");
+    };
+    assert_eq!(mount.source.as_deref(), Some("/data"));
+}
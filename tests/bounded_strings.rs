@@ -0,0 +1,28 @@
+//! Compiles `tests/fixtures/bounded-strings.rs`, the output of the Rust backend with
+//! `bounded_strings`, and checks that the newtypes reject strings that are too long.
+mod common;
+
+common::include_models!(generated, "bounded-strings");
+
+use generated::{Container, ContainerId};
+
+#[test]
+fn enforces_max_length() {
+    let id = ContainerId::try_from("0123456789ab").unwrap();
+    assert_eq!(id.as_str(), "0123456789ab");
+    assert_eq!(
+        ContainerId::try_from("0123456789abc").unwrap_err(),
+        "ContainerId is at most 12 characters long, got 13"
+    );
+    // characters are counted, not bytes
+    assert!("ééééééééééé".parse::<ContainerId>().is_ok());
+
+    let container: Container = serde_json::from_str(r#"{"Id": "abc", "Links": ["def"]}"#).unwrap();
+    assert_eq!(container.id.to_string(), "abc");
+    assert_eq!(
+        serde_json::to_value(&container.id).unwrap(),
+        serde_json::json!("abc")
+    );
+    let err = serde_json::from_str::<Container>(r#"{"Id": "0123456789abc"}"#).unwrap_err();
+    assert!(err.to_string().contains("at most 12 characters"), "{err}");
+}
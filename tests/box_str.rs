@@ -0,0 +1,27 @@
+//! Compiles `tests/fixtures/box-str.rs`, the output of the Rust backend with
+//! `StringType::BoxStr` for `tests/fixtures/borrowed.yaml`, and checks that the models round trip.
+mod common;
+
+common::include_models!(generated, "box-str");
+
+#[test]
+fn round_trip() {
+    use generated::{Container, Containers};
+
+    let input = r#"[{"Id": "abc", "Names": ["web"], "Labels": {"tier": "front"}}]"#;
+    let containers: Containers = serde_json::from_str(input).unwrap();
+    let container: &Container = &containers[0];
+    assert_eq!(&*container.id, "abc");
+    assert_eq!(container.names, ["web".into()]);
+    assert_eq!(container.driver.as_deref(), Some("overlay2"));
+    assert_eq!(
+        container.labels.as_ref().unwrap()["tier"],
+        Box::<str>::from("front")
+    );
+
+    let json = serde_json::to_string(&containers).unwrap();
+    assert_eq!(
+        serde_json::from_str::<Containers>(&json).unwrap(),
+        containers
+    );
+}
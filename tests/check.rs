@@ -0,0 +1,52 @@
+//! Runs `generate models --check` against the golden output of `tests/fixtures/date-times.yaml`
+//! and an outdated copy of it.
+mod common;
+
+use std::{
+    path::Path,
+    process::{Command, Output},
+};
+
+fn check(existing: &Path) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_swagger-gen"))
+        .args(["--quiet", "generate", "models", "--check"])
+        .arg(existing)
+        .arg(common::fixtures().join("date-times.yaml"))
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn up_to_date() {
+    let output = check(&common::fixtures().join("date-times.rs"));
+    assert!(output.status.success());
+    assert!(output.stdout.is_empty());
+}
+
+#[test]
+fn outdated() {
+    let golden = std::fs::read_to_string(common::fixtures().join("date-times.rs")).unwrap();
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join("outdated-date-times.rs");
+    let outdated = golden.replacen("pub struct", "pub struct Outdated", 1);
+    std::fs::write(&path, outdated).unwrap();
+
+    let output = check(&path);
+    assert_eq!(output.status.code(), Some(1));
+    let diff = String::from_utf8(output.stdout).unwrap();
+    assert!(diff.starts_with(&format!("--- {}\n+++ generated\n@@ ", path.display())));
+    let removed: Vec<_> = diff.lines().filter(|line| line.starts_with('-')).collect();
+    let added: Vec<_> = diff.lines().filter(|line| line.starts_with('+')).collect();
+    assert_eq!(removed.len(), 2);
+    assert!(removed[1].starts_with("-pub struct Outdated"));
+    assert_eq!(added.len(), 2);
+    assert!(String::from_utf8(output.stderr)
+        .unwrap()
+        .contains("is not up to date with the spec"));
+}
+
+#[test]
+fn missing_file() {
+    let output = check(&common::fixtures().join("missing.rs"));
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stdout.is_empty());
+}
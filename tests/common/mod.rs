@@ -0,0 +1,57 @@
+//! Loading and generating the specs of `tests/fixtures`, shared by the integration tests.
+#![allow(dead_code)]
+
+use swagger_gen::v2::{
+    codegen::{backend::rust, CodeGenerator},
+    Swagger, Type,
+};
+
+use std::path::{Path, PathBuf};
+
+/// The directory of the specs and of the models generated for them.
+pub fn fixtures() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+}
+
+/// Parses `tests/fixtures/<fixture>.yaml`.
+pub fn load<T: Type>(fixture: &str) -> Swagger<T> {
+    let spec = std::fs::read_to_string(fixtures().join(format!("{fixture}.yaml"))).unwrap();
+    serde_yaml::from_str(&spec).unwrap()
+}
+
+/// The models the Rust backend generates for `swagger` with `opts`.
+pub fn generate_models(swagger: Swagger<rust::Type>, opts: rust::CodegenOptions) -> String {
+    CodeGenerator::new(swagger, Box::new(rust::Codegen::new(opts)))
+        .generate_models_to_string()
+        .unwrap()
+}
+
+/// The models the Rust backend generates for `tests/fixtures/<fixture>.yaml` with `opts`.
+pub fn generate(fixture: &str, opts: rust::CodegenOptions) -> String {
+    generate_models(load(fixture), opts)
+}
+
+/// Includes `tests/fixtures/<fixture>.rs` as the module `$module`, with the imports the Rust
+/// backend leaves to the module including its models.
+#[allow(unused_macros)]
+macro_rules! include_models {
+    ($module:ident, $fixture:literal) => {
+        #[allow(dead_code, unused_imports, clippy::new_without_default)]
+        mod $module {
+            use chrono::{DateTime, Utc};
+            use serde::{Deserialize, Serialize};
+            use serde_json::Value;
+            use std::collections::HashMap;
+
+            include!(concat!(
+                env!("CARGO_MANIFEST_DIR"),
+                "/tests/fixtures/",
+                $fixture,
+                ".rs"
+            ));
+        }
+    };
+}
+
+#[allow(unused_imports)]
+pub(crate) use include_models;
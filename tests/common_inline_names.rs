@@ -0,0 +1,38 @@
+//! Compiles `tests/fixtures/common-inline-names.rs`, the models of
+//! `tests/fixtures/common-inline-names.yaml` with the `{message: string}` signature named
+//! `ErrorDetail`, and checks that the responses share that model.
+mod common;
+
+use swagger_gen::v2::codegen::backend::rust;
+
+use std::collections::HashMap;
+
+common::include_models!(generated, "common-inline-names");
+
+#[test]
+fn one_model_for_three_responses() {
+    let opts = rust::CodegenOptions {
+        common_inline_names: HashMap::from([("message:string".into(), "ErrorDetail".into())]),
+        ..Default::default()
+    };
+    let out = common::generate("common-inline-names", opts);
+    assert_eq!(out.matches("pub struct ErrorDetail {").count(), 1);
+    for use_ in [
+        "pub type ContainerInspect404Response = Vec<ErrorDetail>;",
+        "pub error: Option<ErrorDetail>,",
+        "pub errors: Option<Vec<ErrorDetail>>,",
+    ] {
+        assert!(out.contains(use_), "{use_}");
+    }
+    assert!(out.contains("pub struct ContainerWarningsInlineItem {"));
+
+    let error: generated::ContainerStart500Response =
+        serde_json::from_str(r#"{"error": {"message": "boom"}}"#).unwrap();
+    let errors = generated::ContainerStop500Response {
+        errors: Some(vec![error.error.unwrap()]),
+    };
+    assert_eq!(
+        serde_json::to_string(&errors).unwrap(),
+        r#"{"errors":[{"message":"boom"}]}"#
+    );
+}
@@ -0,0 +1,22 @@
+//! Compiles `tests/fixtures/date-times.rs`, generated for `date-time` fields, and checks the
+//! `datetime_util` helpers it emits.
+mod common;
+
+common::include_models!(generated, "date-times");
+
+use generated::datetime_util::{format_rfc3339, parse_rfc3339};
+
+#[test]
+fn parse_and_format() {
+    let time = parse_rfc3339("2022-01-01T02:00:00.5+02:00").unwrap();
+    assert_eq!(format_rfc3339(&time), "2022-01-01T00:00:00.500Z");
+    assert!(parse_rfc3339("2022-01-01").is_err());
+
+    let event: generated::Event =
+        serde_json::from_str(r#"{"Time": "2022-01-01T00:00:00Z"}"#).unwrap();
+    assert_eq!(format_rfc3339(&event.time), "2022-01-01T00:00:00Z");
+    assert_eq!(
+        serde_json::to_value(&event).unwrap()["Time"],
+        format_rfc3339(&event.time)
+    );
+}
@@ -0,0 +1,53 @@
+//! Compiles `tests/fixtures/dedupe-identical-schemas.rs`, the models of
+//! `tests/fixtures/dedupe-identical-schemas.yaml` with identical inline objects generated once, and
+//! checks which models they were merged into.
+mod common;
+
+use swagger_gen::v2::codegen::backend::{rust, CodegenBackend};
+
+common::include_models!(generated, "dedupe-identical-schemas");
+
+fn codegen() -> rust::Codegen {
+    rust::Codegen::new(rust::CodegenOptions {
+        dedupe_identical_schemas: true,
+        ..Default::default()
+    })
+}
+
+#[test]
+fn aliases_of_the_first_model() {
+    let (_, diagnostics) =
+        codegen().prototypes_with_diagnostics(&common::load("dedupe-identical-schemas"));
+    let mut locations: Vec<_> = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.message.contains("identical schemas"))
+        .map(|diagnostic| diagnostic.locations.clone())
+        .collect();
+    locations.sort();
+    assert_eq!(
+        locations,
+        [
+            [
+                "ContainerHostConfigInlineItem",
+                "ServiceResourcesInlineItem"
+            ],
+            [
+                "ContainerHostConfigInlineItemUlimitsInlineItem",
+                "ServiceResourcesInlineItemUlimitsInlineItem"
+            ],
+        ]
+    );
+
+    let resources: generated::ServiceResourcesInlineItem =
+        serde_json::from_str(r#"{"CpuShares": 512, "Ulimits": [{"Name": "nofile"}]}"#).unwrap();
+    let service = generated::Service {
+        name: None,
+        placement: None,
+        resources: Some(resources.clone()),
+    };
+    let container = generated::Container {
+        host_config: Some(resources),
+        id: None,
+    };
+    assert_eq!(service.resources, container.host_config);
+}
@@ -0,0 +1,78 @@
+//! Generates models of programmatically nested schemas, checking that schemas nested deeper
+//! than the depth limit are skipped with a diagnostic instead of overflowing the stack.
+use swagger_gen::v2::{
+    builder::{SchemaBuilder, SwaggerBuilder},
+    codegen::{backend::rust, CodeGenerator, DEFAULT_MAX_DEPTH},
+    validation::Severity,
+    Swagger,
+};
+
+/// A definition `Deep` with an inline object property `Level` nested `depth` times and a
+/// definition `Matrix` with a property of as many nested arrays.
+fn nested(depth: usize) -> Swagger<rust::Type> {
+    let mut schema = SchemaBuilder::object().prop("Leaf", SchemaBuilder::string());
+    let mut cells = SchemaBuilder::integer();
+    for _ in 0..depth {
+        schema = SchemaBuilder::object().prop("Level", schema);
+        cells = SchemaBuilder::array(cells);
+    }
+    SwaggerBuilder::new()
+        .definition("Deep", schema)
+        .definition("Matrix", SchemaBuilder::object().prop("Cells", cells))
+        .definition(
+            "Shallow",
+            SchemaBuilder::object().prop("Id", SchemaBuilder::string()),
+        )
+        .build()
+}
+
+#[test]
+fn deep_schema_is_skipped() {
+    let mut codegen = CodeGenerator::new(nested(1000), Box::new(rust::Codegen::default()));
+    let out = codegen.generate_models_to_string().unwrap();
+    assert!(out.contains("pub struct Shallow {"));
+    assert!(!out.contains("pub struct Deep {"));
+    assert!(!out.contains("pub struct Matrix {"));
+    assert!(!out.contains("InlineItem"));
+
+    let diagnostics = codegen.diagnostics();
+    let errors: Vec<_> = diagnostics
+        .iter()
+        .filter(|diagnostic| diagnostic.severity == Severity::Error)
+        .collect();
+    assert_eq!(errors.len(), 2, "{diagnostics:?}");
+    assert!(
+        errors[0].message.contains("`Deep`"),
+        "{}",
+        errors[0].message
+    );
+    assert_eq!(
+        errors[0].locations,
+        [format!("Deep{}", ".Level".repeat(DEFAULT_MAX_DEPTH + 1))]
+    );
+    assert!(
+        errors[1].message.contains("`Matrix`"),
+        "{}",
+        errors[1].message
+    );
+    assert_eq!(errors[1].locations, ["Matrix.Cells"]);
+}
+
+#[test]
+fn legitimate_depth_is_generated() {
+    let mut codegen = CodeGenerator::new(nested(20), Box::new(rust::Codegen::default()));
+    let out = codegen.generate_models_to_string().unwrap();
+    assert!(out.contains("pub struct Deep {"));
+    assert!(out.contains(&format!(
+        "pub cells: Option<{}isize{}>,",
+        "Vec<".repeat(20),
+        ">".repeat(20)
+    )));
+    let innermost = format!("pub struct Deep{} {{", "LevelInlineItem".repeat(20));
+    assert!(out.contains(&innermost), "{out}");
+    assert_eq!(out.matches("pub struct").count(), 23);
+    assert!(codegen
+        .diagnostics()
+        .iter()
+        .all(|diagnostic| diagnostic.severity != Severity::Error));
+}
@@ -0,0 +1,62 @@
+//! Compiles `tests/fixtures/dynamic-maps.rs`, the output of the Rust backend with
+//! `dynamic_maps`, and checks the getters of the maps it generates.
+mod common;
+
+common::include_models!(generated, "dynamic-maps");
+
+use generated::{Location, Resource};
+
+#[test]
+fn keeps_every_key() {
+    let json = serde_json::json!({
+        "id": "r1",
+        "metadata": {
+            "owner": "ops",
+            "size": 42,
+            "public": true,
+            "tags": ["a", "b"],
+            "location": {"region": "eu-west", "zone": "b"},
+            "createdAt": "2022-01-01T00:00:00Z",
+            "team": {"name": "core"}
+        },
+        "annotations": {"note": "kept"}
+    });
+    let resource: Resource = serde_json::from_value(json.clone()).unwrap();
+    let metadata = resource.metadata.as_ref().unwrap();
+    assert_eq!(metadata.owner(), Some("ops"));
+    assert_eq!(metadata.size(), Some(42));
+    assert_eq!(metadata.public(), Some(true));
+    assert_eq!(
+        metadata.tags(),
+        Some(vec!["a".to_string(), "b".to_string()])
+    );
+    assert_eq!(
+        metadata.location(),
+        Some(Location {
+            region: Some("eu-west".into()),
+            zone: Some("b".into()),
+        })
+    );
+    assert_eq!(
+        metadata.created_at().unwrap().to_rfc3339(),
+        "2022-01-01T00:00:00+00:00"
+    );
+    assert_eq!(metadata.0["team"]["name"], "core");
+
+    let back = serde_json::to_value(&resource).unwrap();
+    assert_eq!(back["metadata"], json["metadata"]);
+    assert_eq!(back["annotations"], json["annotations"]);
+}
+
+#[test]
+fn mismatched_values() {
+    let resource: Resource = serde_json::from_value(serde_json::json!({
+        "id": "r1",
+        "metadata": {"size": "large", "tags": "a,b"}
+    }))
+    .unwrap();
+    let metadata = resource.metadata.unwrap();
+    assert_eq!(metadata.size(), None);
+    assert_eq!(metadata.tags(), None);
+    assert_eq!(metadata.owner(), None);
+}
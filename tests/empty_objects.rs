@@ -0,0 +1,35 @@
+//! Compiles `tests/fixtures/empty-objects.rs`, generated for objects that can't have any keys,
+//! and makes sure they (de)serialize as `{}`.
+mod common;
+
+use std::collections::HashMap;
+
+common::include_models!(generated, "empty-objects");
+
+use generated::{ContainerConfig, EmptyObject, Marker};
+
+#[test]
+fn round_trips() {
+    let ports: HashMap<String, EmptyObject> = serde_json::from_str(r#"{"80/tcp": {}}"#).unwrap();
+    assert_eq!(ports, HashMap::from([("80/tcp".to_string(), EmptyObject)]));
+    assert_eq!(serde_json::to_string(&ports).unwrap(), r#"{"80/tcp":{}}"#);
+
+    let config: ContainerConfig = serde_json::from_str(
+        r#"{"ExposedPorts": {"80/tcp": {}}, "Volumes": {"/data": {}}, "Managed": {}, "Markers": [{}, {}]}"#,
+    )
+    .unwrap();
+    assert_eq!(config.managed, Some(Marker));
+    assert_eq!(config.markers, Some(vec![Marker, Marker]));
+    let json = serde_json::to_value(&config).unwrap();
+    assert_eq!(json["ExposedPorts"], serde_json::json!({"80/tcp": {}}));
+    assert_eq!(json["Volumes"], serde_json::json!({"/data": {}}));
+    assert_eq!(json["Managed"], serde_json::json!({}));
+}
+
+#[test]
+fn rejects_other_values() {
+    assert!(serde_json::from_str::<EmptyObject>(r#"{"HostPort": "8080"}"#).is_err());
+    assert!(serde_json::from_str::<EmptyObject>("null").is_err());
+    assert!(serde_json::from_str::<Marker>("[]").is_err());
+    assert!(serde_json::from_str::<ContainerConfig>(r#"{"Volumes": {"/data": 1}}"#).is_err());
+}
@@ -0,0 +1,46 @@
+//! Compiles `tests/fixtures/enum-rename-all.rs`, the output of the Rust backend with
+//! `enum_rename_all` enabled, and makes sure the enums keep their wire values.
+mod common;
+
+common::include_models!(generated, "enum-rename-all");
+
+#[test]
+fn wire_values() {
+    use generated::{Health, Mode, Restart, State};
+
+    fn assert_round_trip<T>(value: T, wire: &str)
+    where
+        T: serde::Serialize
+            + serde::de::DeserializeOwned
+            + AsRef<str>
+            + PartialEq
+            + std::fmt::Debug,
+    {
+        assert_eq!(value.as_ref(), wire);
+        let json = serde_json::to_string(&value).unwrap();
+        assert_eq!(json, format!("{wire:?}"));
+        assert_eq!(serde_json::from_str::<T>(&json).unwrap(), value);
+    }
+
+    assert_round_trip(State::OutOfMemory, "OUT_OF_MEMORY");
+    assert_round_trip(State::Running, "RUNNING");
+    assert_round_trip(Restart::No, "no");
+    assert_round_trip(Restart::UnlessStopped, "unless-stopped");
+    assert_round_trip(Health::Unhealthy, "Unhealthy");
+    assert_round_trip(Mode::BridgeV2, "bridge-v2");
+    assert_round_trip(Mode::None, "None");
+}
+
+#[test]
+fn variants_in_spec_order() {
+    use generated::{Mode, State};
+
+    assert_eq!(
+        State::VARIANTS,
+        [State::Running, State::Exited, State::OutOfMemory]
+    );
+    assert_eq!(
+        Mode::iter().collect::<Vec<_>>(),
+        [Mode::Host, Mode::BridgeV2, Mode::None]
+    );
+}
@@ -0,0 +1,6 @@
+//! Compiles `tests/fixtures/enum-round-trips.rs`, the output of the Rust backend with
+//! `enum_round_trip_tests` and `enum_rename_all` enabled, which runs the generated round trip
+//! tests.
+mod common;
+
+common::include_models!(generated, "enum-round-trips");
@@ -0,0 +1,5 @@
+//! Compiles `tests/fixtures/examples.rs`, the output of the Rust backend with `examples_as_tests`
+//! enabled, which runs the generated example tests.
+mod common;
+
+common::include_models!(generated, "examples");
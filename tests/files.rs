@@ -0,0 +1,48 @@
+//! Compiles `tests/fixtures/files.rs`, the models of `type: file` parameters and responses of
+//! `tests/fixtures/files.yaml`, generated as raw bytes with a warning.
+mod common;
+
+use swagger_gen::v2::{
+    codegen::{
+        backend::{python, rust},
+        CodeGenerator,
+    },
+    validation::Severity,
+};
+
+common::include_models!(generated, "files");
+
+#[test]
+fn files_are_bytes() {
+    let archive: generated::ContainerArchive200Response = vec![0x75, 0x73];
+    let form = generated::PutContainerArchiveForm {
+        archive,
+        overwrite: None,
+    };
+    assert_eq!(
+        serde_json::to_value(&form).unwrap(),
+        serde_json::json!({"archive": [0x75, 0x73]})
+    );
+
+    let out = CodeGenerator::new(
+        common::load::<python::Type>("files"),
+        Box::new(python::Codegen::default()),
+    )
+    .generate_models_to_string()
+    .unwrap();
+    assert!(out.contains("archive: bytes"), "{out}");
+}
+
+#[test]
+fn warns_about_files() {
+    let diagnostics = common::load::<rust::Type>("files").validate();
+    assert_eq!(diagnostics.len(), 1, "{diagnostics:?}");
+    assert_eq!(diagnostics[0].severity, Severity::Warning);
+    assert_eq!(
+        diagnostics[0].locations,
+        [
+            "#/paths/~1containers~1{id}~1archive/get/responses/200/schema",
+            "#/paths/~1containers~1{id}~1archive/put/parameters/1",
+        ]
+    );
+}
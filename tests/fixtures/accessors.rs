@@ -0,0 +1,108 @@
+// Required array and map fields are empty when missing from the payload. This is
+// deprecated and will change in the next release, generate with
+// `--lenient-required-collections false` to reject payloads missing them instead.
+
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    T: serde::de::DeserializeOwned,
+{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Container {
+    #[serde(rename = "Env", default, deserialize_with = "deserialize_nonoptional_map")]
+    pub env: HashMap<String, String>,
+    #[serde(rename = "HostConfig")]
+    pub host_config: Option<HostConfig>,
+    #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<HashMap<String, String>>,
+    #[serde(rename = "Mounts", skip_serializing_if = "Option::is_none")]
+    pub mounts: Option<Vec<String>>,
+    #[serde(rename = "Names", default, deserialize_with = "deserialize_nonoptional_vec")]
+    pub names: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub get_labels: Option<String>,
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+}
+
+impl Container {
+    pub fn new(env: HashMap<String, String>, names: Vec<String>) -> Self {
+        Self {
+            env,
+            host_config: None,
+            labels: None,
+            mounts: None,
+            names,
+            get_labels: None,
+            type_: None,
+        }
+    }
+}
+
+impl Container {
+    pub fn get_env(&self, key: &str) -> Option<&String> {
+        self.env.get(key)
+    }
+
+    pub fn host_config(&self) -> Option<&HostConfig> {
+        self.host_config.as_ref()
+    }
+
+    pub fn labels(&self) -> Option<&HashMap<String, String>> {
+        self.labels.as_ref()
+    }
+
+    pub fn get_labels_ref(&self, key: &str) -> Option<&String> {
+        self.labels.as_ref().and_then(|map| map.get(key))
+    }
+
+    pub fn mounts(&self) -> Option<&Vec<String>> {
+        self.mounts.as_ref()
+    }
+
+    pub fn mounts_iter(&self) -> impl Iterator<Item = &String> {
+        self.mounts.iter().flatten()
+    }
+
+    pub fn names_iter(&self) -> impl Iterator<Item = &String> {
+        self.names.iter()
+    }
+
+    pub fn get_labels(&self) -> Option<&String> {
+        self.get_labels.as_ref()
+    }
+
+    pub fn type_(&self) -> Option<&String> {
+        self.type_.as_ref()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HostConfig {
+    #[serde(rename = "Memory", skip_serializing_if = "Option::is_none")]
+    pub memory: Option<i64>,
+}
+
+impl HostConfig {
+    pub fn new() -> Self {
+        Self {
+            memory: None,
+        }
+    }
+}
+
+impl HostConfig {
+    pub fn memory(&self) -> Option<&i64> {
+        self.memory.as_ref()
+    }
+}
+
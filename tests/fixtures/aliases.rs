@@ -0,0 +1,23 @@
+
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    T: serde::de::DeserializeOwned,
+{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Container {
+    #[serde(rename = "Image", alias = "ImageName", alias = "image_ref")]
+    pub image: String,
+    #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<HashMap<String, String>>,
+}
+
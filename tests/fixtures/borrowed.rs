@@ -0,0 +1,122 @@
+// Required array and map fields are empty when missing from the payload. This is
+// deprecated and will change in the next release, generate with
+// `--lenient-required-collections false` to reject payloads missing them instead.
+
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::Deserialize<'de>>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: serde::de::Deserialize<'de> + Eq + std::hash::Hash,
+    T: serde::de::Deserialize<'de>,
+{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_borrowed_option<'de: 'a, 'a, D: serde::de::Deserializer<'de>>(
+    d: D,
+) -> Result<Option<std::borrow::Cow<'a, str>>, D::Error> {
+    #[derive(Deserialize)]
+    struct Wrapper<'a>(#[serde(borrow)] std::borrow::Cow<'a, str>);
+
+    Option::<Wrapper>::deserialize(d).map(|v| v.map(|v| v.0))
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Container<'a> {
+    #[serde(rename = "Driver", borrow, default = "default_container_driver", deserialize_with = "deserialize_borrowed_option", skip_serializing_if = "Option::is_none")]
+    pub driver: Option<std::borrow::Cow<'a, str>>,
+    #[serde(rename = "Id", borrow)]
+    pub id: std::borrow::Cow<'a, str>,
+    #[serde(rename = "Labels", borrow, skip_serializing_if = "Option::is_none")]
+    pub labels: Option<HashMap<String, std::borrow::Cow<'a, str>>>,
+    #[serde(rename = "Mounts", borrow, skip_serializing_if = "Option::is_none")]
+    pub mounts: Option<Vec<Mount<'a>>>,
+    #[serde(rename = "Names", borrow, default, deserialize_with = "deserialize_nonoptional_vec")]
+    pub names: Vec<std::borrow::Cow<'a, str>>,
+    #[serde(rename = "Size")]
+    pub size: Option<Size>,
+    #[serde(rename = "State", borrow, default, deserialize_with = "deserialize_borrowed_option", skip_serializing_if = "Option::is_none")]
+    pub state: Option<std::borrow::Cow<'a, str>>,
+}
+
+fn default_container_driver() -> Option<std::borrow::Cow<'static, str>> {
+    Some(std::borrow::Cow::Borrowed("overlay2"))
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ContainerStateInlineItem {
+    #[serde(rename = "running")]
+Running,
+    #[serde(rename = "exited")]
+Exited,
+}
+
+impl AsRef<str> for ContainerStateInlineItem {
+    fn as_ref(&self) -> &str {
+        match self {
+            ContainerStateInlineItem::Running => "running",
+            ContainerStateInlineItem::Exited => "exited",
+        }
+    }
+}
+
+impl ContainerStateInlineItem {
+    /// Every variant, in the order of the spec.
+    pub const VARIANTS: &[Self] = &[
+        ContainerStateInlineItem::Running,
+        ContainerStateInlineItem::Exited,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::VARIANTS.iter().cloned()
+    }
+}
+
+impl std::fmt::Display for ContainerStateInlineItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+pub type Containers<'a> = Vec<Container<'a>>;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "Type")]
+pub enum Event<'a> {
+    #[serde(rename = "mount")]
+    Mount(#[serde(borrow)] Mount<'a>),
+    #[serde(rename = "size")]
+    Size(Size),
+}
+
+impl<'a> From<Mount<'a>> for Event<'a> {
+    fn from(value: Mount<'a>) -> Self {
+        Self::Mount(value)
+    }
+}
+
+impl<'a> From<Size> for Event<'a> {
+    fn from(value: Size) -> Self {
+        Self::Size(value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Mount<'a> {
+    #[serde(rename = "ReadOnly", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    #[serde(rename = "Source", borrow, default, deserialize_with = "deserialize_borrowed_option", skip_serializing_if = "Option::is_none")]
+    pub source: Option<std::borrow::Cow<'a, str>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Size {
+    #[serde(rename = "Rw", skip_serializing_if = "Option::is_none")]
+    pub rw: Option<i64>,
+}
+
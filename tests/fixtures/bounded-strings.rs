@@ -0,0 +1,95 @@
+
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    T: serde::de::DeserializeOwned,
+{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Container {
+    #[serde(rename = "Hostname")]
+    pub hostname: Option<String>,
+    #[serde(rename = "Id")]
+    pub id: ContainerId,
+    #[serde(rename = "Links", skip_serializing_if = "Option::is_none")]
+    pub links: Option<Vec<ContainerId>>,
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Identifier of a container.
+#[serde(try_from = "String")]
+pub struct ContainerId(String);
+
+impl ContainerId {
+    pub const MAX_LENGTH: usize = 12;
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_inner(self) -> String {
+        self.0
+    }
+}
+
+impl TryFrom<String> for ContainerId {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let length = value.chars().count();
+        if length > Self::MAX_LENGTH {
+            return Err(format!(
+                "ContainerId is at most {} characters long, got {length}",
+                Self::MAX_LENGTH
+            ));
+        }
+        Ok(Self(value))
+    }
+}
+
+impl TryFrom<&str> for ContainerId {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::try_from(value.to_string())
+    }
+}
+
+impl AsRef<str> for ContainerId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<ContainerId> for String {
+    fn from(value: ContainerId) -> Self {
+        value.0
+    }
+}
+
+impl std::str::FromStr for ContainerId {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::try_from(s)
+    }
+}
+
+impl std::fmt::Display for ContainerId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+pub type Hostname = String;
+
@@ -0,0 +1,112 @@
+// Required array and map fields are empty when missing from the payload. This is
+// deprecated and will change in the next release, generate with
+// `--lenient-required-collections false` to reject payloads missing them instead.
+
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    T: serde::de::DeserializeOwned,
+{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Container {
+    #[serde(rename = "Driver", default = "default_container_driver", skip_serializing_if = "Option::is_none")]
+    pub driver: Option<Box<str>>,
+    #[serde(rename = "Id")]
+    pub id: Box<str>,
+    #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<HashMap<String, Box<str>>>,
+    #[serde(rename = "Mounts", skip_serializing_if = "Option::is_none")]
+    pub mounts: Option<Vec<Mount>>,
+    #[serde(rename = "Names", default, deserialize_with = "deserialize_nonoptional_vec")]
+    pub names: Vec<Box<str>>,
+    #[serde(rename = "Size")]
+    pub size: Option<Size>,
+    #[serde(rename = "State", skip_serializing_if = "Option::is_none")]
+    pub state: Option<Box<str>>,
+}
+
+fn default_container_driver() -> Option<Box<str>> {
+    Some("overlay2".into())
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ContainerStateInlineItem {
+    #[serde(rename = "running")]
+Running,
+    #[serde(rename = "exited")]
+Exited,
+}
+
+impl AsRef<str> for ContainerStateInlineItem {
+    fn as_ref(&self) -> &str {
+        match self {
+            ContainerStateInlineItem::Running => "running",
+            ContainerStateInlineItem::Exited => "exited",
+        }
+    }
+}
+
+impl ContainerStateInlineItem {
+    /// Every variant, in the order of the spec.
+    pub const VARIANTS: &[Self] = &[
+        ContainerStateInlineItem::Running,
+        ContainerStateInlineItem::Exited,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::VARIANTS.iter().cloned()
+    }
+}
+
+impl std::fmt::Display for ContainerStateInlineItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+pub type Containers = Vec<Container>;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "Type")]
+pub enum Event {
+    #[serde(rename = "mount")]
+    Mount(Mount),
+    #[serde(rename = "size")]
+    Size(Size),
+}
+
+impl From<Mount> for Event {
+    fn from(value: Mount) -> Self {
+        Self::Mount(value)
+    }
+}
+
+impl From<Size> for Event {
+    fn from(value: Size) -> Self {
+        Self::Size(value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Mount {
+    #[serde(rename = "ReadOnly", skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    #[serde(rename = "Source", skip_serializing_if = "Option::is_none")]
+    pub source: Option<Box<str>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Size {
+    #[serde(rename = "Rw", skip_serializing_if = "Option::is_none")]
+    pub rw: Option<i64>,
+}
+
@@ -0,0 +1,45 @@
+
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    T: serde::de::DeserializeOwned,
+{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+/// Conversions between timestamps and the RFC 3339 strings they are (de)serialized as.
+pub mod datetime_util {
+    use chrono::{DateTime, Utc};
+
+    /// Parses an RFC 3339 timestamp like `2022-01-01T00:00:00Z`, converting it to UTC.
+    pub fn parse_rfc3339(s: &str) -> Result<DateTime<Utc>, chrono::ParseError> {
+        DateTime::parse_from_rfc3339(s).map(|dt| dt.with_timezone(&Utc))
+    }
+
+    /// Formats `dt` the way it is serialized, with as many fractional digits as it needs.
+    pub fn format_rfc3339(dt: &DateTime<Utc>) -> String {
+        dt.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Event {
+    #[serde(rename = "Action", skip_serializing_if = "Option::is_none")]
+    pub action: Option<String>,
+    #[serde(rename = "Expires", skip_serializing_if = "Option::is_none")]
+    /// Converted from and to strings with `datetime_util`.
+    pub expires: Option<DateTime<Utc>>,
+    #[serde(rename = "Time")]
+    /// When the event happened.
+    ///
+    /// Converted from and to strings with `datetime_util`.
+    pub time: DateTime<Utc>,
+}
+
@@ -0,0 +1,66 @@
+
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    T: serde::de::DeserializeOwned,
+{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Container {
+    #[serde(rename = "HostConfig", skip_serializing_if = "Option::is_none")]
+    /// Resources of the container.
+    pub host_config: Option<ContainerHostConfigInlineItem>,
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Resources of the container.
+pub struct ContainerHostConfigInlineItem {
+    #[serde(rename = "CpuShares")]
+    pub cpu_shares: isize,
+    #[serde(rename = "Memory", skip_serializing_if = "Option::is_none")]
+    pub memory: Option<i64>,
+    #[serde(rename = "Ulimits", skip_serializing_if = "Option::is_none")]
+    pub ulimits: Option<Vec<ContainerHostConfigInlineItemUlimitsInlineItem>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContainerHostConfigInlineItemUlimitsInlineItem {
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "Soft", skip_serializing_if = "Option::is_none")]
+    pub soft: Option<isize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Service {
+    #[serde(rename = "Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(rename = "Placement", skip_serializing_if = "Option::is_none")]
+    pub placement: Option<ServicePlacementInlineItem>,
+    #[serde(rename = "Resources", skip_serializing_if = "Option::is_none")]
+    /// Limits of every task of the service.
+    pub resources: Option<ServiceResourcesInlineItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ServicePlacementInlineItem {
+    #[serde(rename = "CpuShares", skip_serializing_if = "Option::is_none")]
+    pub cpu_shares: Option<isize>,
+    #[serde(rename = "Memory")]
+    pub memory: i64,
+}
+
+pub type ServiceResourcesInlineItem = ContainerHostConfigInlineItem;
+
+pub type ServiceResourcesInlineItemUlimitsInlineItem = ContainerHostConfigInlineItemUlimitsInlineItem;
+
@@ -0,0 +1,96 @@
+
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    T: serde::de::DeserializeOwned,
+{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+/// Conversions between timestamps and the RFC 3339 strings they are (de)serialized as.
+pub mod datetime_util {
+    use chrono::{DateTime, Utc};
+
+    /// Parses an RFC 3339 timestamp like `2022-01-01T00:00:00Z`, converting it to UTC.
+    pub fn parse_rfc3339(s: &str) -> Result<DateTime<Utc>, chrono::ParseError> {
+        DateTime::parse_from_rfc3339(s).map(|dt| dt.with_timezone(&Utc))
+    }
+
+    /// Formats `dt` the way it is serialized, with as many fractional digits as it needs.
+    pub fn format_rfc3339(dt: &DateTime<Utc>) -> String {
+        dt.to_rfc3339_opts(chrono::SecondsFormat::AutoSi, true)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+#[serde(transparent)]
+pub struct Annotations(pub serde_json::Map<String, Value>);
+
+impl From<serde_json::Map<String, Value>> for Annotations {
+    fn from(value: serde_json::Map<String, Value>) -> Self {
+        Self(value)
+    }
+}
+
+pub type Labels = HashMap<String, String>;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Location {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zone: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+/// Free form metadata of a resource, with a few well known keys.
+#[serde(transparent)]
+pub struct Metadata(pub serde_json::Map<String, Value>);
+
+impl From<serde_json::Map<String, Value>> for Metadata {
+    fn from(value: serde_json::Map<String, Value>) -> Self {
+        Self(value)
+    }
+}
+
+impl Metadata {
+    pub fn created_at(&self) -> Option<DateTime<Utc>> {
+        self.0.get("createdAt").and_then(|value| serde_json::from_value(value.clone()).ok())
+    }
+
+    pub fn location(&self) -> Option<Location> {
+        self.0.get("location").and_then(|value| serde_json::from_value(value.clone()).ok())
+    }
+
+    pub fn owner(&self) -> Option<&str> {
+        self.0.get("owner").and_then(Value::as_str)
+    }
+
+    pub fn public(&self) -> Option<bool> {
+        self.0.get("public").and_then(Value::as_bool)
+    }
+
+    pub fn size(&self) -> Option<i64> {
+        self.0.get("size").and_then(Value::as_i64)
+    }
+
+    pub fn tags(&self) -> Option<Vec<String>> {
+        self.0.get("tags").and_then(|value| serde_json::from_value(value.clone()).ok())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Resource {
+    pub annotations: Option<Annotations>,
+    pub id: String,
+    pub labels: Option<Labels>,
+    pub metadata: Option<Metadata>,
+}
+
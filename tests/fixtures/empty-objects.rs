@@ -0,0 +1,74 @@
+
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    T: serde::de::DeserializeOwned,
+{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+/// An inline object that can't have any keys, (de)serialized as `{}`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct EmptyObject;
+
+impl serde::Serialize for EmptyObject {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+        s.serialize_map(Some(0))?.end()
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for EmptyObject {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let map: HashMap<String, serde::de::IgnoredAny> = serde::Deserialize::deserialize(d)?;
+        if map.is_empty() {
+            Ok(Self)
+        } else {
+            Err(serde::de::Error::invalid_length(map.len(), &"an empty object"))
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContainerConfig {
+    #[serde(rename = "ExposedPorts", skip_serializing_if = "Option::is_none")]
+    /// An object mapping ports to an empty object.
+    pub exposed_ports: Option<HashMap<String, EmptyObject>>,
+    #[serde(rename = "Image", skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    #[serde(rename = "Managed")]
+    pub managed: Option<Marker>,
+    #[serde(rename = "Markers", skip_serializing_if = "Option::is_none")]
+    pub markers: Option<Vec<Marker>>,
+    #[serde(rename = "Volumes", skip_serializing_if = "Option::is_none")]
+    pub volumes: Option<HashMap<String, EmptyObject>>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+/// Marks a container as managed, it carries no data.
+pub struct Marker;
+
+impl serde::Serialize for Marker {
+    fn serialize<S: serde::Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        use serde::ser::SerializeMap;
+        s.serialize_map(Some(0))?.end()
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for Marker {
+    fn deserialize<D: serde::Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let map: HashMap<String, serde::de::IgnoredAny> = serde::Deserialize::deserialize(d)?;
+        if map.is_empty() {
+            Ok(Self)
+        } else {
+            Err(serde::de::Error::invalid_length(map.len(), &"an empty object"))
+        }
+    }
+}
+
@@ -0,0 +1,161 @@
+
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    T: serde::de::DeserializeOwned,
+{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Health {
+Healthy,
+Unhealthy,
+}
+
+impl AsRef<str> for Health {
+    fn as_ref(&self) -> &str {
+        match self {
+            Health::Healthy => "Healthy",
+            Health::Unhealthy => "Unhealthy",
+        }
+    }
+}
+
+impl Health {
+    /// Every variant, in the order of the spec.
+    pub const VARIANTS: &[Self] = &[
+        Health::Healthy,
+        Health::Unhealthy,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::VARIANTS.iter().cloned()
+    }
+}
+
+impl std::fmt::Display for Health {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Mode {
+    #[serde(rename = "host")]
+Host,
+    #[serde(rename = "bridge-v2")]
+BridgeV2,
+    #[serde(rename = "None")]
+None,
+}
+
+impl AsRef<str> for Mode {
+    fn as_ref(&self) -> &str {
+        match self {
+            Mode::Host => "host",
+            Mode::BridgeV2 => "bridge-v2",
+            Mode::None => "None",
+        }
+    }
+}
+
+impl Mode {
+    /// Every variant, in the order of the spec.
+    pub const VARIANTS: &[Self] = &[
+        Mode::Host,
+        Mode::BridgeV2,
+        Mode::None,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::VARIANTS.iter().cloned()
+    }
+}
+
+impl std::fmt::Display for Mode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Restart {
+No,
+OnFailure,
+UnlessStopped,
+}
+
+impl AsRef<str> for Restart {
+    fn as_ref(&self) -> &str {
+        match self {
+            Restart::No => "no",
+            Restart::OnFailure => "on-failure",
+            Restart::UnlessStopped => "unless-stopped",
+        }
+    }
+}
+
+impl Restart {
+    /// Every variant, in the order of the spec.
+    pub const VARIANTS: &[Self] = &[
+        Restart::No,
+        Restart::OnFailure,
+        Restart::UnlessStopped,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::VARIANTS.iter().cloned()
+    }
+}
+
+impl std::fmt::Display for Restart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum State {
+Running,
+Exited,
+OutOfMemory,
+}
+
+impl AsRef<str> for State {
+    fn as_ref(&self) -> &str {
+        match self {
+            State::Running => "RUNNING",
+            State::Exited => "EXITED",
+            State::OutOfMemory => "OUT_OF_MEMORY",
+        }
+    }
+}
+
+impl State {
+    /// Every variant, in the order of the spec.
+    pub const VARIANTS: &[Self] = &[
+        State::Running,
+        State::Exited,
+        State::OutOfMemory,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::VARIANTS.iter().cloned()
+    }
+}
+
+impl std::fmt::Display for State {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
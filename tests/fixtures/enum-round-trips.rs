@@ -0,0 +1,214 @@
+
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    T: serde::de::DeserializeOwned,
+{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Mount {
+    #[serde(rename = "Consistency", skip_serializing_if = "Option::is_none")]
+    pub consistency: Option<String>,
+    #[serde(rename = "Type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MountConsistencyInlineItem {
+Default,
+Consistent,
+Cached,
+Delegated,
+}
+
+impl AsRef<str> for MountConsistencyInlineItem {
+    fn as_ref(&self) -> &str {
+        match self {
+            MountConsistencyInlineItem::Default => "default",
+            MountConsistencyInlineItem::Consistent => "consistent",
+            MountConsistencyInlineItem::Cached => "cached",
+            MountConsistencyInlineItem::Delegated => "delegated",
+        }
+    }
+}
+
+impl MountConsistencyInlineItem {
+    /// Every variant, in the order of the spec.
+    pub const VARIANTS: &[Self] = &[
+        MountConsistencyInlineItem::Default,
+        MountConsistencyInlineItem::Consistent,
+        MountConsistencyInlineItem::Cached,
+        MountConsistencyInlineItem::Delegated,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::VARIANTS.iter().cloned()
+    }
+}
+
+impl std::fmt::Display for MountConsistencyInlineItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MountTypeInlineItem {
+Bind,
+Volume,
+Tmpfs,
+}
+
+impl AsRef<str> for MountTypeInlineItem {
+    fn as_ref(&self) -> &str {
+        match self {
+            MountTypeInlineItem::Bind => "bind",
+            MountTypeInlineItem::Volume => "volume",
+            MountTypeInlineItem::Tmpfs => "tmpfs",
+        }
+    }
+}
+
+impl MountTypeInlineItem {
+    /// Every variant, in the order of the spec.
+    pub const VARIANTS: &[Self] = &[
+        MountTypeInlineItem::Bind,
+        MountTypeInlineItem::Volume,
+        MountTypeInlineItem::Tmpfs,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::VARIANTS.iter().cloned()
+    }
+}
+
+impl std::fmt::Display for MountTypeInlineItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Restart {
+No,
+OnFailure,
+UnlessStopped,
+}
+
+impl AsRef<str> for Restart {
+    fn as_ref(&self) -> &str {
+        match self {
+            Restart::No => "no",
+            Restart::OnFailure => "on-failure",
+            Restart::UnlessStopped => "unless-stopped",
+        }
+    }
+}
+
+impl Restart {
+    /// Every variant, in the order of the spec.
+    pub const VARIANTS: &[Self] = &[
+        Restart::No,
+        Restart::OnFailure,
+        Restart::UnlessStopped,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::VARIANTS.iter().cloned()
+    }
+}
+
+impl std::fmt::Display for Restart {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum State {
+Running,
+Exited,
+OutOfMemory,
+}
+
+impl AsRef<str> for State {
+    fn as_ref(&self) -> &str {
+        match self {
+            State::Running => "running",
+            State::Exited => "exited",
+            State::OutOfMemory => "out-of-memory",
+        }
+    }
+}
+
+impl State {
+    /// Every variant, in the order of the spec.
+    pub const VARIANTS: &[Self] = &[
+        State::Running,
+        State::Exited,
+        State::OutOfMemory,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::VARIANTS.iter().cloned()
+    }
+}
+
+impl std::fmt::Display for State {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod enum_round_trips {
+    use super::*;
+
+    #[test]
+    fn mount_consistency_inline_item_round_trip() {
+        for value in [MountConsistencyInlineItem::Default, MountConsistencyInlineItem::Consistent, MountConsistencyInlineItem::Cached, MountConsistencyInlineItem::Delegated] {
+            let json = serde_json::to_string(&value).unwrap();
+            assert_eq!(json, serde_json::to_string(value.as_ref()).unwrap());
+            assert_eq!(serde_json::from_str::<MountConsistencyInlineItem>(&json).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn mount_type_inline_item_round_trip() {
+        for value in [MountTypeInlineItem::Bind, MountTypeInlineItem::Volume, MountTypeInlineItem::Tmpfs] {
+            let json = serde_json::to_string(&value).unwrap();
+            assert_eq!(json, serde_json::to_string(value.as_ref()).unwrap());
+            assert_eq!(serde_json::from_str::<MountTypeInlineItem>(&json).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn restart_round_trip() {
+        for value in [Restart::No, Restart::OnFailure, Restart::UnlessStopped] {
+            let json = serde_json::to_string(&value).unwrap();
+            assert_eq!(json, serde_json::to_string(value.as_ref()).unwrap());
+            assert_eq!(serde_json::from_str::<Restart>(&json).unwrap(), value);
+        }
+    }
+
+    #[test]
+    fn state_round_trip() {
+        for value in [State::Running, State::Exited, State::OutOfMemory] {
+            let json = serde_json::to_string(&value).unwrap();
+            assert_eq!(json, serde_json::to_string(value.as_ref()).unwrap());
+            assert_eq!(serde_json::from_str::<State>(&json).unwrap(), value);
+        }
+    }
+}
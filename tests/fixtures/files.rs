@@ -0,0 +1,33 @@
+// Required array and map fields are empty when missing from the payload. This is
+// deprecated and will change in the next release, generate with
+// `--lenient-required-collections false` to reject payloads missing them instead.
+
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    T: serde::de::DeserializeOwned,
+{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+/// the archive of the container's filesystem
+pub type ContainerArchive200Response = Vec<u8>;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Form parameters of the `PutContainerArchive` operation.
+///
+/// Content type: multipart/form-data
+pub struct PutContainerArchiveForm {
+    #[serde(default, deserialize_with = "deserialize_nonoptional_vec")]
+    /// A tar archive to extract into the container.
+    pub archive: Vec<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub overwrite: Option<bool>,
+}
+
@@ -0,0 +1,156 @@
+
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    T: serde::de::DeserializeOwned,
+{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+/// Compares and hashes floats as map keys: `0.0` and `-0.0` are equal, and so are all `NaN`s.
+trait FloatKey {
+    fn key_eq(&self, other: &Self) -> bool;
+    fn key_hash<H: std::hash::Hasher>(&self, state: &mut H);
+}
+
+impl FloatKey for f32 {
+    fn key_eq(&self, other: &Self) -> bool {
+        self == other || (self.is_nan() && other.is_nan())
+    }
+
+    fn key_hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let bits = if self.is_nan() {
+            f32::NAN.to_bits()
+        } else if *self == 0.0 {
+            0
+        } else {
+            self.to_bits()
+        };
+        state.write_u32(bits);
+    }
+}
+
+impl FloatKey for f64 {
+    fn key_eq(&self, other: &Self) -> bool {
+        self == other || (self.is_nan() && other.is_nan())
+    }
+
+    fn key_hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        let bits = if self.is_nan() {
+            f64::NAN.to_bits()
+        } else if *self == 0.0 {
+            0
+        } else {
+            self.to_bits()
+        };
+        state.write_u64(bits);
+    }
+}
+
+impl<T: FloatKey> FloatKey for Option<T> {
+    fn key_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Some(value), Some(other)) => value.key_eq(other),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    fn key_hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        match self {
+            Some(value) => {
+                state.write_u8(1);
+                value.key_hash(state);
+            }
+            None => state.write_u8(0),
+        }
+    }
+}
+
+impl<T: FloatKey> FloatKey for Vec<T> {
+    fn key_eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().zip(other).all(|(a, b)| a.key_eq(b))
+    }
+
+    fn key_hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        state.write_usize(self.len());
+        for value in self {
+            value.key_hash(state);
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Plain {
+    #[serde(rename = "Ratio", skip_serializing_if = "Option::is_none")]
+    pub ratio: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+/// A point used as a cache key.
+pub struct Point {
+    #[serde(rename = "Label", skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(rename = "X")]
+    pub x: f64,
+    #[serde(rename = "Y")]
+    pub y: f64,
+}
+
+/// Floats are compared with `FloatKey`, all `NaN`s are equal.
+impl PartialEq for Point {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label
+            && FloatKey::key_eq(&self.x, &other.x)
+            && FloatKey::key_eq(&self.y, &other.y)
+    }
+}
+
+impl Eq for Point {}
+
+/// Floats are hashed with `FloatKey`, agreeing with `PartialEq`.
+impl std::hash::Hash for Point {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.label, state);
+        FloatKey::key_hash(&self.x, state);
+        FloatKey::key_hash(&self.y, state);
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Sample {
+    #[serde(rename = "Id")]
+    pub id: i64,
+    #[serde(rename = "Values", skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<f64>>,
+    #[serde(rename = "Weight", skip_serializing_if = "Option::is_none")]
+    pub weight: Option<f32>,
+}
+
+/// Floats are compared with `FloatKey`, all `NaN`s are equal.
+impl PartialEq for Sample {
+    fn eq(&self, other: &Self) -> bool {
+        self.id == other.id
+            && FloatKey::key_eq(&self.values, &other.values)
+            && FloatKey::key_eq(&self.weight, &other.weight)
+    }
+}
+
+impl Eq for Sample {}
+
+/// Floats are hashed with `FloatKey`, agreeing with `PartialEq`.
+impl std::hash::Hash for Sample {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::hash::Hash::hash(&self.id, state);
+        FloatKey::key_hash(&self.values, state);
+        FloatKey::key_hash(&self.weight, state);
+    }
+}
+
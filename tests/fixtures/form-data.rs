@@ -0,0 +1,206 @@
+// Required array and map fields are empty when missing from the payload. This is
+// deprecated and will change in the next release, generate with
+// `--lenient-required-collections false` to reject payloads missing them instead.
+
+trait QueryCollection<T> {
+    fn values(&self) -> &[T];
+
+    fn from_values(values: Option<Vec<T>>) -> Self;
+}
+
+impl<T> QueryCollection<T> for Vec<T> {
+    fn values(&self) -> &[T] {
+        self
+    }
+
+    fn from_values(values: Option<Vec<T>>) -> Self {
+        values.unwrap_or_default()
+    }
+}
+
+impl<T> QueryCollection<T> for Option<Vec<T>> {
+    fn values(&self) -> &[T] {
+        self.as_deref().unwrap_or_default()
+    }
+
+    fn from_values(values: Option<Vec<T>>) -> Self {
+        values
+    }
+}
+
+fn serialize_joined<S: serde::Serializer, T: std::fmt::Display>(
+    values: &[T],
+    separator: &str,
+    s: S,
+) -> Result<S::Ok, S::Error> {
+    let values: Vec<_> = values.iter().map(|v| v.to_string()).collect();
+    s.serialize_str(&values.join(separator))
+}
+
+/// A single value of a joined collection, deserialized as a number or a boolean if it parses as
+/// one and as a string otherwise.
+struct JoinedValue<'a, E>(&'a str, std::marker::PhantomData<E>);
+
+impl<'de, E: serde::de::Error> serde::de::Deserializer<'de> for JoinedValue<'_, E> {
+    type Error = E;
+
+    fn deserialize_any<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {
+        if let Ok(v) = self.0.parse() {
+            visitor.visit_bool(v)
+        } else if let Ok(v) = self.0.parse() {
+            visitor.visit_i64(v)
+        } else if let Ok(v) = self.0.parse() {
+            visitor.visit_u64(v)
+        } else if let Ok(v) = self.0.parse() {
+            visitor.visit_f64(v)
+        } else {
+            visitor.visit_str(self.0)
+        }
+    }
+
+    fn deserialize_str<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {
+        visitor.visit_str(self.0)
+    }
+
+    fn deserialize_string<V: serde::de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, E> {
+        visitor.visit_str(self.0)
+    }
+
+    fn deserialize_enum<V: serde::de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, E> {
+        let d: serde::de::value::StrDeserializer<E> =
+            serde::de::IntoDeserializer::into_deserializer(self.0);
+        d.deserialize_enum(name, variants, visitor)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char bytes byte_buf option unit
+        unit_struct newtype_struct seq tuple tuple_struct map struct identifier ignored_any
+    }
+}
+
+fn deserialize_joined<'de, D, C, T>(separator: &str, d: D) -> Result<C, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    C: QueryCollection<T>,
+    T: serde::de::DeserializeOwned,
+{
+    let joined: Option<String> = serde::de::Deserialize::deserialize(d)?;
+    let values = match joined.as_deref() {
+        None => None,
+        Some("") => Some(vec![]),
+        Some(joined) => Some(
+            joined
+                .split(separator)
+                .map(|v| T::deserialize(JoinedValue(v, std::marker::PhantomData)))
+                .collect::<Result<_, _>>()?,
+        ),
+    };
+    Ok(C::from_values(values))
+}
+
+fn serialize_csv<S: serde::Serializer, T: std::fmt::Display>(
+    v: &impl QueryCollection<T>,
+    s: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_joined(v.values(), ",", s)
+}
+
+fn deserialize_csv<'de, D, C, T>(d: D) -> Result<C, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    C: QueryCollection<T>,
+    T: serde::de::DeserializeOwned,
+{
+    deserialize_joined(",", d)
+}
+
+fn serialize_ssv<S: serde::Serializer, T: std::fmt::Display>(
+    v: &impl QueryCollection<T>,
+    s: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_joined(v.values(), " ", s)
+}
+
+fn deserialize_ssv<'de, D, C, T>(d: D) -> Result<C, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    C: QueryCollection<T>,
+    T: serde::de::DeserializeOwned,
+{
+    deserialize_joined(" ", d)
+}
+
+fn serialize_tsv<S: serde::Serializer, T: std::fmt::Display>(
+    v: &impl QueryCollection<T>,
+    s: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_joined(v.values(), "\t", s)
+}
+
+fn deserialize_tsv<'de, D, C, T>(d: D) -> Result<C, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    C: QueryCollection<T>,
+    T: serde::de::DeserializeOwned,
+{
+    deserialize_joined("\t", d)
+}
+
+fn serialize_pipes<S: serde::Serializer, T: std::fmt::Display>(
+    v: &impl QueryCollection<T>,
+    s: S,
+) -> Result<S::Ok, S::Error> {
+    serialize_joined(v.values(), "|", s)
+}
+
+fn deserialize_pipes<'de, D, C, T>(d: D) -> Result<C, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    C: QueryCollection<T>,
+    T: serde::de::DeserializeOwned,
+{
+    deserialize_joined("|", d)
+}
+
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    T: serde::de::DeserializeOwned,
+{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Form parameters of the `ImageLoad` operation.
+///
+/// Content type: multipart/form-data
+pub struct ImageLoadForm {
+    #[serde(default, deserialize_with = "deserialize_nonoptional_vec")]
+    /// The image tarball.
+    pub image: Vec<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub quiet: Option<bool>,
+    #[serde(default, deserialize_with = "deserialize_csv", serialize_with = "serialize_csv", skip_serializing_if = "Option::is_none")]
+    pub tags: Option<Vec<String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Form parameters of the `SystemAuth` operation.
+///
+/// Content type: application/x-www-form-urlencoded
+pub struct SystemAuthForm {
+    pub password: String,
+    pub username: String,
+}
+
@@ -0,0 +1,150 @@
+
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    T: serde::de::DeserializeOwned,
+{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+use container::Container;
+
+pub type ContainerList = Vec<Container>;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ErrorResponse {
+    pub message: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum HealthState {
+    #[serde(rename = "starting")]
+Starting,
+    #[serde(rename = "healthy")]
+Healthy,
+    #[serde(rename = "unhealthy")]
+Unhealthy,
+}
+
+impl AsRef<str> for HealthState {
+    fn as_ref(&self) -> &str {
+        match self {
+            HealthState::Starting => "starting",
+            HealthState::Healthy => "healthy",
+            HealthState::Unhealthy => "unhealthy",
+        }
+    }
+}
+
+impl HealthState {
+    /// Every variant, in the order of the spec.
+    pub const VARIANTS: &[Self] = &[
+        HealthState::Starting,
+        HealthState::Healthy,
+        HealthState::Unhealthy,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::VARIANTS.iter().cloned()
+    }
+}
+
+impl std::fmt::Display for HealthState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+pub mod container {
+    use super::*;
+    use super::network::EndpointSettings;
+    use super::mount::MountPoint;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct Container {
+        #[serde(rename = "Id")]
+        pub id: String,
+        #[serde(rename = "Mounts", skip_serializing_if = "Option::is_none")]
+        pub mounts: Option<Vec<MountPoint>>,
+        #[serde(rename = "Network")]
+        pub network: Option<EndpointSettings>,
+        #[serde(rename = "State", skip_serializing_if = "Option::is_none")]
+        pub state: Option<ContainerStateInlineItem>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct ContainerStateInlineItem {
+        #[serde(rename = "Health")]
+        pub health: Option<String>,
+        #[serde(rename = "Status", skip_serializing_if = "Option::is_none")]
+        pub status: Option<String>,
+    }
+}
+
+pub mod mount {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct MountPoint {
+        #[serde(rename = "Source", skip_serializing_if = "Option::is_none")]
+        pub source: Option<String>,
+        #[serde(rename = "Type")]
+        pub type_: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub enum MountType {
+        #[serde(rename = "bind")]
+    Bind,
+        #[serde(rename = "volume")]
+    Volume,
+        #[serde(rename = "tmpfs")]
+    Tmpfs,
+    }
+
+    impl AsRef<str> for MountType {
+        fn as_ref(&self) -> &str {
+            match self {
+                MountType::Bind => "bind",
+                MountType::Volume => "volume",
+                MountType::Tmpfs => "tmpfs",
+            }
+        }
+    }
+
+    impl MountType {
+        /// Every variant, in the order of the spec.
+        pub const VARIANTS: &[Self] = &[
+            MountType::Bind,
+            MountType::Volume,
+            MountType::Tmpfs,
+        ];
+
+        pub fn iter() -> impl Iterator<Item = Self> {
+            Self::VARIANTS.iter().cloned()
+        }
+    }
+
+    impl std::fmt::Display for MountType {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "{}", self.as_ref())
+        }
+    }
+}
+
+pub mod network {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct EndpointSettings {
+        #[serde(rename = "IPAddress", skip_serializing_if = "Option::is_none")]
+        pub ip_address: Option<String>,
+    }
+}
+
@@ -0,0 +1,56 @@
+
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    T: serde::de::DeserializeOwned,
+{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+use inline::ContainerMountsInlineItem;
+use inline::ContainerStateInlineItem;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Container {
+    #[serde(rename = "Config", skip_serializing_if = "Option::is_none")]
+    pub config: Option<ContainerConfigInlineItem>,
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "Mounts", skip_serializing_if = "Option::is_none")]
+    pub mounts: Option<Vec<ContainerMountsInlineItem>>,
+    #[serde(rename = "State", skip_serializing_if = "Option::is_none")]
+    pub state: Option<ContainerStateInlineItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContainerConfigInlineItem {
+    #[serde(rename = "Env", skip_serializing_if = "Option::is_none")]
+    pub env: Option<Vec<String>>,
+    #[serde(rename = "User", skip_serializing_if = "Option::is_none")]
+    pub user: Option<String>,
+}
+
+pub mod inline {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct ContainerMountsInlineItem {
+        #[serde(rename = "Source", skip_serializing_if = "Option::is_none")]
+        pub source: Option<String>,
+    }
+
+    #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+    pub struct ContainerStateInlineItem {
+        #[serde(rename = "Pid", skip_serializing_if = "Option::is_none")]
+        pub pid: Option<isize>,
+        #[serde(rename = "Running", skip_serializing_if = "Option::is_none")]
+        pub running: Option<bool>,
+    }
+}
+
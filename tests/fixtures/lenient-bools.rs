@@ -0,0 +1,79 @@
+
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    T: serde::de::DeserializeOwned,
+{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_lenient_bool<'de, D: serde::de::Deserializer<'de>>(d: D) -> Result<bool, D::Error> {
+    d.deserialize_any(LenientBoolVisitor)
+}
+
+fn deserialize_lenient_option_bool<'de, D: serde::de::Deserializer<'de>>(
+    d: D,
+) -> Result<Option<bool>, D::Error> {
+    #[derive(Deserialize)]
+    struct Wrapper(#[serde(deserialize_with = "deserialize_lenient_bool")] bool);
+
+    Option::<Wrapper>::deserialize(d).map(|v| v.map(|v| v.0))
+}
+
+struct LenientBoolVisitor;
+
+impl serde::de::Visitor<'_> for LenientBoolVisitor {
+    type Value = bool;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("a boolean, a string of one or a number")
+    }
+
+    fn visit_bool<E: serde::de::Error>(self, v: bool) -> Result<bool, E> {
+        Ok(v)
+    }
+
+    fn visit_i64<E: serde::de::Error>(self, v: i64) -> Result<bool, E> {
+        Ok(v != 0)
+    }
+
+    fn visit_u64<E: serde::de::Error>(self, v: u64) -> Result<bool, E> {
+        Ok(v != 0)
+    }
+
+    fn visit_f64<E: serde::de::Error>(self, v: f64) -> Result<bool, E> {
+        Ok(v != 0.0)
+    }
+
+    fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<bool, E> {
+        match v {
+            "true" | "1" => Ok(true),
+            "false" | "0" => Ok(false),
+            _ => Err(E::invalid_value(serde::de::Unexpected::Str(v), &self)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Port {
+    #[serde(rename = "Internal", default = "default_port_internal", deserialize_with = "deserialize_lenient_option_bool", skip_serializing_if = "Option::is_none")]
+    pub internal: Option<bool>,
+    #[serde(rename = "Ipv6", default, deserialize_with = "deserialize_lenient_option_bool", skip_serializing_if = "Option::is_none")]
+    pub ipv_6: Option<bool>,
+    #[serde(rename = "Published", deserialize_with = "deserialize_lenient_bool")]
+    pub published: bool,
+    #[serde(rename = "Strict", skip_serializing_if = "Option::is_none")]
+    pub strict: Option<bool>,
+}
+
+fn default_port_internal() -> Option<bool> {
+    Some(false)
+}
+
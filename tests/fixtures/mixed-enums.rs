@@ -0,0 +1,53 @@
+
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    T: serde::de::DeserializeOwned,
+{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Number of CPUs, or a keyword.
+///
+/// One of `1`, `2`, `"auto"`, `"max"`.
+#[serde(untagged)]
+pub enum CpuLimit {
+    Int(i64),
+    Str(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// One of `1`, `0.5`, `"none"`.
+#[serde(untagged)]
+pub enum Ratio {
+    Int(i64),
+    Float(f64),
+    Str(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Task {
+    #[serde(rename = "Cpus")]
+    pub cpus: Option<Value>,
+    #[serde(rename = "Retries", skip_serializing_if = "Option::is_none")]
+    /// Retry count, or unlimited.
+    ///
+    /// One of `0`, `3`, `"unlimited"`.
+    pub retries: Option<Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// One of `"on"`, `"off"`, `true`, `false`.
+#[serde(untagged)]
+pub enum Toggle {
+    Str(String),
+    Bool(bool),
+}
+
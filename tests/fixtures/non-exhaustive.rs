@@ -0,0 +1,94 @@
+// Required array and map fields are empty when missing from the payload. This is
+// deprecated and will change in the next release, generate with
+// `--lenient-required-collections false` to reject payloads missing them instead.
+
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    T: serde::de::DeserializeOwned,
+{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+/// A container.
+pub struct Container {
+    #[serde(rename = "Id")]
+    pub id: String,
+    #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<HashMap<String, String>>,
+    #[serde(rename = "Names", default, deserialize_with = "deserialize_nonoptional_vec")]
+    pub names: Vec<String>,
+    #[serde(rename = "Restarts", skip_serializing_if = "Option::is_none")]
+    pub restarts: Option<i32>,
+    #[serde(rename = "Status")]
+    pub status: String,
+}
+
+impl Container {
+    pub fn new(id: String, names: Vec<String>, status: String) -> Self {
+        Self {
+            id,
+            labels: None,
+            names,
+            restarts: None,
+            status,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub enum ContainerStatus {
+    #[serde(rename = "running")]
+Running,
+    #[serde(rename = "exited")]
+Exited,
+}
+
+impl AsRef<str> for ContainerStatus {
+    fn as_ref(&self) -> &str {
+        match self {
+            ContainerStatus::Running => "running",
+            ContainerStatus::Exited => "exited",
+        }
+    }
+}
+
+impl ContainerStatus {
+    /// Every variant, in the order of the spec.
+    pub const VARIANTS: &[Self] = &[
+        ContainerStatus::Running,
+        ContainerStatus::Exited,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::VARIANTS.iter().cloned()
+    }
+}
+
+impl std::fmt::Display for ContainerStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+impl std::str::FromStr for ContainerStatus {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "running" => Ok(ContainerStatus::Running),
+            "exited" => Ok(ContainerStatus::Exited),
+            _ => Err(format!("unknown ContainerStatus value `{s}`")),
+        }
+    }
+}
+
@@ -0,0 +1,94 @@
+
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    T: serde::de::DeserializeOwned,
+{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ContainerRestartPolicyInlineItem {
+    #[serde(rename = "no")]
+No,
+    #[serde(rename = "always")]
+Always,
+    #[serde(rename = "on-failure")]
+OnFailure,
+}
+
+impl AsRef<str> for ContainerRestartPolicyInlineItem {
+    fn as_ref(&self) -> &str {
+        match self {
+            ContainerRestartPolicyInlineItem::No => "no",
+            ContainerRestartPolicyInlineItem::Always => "always",
+            ContainerRestartPolicyInlineItem::OnFailure => "on-failure",
+        }
+    }
+}
+
+impl ContainerRestartPolicyInlineItem {
+    /// Every variant, in the order of the spec.
+    pub const VARIANTS: &[Self] = &[
+        ContainerRestartPolicyInlineItem::No,
+        ContainerRestartPolicyInlineItem::Always,
+        ContainerRestartPolicyInlineItem::OnFailure,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::VARIANTS.iter().cloned()
+    }
+}
+
+impl std::fmt::Display for ContainerRestartPolicyInlineItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// State of a container.
+pub enum ContainerState {
+    #[serde(rename = "created")]
+Created,
+    #[serde(rename = "running")]
+Running,
+    #[serde(rename = "exited")]
+Exited,
+}
+
+impl AsRef<str> for ContainerState {
+    fn as_ref(&self) -> &str {
+        match self {
+            ContainerState::Created => "created",
+            ContainerState::Running => "running",
+            ContainerState::Exited => "exited",
+        }
+    }
+}
+
+impl ContainerState {
+    /// Every variant, in the order of the spec.
+    pub const VARIANTS: &[Self] = &[
+        ContainerState::Created,
+        ContainerState::Running,
+        ContainerState::Exited,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::VARIANTS.iter().cloned()
+    }
+}
+
+impl std::fmt::Display for ContainerState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
@@ -0,0 +1,63 @@
+/// A parameter of an operation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParamDef {
+    pub name: &'static str,
+    /// Where the parameter goes, `path`, `query`, `header`, `body` or `formData`.
+    pub location: &'static str,
+    pub required: bool,
+    /// Type name of the generated model of a body parameter.
+    pub model: Option<&'static str>,
+}
+
+/// An operation of the API together with the type names of the models generated for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OperationDef {
+    pub method: &'static str,
+    pub path: &'static str,
+    pub operation_id: Option<&'static str>,
+    pub params: &'static [ParamDef],
+    /// Type name of the model generated for the query parameters of the operation.
+    pub query_model: Option<&'static str>,
+    /// `(status, type name)` of every response with a body.
+    pub response_models: &'static [(u16, &'static str)],
+    /// Type name of the model of the `default` response.
+    pub default_response: Option<&'static str>,
+}
+
+pub const OPERATIONS: &[OperationDef] = &[
+    OperationDef {
+        method: "post",
+        path: "/containers/create",
+        operation_id: Some("ContainerCreate"),
+        params: &[
+            ParamDef { name: "body", location: "body", required: true, model: Some("ContainerCreateBodyParam") },
+            ParamDef { name: "X-Registry-Auth", location: "header", required: false, model: None },
+        ],
+        query_model: None,
+        response_models: &[(201, "ContainerCreate201Response")],
+        default_response: None,
+    },
+    OperationDef {
+        method: "get",
+        path: "/containers/json",
+        operation_id: Some("ContainerList"),
+        params: &[
+            ParamDef { name: "all", location: "query", required: false, model: None },
+            ParamDef { name: "limit", location: "query", required: false, model: None },
+        ],
+        query_model: Some("ContainerListParams"),
+        response_models: &[(200, "ContainerList200Response")],
+        default_response: Some("ErrorResponse"),
+    },
+    OperationDef {
+        method: "delete",
+        path: "/containers/{id}",
+        operation_id: Some("ContainerDelete"),
+        params: &[
+            ParamDef { name: "id", location: "path", required: true, model: None },
+        ],
+        query_model: None,
+        response_models: &[(404, "ErrorResponse")],
+        default_response: None,
+    },
+];
@@ -0,0 +1,178 @@
+
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    T: serde::de::DeserializeOwned,
+{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, PartialOrd, Eq, Ord)]
+/// Lifecycle of a container.
+pub enum ContainerState {
+    #[serde(rename = "created")]
+Created,
+    #[serde(rename = "running")]
+Running,
+    #[serde(rename = "paused")]
+Paused,
+    #[serde(rename = "exited")]
+Exited,
+}
+
+impl AsRef<str> for ContainerState {
+    fn as_ref(&self) -> &str {
+        match self {
+            ContainerState::Created => "created",
+            ContainerState::Running => "running",
+            ContainerState::Paused => "paused",
+            ContainerState::Exited => "exited",
+        }
+    }
+}
+
+impl ContainerState {
+    /// Every variant, in the order of the spec.
+    pub const VARIANTS: &[Self] = &[
+        ContainerState::Created,
+        ContainerState::Running,
+        ContainerState::Paused,
+        ContainerState::Exited,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::VARIANTS.iter().cloned()
+    }
+
+    /// Position of the value in the spec, which variants are ordered by.
+    pub fn ordinal(&self) -> usize {
+        match self {
+            ContainerState::Created => 0,
+            ContainerState::Running => 1,
+            ContainerState::Paused => 2,
+            ContainerState::Exited => 3,
+        }
+    }
+}
+
+impl std::fmt::Display for ContainerState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, PartialOrd, Eq, Ord)]
+pub enum Severity {
+    #[serde(rename = "low")]
+Low,
+    #[serde(rename = "medium")]
+Medium,
+    #[serde(rename = "high")]
+High,
+    #[serde(rename = "critical")]
+Critical,
+}
+
+impl AsRef<str> for Severity {
+    fn as_ref(&self) -> &str {
+        match self {
+            Severity::Low => "low",
+            Severity::Medium => "medium",
+            Severity::High => "high",
+            Severity::Critical => "critical",
+        }
+    }
+}
+
+impl Severity {
+    /// Every variant, in the order of the spec.
+    pub const VARIANTS: &[Self] = &[
+        Severity::Low,
+        Severity::Medium,
+        Severity::High,
+        Severity::Critical,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::VARIANTS.iter().cloned()
+    }
+
+    /// Position of the value in the spec, which variants are ordered by.
+    pub fn ordinal(&self) -> usize {
+        match self {
+            Severity::Low => 0,
+            Severity::Medium => 1,
+            Severity::High => 2,
+            Severity::Critical => 3,
+        }
+    }
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Task {
+    #[serde(rename = "Priority", skip_serializing_if = "Option::is_none")]
+    pub priority: Option<String>,
+    #[serde(rename = "State")]
+    pub state: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, PartialOrd, Eq, Ord)]
+pub enum TaskPriorityInlineItem {
+    #[serde(rename = "urgent")]
+Urgent,
+    #[serde(rename = "normal")]
+Normal,
+    #[serde(rename = "deferred")]
+Deferred,
+}
+
+impl AsRef<str> for TaskPriorityInlineItem {
+    fn as_ref(&self) -> &str {
+        match self {
+            TaskPriorityInlineItem::Urgent => "urgent",
+            TaskPriorityInlineItem::Normal => "normal",
+            TaskPriorityInlineItem::Deferred => "deferred",
+        }
+    }
+}
+
+impl TaskPriorityInlineItem {
+    /// Every variant, in the order of the spec.
+    pub const VARIANTS: &[Self] = &[
+        TaskPriorityInlineItem::Urgent,
+        TaskPriorityInlineItem::Normal,
+        TaskPriorityInlineItem::Deferred,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::VARIANTS.iter().cloned()
+    }
+
+    /// Position of the value in the spec, which variants are ordered by.
+    pub fn ordinal(&self) -> usize {
+        match self {
+            TaskPriorityInlineItem::Urgent => 0,
+            TaskPriorityInlineItem::Normal => 1,
+            TaskPriorityInlineItem::Deferred => 2,
+        }
+    }
+}
+
+impl std::fmt::Display for TaskPriorityInlineItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
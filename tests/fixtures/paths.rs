@@ -0,0 +1,42 @@
+pub mod paths {
+    fn encode_path_segment(segment: &str) -> String {
+        let mut encoded = String::with_capacity(segment.len());
+        for byte in segment.bytes() {
+            match byte {
+                b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                    encoded.push(byte as char)
+                }
+                _ => encoded.push_str(&format!("%{byte:02X}")),
+            }
+        }
+        encoded
+    }
+
+    /// `get /_ping`
+    pub const SYSTEM_PING: &str = "/_ping";
+
+    pub fn system_ping_path() -> String {
+        SYSTEM_PING.to_string()
+    }
+
+    /// `get /containers/{id}/json`
+    pub const CONTAINER_INSPECT: &str = "/containers/{id}/json";
+
+    pub fn container_inspect_path(id: &str) -> String {
+        format!("/containers/{}/json", encode_path_segment(id))
+    }
+
+    /// `get /containers/{id}/logs`
+    pub const CONTAINER_LOGS: &str = "/containers/{id}/logs";
+
+    pub fn container_logs_path(id: &str) -> String {
+        format!("/containers/{}/logs", encode_path_segment(id))
+    }
+
+    /// `post /containers/{id}/resize/{height}x{width}`
+    pub const POST_CONTAINERS_ID_RESIZE_HEIGHT_X_WIDTH: &str = "/containers/{id}/resize/{height}x{width}";
+
+    pub fn post_containers_id_resize_height_x_width_path(id: &str, height: u32, width: u32) -> String {
+        format!("/containers/{}/resize/{}x{}", encode_path_segment(id), encode_path_segment(&height.to_string()), encode_path_segment(&width.to_string()))
+    }
+}
@@ -0,0 +1,59 @@
+
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    T: serde::de::DeserializeOwned,
+{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContainerConfig {
+    #[serde(rename = "Annotations", skip_serializing_if = "Option::is_none")]
+    /// Keys match one of the patterns `^x-`, `^[a-z]+$`.
+    pub annotations: Option<HashMap<String, Value>>,
+    #[serde(rename = "Devices", skip_serializing_if = "Option::is_none")]
+    /// Keys match the pattern `^/dev/`.
+    pub devices: Option<HashMap<String, ContainerConfigDevicesInlineItem>>,
+    #[serde(rename = "ExposedPorts")]
+    pub exposed_ports: Option<ExposedPorts>,
+    #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
+    /// User-defined metadata.
+    ///
+    /// Keys match the pattern `^[a-z0-9.-]+$`.
+    pub labels: Option<HashMap<String, String>>,
+    #[serde(rename = "PortBindings", skip_serializing_if = "Option::is_none")]
+    /// Keys match the pattern `^\d+/(tcp|udp|sctp)$`.
+    pub port_bindings: Option<HashMap<String, PortBinding>>,
+    #[serde(rename = "Sysctls", skip_serializing_if = "Option::is_none")]
+    /// Keys match one of the patterns `^net\.`, `^kernel\.`.
+    pub sysctls: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContainerConfigDevicesInlineItem {
+    #[serde(rename = "Permissions", skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<String>,
+    #[serde(rename = "Target", skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+}
+
+/// Ports the container listens on.
+///
+/// Keys match the pattern `^\d+/(tcp|udp|sctp)$`.
+pub type ExposedPorts = HashMap<String, Value>;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PortBinding {
+    #[serde(rename = "HostIp", skip_serializing_if = "Option::is_none")]
+    pub host_ip: Option<String>,
+    #[serde(rename = "HostPort", skip_serializing_if = "Option::is_none")]
+    pub host_port: Option<String>,
+}
+
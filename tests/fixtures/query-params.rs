@@ -0,0 +1,216 @@
+
+/// Percent-encodes everything but the unreserved characters of RFC 3986.
+fn encode_query_component(component: &str) -> String {
+    let mut encoded = String::with_capacity(component.len());
+    for byte in component.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+/// Joins the fields of `params` into a query string. Arrays repeat their key for every value and
+/// empty values of the fields in `bare_keys` are sent as the key alone.
+fn query_string(params: &impl serde::Serialize, bare_keys: &[&str]) -> String {
+    let Ok(serde_json::Value::Object(fields)) = serde_json::to_value(params) else {
+        return String::new();
+    };
+    let mut pairs = vec![];
+    for (key, value) in fields {
+        let values = match value {
+            serde_json::Value::Array(values) => values,
+            value => vec![value],
+        };
+        for value in values {
+            let value = match value {
+                serde_json::Value::Null => continue,
+                serde_json::Value::String(s) => s,
+                value => value.to_string(),
+            };
+            if value.is_empty() && bare_keys.contains(&key.as_str()) {
+                pairs.push(encode_query_component(&key));
+            } else {
+                pairs.push(format!(
+                    "{}={}",
+                    encode_query_component(&key),
+                    encode_query_component(&value)
+                ));
+            }
+        }
+    }
+    pairs.join("&")
+}
+
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    T: serde::de::DeserializeOwned,
+{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Query parameters of the `ContainerList` operation.
+pub struct ContainerListParams {
+    #[serde(default = "default_container_list_params_all", skip_serializing_if = "is_default_container_list_params_all")]
+    pub all: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filters: Option<String>,
+    #[serde(default = "default_container_list_params_limit", skip_serializing_if = "is_default_container_list_params_limit")]
+    pub limit: Option<isize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sort: Option<ContainerListParamsSortInlineItem>,
+    #[serde(default = "default_container_list_params_status", skip_serializing_if = "is_default_container_list_params_status")]
+    pub status: Option<ContainerListParamsStatusInlineItem>,
+}
+
+impl Default for ContainerListParams {
+    fn default() -> Self {
+        Self {
+            all: default_container_list_params_all(),
+            filters: None,
+            limit: default_container_list_params_limit(),
+            sort: None,
+            status: default_container_list_params_status(),
+        }
+    }
+}
+
+impl ContainerListParams {
+    /// The query string of these parameters, without the leading `?`.
+    pub fn to_query_string(&self) -> String {
+        query_string(self, &["filters"])
+    }
+}
+
+fn default_container_list_params_all() -> Option<bool> {
+    Some(false)
+}
+
+fn default_container_list_params_limit() -> Option<isize> {
+    Some(10)
+}
+
+fn default_container_list_params_status() -> Option<ContainerListParamsStatusInlineItem> {
+    Some(ContainerListParamsStatusInlineItem::Running)
+}
+
+fn is_default_container_list_params_all(v: &Option<bool>) -> bool {
+    v.is_none() || *v == default_container_list_params_all()
+}
+
+fn is_default_container_list_params_limit(v: &Option<isize>) -> bool {
+    v.is_none() || *v == default_container_list_params_limit()
+}
+
+fn is_default_container_list_params_status(v: &Option<ContainerListParamsStatusInlineItem>) -> bool {
+    v.is_none() || *v == default_container_list_params_status()
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ContainerListParamsSortInlineItem {
+    #[serde(rename = "name")]
+Name,
+    #[serde(rename = "created")]
+Created,
+}
+
+impl AsRef<str> for ContainerListParamsSortInlineItem {
+    fn as_ref(&self) -> &str {
+        match self {
+            ContainerListParamsSortInlineItem::Name => "name",
+            ContainerListParamsSortInlineItem::Created => "created",
+        }
+    }
+}
+
+impl ContainerListParamsSortInlineItem {
+    /// Every variant, in the order of the spec.
+    pub const VARIANTS: &[Self] = &[
+        ContainerListParamsSortInlineItem::Name,
+        ContainerListParamsSortInlineItem::Created,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::VARIANTS.iter().cloned()
+    }
+}
+
+impl std::fmt::Display for ContainerListParamsSortInlineItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ContainerListParamsStatusInlineItem {
+    #[serde(rename = "created")]
+Created,
+    #[serde(rename = "running")]
+Running,
+    #[serde(rename = "exited")]
+Exited,
+}
+
+impl AsRef<str> for ContainerListParamsStatusInlineItem {
+    fn as_ref(&self) -> &str {
+        match self {
+            ContainerListParamsStatusInlineItem::Created => "created",
+            ContainerListParamsStatusInlineItem::Running => "running",
+            ContainerListParamsStatusInlineItem::Exited => "exited",
+        }
+    }
+}
+
+impl ContainerListParamsStatusInlineItem {
+    /// Every variant, in the order of the spec.
+    pub const VARIANTS: &[Self] = &[
+        ContainerListParamsStatusInlineItem::Created,
+        ContainerListParamsStatusInlineItem::Running,
+        ContainerListParamsStatusInlineItem::Exited,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::VARIANTS.iter().cloned()
+    }
+}
+
+impl std::fmt::Display for ContainerListParamsStatusInlineItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Query parameters of the `ImageSearch` operation.
+pub struct ImageSearchParams {
+    #[serde(default = "default_image_search_params_limit", skip_serializing_if = "is_default_image_search_params_limit")]
+    pub limit: Option<isize>,
+    pub term: String,
+}
+
+impl ImageSearchParams {
+    /// The query string of these parameters, without the leading `?`.
+    pub fn to_query_string(&self) -> String {
+        query_string(self, &[])
+    }
+}
+
+fn default_image_search_params_limit() -> Option<isize> {
+    Some(25)
+}
+
+fn is_default_image_search_params_limit(v: &Option<isize>) -> bool {
+    v.is_none() || *v == default_image_search_params_limit()
+}
+
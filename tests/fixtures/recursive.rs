@@ -0,0 +1,46 @@
+
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    T: serde::de::DeserializeOwned,
+{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Branch {
+    #[serde(rename = "Leaves", skip_serializing_if = "Option::is_none")]
+    pub leaves: Option<Vec<Branch>>,
+    #[serde(rename = "Tree")]
+    pub tree: Option<Box<Tree>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Node {
+    #[serde(rename = "Children", skip_serializing_if = "Option::is_none")]
+    pub children: Option<HashMap<String, Node>>,
+    #[serde(rename = "Links", skip_serializing_if = "Option::is_none")]
+    /// Keys match the pattern `^[a-z]+$`.
+    pub links: Option<HashMap<String, Node>>,
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Parent")]
+    pub parent: Option<Box<Node>>,
+    #[serde(rename = "Siblings", skip_serializing_if = "Option::is_none")]
+    pub siblings: Option<Vec<Node>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Tree {
+    #[serde(rename = "Root")]
+    pub root: Option<Box<Branch>>,
+    #[serde(rename = "Size", skip_serializing_if = "Option::is_none")]
+    pub size: Option<isize>,
+}
+
@@ -0,0 +1,90 @@
+
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    T: serde::de::DeserializeOwned,
+{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum Scope {
+    #[serde(rename = "local")]
+Local,
+    #[serde(rename = "global")]
+Global,
+}
+
+impl AsRef<str> for Scope {
+    fn as_ref(&self) -> &str {
+        match self {
+            Scope::Local => "local",
+            Scope::Global => "global",
+        }
+    }
+}
+
+impl Scope {
+    /// Every variant, in the order of the spec.
+    pub const VARIANTS: &[Self] = &[
+        Scope::Local,
+        Scope::Global,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::VARIANTS.iter().cloned()
+    }
+}
+
+impl std::fmt::Display for Scope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+impl Scope {
+    /// Changes whenever the fields or variants of this model do.
+    pub const SCHEMA_HASH: &str = "491f706d1a69272c";
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// One of `"auto"`, `1`, `true`.
+#[serde(untagged)]
+pub enum Setting {
+    Int(i64),
+    Str(String),
+    Bool(bool),
+}
+
+impl Setting {
+    /// Changes whenever the fields or variants of this model do.
+    pub const SCHEMA_HASH: &str = "ced36b1e6e528853";
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// A volume managed by a driver.
+pub struct Volume {
+    #[serde(rename = "Driver")]
+    pub driver: String,
+    #[serde(rename = "Labels", skip_serializing_if = "Option::is_none")]
+    pub labels: Option<HashMap<String, String>>,
+    #[serde(rename = "Name")]
+    pub name: String,
+    #[serde(rename = "Scope")]
+    pub scope: Option<String>,
+}
+
+impl Volume {
+    /// Changes whenever the fields or variants of this model do.
+    pub const SCHEMA_HASH: &str = "bae8649b0e4c2593";
+}
+
+/// Changes whenever the schema hash of any model does.
+pub const MODELS_HASH: &str = "4acf3dda091ac7d5";
+
@@ -0,0 +1,111 @@
+
+/// Percent-encodes everything but the unreserved characters of RFC 3986.
+fn encode_query_component(component: &str) -> String {
+    let mut encoded = String::with_capacity(component.len());
+    for byte in component.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
+
+/// Joins the fields of `params` into a query string. Arrays repeat their key for every value and
+/// empty values of the fields in `bare_keys` are sent as the key alone.
+fn query_string(params: &impl serde::Serialize, bare_keys: &[&str]) -> String {
+    let Ok(serde_json::Value::Object(fields)) = serde_json::to_value(params) else {
+        return String::new();
+    };
+    let mut pairs = vec![];
+    for (key, value) in fields {
+        let values = match value {
+            serde_json::Value::Array(values) => values,
+            value => vec![value],
+        };
+        for value in values {
+            let value = match value {
+                serde_json::Value::Null => continue,
+                serde_json::Value::String(s) => s,
+                value => value.to_string(),
+            };
+            if value.is_empty() && bare_keys.contains(&key.as_str()) {
+                pairs.push(encode_query_component(&key));
+            } else {
+                pairs.push(format!(
+                    "{}={}",
+                    encode_query_component(&key),
+                    encode_query_component(&value)
+                ));
+            }
+        }
+    }
+    pairs.join("&")
+}
+
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    T: serde::de::DeserializeOwned,
+{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+// ===== DEFINITIONS =====
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Container {
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "State", skip_serializing_if = "Option::is_none")]
+    pub state: Option<ContainerStateInlineItem>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContainerStateInlineItem {
+    #[serde(rename = "Running", skip_serializing_if = "Option::is_none")]
+    pub running: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ErrorResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+}
+
+// ===== RESPONSES =====
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// no such object
+pub struct NotFound {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+}
+
+// ===== PATHS =====
+
+/// no error
+pub type ContainerList200Response = Vec<Container>;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Query parameters of the `ContainerList` operation.
+pub struct ContainerListParams {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub all: Option<bool>,
+}
+
+impl ContainerListParams {
+    /// The query string of these parameters, without the leading `?`.
+    pub fn to_query_string(&self) -> String {
+        query_string(self, &[])
+    }
+}
+
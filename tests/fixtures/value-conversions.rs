@@ -0,0 +1,98 @@
+
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    T: serde::de::DeserializeOwned,
+{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Container {
+    #[serde(rename = "Id")]
+    pub id: String,
+    #[serde(rename = "Names", skip_serializing_if = "Option::is_none")]
+    pub names: Option<Vec<String>>,
+    #[serde(rename = "State", skip_serializing_if = "Option::is_none")]
+    pub state: Option<ContainerStateInlineItem>,
+}
+
+impl TryFrom<serde_json::Value> for Container {
+    type Error = serde_json::Error;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        serde_json::from_value(value)
+    }
+}
+
+impl Container {
+    pub fn to_value(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("models serialize to JSON")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContainerStateInlineItem {
+    #[serde(rename = "Pid", skip_serializing_if = "Option::is_none")]
+    pub pid: Option<isize>,
+    #[serde(rename = "Status", skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+}
+
+impl TryFrom<serde_json::Value> for ContainerStateInlineItem {
+    type Error = serde_json::Error;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        serde_json::from_value(value)
+    }
+}
+
+impl ContainerStateInlineItem {
+    pub fn to_value(&self) -> serde_json::Value {
+        serde_json::to_value(self).expect("models serialize to JSON")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ContainerStateInlineItemStatusInlineItem {
+    #[serde(rename = "running")]
+Running,
+    #[serde(rename = "exited")]
+Exited,
+}
+
+impl AsRef<str> for ContainerStateInlineItemStatusInlineItem {
+    fn as_ref(&self) -> &str {
+        match self {
+            ContainerStateInlineItemStatusInlineItem::Running => "running",
+            ContainerStateInlineItemStatusInlineItem::Exited => "exited",
+        }
+    }
+}
+
+impl ContainerStateInlineItemStatusInlineItem {
+    /// Every variant, in the order of the spec.
+    pub const VARIANTS: &[Self] = &[
+        ContainerStateInlineItemStatusInlineItem::Running,
+        ContainerStateInlineItemStatusInlineItem::Exited,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::VARIANTS.iter().cloned()
+    }
+}
+
+impl std::fmt::Display for ContainerStateInlineItemStatusInlineItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+pub type Labels = HashMap<String, String>;
+
@@ -0,0 +1,121 @@
+
+fn deserialize_nonoptional_vec<'de, D: serde::de::Deserializer<'de>, T: serde::de::DeserializeOwned>(
+    d: D,
+) -> Result<Vec<T>, D::Error> {
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+
+fn deserialize_nonoptional_map<'de, D, K, T>(d: D) -> Result<HashMap<K, T>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+    K: serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    T: serde::de::DeserializeOwned,
+{
+    serde::de::Deserialize::deserialize(d).map(|x: Option<_>| x.unwrap_or_default())
+}
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ContainerEvent {
+    #[serde(rename = "Action")]
+    pub action: String,
+    #[serde(rename = "ExitCode", skip_serializing_if = "Option::is_none")]
+    pub exit_code: Option<isize>,
+    #[serde(rename = "ID")]
+    pub id: String,
+    #[serde(rename = "Type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ContainerEventTypeInlineItem {
+    #[serde(rename = "container")]
+Container,
+}
+
+impl AsRef<str> for ContainerEventTypeInlineItem {
+    fn as_ref(&self) -> &str {
+        match self {
+            ContainerEventTypeInlineItem::Container => "container",
+        }
+    }
+}
+
+impl ContainerEventTypeInlineItem {
+    /// Every variant, in the order of the spec.
+    pub const VARIANTS: &[Self] = &[
+        ContainerEventTypeInlineItem::Container,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::VARIANTS.iter().cloned()
+    }
+}
+
+impl std::fmt::Display for ContainerEventTypeInlineItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// A message of the event stream, by the kind of object it is about.
+#[serde(tag = "Type")]
+pub enum EventMessage {
+    #[serde(rename = "image")]
+    Image(ImageEvent),
+    #[serde(rename = "container")]
+    Container(ContainerEvent),
+}
+
+impl From<ImageEvent> for EventMessage {
+    fn from(value: ImageEvent) -> Self {
+        Self::Image(value)
+    }
+}
+
+impl From<ContainerEvent> for EventMessage {
+    fn from(value: ContainerEvent) -> Self {
+        Self::Container(value)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ImageEvent {
+    #[serde(rename = "Action")]
+    pub action: String,
+    #[serde(rename = "Tag", skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    #[serde(rename = "Type", skip_serializing_if = "Option::is_none")]
+    pub type_: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ImageEventTypeInlineItem {
+    #[serde(rename = "image")]
+Image,
+}
+
+impl AsRef<str> for ImageEventTypeInlineItem {
+    fn as_ref(&self) -> &str {
+        match self {
+            ImageEventTypeInlineItem::Image => "image",
+        }
+    }
+}
+
+impl ImageEventTypeInlineItem {
+    /// Every variant, in the order of the spec.
+    pub const VARIANTS: &[Self] = &[
+        ImageEventTypeInlineItem::Image,
+    ];
+
+    pub fn iter() -> impl Iterator<Item = Self> {
+        Self::VARIANTS.iter().cloned()
+    }
+}
+
+impl std::fmt::Display for ImageEventTypeInlineItem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_ref())
+    }
+}
+
@@ -0,0 +1,72 @@
+//! Compiles `tests/fixtures/float-hash.rs`, the output of the Rust backend with
+//! `FloatHash::Bits`, and checks how the structs it generates hash.
+mod common;
+
+use swagger_gen::v2::codegen::backend::rust;
+
+use std::{
+    collections::{hash_map::DefaultHasher, HashSet},
+    hash::{Hash, Hasher},
+};
+
+common::include_models!(generated, "float-hash");
+
+use generated::{Point, Sample};
+
+fn point(x: f64, y: f64) -> Point {
+    Point { label: None, x, y }
+}
+
+fn hash(value: &impl Hash) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn derive_leaves_hash_out() {
+    let out = common::generate(
+        "float-hash",
+        rust::CodegenOptions {
+            float_hash: rust::FloatHash::Derive,
+            ..Default::default()
+        },
+    );
+    assert!(!out.contains("Hash for"));
+    assert!(!out.contains("trait FloatKey"));
+}
+
+#[test]
+fn cache_keys() {
+    let mut points = HashSet::new();
+    assert!(points.insert(point(1.5, 2.0)));
+    assert!(!points.insert(point(1.5, 2.0)));
+    assert!(points.insert(point(2.0, 1.5)));
+    assert!(points.contains(&point(1.5, 2.0)));
+
+    let sample = |values: Option<Vec<f64>>| Sample {
+        id: 1,
+        values,
+        weight: Some(0.5),
+    };
+    let mut samples = HashSet::new();
+    assert!(samples.insert(sample(Some(vec![1.0, 2.0]))));
+    assert!(samples.insert(sample(Some(vec![]))));
+    assert!(samples.insert(sample(None)));
+    assert!(samples.contains(&sample(Some(vec![1.0, 2.0]))));
+    assert!(!samples.contains(&sample(Some(vec![2.0, 1.0]))));
+}
+
+#[test]
+fn nan_and_negative_zero() {
+    let mut points = HashSet::new();
+    points.insert(point(f64::NAN, 0.0));
+    assert!(points.contains(&point(f64::NAN, 0.0)));
+    assert!(points.contains(&point(f64::NAN, -0.0)));
+    assert_eq!(point(f64::NAN, 1.0), point(-f64::NAN, 1.0));
+    assert_eq!(hash(&point(f64::NAN, 1.0)), hash(&point(-f64::NAN, 1.0)));
+
+    assert_eq!(point(0.0, 1.0), point(-0.0, 1.0));
+    assert_eq!(hash(&point(0.0, 1.0)), hash(&point(-0.0, 1.0)));
+    assert_ne!(point(0.0, 1.0), point(1.0, 1.0));
+}
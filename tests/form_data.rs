@@ -0,0 +1,66 @@
+//! Compiles `tests/fixtures/form-data.rs`, the form models generated for the `formData`
+//! parameters of `tests/fixtures/form-data.yaml`, and checks that the operations table points at
+//! them.
+mod common;
+
+use swagger_gen::v2::{
+    codegen::{backend::rust, operations, BodyNames},
+    parameter::Parameter,
+    Swagger,
+};
+
+common::include_models!(generated, "form-data");
+
+fn load() -> Swagger<rust::Type> {
+    common::load("form-data")
+}
+
+#[test]
+fn parses_form_parameters() {
+    let swagger = load();
+    let (_, _, op) = swagger
+        .operations()
+        .find(|(path, _, _)| *path == "/images/load")
+        .unwrap();
+    let Parameter::FormData(image) = &op.parameters[0] else {
+        panic!("expected a form parameter, got {:?}", op.parameters[0]);
+    };
+    assert_eq!(image.type_, "file");
+    assert!(image.required);
+
+    let form = generated::ImageLoadForm {
+        image: vec![1, 2, 3],
+        quiet: None,
+        tags: Some(vec!["a".into(), "b".into()]),
+    };
+    assert_eq!(
+        serde_json::to_value(&form).unwrap(),
+        serde_json::json!({"image": [1, 2, 3], "tags": "a,b"})
+    );
+}
+
+#[test]
+fn joined_collections_round_trip() {
+    let form: generated::ImageLoadForm =
+        serde_json::from_value(serde_json::json!({"image": [1], "tags": "a,b"})).unwrap();
+    assert_eq!(form.tags, Some(vec!["a".to_string(), "b".to_string()]));
+
+    let form: generated::ImageLoadForm =
+        serde_json::from_value(serde_json::json!({"image": [1], "tags": ""})).unwrap();
+    assert_eq!(form.tags, Some(vec![]));
+
+    let form: generated::ImageLoadForm =
+        serde_json::from_value(serde_json::json!({"image": [1]})).unwrap();
+    assert_eq!(form.tags, None);
+}
+
+#[test]
+fn operations_name_form_models() {
+    let defs = operations::operation_defs(&load(), BodyNames::Operation, false);
+    let auth = defs.iter().find(|op| op.path == "/auth").unwrap();
+    assert!(auth
+        .params
+        .iter()
+        .all(|param| param.location == "formData"
+            && param.model.as_deref() == Some("SystemAuthForm")));
+}
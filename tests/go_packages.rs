@@ -0,0 +1,18 @@
+//! Compiles `tests/fixtures/go-packages.rs`, the output of the Rust backend with
+//! `use_go_packages` enabled, and makes sure references across the generated modules resolve.
+mod common;
+
+common::include_models!(generated, "go-packages");
+
+#[test]
+fn cross_package_references() {
+    let containers: generated::ContainerList = serde_json::from_str(
+        r#"[{"Id": "abc", "Mounts": [{"Source": "/data", "Type": "bind"}], "Network": {"IPAddress": "10.0.0.2"}}]"#,
+    )
+    .unwrap();
+    let container: &generated::container::Container = &containers[0];
+    let mount: &generated::mount::MountPoint = &container.mounts.as_ref().unwrap()[0];
+    assert_eq!(mount.source.as_deref(), Some("/data"));
+    let network: &generated::network::EndpointSettings = container.network.as_ref().unwrap();
+    assert_eq!(network.ip_address.as_deref(), Some("10.0.0.2"));
+}
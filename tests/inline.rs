@@ -0,0 +1,14 @@
+//! Compiles `tests/fixtures/inline.rs`, the output of the Rust backend with an
+//! `inline_threshold`, and deserializes the inlined models.
+mod common;
+
+common::include_models!(generated, "inline");
+
+#[test]
+fn inlined_models() {
+    let container: generated::Container =
+        serde_json::from_str(r#"{"Id": "abc", "Health": {"Status": "healthy"}}"#).unwrap();
+    assert_eq!(container.id.as_deref(), Some("abc"));
+    let health: Option<generated::ContainerHealth> = container.health;
+    assert_eq!(health.unwrap().status.as_deref(), Some("healthy"));
+}
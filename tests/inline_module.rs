@@ -0,0 +1,18 @@
+//! Compiles `tests/fixtures/inline-module.rs`, the output of the Rust backend with an
+//! `inline_module_threshold`, and deserializes the models of the inline module.
+mod common;
+
+common::include_models!(generated, "inline-module");
+
+#[test]
+fn inline_models() {
+    let container: generated::Container = serde_json::from_str(
+        r#"{"State": {"Running": true, "Pid": 42}, "Mounts": [{"Source": "/data"}]}"#,
+    )
+    .unwrap();
+    let state: generated::inline::ContainerStateInlineItem = container.state.unwrap();
+    assert_eq!(state.running, Some(true));
+    assert_eq!(state.pid, Some(42));
+    let mounts: Vec<generated::inline::ContainerMountsInlineItem> = container.mounts.unwrap();
+    assert_eq!(mounts[0].source.as_deref(), Some("/data"));
+}
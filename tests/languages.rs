@@ -0,0 +1,44 @@
+//! Runs `generate models` with flags that only apply to some of the languages.
+mod common;
+
+use std::process::{Command, Output};
+
+fn generate(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_swagger-gen"))
+        .args(["--quiet", "generate", "models"])
+        .args(args)
+        .arg(common::fixtures().join("date-times.yaml"))
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn rust_only_flag_with_python() {
+    let output = generate(&["--language", "python", "--api-meta"]);
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("`--api-meta` is not supported with `--language python`"));
+}
+
+#[test]
+fn rust_and_python_flag_with_typescript() {
+    let output = generate(&["--language", "typescript", "--inline-threshold", "2"]);
+    assert_eq!(output.status.code(), Some(2));
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("`--inline-threshold` is not supported with `--language typescript`"));
+}
+
+#[test]
+fn shared_flags() {
+    assert!(
+        generate(&["--language", "python", "--inline-threshold", "2"])
+            .status
+            .success()
+    );
+    assert!(
+        generate(&["--language", "typescript", "--field-order", "spec"])
+            .status
+            .success()
+    );
+    assert!(generate(&["--api-meta"]).status.success());
+}
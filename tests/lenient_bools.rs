@@ -0,0 +1,35 @@
+//! Compiles `tests/fixtures/lenient-bools.rs`, generated for `x-lenient-bool` properties, and
+//! deserializes booleans from strings and numbers.
+mod common;
+
+common::include_models!(generated, "lenient-bools");
+
+#[test]
+fn lenient_values() {
+    let port = |json: &str| serde_json::from_str::<generated::Port>(json);
+
+    for (value, expected) in [
+        ("true", true),
+        ("false", false),
+        (r#""true""#, true),
+        (r#""false""#, false),
+        (r#""1""#, true),
+        (r#""0""#, false),
+        ("1", true),
+        ("0", false),
+        ("2.5", true),
+    ] {
+        let parsed = port(&format!(r#"{{"Published": {value}, "Ipv6": {value}}}"#)).unwrap();
+        assert_eq!(parsed.published, expected, "{value}");
+        assert_eq!(parsed.ipv_6, Some(expected), "{value}");
+    }
+
+    let parsed = port(r#"{"Published": "1"}"#).unwrap();
+    assert_eq!(parsed.ipv_6, None);
+    assert_eq!(parsed.internal, Some(false));
+    let parsed = port(r#"{"Published": true, "Ipv6": null}"#).unwrap();
+    assert_eq!(parsed.ipv_6, None);
+
+    assert!(port(r#"{"Published": "yes"}"#).is_err());
+    assert!(port(r#"{"Published": true, "Strict": "true"}"#).is_err());
+}
@@ -0,0 +1,55 @@
+//! Runs `generate models --manifest` over `tests/fixtures/manifest.yaml`, which has colliding
+//! definition names and identical inline schemas, and checks the manifest against
+//! `tests/fixtures/manifest.json`.
+mod common;
+
+use swagger_gen::v2::{
+    codegen::{backend::python, CodeGenerator},
+    Swagger,
+};
+
+use std::{path::Path, process::Command};
+
+#[test]
+fn golden() {
+    let path = Path::new(env!("CARGO_TARGET_TMPDIR")).join("manifest.json");
+    let output = Command::new(env!("CARGO_BIN_EXE_swagger-gen"))
+        .args([
+            "--quiet",
+            "generate",
+            "models",
+            "--dedupe-identical-schemas",
+        ])
+        .arg("--manifest")
+        .arg(&path)
+        .arg(common::fixtures().join("manifest.yaml"))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let expected = std::fs::read_to_string(common::fixtures().join("manifest.json")).unwrap();
+    assert_eq!(std::fs::read_to_string(path).unwrap(), expected);
+}
+
+#[test]
+fn python() {
+    let swagger: Swagger<python::Type> = common::load("manifest");
+    let codegen = CodeGenerator::new(swagger, Box::new(python::Codegen::default()));
+    let manifest = codegen.manifest();
+
+    let renamed = manifest
+        .models
+        .iter()
+        .find(|model| model.spec_name == "networkSettings")
+        .unwrap();
+    assert_eq!(renamed.name, "NetworkSettings2");
+    assert_eq!(renamed.source, "definition");
+    assert_eq!(renamed.kind, "object");
+    assert!(manifest.models.iter().all(|model| model.alias_of.is_none()));
+
+    assert_eq!(manifest.skipped.len(), 1);
+    assert_eq!(manifest.skipped[0].spec_name, "TaskStateInlineItem");
+    assert_eq!(
+        manifest.skipped[0].reason,
+        "merged 2 identical enums into `ContainerStateInlineItem`"
+    );
+}
@@ -0,0 +1,25 @@
+//! Compiles `tests/fixtures/map-keys.rs`, generated for maps with `x-key-type`, and makes sure
+//! they round-trip with enum keys.
+mod common;
+
+common::include_models!(generated, "map-keys");
+
+#[test]
+fn enum_keys_round_trip() {
+    use generated::Region;
+
+    let quota: generated::Quota =
+        serde_json::from_str(r#"{"Limits": {"eu-west": 10, "us-east": 20}}"#).unwrap();
+    assert_eq!(quota.limits[&Region::EuWest], 10);
+    assert_eq!(quota.limits[&Region::UsEast], 20);
+    assert_eq!(quota.labels, None);
+
+    let value = serde_json::to_value(&quota).unwrap();
+    assert_eq!(value["Limits"]["us-east"], serde_json::json!(20));
+    assert_eq!(
+        serde_json::from_value::<generated::Quota>(value).unwrap(),
+        quota
+    );
+
+    assert!(serde_json::from_str::<generated::Quota>(r#"{"Limits": {"ap-south": 1}}"#).is_err());
+}
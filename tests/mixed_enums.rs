@@ -0,0 +1,32 @@
+//! Compiles `tests/fixtures/mixed-enums.rs`, generated for enums with values of different types,
+//! and (de)serializes their values.
+mod common;
+
+use swagger_gen::v2::{codegen::backend::rust, Swagger};
+
+common::include_models!(generated, "mixed-enums");
+
+#[test]
+fn valid_spec() {
+    let swagger: Swagger<rust::Type> = common::load("mixed-enums");
+    assert!(swagger.validate().is_empty());
+}
+
+#[test]
+fn values() {
+    use generated::{CpuLimit, Ratio, Toggle};
+
+    let limits: Vec<CpuLimit> = serde_json::from_str(r#"[2, "auto"]"#).unwrap();
+    assert_eq!(limits, [CpuLimit::Int(2), CpuLimit::Str("auto".into())]);
+    assert_eq!(serde_json::to_string(&limits).unwrap(), r#"[2,"auto"]"#);
+
+    let ratios: Vec<Ratio> = serde_json::from_str(r#"[1, 0.5, "none"]"#).unwrap();
+    assert_eq!(
+        ratios,
+        [Ratio::Int(1), Ratio::Float(0.5), Ratio::Str("none".into())]
+    );
+
+    let toggles: Vec<Toggle> = serde_json::from_str(r#"["on", false]"#).unwrap();
+    assert_eq!(toggles, [Toggle::Str("on".into()), Toggle::Bool(false)]);
+    assert!(serde_json::from_str::<Toggle>("1").is_err());
+}
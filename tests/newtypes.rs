@@ -0,0 +1,21 @@
+//! Compiles `tests/fixtures/newtypes.rs`, the output of the Rust backend with `newtypes`
+//! enabled, and makes sure the generated newtypes convert as expected.
+mod common;
+
+common::include_models!(generated, "newtypes");
+
+#[test]
+fn conversions() {
+    let key = generated::ApiKey::from("secret");
+    assert_eq!(key, generated::ApiKey::from("secret".to_string()));
+    assert_eq!(key, "secret".parse().unwrap());
+    assert_eq!(key.to_string(), "secret");
+    assert_eq!(generated::Port::from(80).to_string(), "80");
+
+    let client: generated::Client = serde_json::from_str(r#"{"Key": "secret"}"#).unwrap();
+    assert_eq!(client.key, key);
+    assert_eq!(
+        serde_json::to_value(&client).unwrap()["Key"],
+        serde_json::json!("secret")
+    );
+}
@@ -0,0 +1,19 @@
+//! Compiles `tests/fixtures/non-exhaustive.rs`, the output of the Rust backend with
+//! `non_exhaustive` enabled, and makes sure its models can be constructed.
+mod common;
+
+common::include_models!(generated, "non-exhaustive");
+
+#[test]
+fn construct_via_new() {
+    let status: generated::ContainerStatus = "exited".parse().unwrap();
+    assert_eq!(status, generated::ContainerStatus::Exited);
+    assert!("paused".parse::<generated::ContainerStatus>().is_err());
+
+    let container = generated::Container::new("abc".into(), vec!["web".into()], status.to_string());
+    assert_eq!(container.id, "abc");
+    assert_eq!(container.names, vec!["web".to_string()]);
+    assert_eq!(container.labels, None);
+    assert_eq!(container.restarts, None);
+    assert_eq!(container.status, "exited");
+}
@@ -0,0 +1,53 @@
+//! Round-trips `tests/fixtures/extensions.yaml` through the parsed spec and checks that every
+//! key survives, including the vendor extensions that aren't used for generating code.
+mod common;
+
+use swagger_gen::v2::{codegen::backend::rust, Swagger};
+
+use std::{path::PathBuf, process::Command};
+
+fn fixture() -> PathBuf {
+    common::fixtures().join("extensions.yaml")
+}
+
+fn original() -> serde_json::Value {
+    let spec = std::fs::read_to_string(fixture()).unwrap();
+    serde_yaml::from_str(&spec).unwrap()
+}
+
+fn load() -> Swagger<rust::Type> {
+    common::load("extensions")
+}
+
+#[test]
+fn json_keeps_every_key() {
+    let json = load().to_json().unwrap();
+    let normalized: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(normalized, original());
+}
+
+#[test]
+fn yaml_keeps_every_key() {
+    let yaml = load().to_yaml().unwrap();
+    let normalized: serde_json::Value = serde_yaml::from_str(&yaml).unwrap();
+    assert_eq!(normalized, original());
+}
+
+#[test]
+fn normalize_is_idempotent() {
+    let yaml = load().to_yaml().unwrap();
+    let swagger: Swagger<rust::Type> = serde_yaml::from_str(&yaml).unwrap();
+    assert_eq!(swagger.to_yaml().unwrap(), yaml);
+}
+
+#[test]
+fn normalize_command() {
+    let output = Command::new(env!("CARGO_BIN_EXE_swagger-gen"))
+        .args(["--quiet", "normalize", "--json"])
+        .arg(fixture())
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    let normalized: serde_json::Value = serde_json::from_slice(&output.stdout).unwrap();
+    assert_eq!(normalized, original());
+}
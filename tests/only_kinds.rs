@@ -0,0 +1,78 @@
+//! Compiles `tests/fixtures/only-kinds.rs`, the enums of `tests/fixtures/only-kinds.yaml`
+//! generated with `--only`, and checks the other kinds using what dropped models are made of.
+mod common;
+
+use swagger_gen::v2::{
+    codegen::{
+        backend::{rust, CodegenBackend},
+        ModelKind,
+    },
+    validation::Severity,
+    Swagger,
+};
+
+common::include_models!(generated, "only-kinds");
+
+fn swagger() -> Swagger<rust::Type> {
+    common::load("only-kinds")
+}
+
+fn options(only: &[ModelKind]) -> rust::CodegenOptions {
+    rust::CodegenOptions {
+        only: only.to_vec(),
+        ..Default::default()
+    }
+}
+
+fn codegen(only: &[ModelKind]) -> rust::Codegen {
+    rust::Codegen::new(options(only))
+}
+
+fn generate(only: &[ModelKind]) -> String {
+    common::generate("only-kinds", options(only))
+}
+
+#[test]
+fn only_enums() {
+    let (prototypes, diagnostics) =
+        codegen(&[ModelKind::Enum]).prototypes_with_diagnostics(&swagger());
+    let names: Vec<_> = prototypes.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(
+        names,
+        ["ContainerRestartPolicyInlineItem", "ContainerState"]
+    );
+    assert!(diagnostics.is_empty(), "{diagnostics:?}");
+
+    let out = generate(&[ModelKind::Enum]);
+    assert_eq!(out.matches("pub enum ").count(), 2);
+    assert!(!out.contains("pub struct "));
+    assert!(!out.contains("pub type "));
+    let _ = generated::ContainerState::Running;
+}
+
+#[test]
+fn only_structs() {
+    let out = generate(&[ModelKind::Struct]);
+    assert!(out.contains("pub struct Container {"));
+    assert!(out.contains("pub struct ContainerHostConfigInlineItem {"));
+    assert!(out.contains("pub names: Option<Vec<String>>,"));
+    assert!(!out.contains("pub enum "));
+    assert!(!out.contains("pub type "));
+
+    let (_, diagnostics) = codegen(&[ModelKind::Struct]).prototypes_with_diagnostics(&swagger());
+    let names = diagnostics
+        .iter()
+        .find(|diagnostic| diagnostic.message.contains("`Names`"))
+        .unwrap();
+    assert_eq!(names.severity, Severity::Warning);
+    assert_eq!(names.locations, ["Container"]);
+}
+
+#[test]
+fn only_aliases() {
+    let out = generate(&[ModelKind::Alias]);
+    assert!(out.contains("pub type ContainerList = Vec<Value>;"));
+    assert!(out.contains("pub type Names = Vec<String>;"));
+    assert!(!out.contains("pub struct "));
+    assert!(!out.contains("pub enum "));
+}
@@ -0,0 +1,76 @@
+//! Checks the operations manifest of `tests/fixtures/operations.yaml` against the Rust and JSON
+//! golden files, makes sure the Rust table compiles and that it only names generated models.
+mod common;
+
+use swagger_gen::v2::{
+    codegen::{backend::rust, operations, BodyNames},
+    Swagger,
+};
+
+common::include_models!(generated, "operations");
+
+fn load() -> Swagger<rust::Type> {
+    common::load("operations")
+}
+
+#[test]
+fn golden_rust() {
+    let mut out = vec![];
+    operations::write_rust(
+        &operations::operation_defs(&load(), BodyNames::Operation, true),
+        &mut out,
+    )
+    .unwrap();
+    let expected = std::fs::read_to_string(common::fixtures().join("operations.rs")).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), expected);
+}
+
+#[test]
+fn golden_json() {
+    let mut out = vec![];
+    operations::write_json(
+        &operations::operation_defs(&load(), BodyNames::Operation, true),
+        &mut out,
+    )
+    .unwrap();
+    let expected = std::fs::read_to_string(common::fixtures().join("operations.json")).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), expected);
+}
+
+#[test]
+fn names_generated_models() {
+    let models = common::generate_models(
+        load(),
+        rust::CodegenOptions {
+            query_params_models: true,
+            ..Default::default()
+        },
+    );
+    let is_generated = |name: &str| {
+        models.contains(&format!("pub struct {name} "))
+            || models.contains(&format!("pub type {name} "))
+    };
+    for op in generated::OPERATIONS {
+        let models = op
+            .params
+            .iter()
+            .filter_map(|param| param.model)
+            .chain(op.query_model)
+            .chain(op.response_models.iter().map(|(_, model)| *model))
+            .chain(op.default_response);
+        for model in models {
+            assert!(
+                is_generated(model),
+                "{model} of {} is not generated",
+                op.path
+            );
+        }
+    }
+
+    let list = generated::OPERATIONS
+        .iter()
+        .find(|op| op.operation_id == Some("ContainerList"))
+        .unwrap();
+    assert_eq!(list.query_model, Some("ContainerListParams"));
+    assert_eq!(list.default_response, Some("ErrorResponse"));
+}
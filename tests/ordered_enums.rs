@@ -0,0 +1,106 @@
+//! Compiles `tests/fixtures/ordered-enums.rs`, the output of the Rust backend with
+//! `ordered_enums`, and checks that the enums it generates are ordered like the spec.
+mod common;
+
+use swagger_gen::v2::{
+    codegen::{backend::rust, CodeGenerator},
+    Swagger,
+};
+
+common::include_models!(generated, "ordered-enums");
+
+use generated::{ContainerState, Severity, TaskPriorityInlineItem};
+
+fn ordered_codegen() -> Box<rust::Codegen> {
+    Box::new(rust::Codegen::new(rust::CodegenOptions {
+        ordered_enums: true,
+        ..Default::default()
+    }))
+}
+
+#[test]
+fn follows_spec_order() {
+    assert!(ContainerState::Created < ContainerState::Running);
+    assert!(ContainerState::Paused < ContainerState::Exited);
+    assert!(Severity::Low < Severity::Critical);
+    // alphabetically `urgent` would come last
+    assert!(TaskPriorityInlineItem::Urgent < TaskPriorityInlineItem::Deferred);
+
+    let mut states = vec![
+        ContainerState::Exited,
+        ContainerState::Created,
+        ContainerState::Paused,
+        ContainerState::Running,
+    ];
+    states.sort();
+    assert_eq!(states, ContainerState::VARIANTS);
+    assert_eq!(states.iter().max(), Some(&ContainerState::Exited));
+}
+
+#[test]
+fn ordinals() {
+    for (i, state) in ContainerState::iter().enumerate() {
+        assert_eq!(state.ordinal(), i);
+    }
+    assert_eq!(Severity::High.ordinal(), 2);
+    assert_eq!(TaskPriorityInlineItem::Urgent.ordinal(), 0);
+}
+
+#[test]
+fn refuses_merging_differently_ordered_enums() {
+    let spec = r#"
+swagger: "2.0"
+definitions:
+  Job:
+    type: object
+    properties:
+      Level:
+        type: string
+        enum: [low, high]
+  Alert:
+    type: object
+    properties:
+      Level:
+        type: string
+        enum: [high, low]
+"#;
+    let swagger: Swagger<rust::Type> = serde_yaml::from_str(spec).unwrap();
+    let err = CodeGenerator::new(swagger, ordered_codegen())
+        .generate_models_to_string()
+        .unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::InvalidInput);
+    assert!(err
+        .to_string()
+        .contains("`AlertLevelInlineItem`, `JobLevelInlineItem`"));
+
+    // merged as usual without the option
+    let swagger: Swagger<rust::Type> = serde_yaml::from_str(spec).unwrap();
+    let out = common::generate_models(swagger, Default::default());
+    assert_eq!(out.matches("pub enum ").count(), 1);
+}
+
+#[test]
+fn merges_enums_declared_in_the_same_order() {
+    let spec = r#"
+swagger: "2.0"
+definitions:
+  Job:
+    type: object
+    properties:
+      Level:
+        type: string
+        enum: [low, high]
+  Alert:
+    type: object
+    properties:
+      Level:
+        type: string
+        enum: [low, high]
+"#;
+    let swagger: Swagger<rust::Type> = serde_yaml::from_str(spec).unwrap();
+    let out = CodeGenerator::new(swagger, ordered_codegen())
+        .generate_models_to_string()
+        .unwrap();
+    assert_eq!(out.matches("pub enum ").count(), 1);
+    assert!(out.contains("pub fn ordinal(&self) -> usize"));
+}
@@ -0,0 +1,44 @@
+//! Checks the `paths` module generated for `tests/fixtures/paths.yaml` against the golden file
+//! and that the generated functions substitute and encode path parameters.
+mod common;
+
+use swagger_gen::v2::{
+    codegen::{backend::rust, operations},
+    Swagger,
+};
+
+common::include_models!(generated, "paths");
+
+use generated::paths;
+
+#[test]
+fn golden() {
+    let swagger: Swagger<rust::Type> = common::load("paths");
+    let mut out = vec![];
+    operations::write_rust_paths(&swagger, &mut out).unwrap();
+    let expected = std::fs::read_to_string(common::fixtures().join("paths.rs")).unwrap();
+    assert_eq!(String::from_utf8(out).unwrap(), expected);
+}
+
+#[test]
+fn substitutes_parameters() {
+    assert_eq!(paths::CONTAINER_INSPECT, "/containers/{id}/json");
+    assert_eq!(paths::container_inspect_path("abc"), "/containers/abc/json");
+    assert_eq!(paths::system_ping_path(), "/_ping");
+    assert_eq!(
+        paths::post_containers_id_resize_height_x_width_path("abc", 24, 80),
+        "/containers/abc/resize/24x80"
+    );
+}
+
+#[test]
+fn encodes_path_segments() {
+    assert_eq!(
+        paths::container_inspect_path("my/container name"),
+        "/containers/my%2Fcontainer%20name/json"
+    );
+    assert_eq!(
+        paths::container_logs_path("a~b-c_d.e%f"),
+        "/containers/a~b-c_d.e%25f/logs"
+    );
+}
@@ -0,0 +1,53 @@
+//! Compiles `tests/fixtures/pattern-properties.rs`, generated for schemas with
+//! `patternProperties`, and makes sure the maps deserialize.
+mod common;
+
+use swagger_gen::v2::{codegen::backend::rust, Swagger};
+
+common::include_models!(generated, "pattern-properties");
+
+#[test]
+fn warns_about_pattern_properties() {
+    let swagger: Swagger<rust::Type> = common::load("pattern-properties");
+    let diagnostics = swagger.validate();
+    assert_eq!(diagnostics.len(), 1);
+    assert_eq!(
+        diagnostics[0].locations,
+        vec!["#/definitions/ContainerConfig/properties/Annotations".to_string()]
+    );
+}
+
+#[test]
+fn deserializes_maps() {
+    use generated::{ContainerConfig, ContainerConfigDevicesInlineItem};
+
+    let config: ContainerConfig = serde_json::from_str(
+        r#"{
+            "ExposedPorts": {"80/tcp": {}},
+            "Labels": {"com.example.vendor": "ACME"},
+            "PortBindings": {"80/tcp": {"HostIp": "0.0.0.0", "HostPort": "8080"}},
+            "Devices": {"/dev/fuse": {"Permissions": "rwm"}},
+            "Sysctls": {"net.ipv4.ip_forward": "1"},
+            "Annotations": {"x-team": "core", "replicas": 3}
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(
+        config.exposed_ports.unwrap()["80/tcp"],
+        serde_json::json!({})
+    );
+    assert_eq!(config.labels.unwrap()["com.example.vendor"], "ACME");
+    assert_eq!(
+        config.port_bindings.unwrap()["80/tcp"].host_port.as_deref(),
+        Some("8080")
+    );
+    assert_eq!(
+        config.devices.unwrap()["/dev/fuse"],
+        ContainerConfigDevicesInlineItem {
+            permissions: Some("rwm".into()),
+            target: None,
+        }
+    );
+    assert_eq!(config.sysctls.unwrap()["net.ipv4.ip_forward"], "1");
+    assert_eq!(config.annotations.unwrap()["replicas"], 3);
+}
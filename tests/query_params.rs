@@ -0,0 +1,86 @@
+//! Compiles `tests/fixtures/query-params.rs`, the models generated for the query parameters of
+//! `tests/fixtures/query-params.yaml`, and checks the query strings they build.
+mod common;
+
+use swagger_gen::v2::codegen::backend::rust;
+
+common::include_models!(generated, "query-params");
+
+use generated::{
+    ContainerListParams, ContainerListParamsSortInlineItem, ContainerListParamsStatusInlineItem,
+    ImageSearchParams,
+};
+
+#[test]
+fn defaulted_params() {
+    let params = ContainerListParams::default();
+    assert_eq!(params.all, Some(false));
+    assert_eq!(params.limit, Some(10));
+    assert_eq!(
+        params.status,
+        Some(ContainerListParamsStatusInlineItem::Running)
+    );
+    assert_eq!(params.to_query_string(), "");
+
+    let params = ContainerListParams {
+        all: Some(true),
+        status: Some(ContainerListParamsStatusInlineItem::Running),
+        limit: Some(25),
+        ..Default::default()
+    };
+    assert_eq!(params.to_query_string(), "all=true&limit=25");
+
+    let params = ImageSearchParams {
+        limit: None,
+        term: "rust lang".into(),
+    };
+    assert_eq!(params.to_query_string(), "term=rust%20lang");
+}
+
+#[test]
+fn empty_value_params() {
+    let params = ContainerListParams {
+        filters: Some(String::new()),
+        ..Default::default()
+    };
+    assert_eq!(params.to_query_string(), "filters");
+
+    let params = ContainerListParams {
+        filters: Some("status=exited".into()),
+        ..Default::default()
+    };
+    assert_eq!(params.to_query_string(), "filters=status%3Dexited");
+
+    let params = ImageSearchParams {
+        limit: None,
+        term: String::new(),
+    };
+    assert_eq!(params.to_query_string(), "term=");
+}
+
+#[test]
+fn enum_params() {
+    let params = ContainerListParams {
+        status: Some(ContainerListParamsStatusInlineItem::Exited),
+        sort: Some(ContainerListParamsSortInlineItem::Created),
+        ..Default::default()
+    };
+    assert_eq!(params.to_query_string(), "sort=created&status=exited");
+}
+
+#[test]
+fn keep_default_params() {
+    let out = common::generate(
+        "query-params",
+        rust::CodegenOptions {
+            omit_default_params: false,
+            query_params_models: true,
+            ..Default::default()
+        },
+    );
+    assert!(out.contains(
+        "    #[serde(default = \"default_container_list_params_all\", skip_serializing_if = \"Option::is_none\")]\n    pub all: Option<bool>,"
+    ));
+    assert!(!out.contains("fn is_default_"));
+    assert!(out.contains("impl Default for ContainerListParams {"));
+}
@@ -0,0 +1,32 @@
+//! Compiles `tests/fixtures/recursive.rs`, generated for recursive definitions. Direct recursive
+//! fields are boxed, fields behind a `Vec` or `HashMap` are not.
+mod common;
+
+common::include_models!(generated, "recursive");
+
+#[test]
+fn nested_values() {
+    use generated::{Node, Tree};
+
+    let node: Node = serde_json::from_str(
+        r#"{
+            "Name": "b",
+            "Parent": {"Name": "a", "Parent": null},
+            "Children": {"c": {"Name": "c", "Parent": null}},
+            "Siblings": [{"Name": "d", "Parent": null}]
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(
+        node.parent.as_deref().map(|parent| &*parent.name),
+        Some("a")
+    );
+    assert_eq!(node.children.unwrap()["c"].name, "c");
+    assert_eq!(node.siblings.unwrap()[0].name, "d");
+
+    let tree: Tree =
+        serde_json::from_str(r#"{"Root": {"Tree": {"Root": null, "Size": 1}}, "Size": 2}"#)
+            .unwrap();
+    let inner = tree.root.unwrap().tree.unwrap();
+    assert_eq!(inner.size, Some(1));
+}
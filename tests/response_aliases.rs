@@ -0,0 +1,21 @@
+//! Compiles `tests/fixtures/response-aliases.rs`, generated for named responses duplicating a
+//! definition, and makes sure the conversions work.
+mod common;
+
+common::include_models!(generated, "response-aliases");
+
+#[test]
+fn convert_from_definition() {
+    let error = generated::Error {
+        code: Some(409),
+        message: "exists".into(),
+    };
+    let not_found: generated::NotFound = error.clone();
+    assert_eq!(not_found.message, "exists");
+
+    let conflict = generated::Conflict::from(error);
+    assert_eq!(conflict.code, Some(409));
+    assert_eq!(conflict.message, "exists");
+    assert_eq!(conflict.existing, None);
+    assert_eq!(conflict.hints, None);
+}
@@ -0,0 +1,50 @@
+//! Compiles `tests/fixtures/response-array-items.rs`, the models of operations responding with
+//! arrays of identical inline objects, generated as a single struct and an alias for every
+//! operation.
+mod common;
+
+use swagger_gen::v2::{
+    codegen::backend::{rust, CodegenBackend},
+    Swagger,
+};
+
+common::include_models!(generated, "response-array-items");
+
+fn swagger() -> Swagger<rust::Type> {
+    common::load("response-array-items")
+}
+
+fn codegen(dedupe_identical_schemas: bool) -> rust::Codegen {
+    rust::Codegen::new(rust::CodegenOptions {
+        dedupe_identical_schemas,
+        ..Default::default()
+    })
+}
+
+#[test]
+fn shares_the_titled_struct() {
+    let (prototypes, diagnostics) = codegen(true).prototypes_with_diagnostics(&swagger());
+    let names: Vec<_> = prototypes.iter().map(|p| p.name.as_str()).collect();
+    assert!(names.contains(&"ContainerSummary"));
+    assert!(!names.contains(&"ContainerList200ResponseInlineItem"));
+    let merged = diagnostics
+        .iter()
+        .find(|diagnostic| diagnostic.message.contains("as `ContainerSummary`"))
+        .unwrap();
+    assert_eq!(
+        merged.locations,
+        ["ContainerList200ResponseInlineItem", "ContainerSummary"]
+    );
+
+    let containers: generated::ContainerList200Response =
+        serde_json::from_str(r#"[{"Id": "a", "Names": ["/web"]}]"#).unwrap();
+    let archived: generated::ContainerListArchived200Response = containers.clone();
+    assert_eq!(archived[0].names, Some(vec!["/web".to_string()]));
+}
+
+#[test]
+fn separate_structs_without_deduplication() {
+    let out = common::generate("response-array-items", Default::default());
+    assert!(out.contains("pub struct ContainerList200ResponseInlineItem {"));
+    assert!(out.contains("pub struct ContainerSummary {"));
+}
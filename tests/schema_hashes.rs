@@ -0,0 +1,75 @@
+//! Compiles `tests/fixtures/schema-hashes.rs`, the output of the Rust backend with
+//! `schema_hashes` enabled, and checks that the hashes only change with the models.
+mod common;
+
+use swagger_gen::v2::codegen::backend::rust;
+
+use std::collections::BTreeMap;
+
+common::include_models!(generated, "schema-hashes");
+
+fn spec() -> serde_yaml::Value {
+    let spec = std::fs::read_to_string(common::fixtures().join("schema-hashes.yaml")).unwrap();
+    serde_yaml::from_str(&spec).unwrap()
+}
+
+fn generate(spec: serde_yaml::Value) -> String {
+    let opts = rust::CodegenOptions {
+        schema_hashes: true,
+        ..Default::default()
+    };
+    common::generate_models(serde_yaml::from_value(spec).unwrap(), opts)
+}
+
+/// The schema hashes by model, and the combined hash under `MODELS_HASH`.
+fn hashes(out: &str) -> BTreeMap<String, String> {
+    let mut hashes = BTreeMap::new();
+    let mut model = None;
+    for line in out.lines() {
+        if let Some(name) = line.strip_prefix("impl ") {
+            model = name.strip_suffix(" {").map(str::to_string);
+        }
+        let value = |line: &str| line.rsplit('"').nth(1).unwrap().to_string();
+        if line.trim_start().starts_with("pub const SCHEMA_HASH") {
+            hashes.insert(model.clone().unwrap(), value(line));
+        } else if line.starts_with("pub const MODELS_HASH") {
+            hashes.insert("MODELS_HASH".to_string(), value(line));
+        }
+    }
+    hashes
+}
+
+#[test]
+fn stable_hashes() {
+    let out = generate(spec());
+    assert_eq!(generate(spec()), out);
+
+    let hashes = hashes(&out);
+    assert_eq!(hashes.len(), 4);
+    assert_eq!(hashes["Volume"], generated::Volume::SCHEMA_HASH);
+    assert_eq!(hashes["MODELS_HASH"], generated::MODELS_HASH);
+}
+
+#[test]
+fn added_property() {
+    let before = hashes(&generate(spec()));
+    let mut spec = spec();
+    spec["definitions"]["Volume"]["properties"]["Size"] =
+        serde_yaml::from_str("type: integer").unwrap();
+    let after = hashes(&generate(spec));
+
+    let changed: Vec<_> = before
+        .keys()
+        .filter(|model| before[*model] != after[*model])
+        .collect();
+    assert_eq!(changed, ["MODELS_HASH", "Volume"]);
+}
+
+#[test]
+fn ignores_descriptions() {
+    let before = hashes(&generate(spec()));
+    let mut spec = spec();
+    spec["definitions"]["Volume"]["description"] = "Storage of a container.".into();
+    spec["definitions"]["Scope"]["description"] = "Where a volume is available.".into();
+    assert_eq!(hashes(&generate(spec)), before);
+}
@@ -0,0 +1,28 @@
+//! Compiles `tests/fixtures/sections.rs`, the output of the Rust backend with `section_banners`
+//! enabled, and checks the order of the sections.
+mod common;
+
+use swagger_gen::v2::codegen::backend::rust;
+
+common::include_models!(generated, "sections");
+
+#[test]
+fn sections_in_spec_order() {
+    let opts = rust::CodegenOptions {
+        section_banners: true,
+        query_params_models: true,
+        ..Default::default()
+    };
+    let out = common::generate("sections", opts);
+    let position = |needle: &str| out.find(needle).unwrap();
+    let sections = [
+        position("// ===== DEFINITIONS ====="),
+        position("pub struct ContainerStateInlineItem {"),
+        position("// ===== RESPONSES ====="),
+        position("pub struct NotFound {"),
+        position("// ===== PATHS ====="),
+        position("pub struct ContainerListParams {"),
+    ];
+    assert!(sections.windows(2).all(|pair| pair[0] < pair[1]));
+    assert_eq!(out.matches("// =====").count(), 3);
+}
@@ -0,0 +1,59 @@
+
+import typing
+import json
+from typing import List, Dict, TypeAlias, Optional, Enum
+from dataclasses import dataclass
+from json import JSONEncoder, JSONDecoder
+
+Base = typing.NewType("Base", None)
+Named = typing.NewType("Named", None)
+
+
+class BaseJsonEncoder(JSONEncoder):
+    def default(self, o):
+        return {k: v for k, v in o.__dict__.items() if v is not None}
+class BaseJsonDecoder(JSONDecoder):
+    def __init__(self):
+        JSONDecoder.__init__(self, object_hook=BaseJsonDecoder.from_dict)
+
+    @staticmethod
+    def from_dict(d):
+        return Base(**d)
+@dataclass
+class Base:
+    id: int
+
+    @staticmethod
+    def from_json(data) -> Base:
+        return json.loads(data, cls=BaseJsonDecoder)
+
+    def to_json(self) -> str:
+        return json.dumps(self, cls=BaseJsonEncoder)
+
+
+class NamedJsonEncoder(JSONEncoder):
+    def default(self, o):
+        return {k: v for k, v in o.__dict__.items() if v is not None}
+class NamedJsonDecoder(JSONDecoder):
+    def __init__(self):
+        JSONDecoder.__init__(self, object_hook=NamedJsonDecoder.from_dict)
+
+    @staticmethod
+    def from_dict(d):
+        return Named(**d)
+@dataclass
+class Named:
+    """
+A base object with a name.
+
+Required properties:
+"""
+    id: int
+    name: str
+
+    @staticmethod
+    def from_json(data) -> Named:
+        return json.loads(data, cls=NamedJsonDecoder)
+
+    def to_json(self) -> str:
+        return json.dumps(self, cls=NamedJsonEncoder)
@@ -0,0 +1,61 @@
+
+import typing
+import json
+from typing import List, Dict, TypeAlias, Optional, Enum
+from dataclasses import dataclass
+from json import JSONEncoder, JSONDecoder
+
+Labels = typing.NewType("Labels", None)
+Tag = typing.NewType("Tag", None)
+Tags = typing.NewType("Tags", None)
+
+
+class LabelsJsonEncoder(JSONEncoder):
+    def default(self, o):
+        return {k: v for k, v in o.__dict__.items() if v is not None}
+class LabelsJsonDecoder(JSONDecoder):
+    def __init__(self):
+        JSONDecoder.__init__(self, object_hook=LabelsJsonDecoder.from_dict)
+
+    @staticmethod
+    def from_dict(d):
+        return Labels(**d)
+Labels: TypeAlias = "List[str]"
+
+
+
+class TagJsonEncoder(JSONEncoder):
+    def default(self, o):
+        return {k: v for k, v in o.__dict__.items() if v is not None}
+class TagJsonDecoder(JSONDecoder):
+    def __init__(self):
+        JSONDecoder.__init__(self, object_hook=TagJsonDecoder.from_dict)
+
+    @staticmethod
+    def from_dict(d):
+        return Tag(**d)
+@dataclass
+class Tag:
+    name: Optional[str] = None
+
+    @staticmethod
+    def from_json(data) -> Tag:
+        return json.loads(data, cls=TagJsonDecoder)
+
+    def to_json(self) -> str:
+        return json.dumps(self, cls=TagJsonEncoder)
+
+
+class TagsJsonEncoder(JSONEncoder):
+    def default(self, o):
+        return {k: v for k, v in o.__dict__.items() if v is not None}
+class TagsJsonDecoder(JSONDecoder):
+    def __init__(self):
+        JSONDecoder.__init__(self, object_hook=TagsJsonDecoder.from_dict)
+
+    @staticmethod
+    def from_dict(d):
+        return Tags(**d)
+# A list of tags.
+Tags: TypeAlias = "List[Tag]"
+
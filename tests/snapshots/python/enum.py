@@ -0,0 +1,15 @@
+
+import typing
+import json
+from typing import List, Dict, TypeAlias, Optional, Enum
+from dataclasses import dataclass
+from json import JSONEncoder, JSONDecoder
+
+Status = typing.NewType("Status", None)
+
+class Status(Enum):
+    """The status of a container."""
+    CREATED = "created"
+    RUNNING = "running"
+    PAUSED = "paused"
+    EXITED = "exited"
@@ -0,0 +1,64 @@
+
+import typing
+import json
+from typing import List, Dict, TypeAlias, Optional, Enum
+from dataclasses import dataclass
+from json import JSONEncoder, JSONDecoder
+
+Container = typing.NewType("Container", None)
+ContainerStateInlineItem = typing.NewType("ContainerStateInlineItem", None)
+
+
+class ContainerJsonEncoder(JSONEncoder):
+    def default(self, o):
+        return {k: v for k, v in o.__dict__.items() if v is not None}
+class ContainerJsonDecoder(JSONDecoder):
+    def __init__(self):
+        JSONDecoder.__init__(self, object_hook=ContainerJsonDecoder.from_dict)
+
+    @staticmethod
+    def from_dict(d):
+        return Container(**d)
+@dataclass
+class Container:
+    """
+A container.
+
+Required properties:
+    * id: The ID of the container.
+
+Optional properties:
+"""
+    id: str
+    labels: Optional[Dict[str, str]] = None
+    state: Optional[ContainerStateInlineItem] = None
+
+    @staticmethod
+    def from_json(data) -> Container:
+        return json.loads(data, cls=ContainerJsonDecoder)
+
+    def to_json(self) -> str:
+        return json.dumps(self, cls=ContainerJsonEncoder)
+
+
+class ContainerStateInlineItemJsonEncoder(JSONEncoder):
+    def default(self, o):
+        return {k: v for k, v in o.__dict__.items() if v is not None}
+class ContainerStateInlineItemJsonDecoder(JSONDecoder):
+    def __init__(self):
+        JSONDecoder.__init__(self, object_hook=ContainerStateInlineItemJsonDecoder.from_dict)
+
+    @staticmethod
+    def from_dict(d):
+        return ContainerStateInlineItem(**d)
+@dataclass
+class ContainerStateInlineItem:
+    pid: Optional[int] = None
+    running: Optional[bool] = None
+
+    @staticmethod
+    def from_json(data) -> ContainerStateInlineItem:
+        return json.loads(data, cls=ContainerStateInlineItemJsonDecoder)
+
+    def to_json(self) -> str:
+        return json.dumps(self, cls=ContainerStateInlineItemJsonEncoder)
@@ -0,0 +1,32 @@
+
+import typing
+import json
+from typing import List, Dict, TypeAlias, Optional, Enum
+from dataclasses import dataclass
+from json import JSONEncoder, JSONDecoder
+
+Volume = typing.NewType("Volume", None)
+
+
+class VolumeJsonEncoder(JSONEncoder):
+    def default(self, o):
+        return {k: v for k, v in o.__dict__.items() if v is not None}
+class VolumeJsonDecoder(JSONDecoder):
+    def __init__(self):
+        JSONDecoder.__init__(self, object_hook=VolumeJsonDecoder.from_dict)
+
+    @staticmethod
+    def from_dict(d):
+        return Volume(**d)
+@dataclass
+class Volume:
+    name: str
+    status: Dict[str, str]
+    scope: Optional[str] = None
+
+    @staticmethod
+    def from_json(data) -> Volume:
+        return json.loads(data, cls=VolumeJsonDecoder)
+
+    def to_json(self) -> str:
+        return json.dumps(self, cls=VolumeJsonEncoder)
@@ -0,0 +1,80 @@
+
+import typing
+import json
+from typing import List, Dict, TypeAlias, Optional, Enum
+from dataclasses import dataclass
+from json import JSONEncoder, JSONDecoder
+
+FlagRequired = typing.NewType("FlagRequired", None)
+ListRequired = typing.NewType("ListRequired", None)
+MixedRequired = typing.NewType("MixedRequired", None)
+
+
+class FlagRequiredJsonEncoder(JSONEncoder):
+    def default(self, o):
+        return {k: v for k, v in o.__dict__.items() if v is not None}
+class FlagRequiredJsonDecoder(JSONDecoder):
+    def __init__(self):
+        JSONDecoder.__init__(self, object_hook=FlagRequiredJsonDecoder.from_dict)
+
+    @staticmethod
+    def from_dict(d):
+        return FlagRequired(**d)
+@dataclass
+class FlagRequired:
+    id: str
+    name: Optional[str] = None
+
+    @staticmethod
+    def from_json(data) -> FlagRequired:
+        return json.loads(data, cls=FlagRequiredJsonDecoder)
+
+    def to_json(self) -> str:
+        return json.dumps(self, cls=FlagRequiredJsonEncoder)
+
+
+class ListRequiredJsonEncoder(JSONEncoder):
+    def default(self, o):
+        return {k: v for k, v in o.__dict__.items() if v is not None}
+class ListRequiredJsonDecoder(JSONDecoder):
+    def __init__(self):
+        JSONDecoder.__init__(self, object_hook=ListRequiredJsonDecoder.from_dict)
+
+    @staticmethod
+    def from_dict(d):
+        return ListRequired(**d)
+@dataclass
+class ListRequired:
+    id: str
+    name: Optional[str] = None
+
+    @staticmethod
+    def from_json(data) -> ListRequired:
+        return json.loads(data, cls=ListRequiredJsonDecoder)
+
+    def to_json(self) -> str:
+        return json.dumps(self, cls=ListRequiredJsonEncoder)
+
+
+class MixedRequiredJsonEncoder(JSONEncoder):
+    def default(self, o):
+        return {k: v for k, v in o.__dict__.items() if v is not None}
+class MixedRequiredJsonDecoder(JSONDecoder):
+    def __init__(self):
+        JSONDecoder.__init__(self, object_hook=MixedRequiredJsonDecoder.from_dict)
+
+    @staticmethod
+    def from_dict(d):
+        return MixedRequired(**d)
+@dataclass
+class MixedRequired:
+    id: str
+    size: int
+    name: Optional[str] = None
+
+    @staticmethod
+    def from_json(data) -> MixedRequired:
+        return json.loads(data, cls=MixedRequiredJsonDecoder)
+
+    def to_json(self) -> str:
+        return json.dumps(self, cls=MixedRequiredJsonEncoder)
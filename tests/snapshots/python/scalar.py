@@ -0,0 +1,24 @@
+
+import typing
+import json
+from typing import List, Dict, TypeAlias, Optional, Enum
+from dataclasses import dataclass
+from json import JSONEncoder, JSONDecoder
+
+Count = typing.NewType("Count", None)
+Enabled = typing.NewType("Enabled", None)
+Name = typing.NewType("Name", None)
+Ratio = typing.NewType("Ratio", None)
+
+Count = int
+
+
+Enabled = bool
+
+
+# A plain string alias.
+Name = str
+
+
+Ratio = float
+
@@ -0,0 +1,12 @@
+export interface Base {
+    id: number;
+}
+
+/**
+ * A base object with a name.
+ */
+export interface Named {
+    id: number;
+    name: string;
+}
+
@@ -0,0 +1,11 @@
+export type Labels = Array<string>;
+
+export interface Tag {
+    name?: string;
+}
+
+/**
+ * A list of tags.
+ */
+export type Tags = Array<Tag>;
+
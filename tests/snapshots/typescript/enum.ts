@@ -0,0 +1,5 @@
+/**
+ * The status of a container.
+ */
+export type Status = "created" | "running" | "paused" | "exited";
+
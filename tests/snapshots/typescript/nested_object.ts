@@ -0,0 +1,17 @@
+/**
+ * A container.
+ */
+export interface Container {
+    /**
+     * The ID of the container.
+     */
+    Id: string;
+    Labels?: Record<string, string>;
+    State?: ContainerStateInlineItem;
+}
+
+export interface ContainerStateInlineItem {
+    Pid?: number;
+    Running?: boolean;
+}
+
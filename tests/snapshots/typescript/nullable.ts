@@ -0,0 +1,6 @@
+export interface Volume {
+    Name: string;
+    Scope?: string | null;
+    Status: Record<string, string> | null;
+}
+
@@ -0,0 +1,16 @@
+export interface FlagRequired {
+    Id: string;
+    Name?: string;
+}
+
+export interface ListRequired {
+    Id: string;
+    Name?: string;
+}
+
+export interface MixedRequired {
+    Id: string;
+    Name?: string;
+    Size: number;
+}
+
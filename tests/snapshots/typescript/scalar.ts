@@ -0,0 +1,11 @@
+export type Count = number;
+
+export type Enabled = boolean;
+
+/**
+ * A plain string alias.
+ */
+export type Name = string;
+
+export type Ratio = number;
+
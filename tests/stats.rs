@@ -0,0 +1,53 @@
+//! Runs the `stats` subcommand over `tests/fixtures/stats.yaml` and checks its text and JSON
+//! output against the golden files.
+mod common;
+
+use swagger_gen::v2::{codegen::backend::rust, Swagger};
+
+use std::process::Command;
+
+fn stats(json: bool) -> String {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_swagger-gen"));
+    command.args(["--quiet", "stats"]);
+    if json {
+        command.arg("--json");
+    }
+    let output = command
+        .arg(common::fixtures().join("stats.yaml"))
+        .output()
+        .unwrap();
+    assert!(output.status.success());
+    String::from_utf8(output.stdout).unwrap()
+}
+
+#[test]
+fn golden_text() {
+    let expected = std::fs::read_to_string(common::fixtures().join("stats.txt")).unwrap();
+    assert_eq!(stats(false), expected);
+}
+
+#[test]
+fn golden_json() {
+    let expected = std::fs::read_to_string(common::fixtures().join("stats.json")).unwrap();
+    assert_eq!(stats(true), expected);
+}
+
+#[test]
+fn counts() {
+    let swagger: Swagger<rust::Type> = common::load("stats");
+    let stats = swagger.stats();
+    assert_eq!(stats.definitions, 7);
+    assert_eq!(stats.operations["get"], 2);
+    assert_eq!(stats.operations["post"], 1);
+    assert_eq!(stats.operations["delete"], 1);
+    // `Container` > `State` > `Health` > `Log`
+    assert_eq!(stats.depths.keys().last(), Some(&4));
+    assert_eq!(stats.depths[&1], 5);
+    // `State` and `Health` of `Container`, and again of `ContainerDetails` which merges it in
+    assert_eq!(stats.inline_schemas, 4);
+    assert_eq!(stats.refs["Container"], 3);
+    assert_eq!(stats.refs["Config"], 0);
+    assert_eq!(stats.largest[0].name, "ContainerDetails");
+    assert_eq!(stats.largest[0].properties, 7);
+    assert!(stats.largest.iter().all(|size| size.name != "Id"));
+}
@@ -0,0 +1,42 @@
+//! Runs the `validate` subcommand over specs with and without errors.
+mod common;
+
+use std::process::Command;
+
+fn validate(fixture: &str, strict: bool) -> (bool, String) {
+    let spec = common::fixtures().join(fixture);
+    let mut command = Command::new(env!("CARGO_BIN_EXE_swagger-gen"));
+    command.args(["--quiet", "validate"]);
+    if strict {
+        command.arg("--strict");
+    }
+    let output = command.arg(spec).output().unwrap();
+    (
+        output.status.success(),
+        String::from_utf8(output.stdout).unwrap(),
+    )
+}
+
+#[test]
+fn valid_spec() {
+    let (success, out) = validate("operations.yaml", true);
+    assert!(success);
+    assert_eq!(out, "0 error(s), 0 warning(s)\n");
+}
+
+#[test]
+fn spec_with_errors() {
+    let (success, out) = validate("invalid.yaml", false);
+    assert!(!success);
+    assert!(out.starts_with(
+        "error: reference `#/definitions/Owner` doesn't point to a definition or response of the spec\n  --> #/definitions/Pet/properties/Owner\n"
+    ));
+    assert!(out.contains("warning: required property `Name` is not declared"));
+    assert!(out.ends_with("1 error(s), 1 warning(s)\n"));
+}
+
+#[test]
+fn warnings_fail_in_strict_mode() {
+    assert!(validate("harness/property_required.yaml", false).0);
+    assert!(!validate("harness/property_required.yaml", true).0);
+}
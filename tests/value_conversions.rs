@@ -0,0 +1,38 @@
+//! Compiles `tests/fixtures/value-conversions.rs`, the output of the Rust backend with
+//! `value_conversions` enabled, and converts a generated struct both ways.
+mod common;
+
+use swagger_gen::v2::codegen::backend::rust;
+
+common::include_models!(generated, "value-conversions");
+
+fn generate(wire_format: rust::WireFormat) -> String {
+    let opts = rust::CodegenOptions {
+        value_conversions: true,
+        wire_format,
+        ..Default::default()
+    };
+    common::generate("value-conversions", opts)
+}
+
+#[test]
+fn json_wire_format_only() {
+    assert!(generate(rust::WireFormat::Json).contains("serde_json::from_value"));
+    assert!(!generate(rust::WireFormat::Any).contains("serde_json::from_value"));
+}
+
+#[test]
+fn converts_both_ways() {
+    let value = serde_json::json!({
+        "Id": "abc",
+        "Names": ["/web"],
+        "State": {"Status": "running", "Pid": 42}
+    });
+    let container = generated::Container::try_from(value.clone()).unwrap();
+    assert_eq!(container.id, "abc");
+    assert_eq!(container.state.as_ref().unwrap().pid, Some(42));
+    assert_eq!(container.to_value(), value);
+
+    let missing_id = serde_json::json!({"Names": []});
+    assert!(generated::Container::try_from(missing_id).is_err());
+}
@@ -0,0 +1,72 @@
+//! Compiles `tests/fixtures/variants.rs`, generated for `x-variants`, and checks that the
+//! generated enum picks the variant by the tag field.
+mod common;
+
+use swagger_gen::v2::{codegen::backend::rust, Swagger};
+
+common::include_models!(generated, "variants");
+
+#[test]
+fn valid_spec() {
+    let swagger: Swagger<rust::Type> = common::load("variants");
+    assert!(swagger.validate().is_empty());
+}
+
+#[test]
+fn round_trip() {
+    use generated::{ContainerEvent, EventMessage, ImageEvent};
+
+    let image: EventMessage =
+        serde_json::from_str(r#"{"Type": "image", "Action": "pull", "Tag": "latest"}"#).unwrap();
+    assert_eq!(
+        image,
+        EventMessage::Image(ImageEvent {
+            action: "pull".into(),
+            tag: Some("latest".into()),
+            type_: None,
+        })
+    );
+    let converted: EventMessage = ImageEvent {
+        action: "pull".into(),
+        tag: Some("latest".into()),
+        type_: None,
+    }
+    .into();
+    assert_eq!(converted, image);
+    let json = serde_json::to_value(&image).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!({"Type": "image", "Action": "pull", "Tag": "latest"})
+    );
+
+    let container: EventMessage = serde_json::from_str(
+        r#"{"Type": "container", "Action": "die", "ID": "abc", "ExitCode": 1}"#,
+    )
+    .unwrap();
+    assert_eq!(
+        container,
+        EventMessage::Container(ContainerEvent {
+            action: "die".into(),
+            exit_code: Some(1),
+            id: "abc".into(),
+            type_: None,
+        })
+    );
+    let json = serde_json::to_string(&container).unwrap();
+    assert_eq!(
+        serde_json::from_str::<EventMessage>(&json).unwrap(),
+        container
+    );
+}
+
+#[test]
+fn unknown_tag() {
+    let err = serde_json::from_str::<generated::EventMessage>(
+        r#"{"Type": "volume", "Action": "create"}"#,
+    )
+    .unwrap_err();
+    assert!(
+        err.to_string().contains("unknown variant `volume`"),
+        "{err}"
+    );
+}
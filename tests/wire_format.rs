@@ -0,0 +1,32 @@
+//! Compiles `tests/fixtures/wire-format.rs`, the output of the Rust backend for
+//! `WireFormat::Any`, and round-trips a model through CBOR.
+mod common;
+
+common::include_models!(generated, "wire-format");
+
+#[test]
+fn cbor_round_trip() {
+    let blob = generated::Blob {
+        chunks: Some(vec![vec![1, 2], vec![]]),
+        compressed: Some(true),
+        data: vec![0, 255, 7],
+        digest: "sha256:abc".into(),
+        size: Some(3),
+    };
+    let mut encoded = vec![];
+    ciborium::into_writer(&blob, &mut encoded).unwrap();
+    let decoded: generated::Blob = ciborium::from_reader(encoded.as_slice()).unwrap();
+    assert_eq!(decoded, blob);
+
+    let mut encoded = vec![];
+    let sparse = generated::Blob {
+        chunks: None,
+        compressed: None,
+        data: vec![],
+        digest: "sha256:def".into(),
+        size: None,
+    };
+    ciborium::into_writer(&sparse, &mut encoded).unwrap();
+    let decoded: generated::Blob = ciborium::from_reader(encoded.as_slice()).unwrap();
+    assert_eq!(decoded, sparse);
+}